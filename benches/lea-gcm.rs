@@ -0,0 +1,60 @@
+// Copyright © 2023 남기훈 <gihunnam@proton.me>
+//
+// This file and its content are subject to the terms of the MIT License (the "License").
+// If a copy of the License was not distributed with this file, you can obtain one at https://opensource.org/licenses/MIT.
+
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use criterion_cycles_per_byte::CyclesPerByte;
+
+use lea::{prelude::*, Lea128Gcm, Lea192Gcm, Lea256Gcm};
+
+fn criterion_benches(c: &mut Criterion<CyclesPerByte>) {
+	let data = [0; 16];
+	let mut buffer = data;
+	let nonce = Default::default();
+
+	let lea128gcm = Lea128Gcm::<U12>::new(&Default::default());
+	c.bench_function("[lea-gcm] Lea128Gcm::encrypt_in_place_detached", |b| { b.iter(|| {
+		black_box(lea128gcm.encrypt_in_place_detached(&nonce, &[], &mut buffer).unwrap());
+	}) });
+	let mut lea128gcm_buffer = data;
+	let lea128gcm_tag = lea128gcm.encrypt_in_place_detached(&nonce, &[], &mut lea128gcm_buffer).unwrap();
+	c.bench_function("[lea-gcm] Lea128Gcm::decrypt_in_place_detached", |b| { b.iter(|| {
+		let mut ctxt = lea128gcm_buffer;
+		lea128gcm.decrypt_in_place_detached(&nonce, &[], &mut ctxt, &lea128gcm_tag).unwrap();
+		black_box(ctxt);
+	}) });
+
+	let lea192gcm = Lea192Gcm::<U12>::new(&Default::default());
+	c.bench_function("[lea-gcm] Lea192Gcm::encrypt_in_place_detached", |b| { b.iter(|| {
+		black_box(lea192gcm.encrypt_in_place_detached(&nonce, &[], &mut buffer).unwrap());
+	}) });
+	let mut lea192gcm_buffer = data;
+	let lea192gcm_tag = lea192gcm.encrypt_in_place_detached(&nonce, &[], &mut lea192gcm_buffer).unwrap();
+	c.bench_function("[lea-gcm] Lea192Gcm::decrypt_in_place_detached", |b| { b.iter(|| {
+		let mut ctxt = lea192gcm_buffer;
+		lea192gcm.decrypt_in_place_detached(&nonce, &[], &mut ctxt, &lea192gcm_tag).unwrap();
+		black_box(ctxt);
+	}) });
+
+	let lea256gcm = Lea256Gcm::<U12>::new(&Default::default());
+	c.bench_function("[lea-gcm] Lea256Gcm::encrypt_in_place_detached", |b| { b.iter(|| {
+		black_box(lea256gcm.encrypt_in_place_detached(&nonce, &[], &mut buffer).unwrap());
+	}) });
+	let mut lea256gcm_buffer = data;
+	let lea256gcm_tag = lea256gcm.encrypt_in_place_detached(&nonce, &[], &mut lea256gcm_buffer).unwrap();
+	c.bench_function("[lea-gcm] Lea256Gcm::decrypt_in_place_detached", |b| { b.iter(|| {
+		let mut ctxt = lea256gcm_buffer;
+		lea256gcm.decrypt_in_place_detached(&nonce, &[], &mut ctxt, &lea256gcm_tag).unwrap();
+		black_box(ctxt);
+	}) });
+
+	black_box(data);
+}
+
+criterion_group!(
+	name = benches;
+	config = Criterion::default().with_measurement(CyclesPerByte);
+	targets = criterion_benches
+);
+criterion_main!(benches);
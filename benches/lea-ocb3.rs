@@ -0,0 +1,60 @@
+// Copyright © 2023 남기훈 <gihunnam@proton.me>
+//
+// This file and its content are subject to the terms of the MIT License (the "License").
+// If a copy of the License was not distributed with this file, you can obtain one at https://opensource.org/licenses/MIT.
+
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use criterion_cycles_per_byte::CyclesPerByte;
+
+use lea::{prelude::*, Lea128Ocb3, Lea192Ocb3, Lea256Ocb3};
+
+fn criterion_benches(c: &mut Criterion<CyclesPerByte>) {
+	let data = [0; 16];
+	let mut buffer = data;
+	let nonce = Default::default();
+
+	let lea128ocb3 = Lea128Ocb3::<U12>::new(&Default::default());
+	c.bench_function("[lea-ocb3] Lea128Ocb3::encrypt_in_place_detached", |b| { b.iter(|| {
+		black_box(lea128ocb3.encrypt_in_place_detached(&nonce, &[], &mut buffer).unwrap());
+	}) });
+	let mut lea128ocb3_buffer = data;
+	let lea128ocb3_tag = lea128ocb3.encrypt_in_place_detached(&nonce, &[], &mut lea128ocb3_buffer).unwrap();
+	c.bench_function("[lea-ocb3] Lea128Ocb3::decrypt_in_place_detached", |b| { b.iter(|| {
+		let mut ctxt = lea128ocb3_buffer;
+		lea128ocb3.decrypt_in_place_detached(&nonce, &[], &mut ctxt, &lea128ocb3_tag).unwrap();
+		black_box(ctxt);
+	}) });
+
+	let lea192ocb3 = Lea192Ocb3::<U12>::new(&Default::default());
+	c.bench_function("[lea-ocb3] Lea192Ocb3::encrypt_in_place_detached", |b| { b.iter(|| {
+		black_box(lea192ocb3.encrypt_in_place_detached(&nonce, &[], &mut buffer).unwrap());
+	}) });
+	let mut lea192ocb3_buffer = data;
+	let lea192ocb3_tag = lea192ocb3.encrypt_in_place_detached(&nonce, &[], &mut lea192ocb3_buffer).unwrap();
+	c.bench_function("[lea-ocb3] Lea192Ocb3::decrypt_in_place_detached", |b| { b.iter(|| {
+		let mut ctxt = lea192ocb3_buffer;
+		lea192ocb3.decrypt_in_place_detached(&nonce, &[], &mut ctxt, &lea192ocb3_tag).unwrap();
+		black_box(ctxt);
+	}) });
+
+	let lea256ocb3 = Lea256Ocb3::<U12>::new(&Default::default());
+	c.bench_function("[lea-ocb3] Lea256Ocb3::encrypt_in_place_detached", |b| { b.iter(|| {
+		black_box(lea256ocb3.encrypt_in_place_detached(&nonce, &[], &mut buffer).unwrap());
+	}) });
+	let mut lea256ocb3_buffer = data;
+	let lea256ocb3_tag = lea256ocb3.encrypt_in_place_detached(&nonce, &[], &mut lea256ocb3_buffer).unwrap();
+	c.bench_function("[lea-ocb3] Lea256Ocb3::decrypt_in_place_detached", |b| { b.iter(|| {
+		let mut ctxt = lea256ocb3_buffer;
+		lea256ocb3.decrypt_in_place_detached(&nonce, &[], &mut ctxt, &lea256ocb3_tag).unwrap();
+		black_box(ctxt);
+	}) });
+
+	black_box(data);
+}
+
+criterion_group!(
+	name = benches;
+	config = Criterion::default().with_measurement(CyclesPerByte);
+	targets = criterion_benches
+);
+criterion_main!(benches);
@@ -0,0 +1,49 @@
+// Copyright © 2023 남기훈 <gihunnam@proton.me>
+//
+// This file and its content are subject to the terms of the MIT License (the "License").
+// If a copy of the License was not distributed with this file, you can obtain one at https://opensource.org/licenses/MIT.
+
+use cmac::Cmac;
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use criterion_cycles_per_byte::CyclesPerByte;
+
+use lea::{pmac::{Mac, NewMac}, Lea128, Lea256, Lea128Pmac, Lea256Pmac};
+
+fn criterion_benches(c: &mut Criterion<CyclesPerByte>) {
+	let data = [0; 4096];
+
+	let lea128cmac = Cmac::<Lea128>::new(&Default::default());
+	c.bench_function("[lea-pmac] Cmac<Lea128>::update (baseline)", |b| { b.iter(|| {
+		let mut lea128cmac = lea128cmac.clone();
+		lea128cmac.update(&data);
+		black_box(lea128cmac.finalize());
+	}) });
+
+	let lea128pmac = Lea128Pmac::new(&Default::default());
+	c.bench_function("[lea-pmac] Lea128Pmac::update", |b| { b.iter(|| {
+		let mut lea128pmac = lea128pmac.clone();
+		lea128pmac.update(&data);
+		black_box(lea128pmac.finalize());
+	}) });
+
+	let lea256cmac = Cmac::<Lea256>::new(&Default::default());
+	c.bench_function("[lea-pmac] Cmac<Lea256>::update (baseline)", |b| { b.iter(|| {
+		let mut lea256cmac = lea256cmac.clone();
+		lea256cmac.update(&data);
+		black_box(lea256cmac.finalize());
+	}) });
+
+	let lea256pmac = Lea256Pmac::new(&Default::default());
+	c.bench_function("[lea-pmac] Lea256Pmac::update", |b| { b.iter(|| {
+		let mut lea256pmac = lea256pmac.clone();
+		lea256pmac.update(&data);
+		black_box(lea256pmac.finalize());
+	}) });
+}
+
+criterion_group!(
+	name = benches;
+	config = Criterion::default().with_measurement(CyclesPerByte);
+	targets = criterion_benches
+);
+criterion_main!(benches);
@@ -0,0 +1,37 @@
+// Copyright © 2023 남기훈 <gihunnam@proton.me>
+//
+// This file and its content are subject to the terms of the MIT License (the "License").
+// If a copy of the License was not distributed with this file, you can obtain one at https://opensource.org/licenses/MIT.
+
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use criterion_cycles_per_byte::CyclesPerByte;
+
+use lea::{prelude::*, Lea128CtrRng, Lea192CtrRng, Lea256CtrRng};
+
+fn criterion_benches(c: &mut Criterion<CyclesPerByte>) {
+	let mut data = [0; 128];
+
+	let mut lea128ctrrng = Lea128CtrRng::from_seed(Default::default());
+	c.bench_function("[lea-rng] Lea128CtrRng::fill_bytes", |b| { b.iter(|| {
+		lea128ctrrng.fill_bytes(&mut data);
+	}) });
+
+	let mut lea192ctrrng = Lea192CtrRng::from_seed(Default::default());
+	c.bench_function("[lea-rng] Lea192CtrRng::fill_bytes", |b| { b.iter(|| {
+		lea192ctrrng.fill_bytes(&mut data);
+	}) });
+
+	let mut lea256ctrrng = Lea256CtrRng::from_seed(Default::default());
+	c.bench_function("[lea-rng] Lea256CtrRng::fill_bytes", |b| { b.iter(|| {
+		lea256ctrrng.fill_bytes(&mut data);
+	}) });
+
+	black_box(data);
+}
+
+criterion_group!(
+	name = benches;
+	config = Criterion::default().with_measurement(CyclesPerByte);
+	targets = criterion_benches
+);
+criterion_main!(benches);
@@ -0,0 +1,642 @@
+// Copyright © 2023 남기훈 <gihunnam@proton.me>
+//
+// This file and its content are subject to the terms of the MIT License (the "License").
+// If a copy of the License was not distributed with this file, you can obtain one at https://opensource.org/licenses/MIT.
+
+//! Async `tokio::io` adapters for LEA-CTR and STREAM
+//!
+//! [`AsyncLeaCtrReader`] and [`AsyncLeaCtrWriter`] apply the keystream to the bytes read from or written to the inner stream.
+//!
+//! [`AsyncLeaStreamWriter`] splits the plaintext into chunks of a fixed size and seals each with a STREAM [`Encryptor`],
+//! and [`AsyncLeaStreamReader`] opens them with a [`Decryptor`].
+//! Every sealed chunk is the chunk size plus the tag size long, except the last one, which may be shorter or even empty.
+//! The last chunk is sealed by `poll_shutdown`, so the writer must be shut down to complete the ciphertext,
+//! while `poll_flush` writes only the chunks sealed so far.
+//! A ciphertext that is truncated, reordered, or modified fails to read with [`io::ErrorKind::InvalidData`],
+//! but the plaintext of the chunks before the failing one has been returned already.
+//!
+//! * Example
+//! ```
+//! use lea::{async_io::{AsyncLeaStreamReader, AsyncLeaStreamWriter}, prelude::*, Lea128GcmDecryptorBE32, Lea128GcmEncryptorBE32};
+//! use tokio::io::{AsyncReadExt, AsyncWriteExt};
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> std::io::Result<()> {
+//! let key = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+//! let nonce_prefix = arr![u8; 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16];
+//! let (client, server) = tokio::io::duplex(64);
+//!
+//! // Encryption
+//! let mut writer = AsyncLeaStreamWriter::new(client, Lea128GcmEncryptorBE32::new(&key, &nonce_prefix), 1024);
+//! let sender = async move {
+//!     writer.write_all(&[0x42; 3000]).await?;
+//!     writer.shutdown().await
+//! };
+//!
+//! // Decryption
+//! let mut reader = AsyncLeaStreamReader::new(server, Lea128GcmDecryptorBE32::new(&key, &nonce_prefix), 1024);
+//! let mut ptxt = Vec::new();
+//! let (sent, received) = tokio::join!(sender, reader.read_to_end(&mut ptxt));
+//! sent?;
+//! received?;
+//! assert_eq!(ptxt, [0x42; 3000]);
+//! # Ok(())
+//! # }
+//! ```
+
+use core::mem;
+use core::ops::Sub;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use std::io;
+use std::{vec::Vec, vec};
+
+use aead::{generic_array::{typenum::Unsigned, ArrayLength}, stream::{Decryptor, Encryptor, NonceSize, StreamBE32, StreamPrimitive}, AeadInPlace};
+use cipher::{StreamCipher, StreamCipherSeek};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+use crate::Lea128Ctr;
+
+/// Largest number of bytes encrypted by one call of [`AsyncLeaCtrWriter::poll_write`]
+const CHUNK_SIZE: usize = 4096;
+
+/// Reader that applies the keystream of `C` to the bytes read from `R`
+pub struct AsyncLeaCtrReader<R, C = Lea128Ctr> where
+R: AsyncRead + Unpin,
+C: StreamCipher + Unpin {
+	inner: R,
+	cipher: C
+}
+
+impl<R, C> AsyncLeaCtrReader<R, C> where
+R: AsyncRead + Unpin,
+C: StreamCipher + Unpin {
+	pub fn new(inner: R, cipher: C) -> Self {
+		Self { inner, cipher }
+	}
+
+	pub fn get_ref(&self) -> &R {
+		&self.inner
+	}
+
+	pub fn into_inner(self) -> R {
+		self.inner
+	}
+}
+
+impl<R, C> AsyncRead for AsyncLeaCtrReader<R, C> where
+R: AsyncRead + Unpin,
+C: StreamCipher + Unpin {
+	fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>> {
+		let this = self.get_mut();
+		let filled = buf.filled().len();
+		ready!(Pin::new(&mut this.inner).poll_read(cx, buf))?;
+		this.cipher.try_apply_keystream(&mut buf.filled_mut()[filled..]).map_err(|_| keystream_exhausted())?;
+
+		Poll::Ready(Ok(()))
+	}
+}
+
+/// Writer that applies the keystream of `C` to the bytes written to `W`
+///
+/// Nothing is buffered: the keystream is moved back over the bytes that `W` did not accept.
+pub struct AsyncLeaCtrWriter<W, C = Lea128Ctr> where
+W: AsyncWrite + Unpin,
+C: StreamCipher + StreamCipherSeek + Unpin {
+	inner: W,
+	cipher: C
+}
+
+impl<W, C> AsyncLeaCtrWriter<W, C> where
+W: AsyncWrite + Unpin,
+C: StreamCipher + StreamCipherSeek + Unpin {
+	pub fn new(inner: W, cipher: C) -> Self {
+		Self { inner, cipher }
+	}
+
+	pub fn get_ref(&self) -> &W {
+		&self.inner
+	}
+
+	pub fn into_inner(self) -> W {
+		self.inner
+	}
+}
+
+impl<W, C> AsyncWrite for AsyncLeaCtrWriter<W, C> where
+W: AsyncWrite + Unpin,
+C: StreamCipher + StreamCipherSeek + Unpin {
+	fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, data: &[u8]) -> Poll<io::Result<usize>> {
+		let this = self.get_mut();
+		let mut chunk = [0; CHUNK_SIZE];
+		let n = data.len().min(CHUNK_SIZE);
+		chunk[..n].copy_from_slice(&data[..n]);
+
+		let keystream_pos: u64 = this.cipher.try_current_pos().map_err(|_| keystream_exhausted())?;
+		this.cipher.try_apply_keystream(&mut chunk[..n]).map_err(|_| keystream_exhausted())?;
+
+		let result = Pin::new(&mut this.inner).poll_write(cx, &chunk[..n]);
+		let written = match result {
+			Poll::Ready(Ok(written)) => written,
+			_ => 0
+		};
+		if written < n {
+			this.cipher.seek(keystream_pos + written as u64);
+		}
+
+		result
+	}
+
+	fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+		Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+	}
+
+	fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+		Pin::new(&mut self.get_mut().inner).poll_shutdown(cx)
+	}
+}
+
+/// Reader that opens the chunks sealed by [`AsyncLeaStreamWriter`]
+pub struct AsyncLeaStreamReader<R, A, S = StreamBE32<A>> where
+R: AsyncRead + Unpin,
+A: AeadInPlace,
+S: StreamPrimitive<A>,
+A::NonceSize: Sub<S::NonceOverhead>,
+NonceSize<A, S>: ArrayLength<u8>,
+Decryptor<A, S>: Unpin {
+	inner: R,
+	/// `None` after the last chunk or a failure
+	decryptor: Option<Decryptor<A, S>>,
+	failed: bool,
+	/// Sealed chunk followed by one more byte, which tells that it is not the last one
+	ctxt: Vec<u8>,
+	ctxt_len: usize,
+	eof: bool,
+	/// Plaintext ready to be read
+	ptxt: Vec<u8>,
+	ptxt_pos: usize
+}
+
+impl<R, A, S> AsyncLeaStreamReader<R, A, S> where
+R: AsyncRead + Unpin,
+A: AeadInPlace,
+S: StreamPrimitive<A>,
+A::NonceSize: Sub<S::NonceOverhead>,
+NonceSize<A, S>: ArrayLength<u8>,
+Decryptor<A, S>: Unpin {
+	/// `chunk_size` must be the same as that of the writer.
+	///
+	/// # Panics
+	///
+	/// Panics if `chunk_size` is 0.
+	pub fn new(inner: R, decryptor: Decryptor<A, S>, chunk_size: usize) -> Self {
+		assert!(chunk_size != 0);
+
+		Self {
+			inner,
+			decryptor: Some(decryptor),
+			failed: false,
+			ctxt: vec![0; chunk_size + A::TagSize::USIZE + 1],
+			ctxt_len: 0,
+			eof: false,
+			ptxt: Vec::with_capacity(chunk_size + A::TagSize::USIZE),
+			ptxt_pos: 0
+		}
+	}
+
+	pub fn get_ref(&self) -> &R {
+		&self.inner
+	}
+
+	pub fn into_inner(self) -> R {
+		self.inner
+	}
+}
+
+impl<R, A, S> AsyncRead for AsyncLeaStreamReader<R, A, S> where
+R: AsyncRead + Unpin,
+A: AeadInPlace,
+S: StreamPrimitive<A>,
+A::NonceSize: Sub<S::NonceOverhead>,
+NonceSize<A, S>: ArrayLength<u8>,
+Decryptor<A, S>: Unpin {
+	fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>> {
+		let this = self.get_mut();
+
+		while this.ptxt_pos == this.ptxt.len() {
+			if this.failed {
+				return Poll::Ready(Err(authentication_failed()));
+			}
+			if this.decryptor.is_none() || buf.remaining() == 0 {
+				return Poll::Ready(Ok(()));
+			}
+
+			while this.ctxt_len < this.ctxt.len() && !this.eof {
+				let mut ctxt = ReadBuf::new(&mut this.ctxt[this.ctxt_len..]);
+				ready!(Pin::new(&mut this.inner).poll_read(cx, &mut ctxt))?;
+				match ctxt.filled().len() {
+					0 => this.eof = true,
+					n => this.ctxt_len += n
+				}
+			}
+
+			this.ptxt.clear();
+			this.ptxt_pos = 0;
+			let result = if this.ctxt_len == this.ctxt.len() {
+				let sealed_len = this.ctxt.len() - 1;
+				this.ptxt.extend_from_slice(&this.ctxt[..sealed_len]);
+				this.ctxt[0] = this.ctxt[sealed_len];
+				this.ctxt_len = 1;
+
+				this.decryptor.as_mut().map_or(Ok(()), |decryptor| decryptor.decrypt_next_in_place(&[], &mut this.ptxt))
+			} else {
+				this.ptxt.extend_from_slice(&this.ctxt[..this.ctxt_len]);
+				this.ctxt_len = 0;
+
+				this.decryptor.take().map_or(Ok(()), |decryptor| decryptor.decrypt_last_in_place(&[], &mut this.ptxt))
+			};
+
+			if result.is_err() {
+				this.decryptor = None;
+				this.failed = true;
+				this.ptxt.clear();
+			}
+		}
+
+		let n = buf.remaining().min(this.ptxt.len() - this.ptxt_pos);
+		buf.put_slice(&this.ptxt[this.ptxt_pos..(this.ptxt_pos + n)]);
+		this.ptxt_pos += n;
+
+		Poll::Ready(Ok(()))
+	}
+}
+
+/// Writer that seals chunks of the plaintext and writes them to `W`
+///
+/// The ciphertext is complete only after the writer is shut down, which seals and writes the last chunk.
+pub struct AsyncLeaStreamWriter<W, A, S = StreamBE32<A>> where
+W: AsyncWrite + Unpin,
+A: AeadInPlace,
+S: StreamPrimitive<A>,
+A::NonceSize: Sub<S::NonceOverhead>,
+NonceSize<A, S>: ArrayLength<u8>,
+Encryptor<A, S>: Unpin {
+	inner: W,
+	/// `None` after the last chunk
+	encryptor: Option<Encryptor<A, S>>,
+	chunk_size: usize,
+	/// Plaintext of the next chunk
+	buffer: Vec<u8>,
+	/// Sealed chunk not written yet
+	pending: Vec<u8>,
+	pending_pos: usize,
+	/// Whether a chunk has failed to seal
+	failed: bool
+}
+
+impl<W, A, S> AsyncLeaStreamWriter<W, A, S> where
+W: AsyncWrite + Unpin,
+A: AeadInPlace,
+S: StreamPrimitive<A>,
+A::NonceSize: Sub<S::NonceOverhead>,
+NonceSize<A, S>: ArrayLength<u8>,
+Encryptor<A, S>: Unpin {
+	/// # Panics
+	///
+	/// Panics if `chunk_size` is 0.
+	pub fn new(inner: W, encryptor: Encryptor<A, S>, chunk_size: usize) -> Self {
+		assert!(chunk_size != 0);
+
+		Self {
+			inner,
+			encryptor: Some(encryptor),
+			chunk_size,
+			buffer: Vec::with_capacity(chunk_size + A::TagSize::USIZE),
+			pending: Vec::with_capacity(chunk_size + A::TagSize::USIZE),
+			pending_pos: 0,
+			failed: false
+		}
+	}
+
+	pub fn get_ref(&self) -> &W {
+		&self.inner
+	}
+
+	pub fn into_inner(self) -> W {
+		self.inner
+	}
+
+	/// Seals the buffered plaintext, which must be written with [`Self::poll_pending`] before anything else.
+	fn seal(&mut self, last: bool) -> io::Result<()> {
+		mem::swap(&mut self.buffer, &mut self.pending);
+		let result = if last {
+			self.encryptor.take().map_or(Ok(()), |encryptor| encryptor.encrypt_last_in_place(&[], &mut self.pending))
+		} else {
+			self.encryptor.as_mut().map_or(Ok(()), |encryptor| encryptor.encrypt_next_in_place(&[], &mut self.pending))
+		};
+
+		// The plaintext must never reach `W`, so nothing can be written after a failure.
+		result.map_err(|_| {
+			self.pending.clear();
+			self.failed = true;
+
+			stream_exhausted()
+		})
+	}
+
+	fn poll_pending(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+		if self.failed {
+			return Poll::Ready(Err(stream_exhausted()));
+		}
+
+		while self.pending_pos < self.pending.len() {
+			match ready!(Pin::new(&mut self.inner).poll_write(cx, &self.pending[self.pending_pos..]))? {
+				0 => return Poll::Ready(Err(io::Error::new(io::ErrorKind::WriteZero, "failed to write the ciphertext"))),
+				n => self.pending_pos += n
+			}
+		}
+
+		self.pending.clear();
+		self.pending_pos = 0;
+
+		Poll::Ready(Ok(()))
+	}
+}
+
+impl<W, A, S> AsyncWrite for AsyncLeaStreamWriter<W, A, S> where
+W: AsyncWrite + Unpin,
+A: AeadInPlace,
+S: StreamPrimitive<A>,
+A::NonceSize: Sub<S::NonceOverhead>,
+NonceSize<A, S>: ArrayLength<u8>,
+Encryptor<A, S>: Unpin {
+	fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, data: &[u8]) -> Poll<io::Result<usize>> {
+		let this = self.get_mut();
+		ready!(this.poll_pending(cx))?;
+
+		if this.encryptor.is_none() {
+			return Poll::Ready(Err(io::Error::new(io::ErrorKind::Other, "write after shutdown")));
+		}
+		if data.is_empty() {
+			return Poll::Ready(Ok(0));
+		}
+
+		// A full chunk is sealed only when more data arrives, as it may be the last one otherwise.
+		if this.buffer.len() == this.chunk_size {
+			this.seal(false)?;
+			ready!(this.poll_pending(cx))?;
+		}
+
+		let n = data.len().min(this.chunk_size - this.buffer.len());
+		this.buffer.extend_from_slice(&data[..n]);
+
+		Poll::Ready(Ok(n))
+	}
+
+	/// Writes the chunks sealed so far and flushes `W`, leaving the buffered plaintext for the next chunk.
+	fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+		let this = self.get_mut();
+		ready!(this.poll_pending(cx))?;
+
+		Pin::new(&mut this.inner).poll_flush(cx)
+	}
+
+	/// Seals and writes the last chunk, then shuts `W` down.
+	fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+		let this = self.get_mut();
+		ready!(this.poll_pending(cx))?;
+
+		if this.encryptor.is_some() {
+			this.seal(true)?;
+			ready!(this.poll_pending(cx))?;
+		}
+
+		ready!(Pin::new(&mut this.inner).poll_flush(cx))?;
+		Pin::new(&mut this.inner).poll_shutdown(cx)
+	}
+}
+
+fn authentication_failed() -> io::Error {
+	io::Error::new(io::ErrorKind::InvalidData, "chunk authentication failed")
+}
+
+fn keystream_exhausted() -> io::Error {
+	io::Error::new(io::ErrorKind::Other, "keystream exhausted")
+}
+
+fn stream_exhausted() -> io::Error {
+	io::Error::new(io::ErrorKind::Other, "STREAM counter exhausted")
+}
+
+#[cfg(test)]
+mod tests {
+	use std::io;
+	use std::vec::Vec;
+
+	use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+	use aead::{stream::{Encryptor, NewStream, StreamBE32, StreamPrimitive}, Buffer};
+
+	use crate::{async_io::{AsyncLeaCtrReader, AsyncLeaCtrWriter, AsyncLeaStreamReader, AsyncLeaStreamWriter}, prelude::*,
+		Lea128Gcm, Lea128Ctr, Lea128CcmDecryptorBE32, Lea128CcmEncryptorBE32, Lea128GcmDecryptorBE32, Lea128GcmEncryptorBE32};
+
+	/// STREAM with room for only 2 chunks before the last one
+	struct ShortStream(StreamBE32<Lea128Gcm>);
+
+	impl StreamPrimitive<Lea128Gcm> for ShortStream {
+		type NonceOverhead = <StreamBE32<Lea128Gcm> as StreamPrimitive<Lea128Gcm>>::NonceOverhead;
+		type Counter = u32;
+
+		const COUNTER_INCR: u32 = 1;
+		const COUNTER_MAX: u32 = 2;
+
+		fn encrypt_in_place(&self, position: u32, last_block: bool, associated_data: &[u8], buffer: &mut dyn Buffer) -> aead::Result<()> {
+			self.0.encrypt_in_place(position, last_block, associated_data, buffer)
+		}
+
+		fn decrypt_in_place(&self, position: u32, last_block: bool, associated_data: &[u8], buffer: &mut dyn Buffer) -> aead::Result<()> {
+			self.0.decrypt_in_place(position, last_block, associated_data, buffer)
+		}
+	}
+
+	/// Seals `ptxt` through a duplex pipe, writing 7 bytes at a time.
+	async fn seal(ptxt: &[u8], chunk_size: usize) -> io::Result<Vec<u8>> {
+		let key = arr![u8; 0x45, 0x6A, 0x7C, 0x41, 0x14, 0x4D, 0x56, 0x05, 0x12, 0x7B, 0x02, 0x1D, 0x48, 0xCE, 0x1E, 0xF4];
+		let nonce_prefix = arr![u8; 0x4F, 0xDA, 0xBE, 0xA6, 0x09, 0xE6, 0x9F];
+		let (client, mut server) = tokio::io::duplex(64);
+
+		let mut writer = AsyncLeaStreamWriter::new(client, Lea128GcmEncryptorBE32::new(&key, &nonce_prefix), chunk_size);
+		let sender = async move {
+			for piece in ptxt.chunks(7) {
+				writer.write_all(piece).await?;
+			}
+			writer.shutdown().await
+		};
+		let mut ctxt = Vec::new();
+		let (sent, received) = tokio::join!(sender, server.read_to_end(&mut ctxt));
+		sent?;
+		received?;
+
+		Ok(ctxt)
+	}
+
+	/// Opens `ctxt` through a duplex pipe, reading 5 bytes at a time.
+	async fn open(ctxt: &[u8], chunk_size: usize) -> io::Result<Vec<u8>> {
+		let key = arr![u8; 0x45, 0x6A, 0x7C, 0x41, 0x14, 0x4D, 0x56, 0x05, 0x12, 0x7B, 0x02, 0x1D, 0x48, 0xCE, 0x1E, 0xF4];
+		let nonce_prefix = arr![u8; 0x4F, 0xDA, 0xBE, 0xA6, 0x09, 0xE6, 0x9F];
+		let (mut client, server) = tokio::io::duplex(64);
+
+		let sender = async move {
+			client.write_all(ctxt).await?;
+			client.shutdown().await
+		};
+		let mut reader = AsyncLeaStreamReader::new(server, Lea128GcmDecryptorBE32::new(&key, &nonce_prefix), chunk_size);
+		let receiver = async {
+			let mut ptxt = Vec::new();
+			let mut buffer = [0; 5];
+			loop {
+				match reader.read(&mut buffer).await? {
+					0 => return Ok::<_, io::Error>(ptxt),
+					n => ptxt.extend_from_slice(&buffer[..n])
+				}
+			}
+		};
+		// The reader may fail before the ciphertext has been sent.
+		let ((), ptxt) = tokio::try_join!(sender, receiver)?;
+
+		Ok(ptxt)
+	}
+
+	#[tokio::test]
+	async fn lea128ctr_async() -> io::Result<()> {
+		let key = arr![u8; 0x7A, 0xD3, 0x6A, 0x75, 0xD5, 0x5F, 0x30, 0x22, 0x09, 0x4E, 0x06, 0xF7, 0xC8, 0x97, 0xD8, 0xBB];
+		let nonce = arr![u8; 0x0C, 0x5F, 0x04, 0xE8, 0xB5, 0x12, 0x19, 0x5E, 0x74, 0xB3, 0xDE, 0x57, 0xE9, 0x70, 0x97, 0x9E];
+		let ptxt: Vec<u8> = (0..=255).cycle().take(10000).collect();
+		let mut ctxt = ptxt.clone();
+		Lea128Ctr::new(&key, &nonce).apply_keystream(&mut ctxt);
+
+		let (client, server) = tokio::io::duplex(64);
+		let mut writer = AsyncLeaCtrWriter::new(client, Lea128Ctr::new(&key, &nonce));
+		let sender = async {
+			for piece in ptxt.chunks(7) {
+				writer.write_all(piece).await?;
+			}
+			writer.shutdown().await
+		};
+		let mut reader = AsyncLeaCtrReader::new(server, Lea128Ctr::new(&key, &nonce));
+		let mut buffer = Vec::new();
+		let (sent, received) = tokio::join!(sender, reader.read_to_end(&mut buffer));
+		sent?;
+		received?;
+		assert_eq!(buffer, ptxt);
+
+		// Ciphertext, written as more than a chunk at once
+		let mut writer = AsyncLeaCtrWriter::new(Vec::new(), Lea128Ctr::new(&key, &nonce));
+		writer.write_all(&ptxt).await?;
+		assert_eq!(writer.into_inner(), ctxt);
+
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn lea128gcm_stream_async() -> io::Result<()> {
+		let key = arr![u8; 0x45, 0x6A, 0x7C, 0x41, 0x14, 0x4D, 0x56, 0x05, 0x12, 0x7B, 0x02, 0x1D, 0x48, 0xCE, 0x1E, 0xF4];
+		let nonce_prefix = arr![u8; 0x4F, 0xDA, 0xBE, 0xA6, 0x09, 0xE6, 0x9F];
+
+		for len in [0, 1, 99, 100, 101, 250, 300] {
+			let ptxt: Vec<u8> = (0..len).map(|i| i as u8).collect();
+
+			// Chunks as sealed by the STREAM encryptor
+			let mut expected = Vec::new();
+			let mut encryptor = Lea128GcmEncryptorBE32::new(&key, &nonce_prefix);
+			let mut chunks: Vec<&[u8]> = ptxt.chunks(100).collect();
+			let last = chunks.pop().unwrap_or(&[]);
+			for chunk in chunks {
+				expected.extend(encryptor.encrypt_next(chunk).unwrap());
+			}
+			expected.extend(encryptor.encrypt_last(last).unwrap());
+
+			let ctxt = seal(&ptxt, 100).await?;
+			assert_eq!(ctxt, expected);
+			assert_eq!(open(&ctxt, 100).await?, ptxt);
+		}
+
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn lea128ccm_stream_async() -> io::Result<()> {
+		let key = arr![u8; 0x7A, 0xD3, 0x6A, 0x75, 0xD5, 0x5F, 0x30, 0x22, 0x09, 0x4E, 0x06, 0xF7, 0xC8, 0x97, 0xD8, 0xBB];
+		let nonce_prefix = arr![u8; 0x0C, 0x5F, 0x04, 0xE8, 0xB5, 0x12, 0x19, 0x5E];
+		let ptxt: Vec<u8> = (0..=255).cycle().take(1000).collect();
+
+		let encryptor: Lea128CcmEncryptorBE32 = Lea128CcmEncryptorBE32::new(&key, &nonce_prefix);
+		let mut writer = AsyncLeaStreamWriter::new(Vec::new(), encryptor, 64);
+		writer.write_all(&ptxt).await?;
+		writer.shutdown().await?;
+		let ctxt = writer.into_inner();
+		assert_eq!(ctxt.len(), 1000 + 16 * 16);
+
+		let decryptor: Lea128CcmDecryptorBE32 = Lea128CcmDecryptorBE32::new(&key, &nonce_prefix);
+		let mut reader = AsyncLeaStreamReader::new(ctxt.as_slice(), decryptor, 64);
+		let mut buffer = Vec::new();
+		reader.read_to_end(&mut buffer).await?;
+		assert_eq!(buffer, ptxt);
+
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn lea128gcm_stream_async_reject() -> io::Result<()> {
+		let ptxt: Vec<u8> = (0..250).map(|i| i as u8).collect();
+		let ctxt = seal(&ptxt, 100).await?;
+		assert_eq!(ctxt.len(), 250 + 3 * 16);
+
+		// Truncation at a chunk boundary
+		assert_eq!(open(&ctxt[..116], 100).await.unwrap_err().kind(), io::ErrorKind::InvalidData);
+		assert_eq!(open(&ctxt[..232], 100).await.unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+		// Truncation inside a chunk
+		assert_eq!(open(&ctxt[..(ctxt.len() - 1)], 100).await.unwrap_err().kind(), io::ErrorKind::InvalidData);
+		assert_eq!(open(&[], 100).await.unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+		// Reordering
+		let mut reordered = ctxt[116..232].to_vec();
+		reordered.extend_from_slice(&ctxt[..116]);
+		reordered.extend_from_slice(&ctxt[232..]);
+		assert_eq!(open(&reordered, 100).await.unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+		// Modification
+		let mut modified = ctxt.clone();
+		modified[150] ^= 1;
+		assert_eq!(open(&modified, 100).await.unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+		// Appending
+		let mut appended = ctxt.clone();
+		appended.push(0);
+		assert_eq!(open(&appended, 100).await.unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+		// Chunk size
+		assert_eq!(open(&ctxt, 101).await.unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn stream_async_exhausted() -> io::Result<()> {
+		let key = arr![u8; 0x45, 0x6A, 0x7C, 0x41, 0x14, 0x4D, 0x56, 0x05, 0x12, 0x7B, 0x02, 0x1D, 0x48, 0xCE, 0x1E, 0xF4];
+		let nonce_prefix = arr![u8; 0x4F, 0xDA, 0xBE, 0xA6, 0x09, 0xE6, 0x9F];
+		let encryptor = Encryptor::from_stream_primitive(ShortStream(StreamBE32::from_aead(Lea128Gcm::new(&key), &nonce_prefix)));
+		let mut writer = AsyncLeaStreamWriter::new(Vec::new(), encryptor, 4);
+
+		// The third chunk fails to seal, and its plaintext is never written.
+		writer.write_all(&[0x42; 12]).await?;
+		assert_eq!(writer.write_all(&[0x42; 4]).await.unwrap_err().kind(), io::ErrorKind::Other);
+		assert!(writer.write(&[0x42]).await.is_err());
+		assert!(writer.flush().await.is_err());
+		assert!(writer.shutdown().await.is_err());
+		assert_eq!(writer.get_ref().len(), 2 * (4 + 16));
+
+		Ok(())
+	}
+}
@@ -0,0 +1,601 @@
+// Copyright © 2023 남기훈 <gihunnam@proton.me>
+//
+// This file and its content are subject to the terms of the MIT License (the "License").
+// If a copy of the License was not distributed with this file, you can obtain one at https://opensource.org/licenses/MIT.
+
+//! LEA-CBC-MAC
+//!
+//! MAC algorithm 1 ([`CbcMac`]) and MAC algorithm 3 ([`RetailMac`]) as specified in ISO/IEC 9797-1:2011,
+//! with padding method 1 ([`Padding1`]), 2 ([`Padding2`]), or 3 ([`Padding3`]).
+//! Tags are truncated to their leftmost `TagSize` bytes, from 4 to 16, as ISO/IEC 9797-1 recommends tags of at least 32 bits.
+//!
+//! | Padding method | Padded data                                    |
+//! | -------------- | ---------------------------------------------- |
+//! | 1              | Data \|\| 0<sup>*</sup>, or 0<sup>128</sup> if data is empty |
+//! | 2              | Data \|\| 1 \|\| 0<sup>*</sup>                 |
+//! | 3              | \[L\]<sub>128</sub> \|\| data \|\| 0<sup>*</sup>, where L is the data length in bits |
+//!
+//! Not every combination is secure for messages of varying lengths, so the constructors differ:
+//!
+//! * `new` is available where messages may have any length, i.e. for MAC algorithm 1 with padding method 3,
+//!   and for MAC algorithm 3 with padding method 2 or 3.
+//! * `new_fixed_length` is available for any padding method, and is the only constructor of the other combinations.
+//!   It takes the message length, and every message authenticated under the same key must have that length.
+//!
+//! Padding method 3 takes the message length in advance, as it is authenticated first.
+//! A message longer or shorter than declared fails with [`Error::InvalidLength`].
+//!
+//! Use CMAC instead where no peer requires these algorithms.
+//!
+//! * Example
+//! ```
+//! use lea::{cbcmac::{Error, Padding2, Padding3}, cipher::consts::U8, prelude::*, Lea128, Lea128CbcMac, Lea128RetailMac};
+//!
+//! let key = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+//! let key_prime = arr![u8; 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F];
+//! let message = b"amount=1000";
+//!
+//! let lea128 = Lea128::new(&key);
+//! let lea128_prime = Lea128::new(&key_prime);
+//!
+//! // MAC algorithm 1 with padding method 3, truncated to 8 bytes
+//! let mut cbc_mac = Lea128CbcMac::<Padding3, U8>::new(&lea128, message.len() as u64);
+//! cbc_mac.update(message)?;
+//! assert_eq!(cbc_mac.finalize()?, arr![u8; 0x6A, 0xF1, 0x19, 0x6E, 0xAB, 0x32, 0x98, 0x6D]);
+//!
+//! // MAC algorithm 3 with padding method 2, truncated to 8 bytes
+//! let mut retail_mac = Lea128RetailMac::<Padding2, U8>::new(&lea128, &lea128_prime);
+//! retail_mac.update(&message[..6])?;
+//! retail_mac.update(&message[6..])?;
+//! retail_mac.verify(&arr![u8; 0x6A, 0x97, 0x52, 0x3B, 0xBB, 0xC8, 0x2A, 0x5D])?;
+//! # Ok::<(), Error>(())
+//! ```
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use cipher::{generic_array::{typenum::{IsGreaterOrEqual, IsLessOrEqual, True, U4, U16}, ArrayLength, GenericArray}, BlockCipher, BlockDecrypt, BlockEncrypt};
+use subtle::ConstantTimeEq;
+
+use crate::{Lea128, Lea192, Lea256};
+
+pub type Lea128CbcMac<'a, Padding, TagSize = U16> = CbcMac<'a, Lea128, Padding, TagSize>;
+pub type Lea192CbcMac<'a, Padding, TagSize = U16> = CbcMac<'a, Lea192, Padding, TagSize>;
+pub type Lea256CbcMac<'a, Padding, TagSize = U16> = CbcMac<'a, Lea256, Padding, TagSize>;
+
+pub type Lea128RetailMac<'a, Padding, TagSize = U16> = RetailMac<'a, Lea128, Padding, TagSize>;
+pub type Lea192RetailMac<'a, Padding, TagSize = U16> = RetailMac<'a, Lea192, Padding, TagSize>;
+pub type Lea256RetailMac<'a, Padding, TagSize = U16> = RetailMac<'a, Lea256, Padding, TagSize>;
+
+type Block = GenericArray<u8, U16>;
+
+/// Error of CBC-MAC
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Error {
+	/// Message length differs from the declared length.
+	InvalidLength,
+	/// Tag does not match.
+	InvalidTag
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::InvalidLength => "invalid length",
+			Self::InvalidTag => "invalid tag"
+		})
+	}
+}
+
+mod private {
+	pub trait Sealed {
+		const METHOD: u8;
+	}
+}
+
+/// Padding method of ISO/IEC 9797-1
+pub trait Padding: private::Sealed {}
+
+/// Padding method 1, i.e. zero padding
+pub struct Padding1;
+/// Padding method 2, i.e. a one bit followed by zero padding
+pub struct Padding2;
+/// Padding method 3, i.e. the data length followed by zero padding
+pub struct Padding3;
+
+impl private::Sealed for Padding1 {
+	const METHOD: u8 = 1;
+}
+
+impl private::Sealed for Padding2 {
+	const METHOD: u8 = 2;
+}
+
+impl private::Sealed for Padding3 {
+	const METHOD: u8 = 3;
+}
+
+impl Padding for Padding1 {}
+impl Padding for Padding2 {}
+impl Padding for Padding3 {}
+
+/// MAC algorithm 1, i.e. CBC-MAC
+pub struct CbcMac<'a, C, P, M> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+P: Padding,
+M: ArrayLength<u8> + IsGreaterOrEqual<U4, Output = True> + IsLessOrEqual<U16, Output = True> {
+	state: CbcMacState<'a, C, P>,
+	_pd: PhantomData<M>
+}
+
+impl<'a, C, M> CbcMac<'a, C, Padding3, M> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+M: ArrayLength<u8> + IsGreaterOrEqual<U4, Output = True> + IsLessOrEqual<U16, Output = True> {
+	/// Messages may have any length, and this one must be `len` bytes long.
+	pub fn new(cipher: &'a C, len: u64) -> Self {
+		Self { state: CbcMacState::new(cipher, Some(len)), _pd: PhantomData }
+	}
+}
+
+impl<'a, C, P, M> CbcMac<'a, C, P, M> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+P: Padding,
+M: ArrayLength<u8> + IsGreaterOrEqual<U4, Output = True> + IsLessOrEqual<U16, Output = True> {
+	/// Every message authenticated under the same key must be `len` bytes long.
+	pub fn new_fixed_length(cipher: &'a C, len: u64) -> Self {
+		Self { state: CbcMacState::new(cipher, Some(len)), _pd: PhantomData }
+	}
+
+	/// Fails if the message exceeds the declared length.
+	pub fn update(&mut self, data: &[u8]) -> Result<(), Error> {
+		self.state.update(data)
+	}
+
+	/// Fails if the message is shorter than declared.
+	pub fn finalize(self) -> Result<GenericArray<u8, M>, Error> {
+		let h = self.state.finalize()?;
+
+		Ok(GenericArray::clone_from_slice(&h[..M::USIZE]))
+	}
+
+	/// Fails if the message length differs from the declared length, or if the tag does not match.
+	/// Tags are compared in constant time.
+	pub fn verify(self, tag: &GenericArray<u8, M>) -> Result<(), Error> {
+		verify(&self.finalize()?, tag)
+	}
+}
+
+/// MAC algorithm 3, i.e. retail MAC
+///
+/// The last CBC-MAC block is additionally decrypted with K′ and encrypted with K.
+pub struct RetailMac<'a, C, P, M> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+P: Padding,
+M: ArrayLength<u8> + IsGreaterOrEqual<U4, Output = True> + IsLessOrEqual<U16, Output = True> {
+	state: CbcMacState<'a, C, P>,
+	/// Cipher keyed with K′
+	cipher_prime: &'a C,
+	_pd: PhantomData<M>
+}
+
+impl<'a, C, M> RetailMac<'a, C, Padding2, M> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+M: ArrayLength<u8> + IsGreaterOrEqual<U4, Output = True> + IsLessOrEqual<U16, Output = True> {
+	/// Messages may have any length.
+	pub fn new(cipher: &'a C, cipher_prime: &'a C) -> Self {
+		Self { state: CbcMacState::new(cipher, None), cipher_prime, _pd: PhantomData }
+	}
+}
+
+impl<'a, C, M> RetailMac<'a, C, Padding3, M> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+M: ArrayLength<u8> + IsGreaterOrEqual<U4, Output = True> + IsLessOrEqual<U16, Output = True> {
+	/// Messages may have any length, and this one must be `len` bytes long.
+	pub fn new(cipher: &'a C, cipher_prime: &'a C, len: u64) -> Self {
+		Self { state: CbcMacState::new(cipher, Some(len)), cipher_prime, _pd: PhantomData }
+	}
+}
+
+impl<'a, C, P, M> RetailMac<'a, C, P, M> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+P: Padding,
+M: ArrayLength<u8> + IsGreaterOrEqual<U4, Output = True> + IsLessOrEqual<U16, Output = True> {
+	/// Every message authenticated under the same keys must be `len` bytes long.
+	pub fn new_fixed_length(cipher: &'a C, cipher_prime: &'a C, len: u64) -> Self {
+		Self { state: CbcMacState::new(cipher, Some(len)), cipher_prime, _pd: PhantomData }
+	}
+
+	/// Fails if the message exceeds the declared length.
+	pub fn update(&mut self, data: &[u8]) -> Result<(), Error> {
+		self.state.update(data)
+	}
+
+	/// Fails if the message is shorter than declared.
+	pub fn finalize(self) -> Result<GenericArray<u8, M>, Error> {
+		let cipher = self.state.cipher;
+		let mut h = self.state.finalize()?;
+		self.cipher_prime.decrypt_block(&mut h);
+		cipher.encrypt_block(&mut h);
+
+		Ok(GenericArray::clone_from_slice(&h[..M::USIZE]))
+	}
+
+	/// Fails if the message length differs from the declared length, or if the tag does not match.
+	/// Tags are compared in constant time.
+	pub fn verify(self, tag: &GenericArray<u8, M>) -> Result<(), Error> {
+		verify(&self.finalize()?, tag)
+	}
+}
+
+/// CBC-MAC over padded data, up to the last iteration
+struct CbcMacState<'a, C, P> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+P: Padding {
+	cipher: &'a C,
+	h: Block,
+	pos: usize,
+	len: u64,
+	/// Declared message length
+	expected_len: Option<u64>,
+	_pd: PhantomData<P>
+}
+
+impl<'a, C, P> CbcMacState<'a, C, P> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+P: Padding {
+	fn new(cipher: &'a C, expected_len: Option<u64>) -> Self {
+		let mut h = Block::default();
+		if P::METHOD == 3 {
+			let expected_len = expected_len.unwrap_or_default() as u128 * 8;
+			h.copy_from_slice(&expected_len.to_be_bytes());
+			cipher.encrypt_block(&mut h);
+		}
+
+		Self { cipher, h, pos: 0, len: 0, expected_len, _pd: PhantomData }
+	}
+
+	fn update(&mut self, mut data: &[u8]) -> Result<(), Error> {
+		if self.expected_len.is_some_and(|expected_len| data.len() as u64 > expected_len - self.len) {
+			return Err(Error::InvalidLength);
+		}
+		self.len += data.len() as u64;
+
+		while !data.is_empty() {
+			let n = data.len().min(16 - self.pos);
+			self.h[self.pos..(self.pos + n)].iter_mut().zip(&data[..n]).for_each(|(h, d)| *h ^= d);
+			self.pos += n;
+			data = &data[n..];
+
+			if self.pos == 16 {
+				self.cipher.encrypt_block(&mut self.h);
+				self.pos = 0;
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Returns H<sub>q</sub>.
+	fn finalize(mut self) -> Result<Block, Error> {
+		if self.expected_len.is_some_and(|expected_len| self.len != expected_len) {
+			return Err(Error::InvalidLength);
+		}
+
+		match P::METHOD {
+			// Empty data is padded to a zero block.
+			1 if self.pos != 0 || self.len == 0 => self.cipher.encrypt_block(&mut self.h),
+			2 => {
+				self.h[self.pos] ^= 0x80;
+				self.cipher.encrypt_block(&mut self.h);
+			},
+			3 if self.pos != 0 => self.cipher.encrypt_block(&mut self.h),
+			_ => {}
+		}
+
+		Ok(self.h)
+	}
+}
+
+fn verify<M>(calculated_tag: &GenericArray<u8, M>, tag: &GenericArray<u8, M>) -> Result<(), Error> where
+M: ArrayLength<u8> {
+	if calculated_tag.ct_eq(tag).into() {
+		Ok(())
+	} else {
+		Err(Error::InvalidTag)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	extern crate alloc;
+
+	use alloc::{vec::Vec, vec};
+
+	use cipher::{consts::{U4, U8, U16}, BlockCipher, BlockCipherKey, NewBlockCipher};
+
+	use crate::{cbcmac::{Error, Padding1, Padding2, Padding3}, prelude::*, Lea128, Lea192, Lea256, Lea128CbcMac, Lea192CbcMac, Lea256CbcMac, Lea128RetailMac, Lea192RetailMac, Lea256RetailMac};
+
+	struct TestCase<T> where
+	T: BlockCipher + NewBlockCipher {
+		key: BlockCipherKey<T>,
+		key_prime: BlockCipherKey<T>,
+		message: Vec<u8>,
+		/// Tags of MAC algorithm 1 with padding methods 1, 2, and 3
+		cbc_mac: [GenericArray<u8, U16>; 3],
+		/// Tags of MAC algorithm 3 with padding methods 1, 2, and 3
+		retail_mac: [GenericArray<u8, U16>; 3]
+	}
+
+	/// Tag of `message` supplied in chunks of 7 bytes
+	macro_rules! mac {
+		($mac:expr, $message:expr) => {{
+			let mut mac = $mac;
+			for chunk in $message.chunks(7) {
+				mac.update(chunk)?;
+			}
+
+			mac.finalize()
+		}};
+	}
+
+	#[test]
+	fn lea128cbcmac() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea128>; 3] = [
+			TestCase {
+				key: arr![u8; 0xC3, 0xAA, 0x76, 0x55, 0xC5, 0x91, 0x8D, 0xC4, 0x04, 0x7F, 0x09, 0xE0, 0x4F, 0x4C, 0xA3, 0x55],
+				key_prime: arr![u8; 0x83, 0x1E, 0x3D, 0x10, 0xDD, 0x90, 0x6E, 0x25, 0xB8, 0xF3, 0x40, 0x09, 0x3F, 0x32, 0x49, 0x5B],
+				message: vec![],
+				cbc_mac: [
+					arr![u8; 0xB1, 0x5E, 0xC4, 0x0F, 0xAC, 0xCD, 0x64, 0x13, 0x77, 0x20, 0x82, 0x10, 0x09, 0xDA, 0xC0, 0x4F],
+					arr![u8; 0x98, 0x09, 0x64, 0x03, 0x3F, 0xC9, 0xB5, 0x20, 0xA4, 0x27, 0xE6, 0x24, 0xB5, 0x36, 0xC6, 0x20],
+					arr![u8; 0xB1, 0x5E, 0xC4, 0x0F, 0xAC, 0xCD, 0x64, 0x13, 0x77, 0x20, 0x82, 0x10, 0x09, 0xDA, 0xC0, 0x4F]
+				],
+				retail_mac: [
+					arr![u8; 0x39, 0xAE, 0x20, 0xCF, 0xB9, 0x10, 0xDD, 0x9F, 0x3F, 0x36, 0x00, 0x0E, 0x37, 0x86, 0x55, 0x6A],
+					arr![u8; 0x31, 0xE0, 0xCC, 0x39, 0x1F, 0xF8, 0x2C, 0x82, 0x2F, 0xDA, 0xE8, 0xC7, 0x6C, 0xBC, 0x96, 0xB3],
+					arr![u8; 0x39, 0xAE, 0x20, 0xCF, 0xB9, 0x10, 0xDD, 0x9F, 0x3F, 0x36, 0x00, 0x0E, 0x37, 0x86, 0x55, 0x6A]
+				]
+			},
+			TestCase {
+				key: arr![u8; 0xC3, 0xAA, 0x76, 0x55, 0xC5, 0x91, 0x8D, 0xC4, 0x04, 0x7F, 0x09, 0xE0, 0x4F, 0x4C, 0xA3, 0x55],
+				key_prime: arr![u8; 0x83, 0x1E, 0x3D, 0x10, 0xDD, 0x90, 0x6E, 0x25, 0xB8, 0xF3, 0x40, 0x09, 0x3F, 0x32, 0x49, 0x5B],
+				message: vec![0x1B, 0x88, 0xA2, 0x73, 0x0D, 0x5D, 0xDE, 0x1D, 0x9A, 0xCC, 0x4A, 0x36, 0x2F, 0xD0, 0x0B, 0xFC, 0xCA, 0x75, 0x0B, 0x2B],
+				cbc_mac: [
+					arr![u8; 0x8E, 0xEE, 0x8B, 0x54, 0x7F, 0x1A, 0xA2, 0xA4, 0x49, 0x5B, 0x45, 0xAB, 0xAB, 0x6D, 0xFD, 0xA2],
+					arr![u8; 0x03, 0xAC, 0xD1, 0xD6, 0x84, 0x24, 0x68, 0xCB, 0xC7, 0x18, 0x14, 0xB9, 0x7E, 0x38, 0xDD, 0x0A],
+					arr![u8; 0x5E, 0x7C, 0x16, 0x7C, 0x15, 0x8E, 0x29, 0xCE, 0x55, 0xBF, 0x41, 0xFF, 0x54, 0xD4, 0xDB, 0x61]
+				],
+				retail_mac: [
+					arr![u8; 0x9B, 0x51, 0x80, 0xD6, 0x77, 0x5D, 0xF2, 0x0A, 0x54, 0xF2, 0x18, 0xA8, 0x46, 0x5D, 0xE5, 0xE7],
+					arr![u8; 0x1C, 0x0D, 0xB0, 0x4F, 0x59, 0xFE, 0x33, 0x61, 0xC8, 0x81, 0x45, 0x07, 0x68, 0x71, 0x5A, 0x10],
+					arr![u8; 0xE4, 0xEA, 0xFC, 0x2A, 0x35, 0x23, 0x64, 0x91, 0x52, 0xCE, 0x71, 0x0C, 0x36, 0xDA, 0xC7, 0x06]
+				]
+			},
+			TestCase {
+				key: arr![u8; 0xC3, 0xAA, 0x76, 0x55, 0xC5, 0x91, 0x8D, 0xC4, 0x04, 0x7F, 0x09, 0xE0, 0x4F, 0x4C, 0xA3, 0x55],
+				key_prime: arr![u8; 0x83, 0x1E, 0x3D, 0x10, 0xDD, 0x90, 0x6E, 0x25, 0xB8, 0xF3, 0x40, 0x09, 0x3F, 0x32, 0x49, 0x5B],
+				message: vec![0x45, 0x27, 0xEA, 0xCD, 0xD9, 0x8F, 0x0E, 0x07, 0xED, 0xA4, 0x5A, 0x47, 0x21, 0x14, 0x37, 0x41, 0xD3, 0x2B, 0xD6, 0x32, 0x7B, 0x70, 0xDF, 0xB9, 0xEB, 0xD2, 0xE6, 0x2A, 0x8D, 0x6C, 0x7F, 0x8D],
+				cbc_mac: [
+					arr![u8; 0x7C, 0x02, 0x92, 0xF3, 0x7A, 0xBA, 0x6F, 0xC8, 0x22, 0x5A, 0x5B, 0xA4, 0x00, 0x9D, 0x87, 0xE7],
+					arr![u8; 0x6D, 0xCE, 0xE5, 0xF4, 0x1D, 0x47, 0xE8, 0xB8, 0x1A, 0xAE, 0xC8, 0x41, 0x39, 0xE0, 0xF8, 0x3D],
+					arr![u8; 0x2A, 0x1A, 0x04, 0xF1, 0x10, 0x69, 0x7A, 0xE1, 0xB3, 0x9C, 0x31, 0x70, 0x90, 0x62, 0xBE, 0xC7]
+				],
+				retail_mac: [
+					arr![u8; 0x7B, 0x0A, 0x18, 0x58, 0x9B, 0xF3, 0x2F, 0x43, 0x59, 0xF0, 0xCA, 0xFE, 0x80, 0x0D, 0x0A, 0x1A],
+					arr![u8; 0x35, 0x56, 0x80, 0x5C, 0x98, 0x3F, 0x7E, 0x3A, 0x17, 0xFA, 0x75, 0x4F, 0x49, 0x18, 0xB7, 0xCA],
+					arr![u8; 0x73, 0xDC, 0x49, 0xCB, 0xAA, 0x3E, 0xA1, 0x1B, 0xC5, 0x30, 0x23, 0xF0, 0xC9, 0xCD, 0x93, 0xE7]
+				]
+			}
+		];
+
+		for TestCase { key, key_prime, message, cbc_mac, retail_mac } in test_cases {
+			let (lea128, lea128_prime) = (Lea128::new(&key), Lea128::new(&key_prime));
+			let len = message.len() as u64;
+
+			// MAC algorithm 1
+			assert_eq!(mac!(Lea128CbcMac::<Padding1>::new_fixed_length(&lea128, len), &message)?, cbc_mac[0]);
+			assert_eq!(mac!(Lea128CbcMac::<Padding2>::new_fixed_length(&lea128, len), &message)?, cbc_mac[1]);
+			assert_eq!(mac!(Lea128CbcMac::<Padding3>::new(&lea128, len), &message)?, cbc_mac[2]);
+
+			// MAC algorithm 3
+			assert_eq!(mac!(Lea128RetailMac::<Padding1>::new_fixed_length(&lea128, &lea128_prime, len), &message)?, retail_mac[0]);
+			assert_eq!(mac!(Lea128RetailMac::<Padding2>::new(&lea128, &lea128_prime), &message)?, retail_mac[1]);
+			assert_eq!(mac!(Lea128RetailMac::<Padding3>::new(&lea128, &lea128_prime, len), &message)?, retail_mac[2]);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea192cbcmac() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea192>; 3] = [
+			TestCase {
+				key: arr![u8; 0xC1, 0xA6, 0xC2, 0x96, 0xE1, 0x29, 0x6D, 0x54, 0x6D, 0x0F, 0x73, 0x5C, 0xF0, 0x7B, 0x20, 0x79, 0x4F, 0xFC, 0xA8, 0x3E, 0x74, 0xC4, 0x3C, 0xE4],
+				key_prime: arr![u8; 0x62, 0xFE, 0xC9, 0xB6, 0x8A, 0xB5, 0x0F, 0x5B, 0x4E, 0x4E, 0x5A, 0xD2, 0x24, 0x15, 0xB4, 0x6D, 0xCC, 0x9D, 0x91, 0x4D, 0x84, 0xC2, 0x76, 0x3E],
+				message: vec![],
+				cbc_mac: [
+					arr![u8; 0xD8, 0x53, 0x07, 0xCC, 0x75, 0x06, 0x15, 0x18, 0xD6, 0xE5, 0xE2, 0x6C, 0xD0, 0xEA, 0x62, 0xEC],
+					arr![u8; 0xD4, 0x5A, 0x4F, 0x80, 0x03, 0x73, 0xB6, 0x56, 0xD8, 0x24, 0x85, 0x74, 0x53, 0x30, 0x6E, 0xAF],
+					arr![u8; 0xD8, 0x53, 0x07, 0xCC, 0x75, 0x06, 0x15, 0x18, 0xD6, 0xE5, 0xE2, 0x6C, 0xD0, 0xEA, 0x62, 0xEC]
+				],
+				retail_mac: [
+					arr![u8; 0xC9, 0x5D, 0xAC, 0x5D, 0x1F, 0xDE, 0xDA, 0xEF, 0x30, 0xD6, 0x70, 0xF8, 0xD6, 0x3F, 0xF5, 0xD1],
+					arr![u8; 0x84, 0xC1, 0x8B, 0xDB, 0x8D, 0xA5, 0x24, 0x21, 0xE7, 0x67, 0xD8, 0x09, 0x79, 0xDA, 0xF6, 0xC8],
+					arr![u8; 0xC9, 0x5D, 0xAC, 0x5D, 0x1F, 0xDE, 0xDA, 0xEF, 0x30, 0xD6, 0x70, 0xF8, 0xD6, 0x3F, 0xF5, 0xD1]
+				]
+			},
+			TestCase {
+				key: arr![u8; 0xC1, 0xA6, 0xC2, 0x96, 0xE1, 0x29, 0x6D, 0x54, 0x6D, 0x0F, 0x73, 0x5C, 0xF0, 0x7B, 0x20, 0x79, 0x4F, 0xFC, 0xA8, 0x3E, 0x74, 0xC4, 0x3C, 0xE4],
+				key_prime: arr![u8; 0x62, 0xFE, 0xC9, 0xB6, 0x8A, 0xB5, 0x0F, 0x5B, 0x4E, 0x4E, 0x5A, 0xD2, 0x24, 0x15, 0xB4, 0x6D, 0xCC, 0x9D, 0x91, 0x4D, 0x84, 0xC2, 0x76, 0x3E],
+				message: vec![0x8F, 0xD5, 0x9B, 0xCE, 0xFB, 0x7A, 0x31, 0xF0, 0xE1, 0x77, 0xF7, 0x5D, 0xF2, 0x95, 0x24, 0x7E, 0xFD, 0xF1, 0xC0, 0x1B],
+				cbc_mac: [
+					arr![u8; 0x15, 0xF9, 0xA2, 0x3E, 0xAA, 0x6F, 0x8A, 0x93, 0xD0, 0x4A, 0xF3, 0xC2, 0x83, 0x82, 0xAF, 0x53],
+					arr![u8; 0x90, 0x47, 0x9B, 0x2E, 0x3C, 0xC1, 0x35, 0x84, 0xAE, 0x19, 0x49, 0x2B, 0x1F, 0x2B, 0x30, 0x9A],
+					arr![u8; 0x3D, 0x84, 0x30, 0x21, 0xDD, 0x3C, 0x20, 0xC1, 0x82, 0xBC, 0x57, 0xB1, 0xB7, 0xB8, 0x03, 0x2E]
+				],
+				retail_mac: [
+					arr![u8; 0x6E, 0xF5, 0x94, 0x34, 0x41, 0x1C, 0x59, 0x5F, 0x4D, 0xE7, 0x4D, 0x25, 0xC0, 0xED, 0x58, 0x0B],
+					arr![u8; 0xFC, 0xF1, 0xCD, 0xAC, 0xF4, 0xAE, 0x1A, 0xF4, 0x13, 0xBC, 0x33, 0xF2, 0xDE, 0x06, 0x46, 0xB1],
+					arr![u8; 0x46, 0xE0, 0x29, 0x6A, 0x24, 0xA8, 0xB1, 0x27, 0xBC, 0xAF, 0xB8, 0x87, 0xC5, 0xA3, 0xB9, 0xFE]
+				]
+			},
+			TestCase {
+				key: arr![u8; 0xC1, 0xA6, 0xC2, 0x96, 0xE1, 0x29, 0x6D, 0x54, 0x6D, 0x0F, 0x73, 0x5C, 0xF0, 0x7B, 0x20, 0x79, 0x4F, 0xFC, 0xA8, 0x3E, 0x74, 0xC4, 0x3C, 0xE4],
+				key_prime: arr![u8; 0x62, 0xFE, 0xC9, 0xB6, 0x8A, 0xB5, 0x0F, 0x5B, 0x4E, 0x4E, 0x5A, 0xD2, 0x24, 0x15, 0xB4, 0x6D, 0xCC, 0x9D, 0x91, 0x4D, 0x84, 0xC2, 0x76, 0x3E],
+				message: vec![0x0D, 0x64, 0xD3, 0xFF, 0xA5, 0xBB, 0x58, 0xDA, 0x08, 0x71, 0x54, 0xCD, 0x4C, 0x0D, 0xC0, 0x0D, 0x77, 0x8A, 0x0C, 0x7E, 0xC5, 0x5F, 0x16, 0xA7, 0x5E, 0xFA, 0x20, 0x14, 0x61, 0xBD, 0x5C, 0x8D],
+				cbc_mac: [
+					arr![u8; 0x50, 0xE9, 0x2B, 0x83, 0x61, 0x31, 0x4A, 0x56, 0x01, 0x2E, 0xCF, 0x54, 0xFD, 0x8A, 0xE0, 0xA4],
+					arr![u8; 0x93, 0x77, 0x6B, 0x3D, 0x98, 0xDC, 0x79, 0x13, 0x00, 0x57, 0xD0, 0x96, 0xEB, 0x89, 0x9D, 0xB9],
+					arr![u8; 0x41, 0x47, 0x8A, 0xA9, 0x69, 0x99, 0x7F, 0x6A, 0x45, 0x7C, 0x82, 0xB9, 0xA5, 0xC6, 0x00, 0xAD]
+				],
+				retail_mac: [
+					arr![u8; 0xBD, 0xEA, 0xA3, 0x78, 0x5C, 0x53, 0x24, 0x91, 0xA2, 0x16, 0xB0, 0x2B, 0x63, 0xFA, 0x1B, 0x34],
+					arr![u8; 0x55, 0xA1, 0xA6, 0xEF, 0xB5, 0x8F, 0x50, 0x51, 0x58, 0x42, 0x7C, 0x3F, 0x44, 0xCF, 0xBE, 0x29],
+					arr![u8; 0xEA, 0x62, 0x94, 0x79, 0x17, 0x3C, 0x0C, 0x7E, 0xE1, 0xE8, 0x19, 0x7E, 0xB4, 0x47, 0xD1, 0xC4]
+				]
+			}
+		];
+
+		for TestCase { key, key_prime, message, cbc_mac, retail_mac } in test_cases {
+			let (lea192, lea192_prime) = (Lea192::new(&key), Lea192::new(&key_prime));
+			let len = message.len() as u64;
+
+			// MAC algorithm 1
+			assert_eq!(mac!(Lea192CbcMac::<Padding1>::new_fixed_length(&lea192, len), &message)?, cbc_mac[0]);
+			assert_eq!(mac!(Lea192CbcMac::<Padding2>::new_fixed_length(&lea192, len), &message)?, cbc_mac[1]);
+			assert_eq!(mac!(Lea192CbcMac::<Padding3>::new(&lea192, len), &message)?, cbc_mac[2]);
+
+			// MAC algorithm 3
+			assert_eq!(mac!(Lea192RetailMac::<Padding1>::new_fixed_length(&lea192, &lea192_prime, len), &message)?, retail_mac[0]);
+			assert_eq!(mac!(Lea192RetailMac::<Padding2>::new(&lea192, &lea192_prime), &message)?, retail_mac[1]);
+			assert_eq!(mac!(Lea192RetailMac::<Padding3>::new(&lea192, &lea192_prime, len), &message)?, retail_mac[2]);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea256cbcmac() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea256>; 3] = [
+			TestCase {
+				key: arr![u8; 0xF8, 0x2C, 0xE0, 0x08, 0x9E, 0x36, 0x9B, 0xC7, 0x22, 0xA3, 0xE0, 0xBE, 0x5B, 0x2B, 0x24, 0xBD, 0xC7, 0xB3, 0x52, 0xEA, 0x58, 0x87, 0x51, 0x2D, 0x21, 0x07, 0xA1, 0x9D, 0xA0, 0xA5, 0x08, 0x63],
+				key_prime: arr![u8; 0x1A, 0xD8, 0x3C, 0x08, 0x4C, 0x8D, 0x8C, 0xB1, 0x56, 0xF1, 0xCE, 0xF7, 0x81, 0x8B, 0x52, 0x7D, 0x0D, 0x8D, 0x1B, 0x62, 0xA4, 0x90, 0x91, 0x80, 0x95, 0x8A, 0x92, 0x2F, 0x22, 0x87, 0x9C, 0x4F],
+				message: vec![],
+				cbc_mac: [
+					arr![u8; 0xFF, 0x28, 0x76, 0xE2, 0x92, 0x12, 0x5E, 0x72, 0xEE, 0x4D, 0x34, 0xE3, 0x71, 0x89, 0x67, 0xCE],
+					arr![u8; 0x5C, 0xF7, 0x84, 0xEC, 0x71, 0x4A, 0x33, 0x33, 0x7E, 0x58, 0xE1, 0x5D, 0x63, 0x2D, 0x30, 0x8A],
+					arr![u8; 0xFF, 0x28, 0x76, 0xE2, 0x92, 0x12, 0x5E, 0x72, 0xEE, 0x4D, 0x34, 0xE3, 0x71, 0x89, 0x67, 0xCE]
+				],
+				retail_mac: [
+					arr![u8; 0x93, 0x19, 0xDA, 0x82, 0xB3, 0xAD, 0xB3, 0x28, 0x8C, 0xDA, 0x6F, 0x02, 0xE8, 0x32, 0x0D, 0x4E],
+					arr![u8; 0xFF, 0xB8, 0x84, 0x93, 0x52, 0xB5, 0x6A, 0x6A, 0x86, 0x5E, 0x7D, 0xAF, 0x88, 0xC8, 0x50, 0x9E],
+					arr![u8; 0x93, 0x19, 0xDA, 0x82, 0xB3, 0xAD, 0xB3, 0x28, 0x8C, 0xDA, 0x6F, 0x02, 0xE8, 0x32, 0x0D, 0x4E]
+				]
+			},
+			TestCase {
+				key: arr![u8; 0xF8, 0x2C, 0xE0, 0x08, 0x9E, 0x36, 0x9B, 0xC7, 0x22, 0xA3, 0xE0, 0xBE, 0x5B, 0x2B, 0x24, 0xBD, 0xC7, 0xB3, 0x52, 0xEA, 0x58, 0x87, 0x51, 0x2D, 0x21, 0x07, 0xA1, 0x9D, 0xA0, 0xA5, 0x08, 0x63],
+				key_prime: arr![u8; 0x1A, 0xD8, 0x3C, 0x08, 0x4C, 0x8D, 0x8C, 0xB1, 0x56, 0xF1, 0xCE, 0xF7, 0x81, 0x8B, 0x52, 0x7D, 0x0D, 0x8D, 0x1B, 0x62, 0xA4, 0x90, 0x91, 0x80, 0x95, 0x8A, 0x92, 0x2F, 0x22, 0x87, 0x9C, 0x4F],
+				message: vec![0xCF, 0x06, 0xBF, 0x77, 0xBF, 0xD4, 0xE6, 0xD6, 0x71, 0x13, 0x67, 0x1F, 0x97, 0xBD, 0x0A, 0x58, 0xFD, 0xD7, 0x2D, 0xF1],
+				cbc_mac: [
+					arr![u8; 0x27, 0xC1, 0xD2, 0x91, 0x85, 0xA4, 0x3C, 0x80, 0xF5, 0xAD, 0x08, 0xB9, 0x38, 0x74, 0xD1, 0xFB],
+					arr![u8; 0x87, 0x51, 0x12, 0x55, 0x09, 0x5E, 0xC8, 0x76, 0xC1, 0x13, 0x67, 0xA0, 0x55, 0xEE, 0x65, 0x6A],
+					arr![u8; 0x9E, 0x82, 0x2F, 0xF0, 0xC2, 0x1D, 0xAB, 0xC8, 0x0B, 0x78, 0x1D, 0xE5, 0xD9, 0x99, 0xB8, 0x6E]
+				],
+				retail_mac: [
+					arr![u8; 0x85, 0xBD, 0x2A, 0x48, 0xA2, 0xBB, 0x26, 0xA7, 0x83, 0x45, 0x49, 0xAB, 0x20, 0x6E, 0x83, 0xF1],
+					arr![u8; 0x1D, 0x89, 0x4C, 0x2B, 0xA8, 0x62, 0x8C, 0xF8, 0xEC, 0xED, 0xA4, 0x37, 0x46, 0x25, 0xF6, 0xD5],
+					arr![u8; 0x2E, 0xE8, 0xD4, 0x55, 0x58, 0xD1, 0xD6, 0xEB, 0x01, 0xFF, 0x64, 0x8F, 0x9F, 0xFD, 0xC6, 0x8E]
+				]
+			},
+			TestCase {
+				key: arr![u8; 0xF8, 0x2C, 0xE0, 0x08, 0x9E, 0x36, 0x9B, 0xC7, 0x22, 0xA3, 0xE0, 0xBE, 0x5B, 0x2B, 0x24, 0xBD, 0xC7, 0xB3, 0x52, 0xEA, 0x58, 0x87, 0x51, 0x2D, 0x21, 0x07, 0xA1, 0x9D, 0xA0, 0xA5, 0x08, 0x63],
+				key_prime: arr![u8; 0x1A, 0xD8, 0x3C, 0x08, 0x4C, 0x8D, 0x8C, 0xB1, 0x56, 0xF1, 0xCE, 0xF7, 0x81, 0x8B, 0x52, 0x7D, 0x0D, 0x8D, 0x1B, 0x62, 0xA4, 0x90, 0x91, 0x80, 0x95, 0x8A, 0x92, 0x2F, 0x22, 0x87, 0x9C, 0x4F],
+				message: vec![0x18, 0x3D, 0xCF, 0xEA, 0x2A, 0x2E, 0x08, 0x2C, 0x8C, 0xE7, 0xDC, 0xC6, 0x8A, 0x11, 0x4E, 0x2C, 0xCF, 0xC5, 0xB5, 0xF0, 0x66, 0xBD, 0x2C, 0x51, 0x94, 0x69, 0x5E, 0x8A, 0x8A, 0xF8, 0xFC, 0x2D],
+				cbc_mac: [
+					arr![u8; 0x5D, 0x4D, 0x03, 0xD8, 0x8A, 0x35, 0xFA, 0x9B, 0x12, 0xB9, 0xF1, 0x8B, 0xE8, 0x11, 0x1D, 0xA0],
+					arr![u8; 0x65, 0xA0, 0x01, 0x1C, 0x03, 0xF8, 0xB3, 0xA4, 0x78, 0x7E, 0x21, 0xD1, 0x9B, 0x35, 0x35, 0x31],
+					arr![u8; 0x61, 0x0F, 0x17, 0x4E, 0xF3, 0xFC, 0xEF, 0x4A, 0x20, 0x21, 0xD5, 0x23, 0xF0, 0x22, 0x23, 0x11]
+				],
+				retail_mac: [
+					arr![u8; 0xCF, 0x2F, 0x82, 0x79, 0x5E, 0x44, 0xA5, 0x7B, 0xB6, 0x39, 0xB4, 0x7B, 0x77, 0x72, 0x11, 0x41],
+					arr![u8; 0x7A, 0x74, 0xF5, 0xAA, 0x15, 0xAF, 0x6F, 0xFD, 0xA6, 0xCC, 0x12, 0xBD, 0x29, 0xEE, 0x11, 0xFE],
+					arr![u8; 0x34, 0xF7, 0xE3, 0x20, 0x7B, 0xAD, 0x73, 0x44, 0xE7, 0x98, 0xA6, 0x11, 0x21, 0x07, 0x36, 0xE4]
+				]
+			}
+		];
+
+		for TestCase { key, key_prime, message, cbc_mac, retail_mac } in test_cases {
+			let (lea256, lea256_prime) = (Lea256::new(&key), Lea256::new(&key_prime));
+			let len = message.len() as u64;
+
+			// MAC algorithm 1
+			assert_eq!(mac!(Lea256CbcMac::<Padding1>::new_fixed_length(&lea256, len), &message)?, cbc_mac[0]);
+			assert_eq!(mac!(Lea256CbcMac::<Padding2>::new_fixed_length(&lea256, len), &message)?, cbc_mac[1]);
+			assert_eq!(mac!(Lea256CbcMac::<Padding3>::new(&lea256, len), &message)?, cbc_mac[2]);
+
+			// MAC algorithm 3
+			assert_eq!(mac!(Lea256RetailMac::<Padding1>::new_fixed_length(&lea256, &lea256_prime, len), &message)?, retail_mac[0]);
+			assert_eq!(mac!(Lea256RetailMac::<Padding2>::new(&lea256, &lea256_prime), &message)?, retail_mac[1]);
+			assert_eq!(mac!(Lea256RetailMac::<Padding3>::new(&lea256, &lea256_prime, len), &message)?, retail_mac[2]);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128cbcmac_truncation() -> Result<(), Error> {
+		let lea128 = Lea128::new(&Default::default());
+		let lea128_prime = Lea128::new(&arr![u8; 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+		let message = [0x55; 40];
+
+		let tag = mac!(Lea128CbcMac::<Padding3>::new(&lea128, 40), message)?;
+		let truncated_tag = mac!(Lea128CbcMac::<Padding3, U4>::new(&lea128, 40), message)?;
+		assert_eq!(truncated_tag[..], tag[..4]);
+
+		let tag = mac!(Lea128RetailMac::<Padding2>::new(&lea128, &lea128_prime), message)?;
+		let truncated_tag = mac!(Lea128RetailMac::<Padding2, U8>::new(&lea128, &lea128_prime), message)?;
+		assert_eq!(truncated_tag[..], tag[..8]);
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128cbcmac_rejection() -> Result<(), Error> {
+		let lea128 = Lea128::new(&Default::default());
+		let lea128_prime = Lea128::new(&arr![u8; 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+		let message = [0x55; 40];
+		let tag = mac!(Lea128CbcMac::<Padding3>::new(&lea128, 40), message)?;
+
+		// Longer message than declared
+		let mut cbc_mac = Lea128CbcMac::<Padding3>::new(&lea128, 40);
+		cbc_mac.update(&message)?;
+		assert_eq!(cbc_mac.update(&[0]), Err(Error::InvalidLength));
+
+		// Shorter message than declared
+		let mut cbc_mac = Lea128CbcMac::<Padding1>::new_fixed_length(&lea128, 40);
+		cbc_mac.update(&message[..39])?;
+		assert_eq!(cbc_mac.finalize(), Err(Error::InvalidLength));
+
+		// Tampered message
+		let mut cbc_mac = Lea128CbcMac::<Padding3>::new(&lea128, 40);
+		cbc_mac.update(&message[..39])?;
+		cbc_mac.update(&[0x54])?;
+		assert_eq!(cbc_mac.verify(&tag), Err(Error::InvalidTag));
+
+		// Tampered tag
+		let mut tampered_tag = tag;
+		tampered_tag[15] ^= 1;
+		let mut cbc_mac = Lea128CbcMac::<Padding3>::new(&lea128, 40);
+		cbc_mac.update(&message)?;
+		assert_eq!(cbc_mac.verify(&tampered_tag), Err(Error::InvalidTag));
+
+		// Padding method 2 distinguishes trailing zeros.
+		let tag = mac!(Lea128RetailMac::<Padding2>::new(&lea128, &lea128_prime), message)?;
+		let mut retail_mac = Lea128RetailMac::<Padding2>::new(&lea128, &lea128_prime);
+		retail_mac.update(&message)?;
+		retail_mac.update(&[0])?;
+		assert_eq!(retail_mac.verify(&tag), Err(Error::InvalidTag));
+
+		Ok(())
+	}
+}
@@ -31,16 +31,627 @@
 //! assert_eq!(buffer, ptxt);
 //! ```
 
+//!
+//! * Tag sizes
+//!
+//! Tag size defaults to `U16`, and can be any of `U4`, `U6`, `U8`, `U10`, `U12`, `U14`, `U16`.
+//! ```
+//! use lea::{ccm::aead, prelude::*, Lea128Ccm};
+//!
+//! let key = arr![u8; 0x67, 0x0F, 0xD2, 0x86, 0xDF, 0x28, 0x3C, 0x66, 0x2D, 0xB8, 0x64, 0xA6, 0x81, 0xB9, 0xAB, 0x35];
+//! let nonce = arr![u8; 0xE5, 0x9E, 0x05, 0x4A, 0x7E, 0x8B, 0x58, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00];
+//! let ptxt = [0x0E, 0xC5, 0x26, 0xA3, 0xBE, 0x68, 0x6C, 0x8B];
+//!
+//! let lea128ccm = Lea128Ccm::<U13, U8>::new(&key);
+//!
+//! let mut buffer = ptxt;
+//! let tag: Result<_, aead::Error> = lea128ccm.encrypt_in_place_detached(&nonce, &[], &mut buffer);
+//! assert_eq!(tag.unwrap().len(), 8);
+//! ```
+//!
+//! * CCM*
+//!
+//! CCM* (IEEE 802.15.4) additionally allows a tag size of `U0`, which provides encryption only.
+//! With any other tag size, CCM* is identical to CCM.
+//! ```
+//! use lea::{ccm::aead, prelude::*, Lea128CcmStar};
+//!
+//! let key = arr![u8; 0x67, 0x0F, 0xD2, 0x86, 0xDF, 0x28, 0x3C, 0x66, 0x2D, 0xB8, 0x64, 0xA6, 0x81, 0xB9, 0xAB, 0x35];
+//! let nonce = arr![u8; 0xE5, 0x9E, 0x05, 0x4A, 0x7E, 0x8B, 0x58, 0x40, 0x00, 0x00, 0x00, 0x00, 0x05];
+//! let ptxt = [0x0E, 0xC5, 0x26, 0xA3, 0xBE, 0x68, 0x6C, 0x8B];
+//!
+//! // Tag size defaults to `U0`.
+//! let lea128ccmstar = Lea128CcmStar::<U13>::new(&key);
+//!
+//! // Encryption
+//! let mut buffer = ptxt;
+//! let tag: Result<_, aead::Error> = lea128ccmstar.encrypt_in_place_detached(&nonce, &[], &mut buffer);
+//! assert!(tag.unwrap().is_empty());
+//!
+//! // Decryption
+//! let _: Result<(), aead::Error> = lea128ccmstar.decrypt_in_place_detached(&nonce, &[], &mut buffer, &Default::default());
+//! assert_eq!(buffer, ptxt);
+//! ```
+
 pub use ccm::aead;
 
-use aead::consts::U16;
-use ccm::Ccm;
+use core::marker::PhantomData;
+
+use aead::consts::{U0, U16};
+use aead::generic_array::{ArrayLength, GenericArray};
+use aead::{AeadCore, AeadInPlace, Error, Key, NewAead};
+use ccm::{Ccm, Nonce, NonceSize, Tag, TagSize};
+use cipher::{BlockCipher, BlockEncrypt, FromBlockCipher, NewBlockCipher, StreamCipher, StreamCipherSeek};
+use ctr::Ctr64BE;
+use subtle::ConstantTimeEq;
 
 use crate::{Lea128, Lea192, Lea256};
 
-pub type Lea128Ccm<NonceSize> = Ccm<Lea128, U16, NonceSize>;
-pub type Lea192Ccm<NonceSize> = Ccm<Lea192, U16, NonceSize>;
-pub type Lea256Ccm<NonceSize> = Ccm<Lea256, U16, NonceSize>;
+pub type Lea128Ccm<NonceSize, TagSize = U16> = Ccm<Lea128, TagSize, NonceSize>;
+pub type Lea192Ccm<NonceSize, TagSize = U16> = Ccm<Lea192, TagSize, NonceSize>;
+pub type Lea256Ccm<NonceSize, TagSize = U16> = Ccm<Lea256, TagSize, NonceSize>;
+
+pub type Lea128CcmStar<NonceSize, TagSize = U0> = CcmStar<Lea128, TagSize, NonceSize>;
+pub type Lea192CcmStar<NonceSize, TagSize = U0> = CcmStar<Lea192, TagSize, NonceSize>;
+pub type Lea256CcmStar<NonceSize, TagSize = U0> = CcmStar<Lea256, TagSize, NonceSize>;
+
+pub type Lea128CcmEncryptor<'a, NonceSize, TagSize = U16> = CcmEncryptor<'a, Lea128, TagSize, NonceSize>;
+pub type Lea192CcmEncryptor<'a, NonceSize, TagSize = U16> = CcmEncryptor<'a, Lea192, TagSize, NonceSize>;
+pub type Lea256CcmEncryptor<'a, NonceSize, TagSize = U16> = CcmEncryptor<'a, Lea256, TagSize, NonceSize>;
+
+pub type Lea128CcmDecryptor<'a, NonceSize, TagSize = U16> = CcmDecryptor<'a, Lea128, TagSize, NonceSize>;
+pub type Lea192CcmDecryptor<'a, NonceSize, TagSize = U16> = CcmDecryptor<'a, Lea192, TagSize, NonceSize>;
+pub type Lea256CcmDecryptor<'a, NonceSize, TagSize = U16> = CcmDecryptor<'a, Lea256, TagSize, NonceSize>;
+
+type Block = GenericArray<u8, U16>;
+
+/// Valid CCM* tag sizes, i.e. `U0`, `U4`, `U6`, `U8`, `U10`, `U12`, `U14`, `U16`
+pub trait StarTagSize: private::Sealed {}
+
+impl<T> StarTagSize for T where
+T: private::Sealed {}
+
+mod private {
+	use core::marker::PhantomData;
+
+	use ccm::{Ccm, Nonce, NonceSize, Tag};
+	use cipher::{BlockCipher, BlockEncrypt, NewBlockCipher};
+
+	use crate::ccm::aead::consts::{U0, U4, U6, U8, U10, U12, U14, U16};
+	use crate::ccm::aead::{generic_array::ArrayLength, AeadCore, AeadInPlace, Error};
+	use crate::ccm::Block;
+
+	pub trait Sealed: ArrayLength<u8> {
+		/// AEAD that CCM* with this tag size amounts to
+		type Aead<C, N>: AeadInPlace<NonceSize = N, TagSize = Self> + From<C> where
+		C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+		N: ArrayLength<u8> + NonceSize;
+	}
+
+	impl Sealed for U0 {
+		type Aead<C, N> = EncryptionOnly<C, N> where
+		C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+		N: ArrayLength<u8> + NonceSize;
+	}
+
+	impl Sealed for U4 {
+		type Aead<C, N> = Ccm<C, U4, N> where
+		C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+		N: ArrayLength<u8> + NonceSize;
+	}
+
+	impl Sealed for U6 {
+		type Aead<C, N> = Ccm<C, U6, N> where
+		C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+		N: ArrayLength<u8> + NonceSize;
+	}
+
+	impl Sealed for U8 {
+		type Aead<C, N> = Ccm<C, U8, N> where
+		C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+		N: ArrayLength<u8> + NonceSize;
+	}
+
+	impl Sealed for U10 {
+		type Aead<C, N> = Ccm<C, U10, N> where
+		C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+		N: ArrayLength<u8> + NonceSize;
+	}
+
+	impl Sealed for U12 {
+		type Aead<C, N> = Ccm<C, U12, N> where
+		C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+		N: ArrayLength<u8> + NonceSize;
+	}
+
+	impl Sealed for U14 {
+		type Aead<C, N> = Ccm<C, U14, N> where
+		C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+		N: ArrayLength<u8> + NonceSize;
+	}
+
+	impl Sealed for U16 {
+		type Aead<C, N> = Ccm<C, U16, N> where
+		C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+		N: ArrayLength<u8> + NonceSize;
+	}
+
+	/// CCM* without a tag, i.e. CTR starting from the counter block `A_1`
+	pub struct EncryptionOnly<C, N> where
+	C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+	N: ArrayLength<u8> + NonceSize {
+		cipher: C,
+		_pd: PhantomData<N>
+	}
+
+	impl<C, N> EncryptionOnly<C, N> where
+	C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+	N: ArrayLength<u8> + NonceSize {
+		/// Size of the length field in bytes
+		const L: usize = 15 - N::USIZE;
+
+		/// Fails if there is associated data, as it would not be authenticated,
+		/// or if `buffer` does not fit in the length field.
+		fn apply_keystream(&self, nonce: &Nonce<N>, associated_data: &[u8], buffer: &mut [u8]) -> Result<(), Error> {
+			if !associated_data.is_empty() || Self::L < 8 && (buffer.len() as u64) >> (8 * Self::L) != 0 {
+				return Err(Error);
+			}
+
+			let mut ctr = Block::default();
+			ctr[0] = Self::L as u8 - 1;
+			ctr[1..(1 + N::USIZE)].copy_from_slice(nonce);
+
+			for (i, chunk) in (1u64..).zip(buffer.chunks_mut(16)) {
+				let mut keystream = ctr;
+				keystream[(1 + N::USIZE)..].copy_from_slice(&i.to_be_bytes()[(8 - Self::L)..]);
+				self.cipher.encrypt_block(&mut keystream);
+				chunk.iter_mut().zip(keystream).for_each(|(c, k)| *c ^= k);
+			}
+
+			Ok(())
+		}
+	}
+
+	impl<C, N> From<C> for EncryptionOnly<C, N> where
+	C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+	N: ArrayLength<u8> + NonceSize {
+		fn from(cipher: C) -> Self {
+			Self { cipher, _pd: PhantomData }
+		}
+	}
+
+	impl<C, N> AeadCore for EncryptionOnly<C, N> where
+	C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+	N: ArrayLength<u8> + NonceSize {
+		type NonceSize = N;
+		type TagSize = U0;
+		type CiphertextOverhead = U0;
+	}
+
+	impl<C, N> AeadInPlace for EncryptionOnly<C, N> where
+	C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+	N: ArrayLength<u8> + NonceSize {
+		fn encrypt_in_place_detached(&self, nonce: &Nonce<N>, associated_data: &[u8], buffer: &mut [u8]) -> Result<Tag<U0>, Error> {
+			self.apply_keystream(nonce, associated_data, buffer)?;
+
+			Ok(Tag::<U0>::default())
+		}
+
+		fn decrypt_in_place_detached(&self, nonce: &Nonce<N>, associated_data: &[u8], buffer: &mut [u8], _tag: &Tag<U0>) -> Result<(), Error> {
+			self.apply_keystream(nonce, associated_data, buffer)
+		}
+	}
+}
+
+/// CCM* as specified in IEEE 802.15.4
+///
+/// * `M`: Tag size
+/// * `N`: Nonce size
+///
+/// With any tag size but `U0`, this is [`Ccm`].
+/// If `M` is `U0`, associated data must be empty as it would not be authenticated.
+pub struct CcmStar<C, M, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+M: ArrayLength<u8> + StarTagSize,
+N: ArrayLength<u8> + NonceSize {
+	aead: M::Aead<C, N>
+}
+
+impl<C, M, N> From<C> for CcmStar<C, M, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+M: ArrayLength<u8> + StarTagSize,
+N: ArrayLength<u8> + NonceSize {
+	fn from(cipher: C) -> Self {
+		Self { aead: M::Aead::from(cipher) }
+	}
+}
+
+impl<C, M, N> NewAead for CcmStar<C, M, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+M: ArrayLength<u8> + StarTagSize,
+N: ArrayLength<u8> + NonceSize {
+	type KeySize = C::KeySize;
+
+	fn new(key: &Key<Self>) -> Self {
+		Self::from(C::new(key))
+	}
+}
+
+impl<C, M, N> AeadCore for CcmStar<C, M, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+M: ArrayLength<u8> + StarTagSize,
+N: ArrayLength<u8> + NonceSize {
+	type NonceSize = N;
+	type TagSize = M;
+	type CiphertextOverhead = U0;
+}
+
+impl<C, M, N> AeadInPlace for CcmStar<C, M, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+M: ArrayLength<u8> + StarTagSize,
+N: ArrayLength<u8> + NonceSize {
+	fn encrypt_in_place_detached(&self, nonce: &Nonce<N>, associated_data: &[u8], buffer: &mut [u8]) -> Result<Tag<M>, Error> {
+		self.aead.encrypt_in_place_detached(nonce, associated_data, buffer)
+	}
+
+	fn decrypt_in_place_detached(&self, nonce: &Nonce<N>, associated_data: &[u8], buffer: &mut [u8], tag: &Tag<M>) -> Result<(), Error> {
+		self.aead.decrypt_in_place_detached(nonce, associated_data, buffer, tag)
+	}
+}
+
+/// Incremental CCM encryption
+///
+/// CCM requires the lengths of associated data and plaintext up front.
+/// All associated data must be supplied before any plaintext.
+///
+/// * Example
+/// ```
+/// use lea::{ccm::{aead, CcmEncryptor}, prelude::*, Lea128, Lea128Ccm};
+///
+/// let key = arr![u8; 0x67, 0x0F, 0xD2, 0x86, 0xDF, 0x28, 0x3C, 0x66, 0x2D, 0xB8, 0x64, 0xA6, 0x81, 0xB9, 0xAB, 0x35];
+/// let nonce = arr![u8; 0xE5, 0x9E, 0x05, 0x4A, 0x7E, 0x8B, 0x58, 0x40];
+/// let tag = arr![u8; 0xE3, 0xE9, 0x85, 0xF0, 0xD9, 0xA5, 0x9D, 0xB0, 0xB7, 0xB4, 0xEF, 0x63, 0x19, 0x4D, 0x62, 0xFB];
+/// let ptxt = [0x0E, 0xC5, 0x26, 0xA3, 0xBE, 0x68, 0x6C, 0x8B];
+/// let ctxt = [0x90, 0xB7, 0x61, 0x8D, 0x8A, 0x50, 0x72, 0x3C];
+///
+/// let lea128 = Lea128::new(&key);
+/// let mut encryptor = CcmEncryptor::<_, U16, U8>::new(&lea128, &nonce, 0, ptxt.len() as u64)?;
+///
+/// let mut buffer = ptxt;
+/// for chunk in buffer.chunks_mut(3) {
+///     encryptor.encrypt(chunk)?;
+/// }
+/// assert_eq!(buffer, ctxt);
+/// assert_eq!(encryptor.finalize()?, tag);
+/// # Ok::<(), aead::Error>(())
+/// ```
+pub struct CcmEncryptor<'a, C, M, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+M: ArrayLength<u8> + TagSize,
+N: ArrayLength<u8> + NonceSize {
+	state: CcmState<'a, C, N>,
+	_pd: PhantomData<M>
+}
+
+impl<'a, C, M, N> CcmEncryptor<'a, C, M, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+M: ArrayLength<u8> + TagSize,
+N: ArrayLength<u8> + NonceSize {
+	/// Fails if `len` does not fit in the length field of `15 - N` bytes.
+	pub fn new(cipher: &'a C, nonce: &Nonce<N>, associated_data_len: u64, len: u64) -> Result<Self, Error> {
+		Ok(Self { state: CcmState::new(cipher, nonce, M::USIZE, associated_data_len, len)?, _pd: PhantomData })
+	}
+
+	/// Fails if more associated data is supplied than declared.
+	pub fn update_associated_data(&mut self, associated_data: &[u8]) -> Result<(), Error> {
+		self.state.update_associated_data(associated_data)
+	}
+
+	/// Fails if associated data is incomplete, or if more plaintext is supplied than declared.
+	pub fn encrypt(&mut self, buffer: &mut [u8]) -> Result<(), Error> {
+		self.state.encrypt(buffer)
+	}
+
+	/// Fails if less associated data or plaintext has been supplied than declared.
+	pub fn finalize(self) -> Result<Tag<M>, Error> {
+		Ok(Tag::<M>::clone_from_slice(&self.state.tag()?[..M::USIZE]))
+	}
+}
+
+/// Incremental CCM decryption
+///
+/// As CCM authenticates the plaintext, the ciphertext is decrypted either way.
+/// * [`CcmDecryptor::verify`] authenticates ciphertext without releasing plaintext.
+///   Once [`CcmDecryptor::finalize`] succeeds, the ciphertext can be decrypted in a second pass with [`CcmVerifiedDecryptor`],
+///   which must also be finalized, as it authenticates the ciphertext again.
+/// * [`CcmDecryptor::decrypt_unverified`] releases plaintext immediately.
+///   The plaintext must be discarded unless [`CcmDecryptor::finalize`] succeeds.
+///
+/// * Example
+/// ```
+/// use lea::{ccm::{aead, CcmDecryptor}, prelude::*, Lea128};
+///
+/// let key = arr![u8; 0x67, 0x0F, 0xD2, 0x86, 0xDF, 0x28, 0x3C, 0x66, 0x2D, 0xB8, 0x64, 0xA6, 0x81, 0xB9, 0xAB, 0x35];
+/// let nonce = arr![u8; 0xE5, 0x9E, 0x05, 0x4A, 0x7E, 0x8B, 0x58, 0x40];
+/// let tag = arr![u8; 0xE3, 0xE9, 0x85, 0xF0, 0xD9, 0xA5, 0x9D, 0xB0, 0xB7, 0xB4, 0xEF, 0x63, 0x19, 0x4D, 0x62, 0xFB];
+/// let ptxt = [0x0E, 0xC5, 0x26, 0xA3, 0xBE, 0x68, 0x6C, 0x8B];
+/// let ctxt = [0x90, 0xB7, 0x61, 0x8D, 0x8A, 0x50, 0x72, 0x3C];
+///
+/// let lea128 = Lea128::new(&key);
+/// let mut decryptor = CcmDecryptor::<_, U16, U8>::new(&lea128, &nonce, 0, ctxt.len() as u64)?;
+///
+/// // First pass
+/// for chunk in ctxt.chunks(3) {
+///     decryptor.verify(chunk)?;
+/// }
+/// let mut decryptor = decryptor.finalize(&tag)?;
+///
+/// // Second pass
+/// let mut buffer = ctxt;
+/// for chunk in buffer.chunks_mut(3) {
+///     decryptor.decrypt(chunk)?;
+/// }
+/// decryptor.finalize()?;
+/// assert_eq!(buffer, ptxt);
+/// # Ok::<(), aead::Error>(())
+/// ```
+pub struct CcmDecryptor<'a, C, M, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+M: ArrayLength<u8> + TagSize,
+N: ArrayLength<u8> + NonceSize {
+	state: CcmState<'a, C, N>,
+	/// CBC-MAC before the plaintext, for the second pass
+	mac_start: Option<Block>,
+	_pd: PhantomData<M>
+}
+
+impl<'a, C, M, N> CcmDecryptor<'a, C, M, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+M: ArrayLength<u8> + TagSize,
+N: ArrayLength<u8> + NonceSize {
+	/// Fails if `len` does not fit in the length field of `15 - N` bytes.
+	pub fn new(cipher: &'a C, nonce: &Nonce<N>, associated_data_len: u64, len: u64) -> Result<Self, Error> {
+		Ok(Self { state: CcmState::new(cipher, nonce, M::USIZE, associated_data_len, len)?, mac_start: None, _pd: PhantomData })
+	}
+
+	/// Fails if more associated data is supplied than declared.
+	pub fn update_associated_data(&mut self, associated_data: &[u8]) -> Result<(), Error> {
+		self.state.update_associated_data(associated_data)
+	}
+
+	/// Authenticates ciphertext without decrypting it in place.
+	///
+	/// Fails if associated data is incomplete, or if more ciphertext is supplied than declared.
+	pub fn verify(&mut self, buffer: &[u8]) -> Result<(), Error> {
+		self.start();
+		self.state.verify(buffer)
+	}
+
+	/// Decrypts ciphertext in place before it is authenticated.
+	///
+	/// Fails if associated data is incomplete, or if more ciphertext is supplied than declared.
+	pub fn decrypt_unverified(&mut self, buffer: &mut [u8]) -> Result<(), Error> {
+		self.start();
+		self.state.decrypt(buffer)
+	}
+
+	/// Fails if less associated data or ciphertext has been supplied than declared, or if the tag does not match.
+	pub fn finalize(mut self, tag: &Tag<M>) -> Result<CcmVerifiedDecryptor<'a, C, N>, Error> {
+		let calculated_tag = self.state.tag()?;
+		if calculated_tag[..M::USIZE].ct_eq(tag).into() {
+			// Without ciphertext, the CBC-MAC is the same before and after it.
+			self.state.mac = self.mac_start.unwrap_or(self.state.mac);
+			self.state.mac_pos = 0;
+			self.state.len = self.state.total_len;
+			self.state.ctr.seek(16u64);
+
+			Ok(CcmVerifiedDecryptor { state: self.state, tag: calculated_tag })
+		} else {
+			Err(Error)
+		}
+	}
+
+	/// Keeps the CBC-MAC once associated data is complete.
+	fn start(&mut self) {
+		if self.mac_start.is_none() && self.state.associated_data_len == 0 {
+			self.mac_start = Some(self.state.mac);
+		}
+	}
+}
+
+/// Decryption of authenticated ciphertext
+///
+/// Returned by [`CcmDecryptor::finalize`].
+///
+/// # Security
+///
+/// Nothing but the CBC-MAC ties the second pass to the ciphertext authenticated in the first,
+/// so the ciphertext is authenticated again as it is decrypted.
+/// The plaintext must be discarded unless [`CcmVerifiedDecryptor::finalize`] succeeds,
+/// e.g. if the ciphertext was modified between the passes.
+pub struct CcmVerifiedDecryptor<'a, C, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+N: ArrayLength<u8> + NonceSize {
+	state: CcmState<'a, C, N>,
+	/// Tag calculated in the first pass
+	tag: Block
+}
+
+impl<'a, C, N> CcmVerifiedDecryptor<'a, C, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+N: ArrayLength<u8> + NonceSize {
+	/// Decrypts the ciphertext passed to [`CcmDecryptor`] in place, from the beginning.
+	///
+	/// Fails if more ciphertext is supplied than authenticated.
+	pub fn decrypt(&mut self, buffer: &mut [u8]) -> Result<(), Error> {
+		self.state.decrypt(buffer)
+	}
+
+	/// Fails if less ciphertext has been supplied than authenticated, or if it differs from the ciphertext authenticated.
+	pub fn finalize(self) -> Result<(), Error> {
+		if self.state.tag()?.ct_eq(&self.tag).into() {
+			Ok(())
+		} else {
+			Err(Error)
+		}
+	}
+}
+
+struct CcmState<'a, C, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+N: ArrayLength<u8> + NonceSize {
+	cipher: &'a C,
+	ctr: Ctr64BE<&'a C>,
+	mac: Block,
+	mac_pos: usize,
+	tag_mask: Block,
+	/// Remaining length of associated data
+	associated_data_len: u64,
+	/// Remaining length of plaintext
+	len: u64,
+	total_len: u64,
+	_pd: PhantomData<N>
+}
+
+impl<'a, C, N> CcmState<'a, C, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+N: ArrayLength<u8> + NonceSize {
+	/// Size of the length field in bytes
+	const L: usize = 15 - N::USIZE;
+
+	fn new(cipher: &'a C, nonce: &Nonce<N>, tag_size: usize, associated_data_len: u64, len: u64) -> Result<Self, Error> {
+		if Self::L < 8 && len >> (8 * Self::L) != 0 {
+			return Err(Error);
+		}
+
+		// A_0
+		let mut a0 = Block::default();
+		a0[0] = Self::L as u8 - 1;
+		a0[1..(1 + N::USIZE)].copy_from_slice(nonce);
+
+		let mut tag_mask = a0;
+		cipher.encrypt_block(&mut tag_mask);
+
+		let mut ctr = Ctr64BE::from_block_cipher(cipher, &a0);
+		ctr.seek_block(1);
+
+		// B_0
+		let mut mac = Block::default();
+		mac[0] = 64 * (associated_data_len != 0) as u8 + 8 * (tag_size.saturating_sub(2) / 2) as u8 + (Self::L as u8 - 1);
+		mac[1..(1 + N::USIZE)].copy_from_slice(nonce);
+		mac[(1 + N::USIZE)..].copy_from_slice(&len.to_be_bytes()[(8 - Self::L)..]);
+		cipher.encrypt_block(&mut mac);
+
+		let mut state = Self { cipher, ctr, mac, mac_pos: 0, tag_mask, associated_data_len, len, total_len: len, _pd: PhantomData };
+
+		if associated_data_len == 0 {
+		} else if associated_data_len < 0xFF00 {
+			state.absorb(&(associated_data_len as u16).to_be_bytes());
+		} else if associated_data_len <= u32::MAX as u64 {
+			state.absorb(&[0xFF, 0xFE]);
+			state.absorb(&(associated_data_len as u32).to_be_bytes());
+		} else {
+			state.absorb(&[0xFF, 0xFF]);
+			state.absorb(&associated_data_len.to_be_bytes());
+		}
+
+		Ok(state)
+	}
+
+	/// CBC-MAC over bytes, zero-padded by [`CcmState::pad`]
+	fn absorb(&mut self, mut data: &[u8]) {
+		while !data.is_empty() {
+			let n = data.len().min(16 - self.mac_pos);
+			self.mac[self.mac_pos..(self.mac_pos + n)].iter_mut().zip(&data[..n]).for_each(|(m, d)| *m ^= d);
+			self.mac_pos += n;
+			data = &data[n..];
+
+			if self.mac_pos == 16 {
+				self.cipher.encrypt_block(&mut self.mac);
+				self.mac_pos = 0;
+			}
+		}
+	}
+
+	fn pad(&mut self) {
+		if self.mac_pos != 0 {
+			self.cipher.encrypt_block(&mut self.mac);
+			self.mac_pos = 0;
+		}
+	}
+
+	fn update_associated_data(&mut self, associated_data: &[u8]) -> Result<(), Error> {
+		if associated_data.len() as u64 > self.associated_data_len {
+			return Err(Error);
+		}
+
+		self.absorb(associated_data);
+		self.associated_data_len -= associated_data.len() as u64;
+		if self.associated_data_len == 0 {
+			self.pad();
+		}
+
+		Ok(())
+	}
+
+	fn update(&mut self, len: usize) -> Result<(), Error> {
+		if self.associated_data_len != 0 || len as u64 > self.len {
+			return Err(Error);
+		}
+
+		self.len -= len as u64;
+
+		Ok(())
+	}
+
+	fn encrypt(&mut self, buffer: &mut [u8]) -> Result<(), Error> {
+		self.update(buffer.len())?;
+		self.absorb(buffer);
+		self.ctr.apply_keystream(buffer);
+		if self.len == 0 {
+			self.pad();
+		}
+
+		Ok(())
+	}
+
+	fn decrypt(&mut self, buffer: &mut [u8]) -> Result<(), Error> {
+		self.update(buffer.len())?;
+		self.ctr.apply_keystream(buffer);
+		self.absorb(buffer);
+		if self.len == 0 {
+			self.pad();
+		}
+
+		Ok(())
+	}
+
+	fn verify(&mut self, buffer: &[u8]) -> Result<(), Error> {
+		self.update(buffer.len())?;
+		for chunk in buffer.chunks(16) {
+			let mut block = Block::default();
+			block[..chunk.len()].copy_from_slice(chunk);
+			self.ctr.apply_keystream(&mut block[..chunk.len()]);
+			self.absorb(&block[..chunk.len()]);
+		}
+		if self.len == 0 {
+			self.pad();
+		}
+
+		Ok(())
+	}
+
+	fn tag(&self) -> Result<Block, Error> {
+		if self.associated_data_len != 0 || self.len != 0 {
+			return Err(Error);
+		}
+
+		let mut tag = self.mac;
+		tag.iter_mut().zip(self.tag_mask).for_each(|(t, m)| *t ^= m);
+
+		Ok(tag)
+	}
+}
 
 #[cfg(test)]
 mod tests {
@@ -48,7 +659,7 @@ mod tests {
 
 	use alloc::{vec::Vec, vec};
 
-	use crate::{ccm::aead::Error, prelude::*, Lea128Ccm, Lea192Ccm, Lea256Ccm};
+	use crate::{ccm::{aead::{generic_array::ArrayLength, Error}, CcmStar, Lea128CcmDecryptor, Lea128CcmEncryptor, StarTagSize}, prelude::*, Lea128, Lea128Ccm, Lea192Ccm, Lea256Ccm};
 
 	struct TestCase<T> where
 	T: AeadInPlace + NewAead {
@@ -224,4 +835,214 @@ mod tests {
 
 		Ok(())
 	}
+
+	fn lea128ccm_tag_size<M>() -> Result<(), Error> where
+	M: ArrayLength<u8> + ccm::TagSize + StarTagSize {
+		let key = arr![u8; 0x67, 0x0F, 0xD2, 0x86, 0xDF, 0x28, 0x3C, 0x66, 0x2D, 0xB8, 0x64, 0xA6, 0x81, 0xB9, 0xAB, 0x35];
+		let nonce = arr![u8; 0xE5, 0x9E, 0x05, 0x4A, 0x7E, 0x8B, 0x58, 0x40, 0x00, 0x00, 0x00, 0x00, 0x05];
+		let associated_data = [0xC6, 0xC2, 0x08, 0xBA, 0x87, 0x25, 0x2D, 0xA1, 0x0F, 0x18, 0xF3, 0xB3];
+		let ptxt: Vec<u8> = (0..45).collect();
+
+		let lea128ccm = Lea128Ccm::<U13, M>::new(&key);
+		let lea128ccmstar = CcmStar::<Lea128, M, U13>::new(&key);
+
+		// Encryption
+		let mut ctxt = ptxt.clone();
+		let tag = lea128ccm.encrypt_in_place_detached(&nonce, &associated_data, &mut ctxt)?;
+		let mut buffer = ptxt.clone();
+		assert_eq!(lea128ccmstar.encrypt_in_place_detached(&nonce, &associated_data, &mut buffer)?, tag);
+		assert_eq!(buffer, ctxt);
+
+		// Decryption
+		let mut buffer = ctxt.clone();
+		lea128ccmstar.decrypt_in_place_detached(&nonce, &associated_data, &mut buffer, &tag)?;
+		assert_eq!(buffer, ptxt);
+
+		// Tampering
+		let mut buffer = ctxt.clone();
+		buffer[0] ^= 1;
+		assert!(lea128ccmstar.decrypt_in_place_detached(&nonce, &associated_data, &mut buffer, &tag).is_err());
+		assert!(buffer.iter().all(|&b| b == 0));
+
+		let mut bad_tag = tag.clone();
+		bad_tag[M::USIZE - 1] ^= 1;
+		let mut buffer = ctxt.clone();
+		assert!(lea128ccmstar.decrypt_in_place_detached(&nonce, &associated_data, &mut buffer, &bad_tag).is_err());
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128ccm_tag_sizes() -> Result<(), Error> {
+		lea128ccm_tag_size::<U4>()?;
+		lea128ccm_tag_size::<U6>()?;
+		lea128ccm_tag_size::<U8>()?;
+		lea128ccm_tag_size::<U10>()?;
+		lea128ccm_tag_size::<U12>()?;
+		lea128ccm_tag_size::<U14>()?;
+		lea128ccm_tag_size::<U16>()
+	}
+
+	#[test]
+	fn lea128ccmstar() -> Result<(), Error> {
+		let key = arr![u8; 0x67, 0x0F, 0xD2, 0x86, 0xDF, 0x28, 0x3C, 0x66, 0x2D, 0xB8, 0x64, 0xA6, 0x81, 0xB9, 0xAB, 0x35];
+		let nonce = arr![u8; 0xE5, 0x9E, 0x05, 0x4A, 0x7E, 0x8B, 0x58, 0x40, 0x00, 0x00, 0x00, 0x00, 0x04];
+		let ptxt: Vec<u8> = (0..40).collect();
+
+		let lea128 = Lea128::new(&key);
+		let lea128ccmstar = CcmStar::<Lea128, U0, U13>::new(&key);
+
+		// Encryption: `C_i = P_i ^ E(A_i)` where `A_i = L - 1 || N || i`
+		let mut buffer = ptxt.clone();
+		let tag = lea128ccmstar.encrypt_in_place_detached(&nonce, &[], &mut buffer)?;
+		assert!(tag.is_empty());
+		for (i, (p, c)) in ptxt.chunks(16).zip(buffer.chunks(16)).enumerate() {
+			let mut a = GenericArray::default();
+			a[0] = 1;
+			a[1..14].copy_from_slice(&nonce);
+			a[15] = i as u8 + 1;
+			lea128.encrypt_block(&mut a);
+			assert!(p.iter().zip(c).zip(a).all(|((p, c), k)| p ^ k == *c));
+		}
+
+		// Decryption
+		lea128ccmstar.decrypt_in_place_detached(&nonce, &[], &mut buffer, &tag)?;
+		assert_eq!(buffer, ptxt);
+
+		// Unauthenticated associated data is rejected
+		assert!(lea128ccmstar.encrypt_in_place_detached(&nonce, &[0], &mut buffer).is_err());
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128ccmstar_max_len() {
+		let lea128ccmstar = CcmStar::<Lea128, U8, U13>::new(&Default::default());
+
+		// Length field is 2 bytes for 13-byte nonces
+		let mut buffer = vec![0; 0x10000];
+		assert!(lea128ccmstar.encrypt_in_place_detached(&Default::default(), &[], &mut buffer).is_err());
+		assert!(lea128ccmstar.encrypt_in_place_detached(&Default::default(), &[], &mut buffer[1..]).is_ok());
+	}
+
+	#[test]
+	fn lea128ccm_incremental() -> Result<(), Error> {
+		let key = arr![u8; 0x37, 0xD2, 0x28, 0xE3, 0xEE, 0xC6, 0x44, 0xE6, 0x1C, 0xD9, 0x75, 0x59, 0xF3, 0x07, 0x15, 0x3D];
+		let nonce = arr![u8; 0x3E, 0x7B, 0xF2, 0x34, 0xEA, 0x03, 0xF7, 0x94, 0x00, 0x00, 0x00, 0x00];
+		let lea128 = Lea128::new(&key);
+		let lea128ccm = Lea128Ccm::<U12, U10>::new(&key);
+
+		for (associated_data_len, len) in [(0, 0), (0, 33), (12, 0), (12, 32), (0xFF00, 100)] {
+			let associated_data: Vec<u8> = (0..associated_data_len).map(|i| i as u8).collect();
+			let ptxt: Vec<u8> = (0..len).map(|i| (3 * i) as u8).collect();
+
+			let mut ctxt = ptxt.clone();
+			let tag = lea128ccm.encrypt_in_place_detached(&nonce, &associated_data, &mut ctxt)?;
+
+			// Encryption
+			let mut encryptor = Lea128CcmEncryptor::<U12, U10>::new(&lea128, &nonce, associated_data_len as u64, len as u64)?;
+			for chunk in associated_data.chunks(7) {
+				encryptor.update_associated_data(chunk)?;
+			}
+			let mut buffer = ptxt.clone();
+			for chunk in buffer.chunks_mut(5) {
+				encryptor.encrypt(chunk)?;
+			}
+			assert_eq!(buffer, ctxt);
+			assert_eq!(encryptor.finalize()?, tag);
+
+			// Decryption, verified
+			let mut decryptor = Lea128CcmDecryptor::<U12, U10>::new(&lea128, &nonce, associated_data_len as u64, len as u64)?;
+			decryptor.update_associated_data(&associated_data)?;
+			for chunk in ctxt.chunks(17) {
+				decryptor.verify(chunk)?;
+			}
+			let mut decryptor = decryptor.finalize(&tag)?;
+			let mut buffer = ctxt.clone();
+			for chunk in buffer.chunks_mut(9) {
+				decryptor.decrypt(chunk)?;
+			}
+			decryptor.finalize()?;
+			assert_eq!(buffer, ptxt);
+
+			// Decryption, unverified
+			let mut decryptor = Lea128CcmDecryptor::<U12, U10>::new(&lea128, &nonce, associated_data_len as u64, len as u64)?;
+			decryptor.update_associated_data(&associated_data)?;
+			let mut buffer = ctxt.clone();
+			for chunk in buffer.chunks_mut(9) {
+				decryptor.decrypt_unverified(chunk)?;
+			}
+			decryptor.finalize(&tag)?;
+			assert_eq!(buffer, ptxt);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128ccm_incremental_rejection() -> Result<(), Error> {
+		let lea128 = Lea128::new(&Default::default());
+		let nonce = Default::default();
+		let ptxt = [0; 40];
+
+		// Lengths differ from declared
+		let mut encryptor = Lea128CcmEncryptor::<U13>::new(&lea128, &nonce, 3, 40)?;
+		assert!(encryptor.encrypt(&mut [0; 40]).is_err());
+		assert!(encryptor.update_associated_data(&[1, 2, 3, 4]).is_err());
+		encryptor.update_associated_data(&[1, 2, 3])?;
+		assert!(encryptor.encrypt(&mut [0; 41]).is_err());
+		encryptor.encrypt(&mut [0; 39])?;
+		assert!(Lea128CcmEncryptor::<U13>::new(&lea128, &nonce, 0, 0x10000).is_err());
+
+		let mut encryptor = Lea128CcmEncryptor::<U13>::new(&lea128, &nonce, 3, 40)?;
+		encryptor.update_associated_data(&[1, 2, 3])?;
+		let mut ctxt = ptxt;
+		encryptor.encrypt(&mut ctxt)?;
+		let tag = encryptor.finalize()?;
+
+		// Tampered ciphertext
+		let mut decryptor = Lea128CcmDecryptor::<U13>::new(&lea128, &nonce, 3, 40)?;
+		decryptor.update_associated_data(&[1, 2, 3])?;
+		let mut buffer = ctxt;
+		buffer[0] ^= 1;
+		decryptor.verify(&buffer)?;
+		assert!(decryptor.finalize(&tag).is_err());
+
+		// Truncated ciphertext
+		let mut decryptor = Lea128CcmDecryptor::<U13>::new(&lea128, &nonce, 3, 40)?;
+		decryptor.update_associated_data(&[1, 2, 3])?;
+		decryptor.verify(&ctxt[..32])?;
+		assert!(decryptor.finalize(&tag).is_err());
+
+		// Verified decryption does not go past authenticated ciphertext
+		let mut decryptor = Lea128CcmDecryptor::<U13>::new(&lea128, &nonce, 3, 40)?;
+		decryptor.update_associated_data(&[1, 2, 3])?;
+		decryptor.verify(&ctxt)?;
+		let mut decryptor = decryptor.finalize(&tag)?;
+		let mut buffer = [ctxt.as_slice(), &[0]].concat();
+		assert!(decryptor.decrypt(&mut buffer).is_err());
+		decryptor.decrypt(&mut buffer[..40])?;
+		decryptor.finalize()?;
+		assert_eq!(buffer[..40], ptxt);
+
+		// Ciphertext modified or truncated between the passes
+		let mut decryptor = Lea128CcmDecryptor::<U13>::new(&lea128, &nonce, 3, 40)?;
+		decryptor.update_associated_data(&[1, 2, 3])?;
+		decryptor.verify(&ctxt)?;
+		let mut decryptor = decryptor.finalize(&tag)?;
+		let mut buffer = ctxt;
+		buffer[0] ^= 1;
+		decryptor.decrypt(&mut buffer)?;
+		assert!(decryptor.finalize().is_err());
+
+		let mut decryptor = Lea128CcmDecryptor::<U13>::new(&lea128, &nonce, 3, 40)?;
+		decryptor.update_associated_data(&[1, 2, 3])?;
+		decryptor.verify(&ctxt)?;
+		let mut decryptor = decryptor.finalize(&tag)?;
+		let mut buffer = ctxt;
+		decryptor.decrypt(&mut buffer[..39])?;
+		assert!(decryptor.finalize().is_err());
+
+		Ok(())
+	}
 }
@@ -0,0 +1,545 @@
+// Copyright © 2023 남기훈 <gihunnam@proton.me>
+//
+// This file and its content are subject to the terms of the MIT License (the "License").
+// If a copy of the License was not distributed with this file, you can obtain one at https://opensource.org/licenses/MIT.
+
+//! `tokio_util::codec` framing with LEA-AEAD
+//!
+//! [`AeadCodec`] is an [`Encoder`] and a [`Decoder`] that seal each frame with an AEAD such as LEA-GCM or LEA-CCM,
+//! for use with [`Framed`](tokio_util::codec::Framed) over a reliable, ordered transport such as TCP.
+//!
+//! * Frame
+//!
+//! A frame is the length of the rest of the frame as a 32-bit big-endian integer,
+//! followed by the ciphertext of the message and the tag.
+//! The length is the associated data, and the nonce is 0x00..00 \|\| \[seq\]<sub>64</sub>,
+//! where seq is the number of frames sent before under the current key, so nonces are never sent.
+//! A replayed, dropped, reordered, or modified frame fails to authenticate,
+//! after which the decoder fails for good, as the sequence numbers of both sides no longer agree.
+//!
+//! * Keys
+//!
+//! Each direction has its own chain key, derived from the shared key with the LEA-CMAC KDF in counter mode ([`crate::kdf`]),
+//! with the label `LEA AEAD codec` and the context `initiator` or `responder` after the [`Role`] of the sender,
+//! so the two sides never use the same key and nonce, and a frame reflected to its sender fails to authenticate.
+//! The key of the frames is derived from the chain key with the label `LEA AEAD codec frame key` and an empty context.
+//!
+//! After every [`with_rekey_interval`](AeadCodec::with_rekey_interval) frames in a direction,
+//! the chain key is replaced by the key derived from it with the label `LEA AEAD codec key update` and an empty context,
+//! and seq starts again from 0.
+//! The old keys are erased, so a compromise of the current keys does not reveal the earlier frames.
+//!
+//! Both sides must use the same rekey interval and the other role.
+//!
+//! * Example
+//! ```
+//! use futures::{SinkExt, StreamExt};
+//! use lea::{codec::{Lea128GcmCodec, Role}, prelude::*};
+//! use tokio::net::{TcpListener, TcpStream};
+//! use tokio_util::codec::Framed;
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> std::io::Result<()> {
+//! let key = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+//! let listener = TcpListener::bind("127.0.0.1:0").await?;
+//! let (client, server) = tokio::try_join!(TcpStream::connect(listener.local_addr()?), listener.accept())?;
+//!
+//! let mut client = Framed::new(client, Lea128GcmCodec::new(&key, Role::Initiator).with_rekey_interval(1000));
+//! let mut server = Framed::new(server.0, Lea128GcmCodec::new(&key, Role::Responder).with_rekey_interval(1000));
+//!
+//! client.send(&b"ping"[..]).await?;
+//! let request = server.next().await.unwrap()?;
+//! assert_eq!(request, &b"ping"[..]);
+//!
+//! server.send(&b"pong"[..]).await?;
+//! let response = client.next().await.unwrap()?;
+//! assert_eq!(response, &b"pong"[..]);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::io;
+
+use aead::{generic_array::typenum::{IsGreaterOrEqual, True, Unsigned, U8, U16}, AeadInPlace, Key, NewAead, Nonce, Tag};
+use bytes::{Buf, BufMut, BytesMut};
+use cipher::{BlockCipher, BlockCipherKey, BlockEncrypt, NewBlockCipher};
+use tokio_util::codec::{Decoder, Encoder};
+use zeroize::Zeroize;
+
+use crate::kdf::{Kdf, Mode};
+use crate::{Lea128, Lea192, Lea256, Lea128Ccm, Lea192Ccm, Lea256Ccm, Lea128Gcm, Lea192Gcm, Lea256Gcm};
+
+pub type Lea128GcmCodec = AeadCodec<Lea128, Lea128Gcm>;
+pub type Lea192GcmCodec = AeadCodec<Lea192, Lea192Gcm>;
+pub type Lea256GcmCodec = AeadCodec<Lea256, Lea256Gcm>;
+
+pub type Lea128CcmCodec<NonceSize, TagSize = U16> = AeadCodec<Lea128, Lea128Ccm<NonceSize, TagSize>>;
+pub type Lea192CcmCodec<NonceSize, TagSize = U16> = AeadCodec<Lea192, Lea192Ccm<NonceSize, TagSize>>;
+pub type Lea256CcmCodec<NonceSize, TagSize = U16> = AeadCodec<Lea256, Lea256Ccm<NonceSize, TagSize>>;
+
+/// Size of the length at the start of each frame in bytes
+pub const HEADER_SIZE: usize = 4;
+
+/// Default largest message length in bytes
+pub const DEFAULT_MAX_FRAME_LEN: usize = 8 * 1024 * 1024;
+
+const LABEL: &[u8] = b"LEA AEAD codec";
+const FRAME_KEY_LABEL: &[u8] = b"LEA AEAD codec frame key";
+const KEY_UPDATE_LABEL: &[u8] = b"LEA AEAD codec key update";
+
+/// Side of the connection, which must differ between the two peers
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Role {
+	/// Side that opened the connection, e.g. the client
+	Initiator,
+	/// Side that accepted the connection, e.g. the server
+	Responder
+}
+
+impl Role {
+	fn context(self) -> &'static [u8] {
+		match self {
+			Self::Initiator => b"initiator",
+			Self::Responder => b"responder"
+		}
+	}
+}
+
+/// [`Encoder`] and [`Decoder`] of frames sealed with `A`, with the keys derived with the LEA-CMAC KDF of `C`
+pub struct AeadCodec<C, A> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+A: AeadInPlace + NewAead,
+A::NonceSize: IsGreaterOrEqual<U8, Output = True> {
+	sender: Direction<C, A>,
+	receiver: Direction<C, A>,
+	rekey_interval: u64,
+	max_frame_len: usize,
+	/// Whether a frame has been rejected
+	failed: bool
+}
+
+impl<C, A> AeadCodec<C, A> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+A: AeadInPlace + NewAead,
+A::NonceSize: IsGreaterOrEqual<U8, Output = True> {
+	/// Creates a codec from the key shared by both peers.
+	///
+	/// The rekey interval is [`u64::MAX`] frames and the largest message length is [`DEFAULT_MAX_FRAME_LEN`] bytes.
+	pub fn new(key: &BlockCipherKey<C>, role: Role) -> Self {
+		let (sender, receiver) = match role {
+			Role::Initiator => (Role::Initiator, Role::Responder),
+			Role::Responder => (Role::Responder, Role::Initiator)
+		};
+
+		Self {
+			sender: Direction::new(key, sender),
+			receiver: Direction::new(key, receiver),
+			rekey_interval: u64::MAX,
+			max_frame_len: DEFAULT_MAX_FRAME_LEN,
+			failed: false
+		}
+	}
+
+	/// Sets the number of frames in each direction after which the keys of that direction are updated.
+	///
+	/// # Panics
+	/// Panics if `frames` is 0.
+	pub fn with_rekey_interval(mut self, frames: u64) -> Self {
+		assert!(frames > 0, "rekey interval must not be 0");
+		self.rekey_interval = frames;
+
+		self
+	}
+
+	/// Sets the largest message length in bytes, beyond which frames are neither encoded nor decoded.
+	///
+	/// # Panics
+	/// Panics if the frame length would not fit in 32 bits.
+	pub fn with_max_frame_len(mut self, len: usize) -> Self {
+		assert!(len as u64 + A::TagSize::U64 <= u32::MAX as u64, "frame length must fit in 32 bits");
+		self.max_frame_len = len;
+
+		self
+	}
+
+	pub fn rekey_interval(&self) -> u64 {
+		self.rekey_interval
+	}
+
+	pub fn max_frame_len(&self) -> usize {
+		self.max_frame_len
+	}
+}
+
+impl<C, A, T> Encoder<T> for AeadCodec<C, A> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+A: AeadInPlace + NewAead,
+A::NonceSize: IsGreaterOrEqual<U8, Output = True>,
+T: AsRef<[u8]> {
+	type Error = io::Error;
+
+	fn encode(&mut self, item: T, dst: &mut BytesMut) -> io::Result<()> {
+		let item = item.as_ref();
+		if item.len() > self.max_frame_len {
+			return Err(io::Error::new(io::ErrorKind::InvalidInput, "frame too long"));
+		}
+
+		let len = item.len() + A::TagSize::USIZE;
+		let header = (len as u32).to_be_bytes();
+		dst.reserve(HEADER_SIZE + len);
+		dst.put_slice(&header);
+		let start = dst.len();
+		dst.put_slice(item);
+
+		let (aead, nonce) = self.sender.next(self.rekey_interval);
+		match aead.encrypt_in_place_detached(&nonce, &header, &mut dst[start..]) {
+			Ok(tag) => {
+				dst.put_slice(&tag);
+
+				Ok(())
+			},
+			Err(_) => {
+				// The frame is not sent, so its nonce is left for the next one.
+				self.sender.seq -= 1;
+				dst.truncate(start - HEADER_SIZE);
+
+				Err(io::Error::new(io::ErrorKind::InvalidInput, "frame too long"))
+			}
+		}
+	}
+}
+
+impl<C, A> Decoder for AeadCodec<C, A> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+A: AeadInPlace + NewAead,
+A::NonceSize: IsGreaterOrEqual<U8, Output = True> {
+	type Item = BytesMut;
+	type Error = io::Error;
+
+	fn decode(&mut self, src: &mut BytesMut) -> io::Result<Option<BytesMut>> {
+		if self.failed {
+			return Err(invalid_data("frame rejected before"));
+		}
+		if src.len() < HEADER_SIZE {
+			return Ok(None);
+		}
+
+		let header: [u8; HEADER_SIZE] = src[..HEADER_SIZE].try_into().unwrap();
+		let len = u32::from_be_bytes(header) as usize;
+		if len < A::TagSize::USIZE || len - A::TagSize::USIZE > self.max_frame_len {
+			self.failed = true;
+			return Err(invalid_data("invalid frame length"));
+		}
+		if src.len() < HEADER_SIZE + len {
+			src.reserve(HEADER_SIZE + len - src.len());
+			return Ok(None);
+		}
+
+		src.advance(HEADER_SIZE);
+		let mut frame = src.split_to(len);
+		let tag = frame.split_off(len - A::TagSize::USIZE);
+
+		let (aead, nonce) = self.receiver.next(self.rekey_interval);
+		match aead.decrypt_in_place_detached(&nonce, &header, &mut frame, Tag::<A>::from_slice(&tag)) {
+			Ok(()) => Ok(Some(frame)),
+			Err(_) => {
+				self.failed = true;
+
+				Err(invalid_data("frame failed to authenticate"))
+			}
+		}
+	}
+}
+
+/// Keys and sequence number of the frames sent in one direction
+struct Direction<C, A> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+A: AeadInPlace + NewAead,
+A::NonceSize: IsGreaterOrEqual<U8, Output = True> {
+	chain_key: BlockCipherKey<C>,
+	aead: A,
+	/// Number of frames sent under `aead`
+	seq: u64
+}
+
+impl<C, A> Direction<C, A> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+A: AeadInPlace + NewAead,
+A::NonceSize: IsGreaterOrEqual<U8, Output = True> {
+	fn new(key: &BlockCipherKey<C>, role: Role) -> Self {
+		let mut chain_key = BlockCipherKey::<C>::default();
+		let result = Kdf::<C>::new(key).derive(Mode::Counter, LABEL, role.context(), &mut chain_key);
+		debug_assert!(result.is_ok());
+		let aead = frame_aead::<C, A>(&chain_key);
+
+		Self { chain_key, aead, seq: 0 }
+	}
+
+	/// Returns the AEAD and the nonce of the next frame, updating the keys first if the interval has been reached.
+	fn next(&mut self, rekey_interval: u64) -> (&A, Nonce<A>) {
+		if self.seq >= rekey_interval {
+			let mut chain_key = BlockCipherKey::<C>::default();
+			let result = Kdf::<C>::new(&self.chain_key).derive(Mode::Counter, KEY_UPDATE_LABEL, b"", &mut chain_key);
+			debug_assert!(result.is_ok());
+			self.chain_key.zeroize();
+			self.chain_key = chain_key;
+			self.aead = frame_aead::<C, A>(&self.chain_key);
+			self.seq = 0;
+		}
+
+		let mut nonce = Nonce::<A>::default();
+		let offset = nonce.len() - 8;
+		nonce[offset..].copy_from_slice(&self.seq.to_be_bytes());
+		self.seq += 1;
+
+		(&self.aead, nonce)
+	}
+}
+
+impl<C, A> Drop for Direction<C, A> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+A: AeadInPlace + NewAead,
+A::NonceSize: IsGreaterOrEqual<U8, Output = True> {
+	fn drop(&mut self) {
+		self.chain_key.zeroize();
+	}
+}
+
+/// Derives the key of the frames from `chain_key` and returns `A` keyed with it.
+fn frame_aead<C, A>(chain_key: &BlockCipherKey<C>) -> A where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+A: NewAead {
+	let mut key = Key::<A>::default();
+	let result = Kdf::<C>::new(chain_key).derive(Mode::Counter, FRAME_KEY_LABEL, b"", &mut key);
+	debug_assert!(result.is_ok());
+	let aead = A::new(&key);
+	key.zeroize();
+
+	aead
+}
+
+fn invalid_data(msg: &str) -> io::Error {
+	io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+#[cfg(test)]
+mod tests {
+	use std::io;
+	use std::{vec::Vec, vec};
+
+	use bytes::BytesMut;
+	use futures::{SinkExt, StreamExt};
+	use tokio::io::AsyncWriteExt;
+	use tokio::net::{TcpListener, TcpStream};
+	use tokio_util::codec::{Decoder, Encoder, Framed};
+
+	use crate::{codec::{Lea128CcmCodec, Lea128GcmCodec, Lea256GcmCodec, Role}, prelude::*};
+
+	const MESSAGES: [&[u8]; 3] = [b"first", b"", b"third frame"];
+
+	/// Frames of `MESSAGES` sent by the initiator under the key 0x00..0F with a rekey interval of 2
+	const INITIATOR_FRAMES: [u8; 76] = [
+		0x00, 0x00, 0x00, 0x15, 0x43, 0x5A, 0x23, 0x3D, 0xE5, 0x57, 0x9C, 0xE6, 0x61, 0x1B, 0xD3, 0xEE, 0xEA, 0xC0, 0x0F, 0x62, 0xD5, 0x65, 0xFD, 0xE3, 0x55,
+		0x00, 0x00, 0x00, 0x10, 0x44, 0x16, 0xEF, 0x4F, 0x4D, 0xE2, 0x0B, 0x4A, 0xE0, 0x5D, 0x0A, 0x94, 0x74, 0x19, 0x55, 0x08,
+		0x00, 0x00, 0x00, 0x1B, 0xFB, 0x28, 0xD0, 0xF9, 0x44, 0xA4, 0xC6, 0xCA, 0x68, 0xE0, 0x7D, 0x2D, 0x10, 0xA5, 0xC4, 0xF3, 0x86, 0x51, 0x9A, 0x4A, 0xCE, 0x67, 0xAB, 0x61, 0xEB, 0x7D, 0xC2
+	];
+
+	/// Frames of `MESSAGES` sent by the responder under the key 0x00..0F with a rekey interval of 2
+	const RESPONDER_FRAMES: [u8; 76] = [
+		0x00, 0x00, 0x00, 0x15, 0x02, 0x1A, 0xBA, 0x0A, 0x6E, 0xD2, 0xCE, 0x5A, 0xD6, 0x93, 0x2F, 0xEA, 0xCF, 0xFA, 0x57, 0x45, 0xEC, 0xA8, 0x91, 0xEC, 0x65,
+		0x00, 0x00, 0x00, 0x10, 0x25, 0xA2, 0xFC, 0x00, 0x1C, 0xD4, 0x7C, 0xB8, 0x58, 0x7A, 0xC1, 0x81, 0x00, 0x4E, 0x16, 0x0D,
+		0x00, 0x00, 0x00, 0x1B, 0x95, 0xBC, 0xD2, 0x01, 0xBD, 0x35, 0x1C, 0x6C, 0xE4, 0xC0, 0x65, 0xFC, 0x70, 0xC5, 0x1A, 0x32, 0x57, 0xFC, 0x89, 0x61, 0x4D, 0xEA, 0x0D, 0xD1, 0xC6, 0xE4, 0xD7
+	];
+
+	fn lea128gcm_codec(role: Role) -> Lea128GcmCodec {
+		let key = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+
+		Lea128GcmCodec::new(&key, role).with_rekey_interval(2)
+	}
+
+	/// Decodes `frames` fed one byte at a time until the first error.
+	fn decode_all(codec: &mut Lea128GcmCodec, frames: &[u8]) -> Vec<io::Result<BytesMut>> {
+		let mut src = BytesMut::new();
+		let mut results = Vec::new();
+		for &byte in frames {
+			src.extend_from_slice(&[byte]);
+			match codec.decode(&mut src) {
+				Ok(Some(msg)) => results.push(Ok(msg)),
+				Ok(None) => (),
+				Err(e) => {
+					results.push(Err(e));
+					break;
+				}
+			}
+		}
+
+		results
+	}
+
+	#[test]
+	fn lea128gcm_encode() {
+		for (role, frames) in [(Role::Initiator, INITIATOR_FRAMES), (Role::Responder, RESPONDER_FRAMES)] {
+			let mut codec = lea128gcm_codec(role);
+			let mut dst = BytesMut::new();
+			for msg in MESSAGES {
+				codec.encode(msg, &mut dst).unwrap();
+			}
+			assert_eq!(dst, frames[..]);
+		}
+	}
+
+	#[test]
+	fn lea128gcm_decode() {
+		for (role, frames) in [(Role::Responder, INITIATOR_FRAMES), (Role::Initiator, RESPONDER_FRAMES)] {
+			let results = decode_all(&mut lea128gcm_codec(role), &frames);
+			assert_eq!(results.len(), MESSAGES.len());
+			for (result, msg) in results.into_iter().zip(MESSAGES) {
+				assert_eq!(result.unwrap(), msg);
+			}
+		}
+	}
+
+	#[test]
+	fn lea128gcm_reject() {
+		// Reflected to the sender
+		let results = decode_all(&mut lea128gcm_codec(Role::Initiator), &INITIATOR_FRAMES);
+		assert_eq!(results.len(), 1);
+		assert_eq!(results[0].as_ref().unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+		// Modified after the rekeying, and every frame after it
+		let mut frames = INITIATOR_FRAMES.to_vec();
+		frames[50] ^= 0x01;
+		frames.extend_from_slice(&INITIATOR_FRAMES[..25]);
+		let mut codec = lea128gcm_codec(Role::Responder);
+		let results = decode_all(&mut codec, &frames);
+		assert_eq!(results.len(), 3);
+		assert_eq!(results[2].as_ref().unwrap_err().kind(), io::ErrorKind::InvalidData);
+		assert!(codec.decode(&mut BytesMut::from(&frames[..25])).is_err());
+
+		// Length shorter than the tag
+		let results = decode_all(&mut lea128gcm_codec(Role::Responder), &[0x00, 0x00, 0x00, 0x0F]);
+		assert_eq!(results[0].as_ref().unwrap_err().kind(), io::ErrorKind::InvalidData);
+	}
+
+	#[test]
+	fn encode_too_long() {
+		let key = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+		let mut sender = Lea128CcmCodec::<U13>::new(&key, Role::Initiator).with_max_frame_len(1 << 17);
+		let mut receiver = Lea128CcmCodec::<U13>::new(&key, Role::Responder).with_max_frame_len(1 << 17);
+		let mut dst = BytesMut::new();
+
+		// Longer than the largest message length
+		let e = sender.encode(vec![0; (1 << 17) + 1], &mut dst).unwrap_err();
+		assert_eq!(e.kind(), io::ErrorKind::InvalidInput);
+		// Longer than LEA-CCM with a 13-byte nonce can seal
+		let e = sender.encode(vec![0; 1 << 16], &mut dst).unwrap_err();
+		assert_eq!(e.kind(), io::ErrorKind::InvalidInput);
+		assert!(dst.is_empty());
+
+		// No sequence number is used by the rejected messages.
+		sender.encode(b"after", &mut dst).unwrap();
+		assert_eq!(receiver.decode(&mut dst).unwrap().unwrap(), &b"after"[..]);
+	}
+
+	/// Connects a pair of framed TCP streams over the loopback interface.
+	async fn connect<T>(initiator: T, responder: T) -> io::Result<(Framed<TcpStream, T>, Framed<TcpStream, T>)> {
+		let listener = TcpListener::bind("127.0.0.1:0").await?;
+		let (client, (server, _)) = tokio::try_join!(TcpStream::connect(listener.local_addr()?), listener.accept())?;
+
+		Ok((Framed::new(client, initiator), Framed::new(server, responder)))
+	}
+
+	/// Sends messages of increasing length to the responder, which echoes them back.
+	async fn echo<T>(initiator: T, responder: T) -> io::Result<()> where
+	T: Decoder<Item = BytesMut, Error = io::Error> + Encoder<Vec<u8>, Error = io::Error> + Encoder<BytesMut, Error = io::Error> {
+		let (mut client, mut server) = connect(initiator, responder).await?;
+		let msgs: Vec<Vec<u8>> = (0..10).map(|i| (0..i * 97).map(|j| (i + j) as u8).collect()).collect();
+
+		let sender = async {
+			for msg in &msgs {
+				client.send(msg.clone()).await?;
+				assert_eq!(client.next().await.unwrap()?, msg[..]);
+			}
+
+			Ok::<_, io::Error>(())
+		};
+		let echoer = async {
+			for _ in &msgs {
+				let msg = server.next().await.unwrap()?;
+				server.send(msg).await?;
+			}
+
+			Ok(())
+		};
+		tokio::try_join!(sender, echoer)?;
+
+		Ok(())
+	}
+
+	/// Sends `frames` as is to a responder and returns the messages it decodes, up to the first error.
+	async fn receive(frames: &[u8], responder: Lea128GcmCodec) -> io::Result<Vec<io::Result<BytesMut>>> {
+		let (client, mut server) = connect(lea128gcm_codec(Role::Initiator), responder).await?;
+		let mut client = client.into_inner();
+
+		let sender = async {
+			client.write_all(frames).await?;
+			client.shutdown().await
+		};
+		let receiver = async {
+			let mut results = Vec::new();
+			while let Some(result) = server.next().await {
+				let failed = result.is_err();
+				results.push(result);
+				if failed {
+					break;
+				}
+			}
+
+			Ok(results)
+		};
+
+		Ok(tokio::try_join!(sender, receiver)?.1)
+	}
+
+	#[tokio::test(flavor = "current_thread")]
+	async fn echo_over_tcp() -> io::Result<()> {
+		let key = arr![u8;
+			0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F,
+			0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F
+		];
+		echo(
+			Lea256GcmCodec::new(&key, Role::Initiator).with_rekey_interval(3),
+			Lea256GcmCodec::new(&key, Role::Responder).with_rekey_interval(3)
+		).await?;
+
+		let key = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+		echo(
+			Lea128CcmCodec::<U13>::new(&key, Role::Initiator).with_rekey_interval(4),
+			Lea128CcmCodec::<U13>::new(&key, Role::Responder).with_rekey_interval(4)
+		).await
+	}
+
+	#[tokio::test(flavor = "current_thread")]
+	async fn reject_over_tcp() -> io::Result<()> {
+		let frames: [&[u8]; 3] = [&INITIATOR_FRAMES[..25], &INITIATOR_FRAMES[25..45], &INITIATOR_FRAMES[45..]];
+
+		// In order
+		let results = receive(&INITIATOR_FRAMES, lea128gcm_codec(Role::Responder)).await?;
+		assert_eq!(results.len(), 3);
+		assert!(results.iter().all(Result::is_ok));
+
+		// Replayed, dropped, and reordered
+		for frames in [[frames[0], frames[0]], [frames[0], frames[2]], [frames[1], frames[0]]] {
+			let results = receive(&frames.concat(), lea128gcm_codec(Role::Responder)).await?;
+			assert_eq!(results.last().unwrap().as_ref().unwrap_err().kind(), io::ErrorKind::InvalidData);
+			assert!(results[..results.len() - 1].iter().all(Result::is_ok));
+		}
+
+		// Longer than the largest message length, rejected before it is received
+		let results = receive(&[0x00, 0x00, 0x00, 0x1B], lea128gcm_codec(Role::Responder).with_max_frame_len(10)).await?;
+		assert_eq!(results.len(), 1);
+		assert_eq!(results[0].as_ref().unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+		Ok(())
+	}
+}
@@ -0,0 +1,318 @@
+// Copyright © 2023 남기훈 <gihunnam@proton.me>
+//
+// This file and its content are subject to the terms of the MIT License (the "License").
+// If a copy of the License was not distributed with this file, you can obtain one at https://opensource.org/licenses/MIT.
+
+//! Key-committing LEA-CCM and LEA-GCM
+//!
+//! CCM and GCM are not key-committing: a ciphertext can be crafted to decrypt validly under two different keys.
+//! [`Committing`] wraps an AEAD so that a ciphertext only decrypts under the key it was made with.
+//!
+//! The wrapped AEAD runs under the subkey E<sub>K</sub>(0<sup>120</sup> \|\| 0x02) \|\| E<sub>K</sub>(0<sup>120</sup> \|\| 0x03),
+//! truncated to its key size, so that the key K itself is used only for the subkey and the commitment.
+//! The 32-byte commitment is E<sub>K</sub>(N \|\| 0x00) \|\| E<sub>K</sub>(N \|\| 0x01), where N is the nonce zero-padded to 15 bytes.
+//! Finding two keys with the same commitment takes about 2<sup>128</sup> block cipher calls.
+//!
+//! The tag is the commitment followed by the tag of the wrapped AEAD.
+//! `encrypt_in_place` and `decrypt_in_place` put the commitment in front of the ciphertext and the wrapped tag after it.
+//! The commitment is checked before anything is decrypted.
+//!
+//! * Example
+//! ```
+//! use lea::{committing::aead, prelude::*, Lea128CommittingGcm};
+//!
+//! let key = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+//! let nonce = arr![u8; 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B];
+//! let associated_data = b"header";
+//! let ptxt = b"plaintext";
+//! let ctxt = [
+//!     0x8F, 0xA4, 0x58, 0x1A, 0xCA, 0x3B, 0xC8, 0xC6, 0xDD, 0x1F, 0x84, 0xF7, 0xB8, 0xB3, 0x5B, 0x06,
+//!     0x7D, 0x99, 0xDB, 0xEA, 0xF6, 0x24, 0xF7, 0xF1, 0x60, 0xDB, 0x62, 0xBC, 0x73, 0x5F, 0xA6, 0xA7,
+//!     0xF4, 0x9F, 0xA7, 0x0E, 0x25, 0x1D, 0x39, 0xEA, 0x4F, 0x02, 0xDA, 0x01, 0xA2, 0xE3, 0x36, 0x72,
+//!     0xF5, 0x9E, 0x4B, 0x6F, 0x44, 0xD3, 0x17, 0x8E, 0x2E
+//! ];
+//!
+//! let lea128committing_gcm = Lea128CommittingGcm::new(&key);
+//!
+//! // Encryption
+//! let mut buffer = ptxt.to_vec();
+//! lea128committing_gcm.encrypt_in_place(&nonce, associated_data, &mut buffer)?;
+//! assert_eq!(buffer, ctxt);
+//!
+//! // Decryption
+//! lea128committing_gcm.decrypt_in_place(&nonce, associated_data, &mut buffer)?;
+//! assert_eq!(buffer, ptxt);
+//!
+//! // Decryption with another key
+//! let mut buffer = ctxt.to_vec();
+//! let lea128committing_gcm = Lea128CommittingGcm::new(&Default::default());
+//! assert!(lea128committing_gcm.decrypt_in_place(&nonce, associated_data, &mut buffer).is_err());
+//! # Ok::<(), aead::Error>(())
+//! ```
+
+pub use aead;
+
+use core::ops::Add;
+
+use aead::consts::{U12, U15, U16, U32};
+use aead::generic_array::{typenum::{IsLessOrEqual, Sum, True, Unsigned}, ArrayLength, GenericArray};
+use aead::{AeadCore, AeadInPlace, Buffer, Error, Key, NewAead, Nonce, Tag};
+use cipher::{BlockCipher, BlockEncrypt, NewBlockCipher};
+use subtle::ConstantTimeEq;
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+use crate::{Lea128, Lea192, Lea256, Lea128Ccm, Lea192Ccm, Lea256Ccm, Lea128Gcm, Lea192Gcm, Lea256Gcm};
+
+pub type Lea128CommittingCcm<NonceSize, TagSize = U16> = Committing<Lea128, Lea128Ccm<NonceSize, TagSize>>;
+pub type Lea192CommittingCcm<NonceSize, TagSize = U16> = Committing<Lea192, Lea192Ccm<NonceSize, TagSize>>;
+pub type Lea256CommittingCcm<NonceSize, TagSize = U16> = Committing<Lea256, Lea256Ccm<NonceSize, TagSize>>;
+
+pub type Lea128CommittingGcm<NonceSize = U12> = Committing<Lea128, Lea128Gcm<NonceSize>>;
+pub type Lea192CommittingGcm<NonceSize = U12> = Committing<Lea192, Lea192Gcm<NonceSize>>;
+pub type Lea256CommittingGcm<NonceSize = U12> = Committing<Lea256, Lea256Gcm<NonceSize>>;
+
+type Block = GenericArray<u8, U16>;
+
+/// Size of the commitment in bytes
+pub const COMMITMENT_SIZE: usize = 32;
+
+/// Key-committing AEAD
+///
+/// * `C`: Block cipher for the subkey and the commitment
+/// * `A`: Wrapped AEAD, whose nonce must be at most 15 bytes long
+pub struct Committing<C, A> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	cipher: C,
+	aead: A
+}
+
+impl<C, A> Committing<C, A> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	fn commitment(&self, nonce: &[u8]) -> GenericArray<u8, U32> {
+		let mut commitment = GenericArray::<u8, U32>::default();
+		let (block0, block1) = commitment.split_at_mut(16);
+		block0[..nonce.len()].copy_from_slice(nonce);
+		block1[..nonce.len()].copy_from_slice(nonce);
+		block1[15] = 0x01;
+		self.cipher.encrypt_block(Block::from_mut_slice(block0));
+		self.cipher.encrypt_block(Block::from_mut_slice(block1));
+
+		commitment
+	}
+}
+
+impl<C, A> From<C> for Committing<C, A> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+A: NewAead,
+A::KeySize: IsLessOrEqual<U32, Output = True> {
+	fn from(cipher: C) -> Self {
+		let mut subkey = GenericArray::<u8, U32>::default();
+		let (block0, block1) = subkey.split_at_mut(16);
+		block0[15] = 0x02;
+		block1[15] = 0x03;
+		cipher.encrypt_block(Block::from_mut_slice(block0));
+		cipher.encrypt_block(Block::from_mut_slice(block1));
+
+		let aead = A::new(Key::<A>::from_slice(&subkey[..A::KeySize::USIZE]));
+		#[cfg(feature = "zeroize")]
+		subkey.zeroize();
+
+		Self { cipher, aead }
+	}
+}
+
+impl<C, A> NewAead for Committing<C, A> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+A: NewAead,
+A::KeySize: IsLessOrEqual<U32, Output = True> {
+	type KeySize = C::KeySize;
+
+	fn new(key: &Key<Self>) -> Self {
+		Self::from(C::new(key))
+	}
+}
+
+impl<C, A> AeadCore for Committing<C, A> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+A: AeadCore,
+A::NonceSize: IsLessOrEqual<U15, Output = True>,
+U32: Add<A::TagSize>,
+Sum<U32, A::TagSize>: ArrayLength<u8> {
+	type NonceSize = A::NonceSize;
+	type TagSize = Sum<U32, A::TagSize>;
+	type CiphertextOverhead = A::CiphertextOverhead;
+}
+
+impl<C, A> AeadInPlace for Committing<C, A> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+A: AeadInPlace,
+A::NonceSize: IsLessOrEqual<U15, Output = True>,
+U32: Add<A::TagSize>,
+Sum<U32, A::TagSize>: ArrayLength<u8> {
+	/// Encrypts `buffer` in place, inserts the commitment in front of it, and appends the tag of the wrapped AEAD.
+	fn encrypt_in_place(&self, nonce: &Nonce<Self>, associated_data: &[u8], buffer: &mut dyn Buffer) -> Result<(), Error> {
+		self.aead.encrypt_in_place(nonce, associated_data, buffer)?;
+
+		let len = buffer.len();
+		buffer.extend_from_slice(&[0; COMMITMENT_SIZE])?;
+		buffer.as_mut().copy_within(..len, COMMITMENT_SIZE);
+		buffer.as_mut()[..COMMITMENT_SIZE].copy_from_slice(&self.commitment(nonce));
+
+		Ok(())
+	}
+
+	fn encrypt_in_place_detached(&self, nonce: &Nonce<Self>, associated_data: &[u8], buffer: &mut [u8]) -> Result<Tag<Self>, Error> {
+		let aead_tag = self.aead.encrypt_in_place_detached(nonce, associated_data, buffer)?;
+
+		let mut tag = Tag::<Self>::default();
+		tag[..COMMITMENT_SIZE].copy_from_slice(&self.commitment(nonce));
+		tag[COMMITMENT_SIZE..].copy_from_slice(&aead_tag);
+
+		Ok(tag)
+	}
+
+	/// Decrypts `buffer` in place, which starts with the commitment and ends with the tag of the wrapped AEAD, and removes both.
+	fn decrypt_in_place(&self, nonce: &Nonce<Self>, associated_data: &[u8], buffer: &mut dyn Buffer) -> Result<(), Error> {
+		let len = buffer.len();
+		if len < COMMITMENT_SIZE || !bool::from(self.commitment(nonce).ct_eq(&buffer.as_ref()[..COMMITMENT_SIZE])) {
+			return Err(Error);
+		}
+
+		buffer.as_mut().copy_within(COMMITMENT_SIZE.., 0);
+		buffer.truncate(len - COMMITMENT_SIZE);
+
+		self.aead.decrypt_in_place(nonce, associated_data, buffer)
+	}
+
+	fn decrypt_in_place_detached(&self, nonce: &Nonce<Self>, associated_data: &[u8], buffer: &mut [u8], tag: &Tag<Self>) -> Result<(), Error> {
+		let (commitment, aead_tag) = tag.split_at(COMMITMENT_SIZE);
+		if !bool::from(self.commitment(nonce).ct_eq(commitment)) {
+			return Err(Error);
+		}
+
+		self.aead.decrypt_in_place_detached(nonce, associated_data, buffer, Tag::<A>::from_slice(aead_tag))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	extern crate alloc;
+
+	use alloc::{vec::Vec, vec};
+
+	use cipher::{BlockCipher, BlockCipherKey, NewBlockCipher};
+
+	use crate::committing::{aead::{Error, Nonce}, COMMITMENT_SIZE};
+	use crate::{prelude::*, Lea128, Lea128Gcm, Lea128CommittingCcm, Lea128CommittingGcm};
+
+	struct TestCase<T> where
+	T: BlockCipher + NewBlockCipher {
+		key: BlockCipherKey<T>,
+		nonce: GenericArray<u8, U12>,
+		associated_data: Vec<u8>,
+		ptxt: Vec<u8>,
+		ctxt: Vec<u8>
+	}
+
+	#[test]
+	fn lea128committing_gcm() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea128>; 3] = [
+			TestCase {
+				key: arr![u8; 0x09, 0x49, 0xB2, 0xC3, 0x24, 0xF5, 0x76, 0x5E, 0xAB, 0xB2, 0xCA, 0x18, 0x74, 0x99, 0x7F, 0x9B],
+				nonce: arr![u8; 0x04, 0x83, 0x6E, 0x93, 0x5F, 0x9F, 0xDA, 0xF2, 0x8C, 0xE2, 0xC1, 0xF3],
+				associated_data: vec![],
+				ptxt: vec![],
+				ctxt: vec![0x35, 0x9C, 0x0F, 0x2A, 0x65, 0x40, 0xF5, 0xE5, 0xB9, 0xAB, 0xB1, 0x24, 0x44, 0x9F, 0x53, 0x77, 0x02, 0x22, 0x93, 0x30, 0x1C, 0xA4, 0x6A, 0x6C, 0x8C, 0x2D, 0x0F, 0xBD, 0x25, 0x0E, 0x0B, 0x6B, 0x4F, 0xF5, 0x33, 0x0F, 0x78, 0x86, 0x9A, 0x9A, 0x37, 0x33, 0xDA, 0x18, 0xEF, 0x91, 0x7E, 0xB5]
+			},
+			TestCase {
+				key: arr![u8; 0x6F, 0x64, 0x2D, 0x0E, 0xD9, 0x1B, 0xD1, 0x1F, 0x60, 0x27, 0x18, 0x29, 0x83, 0xD5, 0x7E, 0x20],
+				nonce: arr![u8; 0xC5, 0x63, 0x98, 0xFA, 0x81, 0x45, 0x93, 0x2E, 0x60, 0x10, 0x0C, 0x94],
+				associated_data: vec![0x0F, 0x2F, 0xF1, 0x0F, 0xD6],
+				ptxt: vec![0x2A, 0x4B, 0x46, 0xE3, 0x13, 0x85, 0x9F, 0x1A, 0xFC, 0xDB, 0xD0, 0x95, 0xA7, 0xEE, 0xA6, 0xAD],
+				ctxt: vec![0x8A, 0xD9, 0x85, 0x20, 0x7B, 0x97, 0x56, 0x75, 0x55, 0xDC, 0x45, 0x75, 0xBA, 0x14, 0x44, 0xBA, 0xD4, 0x3C, 0xB9, 0xC8, 0xBE, 0x60, 0xA0, 0x9A, 0x56, 0x6D, 0x22, 0xE6, 0x3A, 0x8B, 0xA9, 0x2A, 0x51, 0x5D, 0x3E, 0x11, 0x38, 0x67, 0x49, 0x92, 0x23, 0x4C, 0x03, 0xA6, 0x08, 0xE7, 0x6F, 0xB7, 0x72, 0x6F, 0x24, 0xE8, 0x43, 0x9C, 0x8F, 0x4E, 0xBA, 0x10, 0x59, 0x8D, 0xB2, 0x4B, 0xEE, 0x7B]
+			},
+			TestCase {
+				key: arr![u8; 0x0F, 0x18, 0xD8, 0x9F, 0x75, 0xC9, 0xA5, 0xE6, 0xB9, 0x54, 0x54, 0x87, 0xAA, 0xB3, 0x70, 0x2A],
+				nonce: arr![u8; 0x20, 0xD9, 0x14, 0xC7, 0x51, 0x0C, 0xE0, 0x97, 0x44, 0x5E, 0xBA, 0x2F],
+				associated_data: vec![0xAC, 0x9A, 0x12, 0xF8, 0xFE, 0x8D, 0xA9, 0x1D, 0x4C, 0x7E, 0xE6, 0xC7, 0xBA, 0xF4, 0x5C, 0xB7, 0x6F, 0x0A, 0x79, 0xF7],
+				ptxt: vec![0x6A, 0x9A, 0xC6, 0x15, 0x5B, 0x20, 0xA5, 0x16, 0xDF, 0xAA, 0xBB, 0x27, 0x92, 0xCE, 0x4E, 0x1B, 0xB0, 0x89, 0x6C, 0x71, 0x96, 0x09, 0x25, 0x00, 0x1B, 0xE7, 0xCA, 0xFA, 0xD3, 0x1F, 0x20, 0xBC, 0x04, 0xFD, 0xC5, 0xE7, 0xD2, 0xE2, 0xC7, 0x56, 0x1E, 0xB7, 0x88, 0x7F, 0x42],
+				ctxt: vec![0xE8, 0x26, 0xBA, 0x04, 0x5F, 0xB4, 0x48, 0x93, 0x3F, 0x8F, 0x3D, 0x16, 0xDB, 0x93, 0xF2, 0x13, 0xF3, 0x7F, 0x74, 0xD6, 0x31, 0x1B, 0x5F, 0xDF, 0x08, 0x66, 0x37, 0xED, 0x8B, 0x6B, 0x50, 0xA8, 0x68, 0x1F, 0xC3, 0x61, 0x44, 0x52, 0x85, 0xA6, 0x87, 0x9E, 0xE2, 0xA8, 0x03, 0x4F, 0x7A, 0xDF, 0xCC, 0xF2, 0x42, 0x85, 0x8D, 0xBB, 0xB6, 0x0E, 0xF1, 0x20, 0x21, 0xBE, 0x50, 0xFD, 0x55, 0x4B, 0x97, 0x60, 0x4D, 0x11, 0x25, 0xAE, 0x8B, 0xD8, 0xFA, 0x5B, 0xCB, 0x7A, 0x32, 0xA6, 0x29, 0xF4, 0x25, 0x41, 0xB5, 0x74, 0xB4, 0x8E, 0xDA, 0xAD, 0x3F, 0x01, 0xD1, 0xD2, 0xF1]
+			}
+		];
+
+		for TestCase { key, nonce, associated_data, ptxt, ctxt } in test_cases {
+			let lea128committing_gcm = Lea128CommittingGcm::new(&key);
+
+			// Encryption
+			let mut buffer = ptxt.clone();
+			lea128committing_gcm.encrypt_in_place(&nonce, &associated_data, &mut buffer)?;
+			assert_eq!(buffer, ctxt);
+
+			// Decryption
+			lea128committing_gcm.decrypt_in_place(&nonce, &associated_data, &mut buffer)?;
+			assert_eq!(buffer, ptxt);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128gcm_is_not_key_committing() -> Result<(), Error> {
+		let key1 = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+		let key2 = arr![u8; 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F];
+		let nonce = Nonce::<Lea128Gcm>::default();
+		let ctxt = [0x6D, 0x75, 0x6C, 0x74, 0x69, 0x2D, 0x72, 0x65, 0x63, 0x69, 0x70, 0x69, 0x65, 0x6E, 0x74, 0x21, 0x92, 0x05, 0x06, 0xDD, 0xC3, 0x28, 0x84, 0x50, 0x47, 0xE1, 0x72, 0x5A, 0x5C, 0x0A, 0x85, 0x98];
+		let tag = arr![u8; 0x82, 0x72, 0x1C, 0xEA, 0x2C, 0x72, 0x0C, 0x07, 0x74, 0x1E, 0x36, 0x13, 0xAB, 0x4B, 0xF0, 0x90];
+
+		// Both keys accept the same ciphertext
+		Lea128Gcm::new(&key1).decrypt_in_place_detached(&nonce, &[], &mut ctxt.clone(), &tag)?;
+		Lea128Gcm::new(&key2).decrypt_in_place_detached(&nonce, &[], &mut ctxt.clone(), &tag)?;
+
+		// Only the key used for encryption accepts a committing ciphertext
+		let mut buffer = ctxt.to_vec();
+		Lea128CommittingGcm::new(&key1).encrypt_in_place(&nonce, &[], &mut buffer)?;
+		assert_eq!(Lea128CommittingGcm::new(&key2).decrypt_in_place(&nonce, &[], &mut buffer.clone()), Err(Error));
+		Lea128CommittingGcm::new(&key1).decrypt_in_place(&nonce, &[], &mut buffer)?;
+		assert_eq!(buffer, ctxt);
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128committing_ccm() -> Result<(), Error> {
+		let key1 = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+		let key2 = arr![u8; 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F];
+		let nonce = arr![u8; 0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2A, 0x2B, 0x2C];
+		let ptxt = *b"multi-recipient message";
+
+		let lea128committing_ccm = Lea128CommittingCcm::<U13, U8>::new(&key1);
+
+		// Detached
+		let mut buffer = ptxt;
+		let tag = lea128committing_ccm.encrypt_in_place_detached(&nonce, b"header", &mut buffer)?;
+		assert_eq!(tag.len(), COMMITMENT_SIZE + 8);
+		let ctxt = buffer;
+		assert_eq!(Lea128CommittingCcm::<U13, U8>::new(&key2).decrypt_in_place_detached(&nonce, b"header", &mut buffer, &tag), Err(Error));
+		assert_eq!(buffer, ctxt);
+		lea128committing_ccm.decrypt_in_place_detached(&nonce, b"header", &mut buffer, &tag)?;
+		assert_eq!(buffer, ptxt);
+
+		// Commitment in front of the ciphertext
+		let mut buffer = ptxt.to_vec();
+		lea128committing_ccm.encrypt_in_place(&nonce, b"header", &mut buffer)?;
+		assert_eq!(buffer[..COMMITMENT_SIZE], tag[..COMMITMENT_SIZE]);
+		assert_eq!(buffer[COMMITMENT_SIZE..(COMMITMENT_SIZE + ptxt.len())], ctxt);
+		assert_eq!(buffer[(COMMITMENT_SIZE + ptxt.len())..], tag[COMMITMENT_SIZE..]);
+
+		let mut tampered = buffer.clone();
+		tampered[0] ^= 1;
+		assert_eq!(lea128committing_ccm.decrypt_in_place(&nonce, b"header", &mut tampered), Err(Error));
+		assert_eq!(lea128committing_ccm.decrypt_in_place(&nonce, b"header", &mut buffer[..(COMMITMENT_SIZE - 1)].to_vec()), Err(Error));
+
+		lea128committing_ccm.decrypt_in_place(&nonce, b"header", &mut buffer)?;
+		assert_eq!(buffer, ptxt);
+
+		Ok(())
+	}
+}
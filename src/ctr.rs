@@ -28,10 +28,47 @@
 //! lea128ctr.apply_keystream(&mut block);
 //! assert_eq!(block, ptxt);
 //! ```
+//!
+//! * Counter layouts
+//!
+//! [`Lea128Ctr`] increments the last 64 bits of the counter block as a big-endian integer.
+//! Other layouts are available for interoperability:
+//!
+//! | Type              | Counter                      |
+//! | ----------------- | ---------------------------- |
+//! | `Lea128Ctr`       | Last 64 bits, big-endian     |
+//! | `Lea128Ctr128BE`  | All 128 bits, big-endian     |
+//! | `Lea128Ctr32BE`   | Last 32 bits, big-endian     |
+//! | `Lea128Ctr32LE`   | First 32 bits, little-endian |
+//! | `Lea128Ctr64LE`   | First 64 bits, little-endian |
+//!
+//! The counter wraps around within its own bits, leaving the rest of the counter block unchanged.
+//! Seeking past the end of the counter space fails.
+//!
+//! * Keystream limits
+//!
+//! [`CheckedCtr`] tracks how much keystream has been used,
+//! and fails instead of running past the counter space or a configured limit.
+//! ```
+//! use lea::{ctr::Lea128CheckedCtr, prelude::*};
+//!
+//! let key = arr![u8; 0x7A, 0xD3, 0x6A, 0x75, 0xD5, 0x5F, 0x30, 0x22, 0x09, 0x4E, 0x06, 0xF7, 0xC8, 0x97, 0xD8, 0xBB];
+//! let nonce = arr![u8; 0x0C, 0x5F, 0x04, 0xE8, 0xB5, 0x12, 0x19, 0x5E, 0x74, 0xB3, 0xDE, 0x57, 0xE9, 0x70, 0x97, 0x9E];
+//!
+//! let mut lea128ctr = Lea128CheckedCtr::new(&key, &nonce).with_limit(32);
+//!
+//! let mut buffer = [0; 48];
+//! assert!(lea128ctr.try_apply_keystream(&mut buffer).is_err());
+//! assert!(lea128ctr.try_apply_keystream(&mut buffer[..32]).is_ok());
+//! assert!(lea128ctr.try_apply_keystream(&mut buffer[32..33]).is_err());
+//! assert!(lea128ctr.try_seek(33).is_err());
+//! ```
 
-pub use ctr::cipher;
+pub use ctr::{cipher, flavors};
 
-use ctr::Ctr64BE;
+use core::{convert::TryInto, mem};
+
+use ctr::{cipher::{errors::{LoopError, OverflowError}, generic_array::typenum::Unsigned, Block, BlockEncrypt, FromBlockCipher, SeekNum, StreamCipher, StreamCipherSeek}, flavors::CtrFlavor, Ctr, Ctr128BE, Ctr32BE, Ctr32LE, Ctr64BE, Ctr64LE};
 
 use crate::{Lea128, Lea192, Lea256};
 
@@ -39,13 +76,128 @@ pub type Lea128Ctr = Ctr64BE<Lea128>;
 pub type Lea192Ctr = Ctr64BE<Lea192>;
 pub type Lea256Ctr = Ctr64BE<Lea256>;
 
+pub type Lea128Ctr128BE = Ctr128BE<Lea128>;
+pub type Lea192Ctr128BE = Ctr128BE<Lea192>;
+pub type Lea256Ctr128BE = Ctr128BE<Lea256>;
+
+pub type Lea128Ctr32BE = Ctr32BE<Lea128>;
+pub type Lea192Ctr32BE = Ctr32BE<Lea192>;
+pub type Lea256Ctr32BE = Ctr32BE<Lea256>;
+
+pub type Lea128Ctr32LE = Ctr32LE<Lea128>;
+pub type Lea192Ctr32LE = Ctr32LE<Lea192>;
+pub type Lea256Ctr32LE = Ctr32LE<Lea256>;
+
+pub type Lea128Ctr64LE = Ctr64LE<Lea128>;
+pub type Lea192Ctr64LE = Ctr64LE<Lea192>;
+pub type Lea256Ctr64LE = Ctr64LE<Lea256>;
+
+pub type Lea128CheckedCtr = CheckedCtr<Lea128, flavors::Ctr64BE>;
+pub type Lea192CheckedCtr = CheckedCtr<Lea192, flavors::Ctr64BE>;
+pub type Lea256CheckedCtr = CheckedCtr<Lea256, flavors::Ctr64BE>;
+
+/// CTR mode which fails instead of reusing keystream
+///
+/// Keystream is limited to the counter space of `F` by default, and can be further limited with [`CheckedCtr::with_limit`].
+/// [`StreamCipher::try_apply_keystream`] and [`StreamCipherSeek::try_seek`] fail without side effects if they would go past the limit.
+#[derive(Clone)]
+pub struct CheckedCtr<C, F> where
+C: BlockEncrypt,
+F: CtrFlavor<C::BlockSize> {
+	ctr: Ctr<C, F>,
+	/// Position in bytes
+	pos: u128,
+	/// Limit of `pos`
+	limit: u128
+}
+
+impl<C, F> CheckedCtr<C, F> where
+C: BlockEncrypt,
+F: CtrFlavor<C::BlockSize> {
+	/// Length of keystream in bytes, before the counter wraps around
+	///
+	/// The last counter value is not used, as in [`Ctr`].
+	/// Saturates at `u128::MAX` for 128-bit counters.
+	pub fn max_len() -> u128 {
+		1u128.checked_shl(8 * mem::size_of::<F::Backend>() as u32)
+			.and_then(|blocks| (blocks - 1).checked_mul(C::BlockSize::U8 as u128))
+			.unwrap_or(u128::MAX)
+	}
+
+	/// Limits keystream to `limit` bytes from the beginning.
+	///
+	/// Limits above [`CheckedCtr::max_len`] have no effect.
+	pub fn with_limit(mut self, limit: u128) -> Self {
+		self.limit = limit.min(Self::max_len());
+		self
+	}
+
+	/// Limit of keystream in bytes from the beginning
+	pub fn limit(&self) -> u128 {
+		self.limit
+	}
+
+	/// Keystream left before the limit in bytes
+	pub fn remaining(&self) -> u128 {
+		self.limit - self.pos
+	}
+}
+
+impl<C, F> FromBlockCipher for CheckedCtr<C, F> where
+C: BlockEncrypt,
+F: CtrFlavor<C::BlockSize> {
+	type BlockCipher = C;
+	type NonceSize = C::BlockSize;
+
+	fn from_block_cipher(cipher: C, nonce: &Block<C>) -> Self {
+		Self { ctr: Ctr::from_block_cipher(cipher, nonce), pos: 0, limit: Self::max_len() }
+	}
+}
+
+impl<C, F> StreamCipher for CheckedCtr<C, F> where
+C: BlockEncrypt,
+F: CtrFlavor<C::BlockSize> {
+	fn try_apply_keystream(&mut self, data: &mut [u8]) -> Result<(), LoopError> {
+		if data.len() as u128 > self.remaining() {
+			return Err(LoopError);
+		}
+
+		self.ctr.try_apply_keystream(data)?;
+		self.pos += data.len() as u128;
+
+		Ok(())
+	}
+}
+
+impl<C, F> StreamCipherSeek for CheckedCtr<C, F> where
+C: BlockEncrypt,
+F: CtrFlavor<C::BlockSize> {
+	fn try_current_pos<T: SeekNum>(&self) -> Result<T, OverflowError> {
+		self.pos.try_into().map_err(|_| OverflowError)
+	}
+
+	fn try_seek<T: SeekNum>(&mut self, pos: T) -> Result<(), LoopError> {
+		let pos: u128 = pos.try_into().map_err(|_| LoopError)?;
+		if pos > self.limit {
+			return Err(LoopError);
+		}
+
+		self.ctr.try_seek(pos)?;
+		self.pos = pos;
+
+		Ok(())
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	extern crate alloc;
 
 	use alloc::{vec::Vec, vec};
 
-	use crate::{prelude::*, Lea128Ctr, Lea192Ctr, Lea256Ctr};
+	use cipher::consts::U16;
+
+	use crate::{ctr::{flavors, CheckedCtr, Lea128CheckedCtr, Lea128Ctr128BE, Lea128Ctr32BE, Lea128Ctr32LE, Lea128Ctr64LE, Lea256Ctr128BE}, prelude::*, Lea128, Lea128Ctr, Lea192Ctr, Lea256, Lea256Ctr};
 
 	struct TestCase<T> where
 	T: NewCipher {
@@ -97,6 +249,11 @@ mod tests {
 			let mut block = ctxt.clone();
 			lea128ctr.apply_keystream(&mut block);
 			assert_eq!(block, ptxt);
+
+			// Full 128-bit counter
+			let mut block = ptxt.clone();
+			Lea128Ctr128BE::new(&key, &nonce).apply_keystream(&mut block);
+			assert_eq!(block, ctxt);
 		}
 	}
 
@@ -189,4 +346,135 @@ mod tests {
 			assert_eq!(block, ptxt);
 		}
 	}
+
+	/// Compares the keystream from `nonce` with encrypted `counter_blocks`, also after seeking.
+	fn wraparound<C, S>(key: &GenericArray<u8, S::KeySize>, nonce: &[u8], counter_blocks: &[Vec<u8>]) where
+	C: BlockEncrypt<BlockSize = U16> + NewBlockCipher<KeySize = S::KeySize>,
+	S: NewCipher<NonceSize = U16> + StreamCipher + StreamCipherSeek {
+		let cipher = C::new(key);
+		let keystream: Vec<u8> = counter_blocks.iter().flat_map(|counter_block| {
+			let mut block = GenericArray::clone_from_slice(counter_block);
+			cipher.encrypt_block(&mut block);
+			block
+		}).collect();
+
+		let mut ctr = S::new(key, GenericArray::from_slice(nonce));
+		let mut buffer = vec![0; keystream.len()];
+		ctr.apply_keystream(&mut buffer);
+		assert_eq!(buffer, keystream);
+
+		for pos in [0, 15, 16, 20] {
+			ctr.seek(pos as u64);
+			let mut buffer = vec![0; keystream.len() - pos];
+			ctr.apply_keystream(&mut buffer);
+			assert_eq!(buffer, keystream[pos..]);
+		}
+	}
+
+	#[test]
+	fn lea128ctr_wraparound() {
+		let key = arr![u8; 0x7A, 0xD3, 0x6A, 0x75, 0xD5, 0x5F, 0x30, 0x22, 0x09, 0x4E, 0x06, 0xF7, 0xC8, 0x97, 0xD8, 0xBB];
+
+		wraparound::<Lea128, Lea128Ctr>(&key, &[[0xAA; 8], [0xFF; 8]].concat(), &[
+			[[0xAA; 8], [0xFF; 8]].concat(),
+			[[0xAA; 8], [0x00; 8]].concat(),
+			[&[0xAA; 8][..], &[0x00; 7], &[0x01]].concat()
+		]);
+		wraparound::<Lea128, Lea128Ctr128BE>(&key, &[0xFF; 16], &[
+			[0xFF; 16].to_vec(),
+			[0x00; 16].to_vec(),
+			[&[0x00; 15][..], &[0x01]].concat()
+		]);
+		wraparound::<Lea128, Lea128Ctr128BE>(&key, &[[0x00; 8], [0xFF; 8]].concat(), &[
+			[[0x00; 8], [0xFF; 8]].concat(),
+			[&[0x00; 7][..], &[0x01], &[0x00; 8]].concat(),
+			[&[0x00; 7][..], &[0x01], &[0x00; 7], &[0x01]].concat()
+		]);
+		wraparound::<Lea128, Lea128Ctr32BE>(&key, &[&[0xAA; 12][..], &[0xFF; 4]].concat(), &[
+			[&[0xAA; 12][..], &[0xFF; 4]].concat(),
+			[&[0xAA; 12][..], &[0x00; 4]].concat(),
+			[&[0xAA; 12][..], &[0x00; 3], &[0x01]].concat()
+		]);
+		wraparound::<Lea128, Lea128Ctr32LE>(&key, &[&[0xFF; 4][..], &[0xAA; 12]].concat(), &[
+			[&[0xFF; 4][..], &[0xAA; 12]].concat(),
+			[&[0x00; 4][..], &[0xAA; 12]].concat(),
+			[&[0x01][..], &[0x00; 3], &[0xAA; 12]].concat()
+		]);
+		wraparound::<Lea128, Lea128Ctr64LE>(&key, &[[0xFF; 8], [0xAA; 8]].concat(), &[
+			[[0xFF; 8], [0xAA; 8]].concat(),
+			[[0x00; 8], [0xAA; 8]].concat(),
+			[&[0x01][..], &[0x00; 7], &[0xAA; 8]].concat()
+		]);
+
+		let key = arr![u8; 0xEB, 0xE8, 0xEE, 0x96, 0x66, 0xD0, 0x6D, 0xB7, 0x69, 0xCD, 0xA8, 0xB9, 0x8F, 0x1E, 0xAB, 0x04, 0xE7, 0xA6, 0xA4, 0xA8, 0x99, 0xFB, 0x9F, 0x05, 0xCD, 0xBB, 0x95, 0xCB, 0xC8, 0x1F, 0xA5, 0x26];
+
+		wraparound::<Lea256, Lea256Ctr128BE>(&key, &[[0x00; 8], [0xFF; 8]].concat(), &[
+			[[0x00; 8], [0xFF; 8]].concat(),
+			[&[0x00; 7][..], &[0x01], &[0x00; 8]].concat(),
+			[&[0x00; 7][..], &[0x01], &[0x00; 7], &[0x01]].concat()
+		]);
+	}
+
+	#[test]
+	fn lea128ctr_seek_out_of_range() {
+		let key = Default::default();
+		let nonce = Default::default();
+
+		let mut lea128ctr = Lea128Ctr32BE::new(&key, &nonce);
+		assert!(lea128ctr.try_seek((u32::MAX as u64) << 4).is_ok());
+		assert!(lea128ctr.try_seek(1u64 << 36).is_err());
+
+		let mut lea128ctr = Lea128Ctr32LE::new(&key, &nonce);
+		assert!(lea128ctr.try_seek(1u64 << 36).is_err());
+
+		let mut lea128ctr = Lea128Ctr64LE::new(&key, &nonce);
+		assert!(lea128ctr.try_seek(u64::MAX).is_ok());
+		assert!(lea128ctr.try_seek(1u128 << 68).is_err());
+	}
+
+	#[test]
+	fn lea128ctr_checked() {
+		let key = arr![u8; 0x7A, 0xD3, 0x6A, 0x75, 0xD5, 0x5F, 0x30, 0x22, 0x09, 0x4E, 0x06, 0xF7, 0xC8, 0x97, 0xD8, 0xBB];
+		let nonce = arr![u8; 0x0C, 0x5F, 0x04, 0xE8, 0xB5, 0x12, 0x19, 0x5E, 0x74, 0xB3, 0xDE, 0x57, 0xE9, 0x70, 0x97, 0x9E];
+
+		let mut keystream = [0; 100];
+		Lea128Ctr::new(&key, &nonce).apply_keystream(&mut keystream);
+
+		// Configured limit
+		let mut lea128ctr = Lea128CheckedCtr::new(&key, &nonce).with_limit(100);
+		assert_eq!(lea128ctr.limit(), 100);
+		let mut buffer = [0; 101];
+		assert!(lea128ctr.try_apply_keystream(&mut buffer).is_err());
+		assert_eq!(buffer, [0; 101]);
+		lea128ctr.try_apply_keystream(&mut buffer[..64]).unwrap();
+		assert_eq!(lea128ctr.remaining(), 36);
+		assert!(lea128ctr.try_apply_keystream(&mut buffer[64..]).is_err());
+		lea128ctr.try_apply_keystream(&mut buffer[64..100]).unwrap();
+		assert_eq!(buffer[..100], keystream);
+		assert!(lea128ctr.try_apply_keystream(&mut [0]).is_err());
+		lea128ctr.try_apply_keystream(&mut []).unwrap();
+
+		// Seeking
+		assert!(lea128ctr.try_seek(101).is_err());
+		assert_eq!(lea128ctr.current_pos::<u64>(), 100);
+		lea128ctr.try_seek(100).unwrap();
+		lea128ctr.try_seek(20).unwrap();
+		let mut buffer = [0; 80];
+		lea128ctr.try_apply_keystream(&mut buffer).unwrap();
+		assert_eq!(buffer, keystream[20..]);
+
+		// Counter space
+		assert_eq!(CheckedCtr::<Lea128, flavors::Ctr32BE>::max_len(), (1 << 36) - 16);
+		assert_eq!(CheckedCtr::<Lea128, flavors::Ctr64LE>::max_len(), (1 << 68) - 16);
+		assert_eq!(CheckedCtr::<Lea128, flavors::Ctr128BE>::max_len(), u128::MAX);
+
+		let lea128ctr = CheckedCtr::<Lea128, flavors::Ctr32BE>::new(&key, &nonce);
+		assert_eq!(lea128ctr.with_limit(u128::MAX).limit(), (1 << 36) - 16);
+		let mut lea128ctr = CheckedCtr::<Lea128, flavors::Ctr32BE>::new(&key, &nonce);
+		assert!(lea128ctr.try_seek((1u64 << 36) - 15).is_err());
+		lea128ctr.try_seek((1u64 << 36) - 48).unwrap();
+		lea128ctr.try_apply_keystream(&mut [0; 32]).unwrap();
+		assert_eq!(lea128ctr.remaining(), 0);
+		assert!(lea128ctr.try_apply_keystream(&mut [0]).is_err());
+	}
 }
@@ -0,0 +1,819 @@
+// Copyright © 2023 남기훈 <gihunnam@proton.me>
+//
+// This file and its content are subject to the terms of the MIT License (the "License").
+// If a copy of the License was not distributed with this file, you can obtain one at https://opensource.org/licenses/MIT.
+
+//! LEA-CTR_DRBG
+//!
+//! CTR_DRBG as specified in NIST SP 800-90A Rev. 1, with or without the derivation function.
+//! The DRBG does not gather entropy by itself; entropy inputs are passed to [`CtrDrbg::instantiate`], [`CtrDrbg::reseed`],
+//! and [`CtrDrbg::generate_with_prediction_resistance`].
+//!
+//! | Parameter                   | Value                                   |
+//! | --------------------------- | --------------------------------------- |
+//! | Security strength           | Key size                                |
+//! | Seed length                 | Key size + 16 bytes                     |
+//! | Entropy input length        | Key size or more (with df), seed length |
+//! | Maximum request length      | 65,536 bytes                            |
+//! | Reseed interval             | 2<sup>48</sup> requests                 |
+//!
+//! [`RngCore`] requests at most 65,536 bytes at a time without additional input.
+//!
+//! * Example
+//! ```
+//! use lea::{drbg::rand_core::RngCore, Lea128CtrDrbg};
+//!
+//! let entropy_input = [0x01; 16];
+//! let nonce = [0x02; 8];
+//! let personalization_string = b"lea-rust";
+//!
+//! let mut lea128ctrdrbg = Lea128CtrDrbg::instantiate(&entropy_input, &nonce, personalization_string).unwrap();
+//!
+//! let mut buffer = [0; 32];
+//! lea128ctrdrbg.generate(&mut buffer, b"additional input").unwrap();
+//! lea128ctrdrbg.fill_bytes(&mut buffer);
+//!
+//! lea128ctrdrbg.reseed(&[0x03; 16], &[]).unwrap();
+//! assert_eq!(lea128ctrdrbg.reseed_counter(), 1);
+//! ```
+
+pub use rand_core;
+
+use core::fmt;
+use core::num::NonZeroU32;
+
+use cipher::{generic_array::{typenum::{U16, Unsigned}, GenericArray}, BlockCipher, BlockEncrypt, NewBlockCipher, ParBlocks};
+use rand_core::{CryptoRng, RngCore};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+use crate::{Lea128, Lea192, Lea256};
+
+pub type Lea128CtrDrbg = CtrDrbg<Lea128>;
+pub type Lea192CtrDrbg = CtrDrbg<Lea192>;
+pub type Lea256CtrDrbg = CtrDrbg<Lea256>;
+
+type Block = GenericArray<u8, U16>;
+
+/// Maximum number of bytes per request
+pub const MAX_REQUEST_LEN: usize = 1 << 16;
+/// Maximum number of requests between reseeds
+pub const RESEED_INTERVAL: u64 = 1 << 48;
+
+/// Maximum seed length, i.e. of LEA-256
+const MAX_SEED_LEN: usize = 48;
+
+/// Error of CTR_DRBG
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Error {
+	/// Entropy input is too short, or does not have the seed length without the derivation function.
+	InvalidEntropyLength,
+	/// Personalization string or additional input is too long.
+	InvalidInputLength,
+	/// More than 65,536 bytes have been requested.
+	RequestTooLong,
+	/// Reseed counter has exceeded the reseed interval.
+	ReseedRequired
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::InvalidEntropyLength => "invalid entropy length",
+			Self::InvalidInputLength => "invalid input length",
+			Self::RequestTooLong => "request too long",
+			Self::ReseedRequired => "reseed required"
+		})
+	}
+}
+
+impl From<Error> for rand_core::Error {
+	fn from(error: Error) -> Self {
+		let code = rand_core::Error::CUSTOM_START + error as u32;
+
+		Self::from(NonZeroU32::new(code).unwrap())
+	}
+}
+
+/// CTR_DRBG
+pub struct CtrDrbg<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher {
+	/// Cipher keyed with Key
+	cipher: C,
+	v: u128,
+	reseed_counter: u64,
+	derivation_function: bool
+}
+
+impl<C> CtrDrbg<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher {
+	/// Instantiates CTR_DRBG with the derivation function.
+	///
+	/// `entropy_input` must be at least as long as the key.
+	pub fn instantiate(entropy_input: &[u8], nonce: &[u8], personalization_string: &[u8]) -> Result<Self, Error> {
+		if entropy_input.len() < C::KeySize::USIZE {
+			return Err(Error::InvalidEntropyLength);
+		}
+
+		let mut seed_material = [0; MAX_SEED_LEN];
+		let seed_material = &mut seed_material[..Self::seed_len()];
+		let drbg = block_cipher_df::<C>(&[entropy_input, nonce, personalization_string], seed_material).map(|()| Self::new(seed_material, true));
+
+		#[cfg(feature = "zeroize")]
+		seed_material.zeroize();
+
+		drbg
+	}
+
+	/// Instantiates CTR_DRBG without the derivation function.
+	///
+	/// `entropy_input` must be exactly as long as the seed, and `personalization_string` must not be longer.
+	pub fn instantiate_without_df(entropy_input: &[u8], personalization_string: &[u8]) -> Result<Self, Error> {
+		if entropy_input.len() != Self::seed_len() {
+			return Err(Error::InvalidEntropyLength);
+		}
+		if personalization_string.len() > Self::seed_len() {
+			return Err(Error::InvalidInputLength);
+		}
+
+		let mut seed_material = [0; MAX_SEED_LEN];
+		let seed_material = &mut seed_material[..Self::seed_len()];
+		seed_material.copy_from_slice(entropy_input);
+		seed_material.iter_mut().zip(personalization_string).for_each(|(s, p)| *s ^= p);
+		let drbg = Self::new(seed_material, false);
+
+		#[cfg(feature = "zeroize")]
+		seed_material.zeroize();
+
+		Ok(drbg)
+	}
+
+	pub fn reseed(&mut self, entropy_input: &[u8], additional_input: &[u8]) -> Result<(), Error> {
+		let mut seed_material = [0; MAX_SEED_LEN];
+		let seed_material = &mut seed_material[..Self::seed_len()];
+		let result = self.reseed_material(entropy_input, additional_input, seed_material);
+		if result.is_ok() {
+			self.update(seed_material);
+			self.reseed_counter = 1;
+		}
+
+		#[cfg(feature = "zeroize")]
+		seed_material.zeroize();
+
+		result
+	}
+
+	/// Fills `out`, at most 65,536 bytes long.
+	pub fn generate(&mut self, out: &mut [u8], additional_input: &[u8]) -> Result<(), Error> {
+		if out.len() > MAX_REQUEST_LEN {
+			return Err(Error::RequestTooLong);
+		}
+		if self.reseed_counter > RESEED_INTERVAL {
+			return Err(Error::ReseedRequired);
+		}
+
+		let mut additional_input_block = [0; MAX_SEED_LEN];
+		let additional_input_block = &mut additional_input_block[..Self::seed_len()];
+		if !additional_input.is_empty() {
+			if self.derivation_function {
+				block_cipher_df::<C>(&[additional_input], additional_input_block)?;
+			} else if additional_input.len() <= Self::seed_len() {
+				additional_input_block[..additional_input.len()].copy_from_slice(additional_input);
+			} else {
+				return Err(Error::InvalidInputLength);
+			}
+
+			self.update(additional_input_block);
+		}
+
+		self.keystream(out);
+		self.update(additional_input_block);
+		self.reseed_counter += 1;
+
+		Ok(())
+	}
+
+	/// Reseeds with `entropy_input` and `additional_input`, then fills `out` without additional input.
+	pub fn generate_with_prediction_resistance(&mut self, entropy_input: &[u8], out: &mut [u8], additional_input: &[u8]) -> Result<(), Error> {
+		if out.len() > MAX_REQUEST_LEN {
+			return Err(Error::RequestTooLong);
+		}
+
+		self.reseed(entropy_input, additional_input)?;
+		self.generate(out, &[])
+	}
+
+	/// Number of requests since instantiation or the last reseed, plus one
+	pub fn reseed_counter(&self) -> u64 {
+		self.reseed_counter
+	}
+
+	fn new(seed_material: &[u8], derivation_function: bool) -> Self {
+		let mut drbg = Self { cipher: C::new(&Default::default()), v: 0, reseed_counter: 1, derivation_function };
+		drbg.update(seed_material);
+
+		drbg
+	}
+
+	/// Seed material of a reseed
+	fn reseed_material(&self, entropy_input: &[u8], additional_input: &[u8], seed_material: &mut [u8]) -> Result<(), Error> {
+		if self.derivation_function {
+			if entropy_input.len() < C::KeySize::USIZE {
+				return Err(Error::InvalidEntropyLength);
+			}
+
+			block_cipher_df::<C>(&[entropy_input, additional_input], seed_material)
+		} else {
+			if entropy_input.len() != Self::seed_len() {
+				return Err(Error::InvalidEntropyLength);
+			}
+			if additional_input.len() > Self::seed_len() {
+				return Err(Error::InvalidInputLength);
+			}
+
+			seed_material.copy_from_slice(entropy_input);
+			seed_material.iter_mut().zip(additional_input).for_each(|(s, a)| *s ^= a);
+
+			Ok(())
+		}
+	}
+
+	fn seed_len() -> usize {
+		C::KeySize::USIZE + 16
+	}
+
+	/// CTR_DRBG_Update
+	fn update(&mut self, provided_data: &[u8]) {
+		let mut temp = [0; MAX_SEED_LEN];
+		let temp = &mut temp[..Self::seed_len()];
+		self.keystream(temp);
+		temp.iter_mut().zip(provided_data).for_each(|(t, p)| *t ^= p);
+
+		let (key, v) = temp.split_at(C::KeySize::USIZE);
+		self.cipher = C::new(GenericArray::from_slice(key));
+		self.v = u128::from_be_bytes(v.try_into().unwrap());
+
+		#[cfg(feature = "zeroize")]
+		temp.zeroize();
+	}
+
+	/// Encrypts V + 1, V + 2, … into `out` in batches of [`BlockCipher::ParBlocks`], and leaves V at the last counter.
+	fn keystream(&mut self, out: &mut [u8]) {
+		let mut blocks = ParBlocks::<C>::default();
+		for chunk in out.chunks_mut(16 * C::ParBlocks::USIZE) {
+			let n = chunk.len().div_ceil(16);
+			for block in blocks[..n].iter_mut() {
+				self.v = self.v.wrapping_add(1);
+				*block = Block::from(self.v.to_be_bytes());
+			}
+
+			self.cipher.encrypt_blocks(&mut blocks[..n]);
+			chunk.iter_mut().zip(blocks.iter().flatten()).for_each(|(o, k)| *o = *k);
+		}
+
+		#[cfg(feature = "zeroize")]
+		blocks.iter_mut().for_each(|block| block.zeroize());
+	}
+}
+
+impl<C> RngCore for CtrDrbg<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher {
+	fn next_u32(&mut self) -> u32 {
+		rand_core::impls::next_u32_via_fill(self)
+	}
+
+	fn next_u64(&mut self) -> u64 {
+		rand_core::impls::next_u64_via_fill(self)
+	}
+
+	/// # Panics
+	///
+	/// Panics if a reseed is required.
+	fn fill_bytes(&mut self, dest: &mut [u8]) {
+		self.try_fill_bytes(dest).unwrap()
+	}
+
+	fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+		for chunk in dest.chunks_mut(MAX_REQUEST_LEN) {
+			self.generate(chunk, &[])?;
+		}
+
+		Ok(())
+	}
+}
+
+impl<C> CryptoRng for CtrDrbg<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher {}
+
+#[cfg(feature = "zeroize")]
+impl<C> Drop for CtrDrbg<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher {
+	fn drop(&mut self) {
+		// The round keys of Key are overwritten by those of the all-zero key, as C is not required to be Zeroize.
+		self.cipher = C::new(&GenericArray::default());
+		core::hint::black_box(&self.cipher);
+		self.v.zeroize();
+	}
+}
+
+/// Block_Cipher_df over the concatenation of `input`
+fn block_cipher_df<C>(input: &[&[u8]], out: &mut [u8]) -> Result<(), Error> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher {
+	let l = input.iter().map(|input| input.len()).sum::<usize>();
+	let l = u32::try_from(l).map_err(|_| Error::InvalidInputLength)?;
+	let n = out.len() as u32;
+
+	// K || X ← BCC(K₀, IV₀ || S) || BCC(K₀, IV₁ || S) || …, where S = L || N || input || 0x80 || 0*
+	let key_len = C::KeySize::USIZE;
+	let initial_key: [u8; 32] = core::array::from_fn(|i| i as u8);
+	let cipher = C::new(GenericArray::from_slice(&initial_key[..key_len]));
+
+	let mut temp = [0; MAX_SEED_LEN];
+	let temp = &mut temp[..(key_len + 16)];
+	for (i, chunk) in temp.chunks_mut(16).enumerate() {
+		let mut bcc = Bcc::new(&cipher);
+		bcc.update(&(i as u32).to_be_bytes());
+		bcc.update(&[0; 12]);
+		bcc.update(&l.to_be_bytes());
+		bcc.update(&n.to_be_bytes());
+		input.iter().for_each(|input| bcc.update(input));
+		bcc.update(&[0x80]);
+		chunk.copy_from_slice(&bcc.finalize()[..chunk.len()]);
+	}
+
+	let (key, x) = temp.split_at(key_len);
+	let cipher = C::new(GenericArray::from_slice(key));
+	let mut x = *Block::from_slice(x);
+	for chunk in out.chunks_mut(16) {
+		cipher.encrypt_block(&mut x);
+		chunk.copy_from_slice(&x[..chunk.len()]);
+	}
+
+	#[cfg(feature = "zeroize")]
+	{
+		temp.zeroize();
+		x.zeroize();
+	}
+
+	Ok(())
+}
+
+/// BCC, i.e. CBC-MAC with a zero IV, padding with zero bytes
+struct Bcc<'c, C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	cipher: &'c C,
+	chaining_value: Block,
+	pos: usize
+}
+
+impl<'c, C> Bcc<'c, C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	fn new(cipher: &'c C) -> Self {
+		Self { cipher, chaining_value: Block::default(), pos: 0 }
+	}
+
+	fn update(&mut self, data: &[u8]) {
+		for byte in data {
+			self.chaining_value[self.pos] ^= byte;
+			self.pos += 1;
+
+			if self.pos == 16 {
+				self.cipher.encrypt_block(&mut self.chaining_value);
+				self.pos = 0;
+			}
+		}
+	}
+
+	fn finalize(mut self) -> Block {
+		if self.pos != 0 {
+			self.cipher.encrypt_block(&mut self.chaining_value);
+		}
+
+		self.chaining_value
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	extern crate alloc;
+
+	use alloc::{vec::Vec, vec};
+
+	use crate::{drbg::{rand_core::RngCore, Error, MAX_REQUEST_LEN, RESEED_INTERVAL}, Lea128CtrDrbg, Lea192CtrDrbg, Lea256CtrDrbg};
+
+	struct TestCase {
+		entropy_input: Vec<u8>,
+		nonce: Vec<u8>,
+		personalization_string: Vec<u8>,
+		entropy_input_reseed: Vec<u8>,
+		additional_input_reseed: Vec<u8>,
+		additional_input_1: Vec<u8>,
+		additional_input_2: Vec<u8>,
+		returned_bits: Vec<u8>
+	}
+
+	#[test]
+	fn lea128ctrdrbg() -> Result<(), Error> {
+		let test_cases = [
+			TestCase {
+				entropy_input: vec![0x34, 0xB6, 0x17, 0x7B, 0xA8, 0x93, 0x96, 0x4F, 0xA2, 0xE2, 0x5A, 0xCE, 0x73, 0x93, 0xE3, 0xF8],
+				nonce: vec![0xD1, 0x4E, 0x43, 0x7D, 0x45, 0x5E, 0x13, 0x8B],
+				personalization_string: vec![],
+				entropy_input_reseed: vec![0xBB, 0x24, 0x0A, 0xFA, 0xAD, 0xA2, 0xF3, 0xFD, 0xD8, 0x61, 0x5F, 0x75, 0xCF, 0x1B, 0x21, 0x8B],
+				additional_input_reseed: vec![],
+				additional_input_1: vec![],
+				additional_input_2: vec![],
+				returned_bits: vec![0xAA, 0xD8, 0xCE, 0x58, 0x95, 0xE0, 0x1B, 0x9F, 0xBA, 0x88, 0xBB, 0x48, 0x46, 0xAE, 0xB2, 0xEC, 0x69, 0x22, 0x60, 0xE4, 0xC0, 0x86, 0xAF, 0x16, 0x52, 0x21, 0x5C, 0x7F, 0xE8, 0x4C, 0xA5, 0xB2, 0x3E, 0x77, 0x33, 0x24, 0x2A, 0x71, 0xF1, 0x93, 0x74, 0xE2, 0x4B, 0x10, 0x6C, 0x46, 0xE1, 0x82, 0x26, 0xA9, 0x0B, 0x0F, 0x68, 0xBC, 0x9D, 0x68, 0xC2, 0x45, 0xA3, 0xB5, 0xE5, 0xAA, 0xC9, 0x90]
+			},
+			TestCase {
+				entropy_input: vec![0xC5, 0x86, 0x2E, 0x0D, 0xAD, 0x13, 0x06, 0x1F, 0x81, 0x49, 0xED, 0xC5, 0x36, 0x1F, 0x1A, 0xA9],
+				nonce: vec![0x6C, 0x13, 0x02, 0x00, 0x15, 0x3E, 0xA6, 0x29],
+				personalization_string: vec![0xFC, 0xCA, 0xF8, 0x07, 0xCF, 0x8C, 0x6F, 0x81, 0xC8, 0xEF, 0xBD, 0xFD, 0xC4, 0x10, 0x7A, 0x52],
+				entropy_input_reseed: vec![0xBF, 0xFE, 0x56, 0x92, 0x46, 0xD8, 0x2D, 0x9A, 0x5F, 0x4F, 0x70, 0xE8, 0x51, 0xAA, 0xDF, 0xF1],
+				additional_input_reseed: vec![],
+				additional_input_1: vec![],
+				additional_input_2: vec![],
+				returned_bits: vec![0x79, 0x06, 0x6A, 0x91, 0x74, 0x53, 0x9A, 0xA5, 0x31, 0x65, 0x67, 0x07, 0x10, 0xF6, 0xB8, 0x5B, 0x4C, 0xC2, 0x74, 0x3E, 0x8F, 0xEF, 0x40, 0x1F, 0x56, 0xDE, 0xEB, 0xBE, 0xF5, 0x86, 0xE9, 0x4C, 0x5F, 0x32, 0x88, 0x7F, 0xAE, 0x29, 0xDD, 0xB8, 0xEA, 0xB5, 0xC8, 0xAF, 0x68, 0xE6, 0x27, 0x42, 0xCD, 0x3D, 0xEE, 0x0C, 0xC3, 0x8D, 0x2B, 0x28, 0xDC, 0x59, 0x6F, 0x86, 0xBB, 0x47, 0x60, 0xBF]
+			},
+			TestCase {
+				entropy_input: vec![0x88, 0x92, 0x6C, 0x7F, 0xF6, 0x88, 0xE2, 0xF2, 0x0E, 0x7F, 0x57, 0x8E, 0xB0, 0x7F, 0x6D, 0xED],
+				nonce: vec![0xF5, 0x8B, 0xB9, 0x2E, 0xEE, 0xD8, 0xAD, 0x42],
+				personalization_string: vec![],
+				entropy_input_reseed: vec![0x31, 0x4D, 0x0E, 0x95, 0x67, 0x7A, 0x29, 0x1D, 0x8E, 0x27, 0xC4, 0x8F, 0x8F, 0xE6, 0xB4, 0xE5],
+				additional_input_reseed: vec![0x20, 0xC7, 0xFE, 0x39, 0xFE, 0x8E, 0x4D, 0xAC, 0xAD, 0x34, 0xAF, 0x2D, 0xA2, 0x59, 0x49, 0x45],
+				additional_input_1: vec![0x61, 0x1E, 0x5B, 0x0D, 0x4D, 0x7F, 0xDF, 0xA0, 0xB0, 0xF3, 0xA3, 0x9A, 0x01, 0x6A, 0x1F, 0x90],
+				additional_input_2: vec![0x7D, 0xAE, 0xBD, 0xFC, 0x65, 0x21, 0xCA, 0x05, 0x27, 0xCA, 0xB9, 0x68, 0xF1, 0x93, 0xD6, 0x9D],
+				returned_bits: vec![0xC8, 0x7F, 0xAF, 0x92, 0x04, 0xED, 0xAB, 0x0A, 0x7A, 0x67, 0x52, 0xBE, 0x13, 0x35, 0xAE, 0x43, 0x34, 0x12, 0xFC, 0x3B, 0xB9, 0xC9, 0x15, 0x75, 0xE7, 0x40, 0xFF, 0x10, 0x9B, 0x6F, 0x11, 0x29, 0x86, 0xDE, 0x8B, 0x1B, 0xB1, 0x34, 0xF8, 0x60, 0x7F, 0x16, 0x65, 0xAB, 0xCE, 0xC8, 0xD9, 0x95, 0x6C, 0x98, 0x27, 0x8F, 0xF4, 0xA4, 0xEE, 0xE5, 0x39, 0x61, 0x2C, 0x56, 0x27, 0x64, 0xB5, 0x31]
+			},
+			TestCase {
+				entropy_input: vec![0xB3, 0x81, 0x15, 0x60, 0x50, 0x99, 0x60, 0xE3, 0x03, 0x14, 0x4D, 0x8C, 0xF5, 0xC4, 0x6D, 0x19],
+				nonce: vec![0xC4, 0xBA, 0x31, 0x0A, 0x27, 0x8B, 0x13, 0x16],
+				personalization_string: vec![0x5D, 0xFB, 0xDA, 0x7B, 0xBC, 0xA0, 0x2B, 0xCF, 0x61, 0x66, 0x35, 0x0C, 0x66, 0x2A, 0x77, 0x70],
+				entropy_input_reseed: vec![0xDC, 0xCF, 0x0F, 0x74, 0x98, 0x9A, 0x62, 0x3A, 0xAA, 0xA5, 0xDB, 0x15, 0x41, 0x6A, 0x78, 0x1C],
+				additional_input_reseed: vec![0xF1, 0x3C, 0xA3, 0x5B, 0x7C, 0x68, 0x50, 0x98, 0x94, 0x24, 0xA7, 0x19, 0x7B, 0xA5, 0x42, 0xE3],
+				additional_input_1: vec![0x64, 0xEE, 0x20, 0x8A, 0x57, 0x28, 0xB3, 0xD9, 0xE8, 0xF0, 0x59, 0x64, 0x5D, 0x2E, 0xA8, 0xDA],
+				additional_input_2: vec![0xD9, 0x98, 0x42, 0xBE, 0xF1, 0x84, 0x0E, 0xC8, 0xCC, 0x3B, 0x29, 0x07, 0x83, 0x4F, 0x35, 0xFE],
+				returned_bits: vec![0xC9, 0x24, 0x0C, 0x96, 0xAD, 0xEF, 0x45, 0x66, 0x60, 0x32, 0x85, 0x09, 0xA6, 0xFC, 0x10, 0xD2, 0x2B, 0x55, 0xC3, 0x37, 0xF1, 0x1A, 0xCF, 0xE2, 0x14, 0xCD, 0xB2, 0x13, 0xFF, 0xFA, 0x04, 0x78, 0x29, 0x70, 0x60, 0x30, 0x7B, 0xDE, 0x53, 0xCD, 0x9B, 0x0F, 0xC3, 0x45, 0xDA, 0xA4, 0xF3, 0x13, 0xD4, 0x9F, 0xA8, 0x2C, 0x4E, 0x00, 0x34, 0x63, 0x8D, 0x59, 0xFE, 0x11, 0x32, 0x50, 0x67, 0x58]
+			}
+		];
+
+		for TestCase { entropy_input, nonce, personalization_string, entropy_input_reseed, additional_input_reseed, additional_input_1, additional_input_2, returned_bits } in test_cases {
+			let mut lea128ctrdrbg = Lea128CtrDrbg::instantiate(&entropy_input, &nonce, &personalization_string)?;
+			lea128ctrdrbg.reseed(&entropy_input_reseed, &additional_input_reseed)?;
+
+			let mut buffer = [0; 64];
+			lea128ctrdrbg.generate(&mut buffer, &additional_input_1)?;
+			lea128ctrdrbg.generate(&mut buffer, &additional_input_2)?;
+			assert_eq!(buffer[..], returned_bits);
+			assert_eq!(lea128ctrdrbg.reseed_counter(), 3);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128ctrdrbg_no_df() -> Result<(), Error> {
+		let test_cases = [
+			TestCase {
+				entropy_input: vec![0xB9, 0x10, 0x2C, 0xB0, 0x82, 0xE1, 0x21, 0x70, 0x3A, 0x4E, 0x56, 0x4C, 0x7F, 0x37, 0x6E, 0x62, 0xD3, 0x5E, 0x2A, 0x22, 0x9E, 0x99, 0x03, 0x16, 0x7A, 0x41, 0x80, 0x59, 0xD4, 0xA4, 0x51, 0x8F],
+				nonce: vec![],
+				personalization_string: vec![],
+				entropy_input_reseed: vec![0x3F, 0x86, 0x0B, 0xCE, 0xC0, 0x9E, 0xDB, 0x6D, 0x44, 0x30, 0x7F, 0x96, 0x7A, 0x00, 0x51, 0x55, 0xE1, 0x60, 0x7A, 0xD4, 0x50, 0xB4, 0xB2, 0x96, 0xC1, 0xFB, 0xC2, 0x20, 0xDA, 0xD3, 0x0D, 0x4D],
+				additional_input_reseed: vec![],
+				additional_input_1: vec![],
+				additional_input_2: vec![],
+				returned_bits: vec![0x57, 0x77, 0x15, 0xDD, 0xC5, 0x75, 0x12, 0x10, 0x72, 0x69, 0x42, 0xA1, 0xF7, 0xD7, 0x40, 0xDD, 0x0B, 0x37, 0xCF, 0x52, 0xEE, 0xFC, 0x85, 0x9F, 0x54, 0x5B, 0x92, 0x28, 0xF2, 0xE7, 0x93, 0xB2, 0xEA, 0xB8, 0x38, 0xE9, 0xA0, 0x88, 0xEA, 0x82, 0x3A, 0x58, 0x53, 0x71, 0xB5, 0xB4, 0xAD, 0xF8, 0xB3, 0x3B, 0xDE, 0x6B, 0xAF, 0x0A, 0xEA, 0x1C, 0xA1, 0x50, 0x67, 0xDB, 0xC6, 0x6D, 0xEC, 0x85]
+			},
+			TestCase {
+				entropy_input: vec![0x67, 0x48, 0x1E, 0xF8, 0x68, 0x7C, 0x90, 0xE5, 0xFB, 0xFB, 0xD1, 0x67, 0x8D, 0x15, 0xAB, 0xEE, 0xC2, 0x45, 0xD7, 0x11, 0x9D, 0xFE, 0x00, 0x5C, 0xC2, 0xD5, 0xB7, 0x81, 0xAE, 0xF5, 0x8F, 0xB4],
+				nonce: vec![],
+				personalization_string: vec![0xF8, 0x26, 0xC5, 0x41, 0xA0, 0x35, 0x27, 0xC4, 0x47, 0x35, 0xE6, 0x7C, 0x63, 0x5B, 0x33, 0xB8, 0x9A, 0xDB, 0x3A, 0xC4, 0xA6, 0x40, 0x2F, 0xD3, 0x24, 0xBC, 0x38, 0xA3, 0x89, 0x1A, 0xE8, 0x45],
+				entropy_input_reseed: vec![0x08, 0x03, 0xE6, 0x4A, 0x19, 0x6A, 0x92, 0x0C, 0xB8, 0x9D, 0x4F, 0xC0, 0x15, 0x0C, 0xF4, 0x45, 0xE4, 0x4A, 0x3A, 0x7C, 0x3C, 0x57, 0x61, 0x5D, 0x06, 0x2E, 0x9A, 0xB3, 0xF2, 0x56, 0xA6, 0xA5],
+				additional_input_reseed: vec![],
+				additional_input_1: vec![],
+				additional_input_2: vec![],
+				returned_bits: vec![0x6C, 0x90, 0xE3, 0xE1, 0x71, 0x9C, 0xAB, 0x30, 0x26, 0x00, 0xDA, 0xCF, 0x50, 0xA8, 0x09, 0x43, 0x15, 0x13, 0xD1, 0x8D, 0x28, 0xA2, 0xAB, 0xC7, 0xE5, 0xB3, 0x89, 0xD0, 0x44, 0x8B, 0x39, 0x6B, 0x5E, 0xBF, 0x63, 0x04, 0xF9, 0xD8, 0xC6, 0x3D, 0x0D, 0xF0, 0xEB, 0x79, 0xA4, 0x7B, 0x94, 0x25, 0xE2, 0xED, 0x48, 0xAC, 0x27, 0xB0, 0x70, 0x0F, 0x04, 0x1F, 0xCE, 0x60, 0xA9, 0x2A, 0x45, 0x80]
+			},
+			TestCase {
+				entropy_input: vec![0xCD, 0xAD, 0x85, 0x8C, 0x00, 0xE9, 0xDA, 0x01, 0x61, 0xE1, 0x3F, 0x62, 0x12, 0x47, 0x7E, 0x79, 0x20, 0x61, 0xAD, 0x91, 0xF1, 0x04, 0x17, 0x41, 0x17, 0xE8, 0x04, 0xA7, 0x70, 0x8D, 0x7A, 0xD2],
+				nonce: vec![],
+				personalization_string: vec![],
+				entropy_input_reseed: vec![0xB7, 0xB4, 0xE3, 0x2B, 0x43, 0x41, 0xDD, 0x78, 0xC0, 0x43, 0x3E, 0xEF, 0xAA, 0x55, 0xC2, 0x4C, 0x70, 0x11, 0x9B, 0x54, 0x4C, 0x89, 0xFD, 0x90, 0x09, 0x79, 0x8B, 0x66, 0xCA, 0xB6, 0xF2, 0xEF],
+				additional_input_reseed: vec![0x20, 0x8C, 0xDE, 0xE6, 0xBE, 0x3F, 0x10, 0xF7, 0x69, 0xAB, 0x0E, 0x91, 0x55, 0xE0, 0xFE, 0x91, 0x8E, 0x9D, 0x5B, 0xED, 0xF4, 0x5B, 0xFE, 0x08, 0x38, 0x2C, 0xA9, 0x9E, 0xAE, 0x7F, 0xEB, 0x00],
+				additional_input_1: vec![0x08, 0xD9, 0xCA, 0x29, 0x50, 0xCA, 0x73, 0x45, 0x5C, 0x16, 0x86, 0x0C, 0x62, 0x41, 0x1B, 0xBA, 0x50, 0x6B, 0x8B, 0x74, 0xB0, 0x0B, 0xC1, 0x06, 0xD9, 0xE1, 0xB1, 0x2E, 0x31, 0x06, 0xD8, 0xAF],
+				additional_input_2: vec![0xF2, 0xC4, 0xB1, 0x66, 0x7E, 0x4D, 0x17, 0xF4, 0x61, 0xF5, 0xE1, 0x3A, 0x3F, 0x19, 0xDC, 0xBB, 0xBB, 0xE6, 0x9E, 0x9F, 0x22, 0x46, 0xD1, 0x9F, 0x7A, 0xAD, 0x06, 0x33, 0x51, 0xF3, 0x9C, 0xE8],
+				returned_bits: vec![0xFC, 0x74, 0x80, 0xE5, 0x13, 0x31, 0x29, 0x9B, 0xEE, 0x29, 0x07, 0x81, 0xC4, 0xDC, 0x3D, 0xC0, 0x54, 0xDA, 0x67, 0x51, 0x1C, 0x67, 0x37, 0x8C, 0xE3, 0xA3, 0xC3, 0xDD, 0x29, 0xE0, 0x6A, 0xC7, 0x05, 0xEF, 0xB8, 0x4B, 0xFF, 0x81, 0xFB, 0x0D, 0x6F, 0xA8, 0x83, 0xD7, 0xCC, 0x4E, 0xB9, 0x7E, 0x26, 0x44, 0xD3, 0xD9, 0x24, 0x4C, 0xBF, 0x2A, 0xFA, 0xB2, 0x3F, 0x17, 0xD1, 0x6D, 0x74, 0x44]
+			},
+			TestCase {
+				entropy_input: vec![0x58, 0x43, 0x57, 0x85, 0x7D, 0x9C, 0x10, 0xE8, 0xB8, 0x27, 0xBB, 0xD1, 0xDC, 0x30, 0xEB, 0x8A, 0xB1, 0xA7, 0x35, 0x69, 0xE4, 0x70, 0x9E, 0x3F, 0xCE, 0x88, 0x38, 0xF3, 0x57, 0x46, 0x0F, 0xE0],
+				nonce: vec![],
+				personalization_string: vec![0x5C, 0xB9, 0x4E, 0xE3, 0xA4, 0xA3, 0x32, 0xCC, 0xDF, 0x8B, 0xA9, 0x07, 0x8E, 0xFC, 0x0C, 0x5C, 0x43, 0x1B, 0x8B, 0xA7, 0x08, 0xB1, 0x14, 0x7A, 0x38, 0x1F, 0xF8, 0x55, 0x3F, 0x48, 0x7C, 0xB6],
+				entropy_input_reseed: vec![0x32, 0xEF, 0xCF, 0x64, 0x0C, 0xF8, 0xBD, 0x5F, 0x33, 0x6E, 0x16, 0xB8, 0xA6, 0xAE, 0x89, 0x01, 0x76, 0x4A, 0xFD, 0x20, 0xD1, 0xEF, 0x4A, 0x18, 0xBD, 0xFC, 0xCE, 0xA2, 0x27, 0xF9, 0x47, 0x67],
+				additional_input_reseed: vec![0xFB, 0xD6, 0x5D, 0xDC, 0x62, 0xBD, 0x83, 0x88, 0x9E, 0x4D, 0x9D, 0x6E, 0x44, 0x0C, 0xCA, 0x81, 0x4C, 0x2C, 0xDA, 0xE7, 0x35, 0xCD, 0x03, 0xA3, 0xC9, 0x06, 0xB7, 0x14, 0x15, 0xA8, 0xD8, 0x17],
+				additional_input_1: vec![0x91, 0xCC, 0x13, 0xA3, 0x62, 0xB9, 0x22, 0x11, 0x48, 0x64, 0xB2, 0x74, 0xB2, 0x6E, 0x8C, 0xA1, 0x5C, 0x2C, 0xA3, 0xB8, 0x49, 0x98, 0x69, 0x57, 0x4D, 0xFE, 0x19, 0xD1, 0xEA, 0xC3, 0xF0, 0xC5],
+				additional_input_2: vec![0x08, 0x74, 0x51, 0xFE, 0x53, 0x97, 0xFF, 0xC8, 0xE1, 0x2B, 0xE3, 0xCD, 0xC3, 0x1D, 0x4F, 0x18, 0x2E, 0x7A, 0xD3, 0xF3, 0x2E, 0xD9, 0x6E, 0x45, 0x8B, 0x81, 0x2D, 0xCD, 0xFE, 0x58, 0x4C, 0x5A],
+				returned_bits: vec![0xBC, 0xAA, 0x73, 0x79, 0x69, 0x3D, 0x8A, 0x92, 0x69, 0x03, 0x92, 0x23, 0x4D, 0x80, 0x34, 0xA8, 0x28, 0xAE, 0xAE, 0x6D, 0xD1, 0xF4, 0xFB, 0x2B, 0xBF, 0x64, 0x39, 0xB7, 0x38, 0x51, 0xA2, 0x18, 0x43, 0x75, 0x75, 0x17, 0x2C, 0x37, 0xDB, 0x7B, 0x3A, 0x40, 0xF3, 0x9F, 0xE1, 0x2D, 0xA6, 0x68, 0x0A, 0x8F, 0x02, 0x51, 0xFF, 0x60, 0x1C, 0xF3, 0x3D, 0x9E, 0x07, 0xFE, 0xE8, 0x22, 0xFE, 0xBA]
+			}
+		];
+
+		for TestCase { entropy_input, nonce: _, personalization_string, entropy_input_reseed, additional_input_reseed, additional_input_1, additional_input_2, returned_bits } in test_cases {
+			let mut lea128ctrdrbg = Lea128CtrDrbg::instantiate_without_df(&entropy_input, &personalization_string)?;
+			lea128ctrdrbg.reseed(&entropy_input_reseed, &additional_input_reseed)?;
+
+			let mut buffer = [0; 64];
+			lea128ctrdrbg.generate(&mut buffer, &additional_input_1)?;
+			lea128ctrdrbg.generate(&mut buffer, &additional_input_2)?;
+			assert_eq!(buffer[..], returned_bits);
+			assert_eq!(lea128ctrdrbg.reseed_counter(), 3);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea192ctrdrbg() -> Result<(), Error> {
+		let test_cases = [
+			TestCase {
+				entropy_input: vec![0xB5, 0xEC, 0x20, 0x5D, 0xE1, 0xDC, 0xC2, 0xF4, 0x0E, 0x59, 0x5B, 0x84, 0x09, 0xEE, 0x66, 0x9E, 0x05, 0x87, 0xC3, 0x33, 0xFF, 0xB5, 0x2A, 0xDC],
+				nonce: vec![0x21, 0xB0, 0xCB, 0x10, 0x17, 0xBD, 0x66, 0xDE, 0x72, 0x43, 0xF4, 0x1E],
+				personalization_string: vec![],
+				entropy_input_reseed: vec![0xCB, 0x13, 0x26, 0x7A, 0x47, 0x5F, 0xD3, 0x1D, 0x2A, 0xE7, 0xCF, 0x74, 0x53, 0xDB, 0x99, 0xFC, 0x79, 0x93, 0xE9, 0x06, 0x2C, 0xE8, 0x9E, 0xCE],
+				additional_input_reseed: vec![],
+				additional_input_1: vec![],
+				additional_input_2: vec![],
+				returned_bits: vec![0xAD, 0xD8, 0x6D, 0x5B, 0xAE, 0xBB, 0x4A, 0x25, 0x2F, 0x93, 0x40, 0x2A, 0x7E, 0x96, 0xCD, 0xF4, 0x51, 0xD7, 0xD0, 0xF1, 0xE0, 0x2D, 0xF9, 0xFD, 0xD4, 0x97, 0xD8, 0x80, 0x4E, 0x2D, 0xFD, 0x15, 0xC3, 0xAE, 0xA9, 0x81, 0x4C, 0x27, 0xBD, 0xED, 0x51, 0x82, 0x41, 0xD6, 0x6E, 0x2D, 0x01, 0xB0, 0x58, 0xBE, 0xDC, 0xF4, 0xB9, 0x85, 0x04, 0xC3, 0xB8, 0xF3, 0x20, 0xE9, 0xC3, 0xF1, 0xEA, 0x0A]
+			},
+			TestCase {
+				entropy_input: vec![0x98, 0xE0, 0xEE, 0x0B, 0x79, 0x37, 0x41, 0xD5, 0xD7, 0x6A, 0x4B, 0x25, 0x35, 0xA9, 0x72, 0x54, 0x90, 0x30, 0x7D, 0x62, 0xF9, 0xF1, 0x0F, 0x53],
+				nonce: vec![0x64, 0xFC, 0xF4, 0x50, 0x85, 0x73, 0xF9, 0x60, 0x29, 0x3F, 0x67, 0xF6],
+				personalization_string: vec![0xEB, 0x37, 0x99, 0xD6, 0x0B, 0xE9, 0x5A, 0x00, 0x5E, 0xAB, 0x60, 0x1C, 0x3B, 0x7E, 0x5C, 0x1F, 0x51, 0xB9, 0x4A, 0xE3, 0x17, 0xA2, 0x24, 0x42],
+				entropy_input_reseed: vec![0x37, 0x38, 0xBC, 0xCA, 0xFA, 0x0B, 0xFA, 0x39, 0x9D, 0x6A, 0x2E, 0x10, 0x62, 0xA1, 0xB6, 0x4C, 0xB5, 0x22, 0x89, 0x83, 0x3D, 0xF3, 0x5C, 0x28],
+				additional_input_reseed: vec![],
+				additional_input_1: vec![],
+				additional_input_2: vec![],
+				returned_bits: vec![0x0F, 0xEC, 0x31, 0xDC, 0x1C, 0x6F, 0x40, 0x43, 0x2C, 0xEC, 0xD9, 0x77, 0xF1, 0xE1, 0xE4, 0x29, 0x11, 0x4A, 0xE4, 0xA0, 0x16, 0x49, 0x09, 0x5D, 0x51, 0x27, 0x25, 0xBB, 0x12, 0x31, 0xAF, 0x14, 0x75, 0x3F, 0x65, 0x7E, 0xE9, 0x12, 0x9A, 0xC4, 0xDE, 0xE4, 0x7B, 0x5A, 0xFB, 0xC1, 0x19, 0xD1, 0xD2, 0xFA, 0x2B, 0x57, 0xB0, 0x6C, 0x8E, 0x7D, 0x07, 0x51, 0x4D, 0x95, 0x88, 0x7E, 0x5F, 0xC7]
+			},
+			TestCase {
+				entropy_input: vec![0x61, 0xD8, 0xBC, 0x3D, 0xD5, 0x96, 0x50, 0xE4, 0x47, 0x3D, 0x32, 0x71, 0x75, 0x29, 0x2A, 0x66, 0x7E, 0xF4, 0x40, 0x40, 0x8A, 0x13, 0x8F, 0x88],
+				nonce: vec![0x60, 0x58, 0x5D, 0xA4, 0x65, 0xD4, 0xDC, 0xB6, 0xB1, 0xFA, 0x5E, 0xE0],
+				personalization_string: vec![],
+				entropy_input_reseed: vec![0x88, 0x09, 0xAB, 0xE4, 0x57, 0xF8, 0xBF, 0x41, 0x76, 0xA5, 0xB8, 0xDA, 0x7B, 0xCA, 0x87, 0x3E, 0x63, 0x0C, 0xE6, 0xF8, 0xA7, 0xBA, 0x38, 0xA8],
+				additional_input_reseed: vec![0xE0, 0xD7, 0xD7, 0x5E, 0x69, 0x66, 0x1A, 0xAE, 0x38, 0x43, 0x78, 0x66, 0x71, 0xB6, 0x3C, 0x30, 0xFE, 0x7D, 0x2D, 0x26, 0x8D, 0x02, 0x0D, 0x7E],
+				additional_input_1: vec![0x3B, 0x6E, 0xBE, 0x48, 0x47, 0xC2, 0xBE, 0x89, 0xD1, 0x18, 0x0F, 0x0D, 0x79, 0x5D, 0xCF, 0xBE, 0x66, 0x19, 0x94, 0x6B, 0x00, 0x7C, 0x31, 0x43],
+				additional_input_2: vec![0x46, 0x8E, 0x25, 0x28, 0x8D, 0xCF, 0xA4, 0xAB, 0x8E, 0x69, 0x6F, 0x07, 0xE9, 0xE1, 0xE9, 0x8E, 0x9C, 0x17, 0x25, 0x64, 0x16, 0xC7, 0x89, 0xCB],
+				returned_bits: vec![0x69, 0xA7, 0xA7, 0x40, 0x70, 0xFC, 0xD6, 0x6C, 0x26, 0x6F, 0x09, 0x32, 0xC7, 0xCA, 0xAA, 0x01, 0xF7, 0xFC, 0x89, 0xD2, 0x79, 0x92, 0xBF, 0x83, 0x85, 0x0D, 0xF8, 0x97, 0x70, 0x0C, 0xEB, 0xA2, 0xFA, 0x69, 0x2D, 0x26, 0x25, 0xB6, 0x8E, 0xDA, 0xB0, 0x8A, 0x9F, 0x66, 0x7C, 0x8A, 0x48, 0x0E, 0x55, 0x15, 0xFD, 0x0B, 0x61, 0xC2, 0xB9, 0xAB, 0xCA, 0xD0, 0x8B, 0x67, 0xF6, 0x7E, 0x6F, 0x7B]
+			},
+			TestCase {
+				entropy_input: vec![0x3A, 0x3E, 0xDF, 0x6A, 0x0D, 0x9C, 0x1E, 0x7F, 0x9A, 0x6F, 0x9A, 0x32, 0xA0, 0xEC, 0x53, 0x93, 0x30, 0xF7, 0x03, 0x35, 0xD3, 0x75, 0x60, 0x5E],
+				nonce: vec![0x6B, 0x52, 0xD8, 0x43, 0xEA, 0xC7, 0x16, 0x82, 0x43, 0xD2, 0x87, 0x10],
+				personalization_string: vec![0x4B, 0xA2, 0x76, 0x7B, 0x08, 0x83, 0xD9, 0xD5, 0x71, 0xB4, 0x50, 0x30, 0x84, 0xFA, 0x0D, 0x1C, 0x10, 0xA6, 0x61, 0x40, 0x6F, 0x62, 0xCC, 0x38],
+				entropy_input_reseed: vec![0xAD, 0x8F, 0x00, 0xC3, 0x55, 0xAB, 0x4A, 0xBB, 0x9B, 0x73, 0x61, 0xAC, 0xE2, 0x8A, 0xD6, 0x1B, 0x0E, 0x92, 0x8F, 0x96, 0xEA, 0x44, 0xCE, 0xE1],
+				additional_input_reseed: vec![0x88, 0x2A, 0x6E, 0x64, 0x0A, 0x64, 0x39, 0x76, 0x2B, 0xBE, 0xCF, 0x81, 0x14, 0xBF, 0xDB, 0xEB, 0xE9, 0xC5, 0xFB, 0xB1, 0xD7, 0x11, 0xA4, 0x50],
+				additional_input_1: vec![0xD5, 0x1D, 0x28, 0xD6, 0xAD, 0x78, 0xC2, 0x42, 0x38, 0x44, 0x34, 0x75, 0xEE, 0xC8, 0x61, 0xBD, 0x3C, 0xCB, 0xAE, 0x73, 0x5E, 0xA5, 0x1A, 0xA6],
+				additional_input_2: vec![0x49, 0x36, 0xAA, 0x9A, 0xA3, 0xD6, 0x13, 0x8A, 0x84, 0x64, 0x8D, 0x7B, 0x4C, 0x77, 0x15, 0x34, 0xD4, 0xE8, 0x05, 0x51, 0xE7, 0x4A, 0xD9, 0x4A],
+				returned_bits: vec![0x14, 0x32, 0xDD, 0xB8, 0x14, 0x77, 0x28, 0xE8, 0x4A, 0xAC, 0x28, 0x7D, 0xD3, 0xD6, 0x21, 0x67, 0xE2, 0x01, 0xA8, 0x47, 0xED, 0x5A, 0xCC, 0xA5, 0x4D, 0x66, 0x0D, 0x23, 0x79, 0xD4, 0xA5, 0xB1, 0xB0, 0x23, 0x5C, 0x28, 0x62, 0x90, 0x34, 0xAE, 0x03, 0xB0, 0x1F, 0x7C, 0xAE, 0x96, 0x0C, 0x85, 0x19, 0xD8, 0x97, 0x1E, 0xD6, 0x73, 0x36, 0xAE, 0xEB, 0xB5, 0x30, 0xF6, 0x17, 0xFC, 0x97, 0xD3]
+			}
+		];
+
+		for TestCase { entropy_input, nonce, personalization_string, entropy_input_reseed, additional_input_reseed, additional_input_1, additional_input_2, returned_bits } in test_cases {
+			let mut lea192ctrdrbg = Lea192CtrDrbg::instantiate(&entropy_input, &nonce, &personalization_string)?;
+			lea192ctrdrbg.reseed(&entropy_input_reseed, &additional_input_reseed)?;
+
+			let mut buffer = [0; 64];
+			lea192ctrdrbg.generate(&mut buffer, &additional_input_1)?;
+			lea192ctrdrbg.generate(&mut buffer, &additional_input_2)?;
+			assert_eq!(buffer[..], returned_bits);
+			assert_eq!(lea192ctrdrbg.reseed_counter(), 3);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea192ctrdrbg_no_df() -> Result<(), Error> {
+		let test_cases = [
+			TestCase {
+				entropy_input: vec![0xFF, 0x6D, 0xB4, 0x7F, 0x97, 0xFF, 0xF1, 0x17, 0xBB, 0x8B, 0x11, 0xB6, 0xB5, 0xC5, 0x96, 0x14, 0xD1, 0x60, 0xFF, 0x31, 0xDC, 0xAE, 0x32, 0xC5, 0x22, 0x68, 0xE6, 0x3C, 0x85, 0xE2, 0x64, 0xED, 0xC1, 0xFE, 0x4E, 0x20, 0x24, 0xA6, 0xE5, 0x2D],
+				nonce: vec![],
+				personalization_string: vec![],
+				entropy_input_reseed: vec![0x3D, 0x17, 0xB9, 0x8F, 0xD3, 0x50, 0xE6, 0x42, 0xEF, 0x4F, 0x2A, 0xBF, 0x36, 0x69, 0xEA, 0xD9, 0x08, 0x88, 0x5B, 0xD9, 0xA7, 0x86, 0xA2, 0x57, 0xA7, 0xA2, 0xFB, 0x1A, 0x42, 0xE4, 0x52, 0x1A, 0x93, 0xDA, 0x57, 0xC4, 0x83, 0x6A, 0x5A, 0xA8],
+				additional_input_reseed: vec![],
+				additional_input_1: vec![],
+				additional_input_2: vec![],
+				returned_bits: vec![0xCE, 0xFC, 0x55, 0xDA, 0x6E, 0x2B, 0x26, 0xAB, 0x03, 0x30, 0x7D, 0x51, 0x3D, 0x31, 0x52, 0x01, 0xBC, 0x6D, 0x5B, 0xC8, 0xC6, 0xB0, 0x66, 0x19, 0xE6, 0x95, 0x35, 0x28, 0x1F, 0xB7, 0xE3, 0x7D, 0x78, 0x8F, 0x81, 0x71, 0xF3, 0xA9, 0x1F, 0x67, 0x85, 0xC3, 0x3E, 0xBE, 0xC4, 0x14, 0x99, 0xD1, 0x26, 0x39, 0xF2, 0x4E, 0xF4, 0x75, 0x8D, 0x7F, 0x8D, 0x90, 0x38, 0x63, 0x1B, 0x3D, 0x1A, 0x23]
+			},
+			TestCase {
+				entropy_input: vec![0x48, 0x54, 0xB0, 0x08, 0x5D, 0x36, 0x82, 0x6F, 0xE3, 0x7D, 0x55, 0xBE, 0x54, 0xC5, 0xAC, 0xC4, 0x8E, 0xCB, 0xDE, 0x63, 0xDE, 0x31, 0xD4, 0x9B, 0x42, 0x4F, 0xEC, 0xA6, 0xCC, 0xF2, 0x2E, 0x04, 0x29, 0x0F, 0x65, 0x67, 0x8B, 0x33, 0x6E, 0xD0],
+				nonce: vec![],
+				personalization_string: vec![0x2D, 0x5F, 0x73, 0xAE, 0x74, 0x8D, 0x10, 0x89, 0xE2, 0x63, 0x75, 0x67, 0x60, 0x6A, 0x98, 0x1C, 0xDB, 0x22, 0xC9, 0xF7, 0xF7, 0x3C, 0x46, 0x69, 0x27, 0x13, 0xBE, 0x04, 0x55, 0x97, 0x5D, 0x48, 0x5C, 0xE0, 0x1A, 0x0E, 0x67, 0x66, 0xE8, 0x8D],
+				entropy_input_reseed: vec![0x6F, 0x8B, 0x5A, 0x5E, 0x43, 0x9A, 0xE3, 0xB3, 0x9D, 0x42, 0xD1, 0x04, 0x34, 0xDB, 0xD6, 0x8D, 0x1E, 0x6A, 0x71, 0x7B, 0x97, 0xE5, 0x42, 0x15, 0xDB, 0x96, 0xFF, 0xAA, 0xF3, 0x11, 0xFC, 0xC8, 0xFD, 0x9D, 0xDA, 0xB5, 0x68, 0x9C, 0x50, 0xCB],
+				additional_input_reseed: vec![],
+				additional_input_1: vec![],
+				additional_input_2: vec![],
+				returned_bits: vec![0xFF, 0xA0, 0x24, 0x4A, 0x33, 0xFF, 0xE7, 0xE1, 0xB8, 0x25, 0xEA, 0x00, 0x02, 0x19, 0x9A, 0x4A, 0xAB, 0x22, 0xFA, 0x1B, 0x35, 0x08, 0xBE, 0x73, 0xBF, 0xF0, 0xA9, 0xB0, 0x6B, 0x4C, 0x29, 0xE0, 0x9D, 0x22, 0xF8, 0x99, 0xC0, 0xB5, 0x06, 0x2F, 0x2F, 0xE5, 0x4A, 0x82, 0x5E, 0x32, 0xD7, 0x92, 0xDF, 0xF0, 0xAF, 0xBC, 0xDA, 0xB0, 0x14, 0x02, 0x60, 0xF2, 0x52, 0x1A, 0x8B, 0x01, 0x22, 0x9A]
+			},
+			TestCase {
+				entropy_input: vec![0xB7, 0x82, 0x64, 0xDF, 0x05, 0x9D, 0xD6, 0x8D, 0x54, 0x71, 0xB9, 0x94, 0xB6, 0x5C, 0x00, 0xF9, 0x27, 0x48, 0xAB, 0xC6, 0x5D, 0x08, 0x9C, 0x55, 0xE6, 0x44, 0x3F, 0x60, 0xED, 0x16, 0xF9, 0xFD, 0xE8, 0xE3, 0x5A, 0x94, 0xE6, 0x75, 0x8A, 0x0A],
+				nonce: vec![],
+				personalization_string: vec![],
+				entropy_input_reseed: vec![0xE8, 0x96, 0x5A, 0xBB, 0x1F, 0xC4, 0xB9, 0xA6, 0x34, 0x8F, 0x2D, 0x2C, 0xDA, 0x5C, 0x9F, 0xFA, 0xB2, 0x16, 0xE0, 0x4B, 0xAD, 0xD3, 0x72, 0xAE, 0x07, 0x5D, 0x16, 0x1E, 0x9F, 0xAB, 0x47, 0x32, 0xF5, 0x30, 0xE5, 0x7A, 0x3A, 0xA8, 0x99, 0xBA],
+				additional_input_reseed: vec![0xCE, 0x57, 0xDD, 0xD3, 0xD6, 0x54, 0x62, 0x1D, 0x02, 0x36, 0xC8, 0xCF, 0x51, 0xE2, 0xFB, 0x75, 0xB5, 0x16, 0xFA, 0x7D, 0xA0, 0x12, 0x28, 0xFA, 0xF6, 0x29, 0xBD, 0x7D, 0xFF, 0xFB, 0xBD, 0xEA, 0x5D, 0xBF, 0xB0, 0x48, 0xB0, 0x05, 0x91, 0x1D],
+				additional_input_1: vec![0xBF, 0x7F, 0x75, 0x8E, 0xB6, 0x0A, 0xDF, 0x4A, 0xED, 0x2B, 0x9A, 0xF1, 0xDA, 0x74, 0x52, 0x00, 0x65, 0xAC, 0xE7, 0x43, 0x22, 0xA7, 0xE8, 0x90, 0x1D, 0xD0, 0x60, 0x08, 0x94, 0x29, 0x78, 0x99, 0x0A, 0x69, 0x73, 0xDC, 0x80, 0x2E, 0xF3, 0xAB],
+				additional_input_2: vec![0x98, 0x84, 0x53, 0x33, 0x97, 0xB0, 0xB6, 0x5E, 0xEC, 0x54, 0x40, 0xF3, 0xED, 0x12, 0xFD, 0x3A, 0xC6, 0x95, 0x46, 0xF8, 0x70, 0xBD, 0xB0, 0x5B, 0x25, 0x87, 0x9E, 0xA1, 0xCE, 0x6B, 0x7B, 0xEE, 0xC5, 0x0D, 0x09, 0xFA, 0xBD, 0xD6, 0xF1, 0x53],
+				returned_bits: vec![0x77, 0x01, 0x1F, 0x35, 0xC5, 0x20, 0xB0, 0xE6, 0x5E, 0x5B, 0xA0, 0xA1, 0xAB, 0x8E, 0x65, 0xA2, 0x2E, 0x88, 0x18, 0x1C, 0xDA, 0x2F, 0x91, 0x41, 0x9F, 0x8D, 0x96, 0xBE, 0x8C, 0x8C, 0xC3, 0xA0, 0x5A, 0xA2, 0x2F, 0x55, 0x43, 0x75, 0xB1, 0x10, 0x45, 0xE6, 0x9A, 0x32, 0xB8, 0xC9, 0xC2, 0xE8, 0xEB, 0xB0, 0x3F, 0x8A, 0x49, 0xF0, 0xB6, 0x70, 0xC2, 0xFF, 0x07, 0x3B, 0xA2, 0x8E, 0xCA, 0xE6]
+			},
+			TestCase {
+				entropy_input: vec![0x28, 0x55, 0x51, 0x8E, 0xF4, 0x76, 0xBD, 0xD8, 0xD6, 0x2A, 0x14, 0x7F, 0x34, 0x42, 0x8E, 0xBA, 0x80, 0x56, 0x5F, 0x2C, 0x46, 0x94, 0x53, 0x88, 0xAA, 0x9A, 0x2C, 0x5F, 0xF9, 0x1F, 0xC6, 0xCB, 0xCF, 0x20, 0x0A, 0x14, 0xBF, 0xB3, 0x10, 0x36],
+				nonce: vec![],
+				personalization_string: vec![0x31, 0xA7, 0x57, 0x9B, 0x1B, 0x64, 0xD6, 0x30, 0x80, 0x50, 0x8F, 0xCC, 0x43, 0x86, 0xDD, 0x1A, 0x8F, 0x09, 0x7C, 0x76, 0x38, 0x1B, 0x9F, 0x29, 0x91, 0x4E, 0xED, 0xA9, 0x26, 0x19, 0x3C, 0xC8, 0x89, 0xC8, 0x1A, 0xBD, 0x9B, 0x91, 0x7B, 0xA1],
+				entropy_input_reseed: vec![0xD1, 0xB5, 0x07, 0xB9, 0x4A, 0x98, 0xE5, 0xD2, 0x82, 0xDB, 0x4C, 0x0E, 0x93, 0x70, 0xED, 0x1A, 0x54, 0xB1, 0x9A, 0xE4, 0xE9, 0x95, 0x06, 0x7D, 0x00, 0x53, 0xFC, 0x2C, 0xFD, 0xD7, 0xAB, 0x28, 0x90, 0xB3, 0xDC, 0xE2, 0x9C, 0xBC, 0xED, 0xC0],
+				additional_input_reseed: vec![0x13, 0xF6, 0x94, 0x59, 0xF1, 0xA2, 0x5F, 0x19, 0xCF, 0x92, 0xF1, 0x98, 0x18, 0xF8, 0x10, 0xFB, 0xDE, 0x6D, 0xD8, 0x89, 0xD0, 0xED, 0x87, 0x8F, 0x42, 0x6D, 0x6D, 0x17, 0x1E, 0xFC, 0x47, 0x7C, 0x11, 0x7C, 0x74, 0xA2, 0x4A, 0x4E, 0xC0, 0x35],
+				additional_input_1: vec![0x6C, 0xD0, 0xE2, 0x29, 0xF5, 0xEC, 0x0D, 0xAD, 0x0F, 0xF9, 0x52, 0x18, 0xF5, 0xF3, 0x38, 0x18, 0x1E, 0x12, 0xC6, 0xDC, 0x88, 0x5C, 0xB3, 0x5A, 0x1F, 0x5F, 0x22, 0x3C, 0xEF, 0xF7, 0x0F, 0x66, 0x3E, 0x6A, 0x4B, 0x94, 0xD6, 0xF9, 0x4A, 0x96],
+				additional_input_2: vec![0x07, 0x4E, 0xCB, 0x73, 0x4D, 0x4B, 0x25, 0x20, 0xCB, 0x2C, 0x12, 0xBD, 0x5D, 0x27, 0xA2, 0x04, 0x6A, 0x08, 0xD9, 0xAA, 0x99, 0x19, 0x8B, 0x79, 0xE2, 0x66, 0xBD, 0x44, 0xAE, 0x2A, 0xF5, 0x26, 0xBB, 0xD9, 0xD9, 0xB9, 0x24, 0x80, 0xEF, 0x97],
+				returned_bits: vec![0x52, 0xBF, 0x32, 0x32, 0x13, 0x38, 0xAA, 0x33, 0x0D, 0x70, 0x48, 0x3F, 0x63, 0x75, 0x1B, 0xB0, 0xE8, 0xE1, 0x3A, 0x4C, 0xA3, 0xDB, 0x02, 0xEF, 0x48, 0xE5, 0xB3, 0x50, 0xBB, 0xC5, 0x30, 0x16, 0x8E, 0xDC, 0xDB, 0x13, 0xCA, 0x63, 0xA6, 0x2B, 0xB5, 0x8B, 0xA9, 0xD5, 0xEF, 0x28, 0x47, 0x80, 0x80, 0x4F, 0x91, 0x31, 0x81, 0x36, 0xD5, 0xDF, 0x3B, 0xCF, 0xDA, 0xA4, 0xE5, 0xD1, 0xE8, 0x9F]
+			}
+		];
+
+		for TestCase { entropy_input, nonce: _, personalization_string, entropy_input_reseed, additional_input_reseed, additional_input_1, additional_input_2, returned_bits } in test_cases {
+			let mut lea192ctrdrbg = Lea192CtrDrbg::instantiate_without_df(&entropy_input, &personalization_string)?;
+			lea192ctrdrbg.reseed(&entropy_input_reseed, &additional_input_reseed)?;
+
+			let mut buffer = [0; 64];
+			lea192ctrdrbg.generate(&mut buffer, &additional_input_1)?;
+			lea192ctrdrbg.generate(&mut buffer, &additional_input_2)?;
+			assert_eq!(buffer[..], returned_bits);
+			assert_eq!(lea192ctrdrbg.reseed_counter(), 3);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea256ctrdrbg() -> Result<(), Error> {
+		let test_cases = [
+			TestCase {
+				entropy_input: vec![0x64, 0x5E, 0x6A, 0x82, 0x3F, 0xB6, 0xD6, 0x87, 0x56, 0x28, 0x8A, 0xB2, 0xA5, 0x3D, 0xD7, 0xE3, 0xC2, 0x85, 0x25, 0x8A, 0x2A, 0x39, 0x63, 0x98, 0x60, 0x46, 0x26, 0x44, 0x96, 0x82, 0x8E, 0xF6],
+				nonce: vec![0xD3, 0x0D, 0x1E, 0xB1, 0x23, 0xD0, 0x32, 0x2B, 0x3B, 0x6F, 0x3A, 0xD3, 0xA9, 0x4D, 0xE5, 0x9F],
+				personalization_string: vec![],
+				entropy_input_reseed: vec![0xBD, 0x51, 0x55, 0xA8, 0x9A, 0xB9, 0x67, 0x27, 0x7E, 0x39, 0xF2, 0x19, 0xEA, 0x7C, 0xC2, 0xFE, 0xF0, 0xEA, 0x02, 0xCE, 0x17, 0xB0, 0x27, 0x5C, 0x63, 0xC5, 0x4B, 0xBB, 0xD7, 0x48, 0xD4, 0xB3],
+				additional_input_reseed: vec![],
+				additional_input_1: vec![],
+				additional_input_2: vec![],
+				returned_bits: vec![0x5C, 0x10, 0x13, 0x19, 0xBA, 0x2F, 0xC9, 0x78, 0xEF, 0x39, 0x0B, 0xAE, 0xF5, 0xF0, 0x5E, 0xCC, 0x75, 0xDA, 0x81, 0xEB, 0x1A, 0xDC, 0x2E, 0x33, 0x2B, 0xDF, 0x9D, 0x76, 0xBB, 0x97, 0xA9, 0xEB, 0xF5, 0x8E, 0x45, 0x1D, 0xAF, 0x7F, 0x82, 0xBF, 0x43, 0x01, 0x58, 0x5B, 0x61, 0xEB, 0x45, 0xBD, 0xC0, 0xA0, 0xE9, 0xD5, 0x23, 0xCF, 0x64, 0x02, 0x1B, 0x85, 0x66, 0x86, 0xEA, 0xA4, 0x0F, 0x07]
+			},
+			TestCase {
+				entropy_input: vec![0xDC, 0x69, 0xC8, 0xCE, 0x8E, 0x4B, 0xA7, 0xED, 0xAE, 0x66, 0x02, 0x4C, 0x4C, 0x08, 0xD8, 0x6B, 0xDF, 0x02, 0xC2, 0x99, 0x19, 0x50, 0xED, 0x84, 0xB3, 0x24, 0x8B, 0xB1, 0x1B, 0xDD, 0xA5, 0xAC],
+				nonce: vec![0xD4, 0xB9, 0xBE, 0xE4, 0xFA, 0x7D, 0xDF, 0xE7, 0x73, 0xF4, 0x04, 0x5C, 0xAB, 0x39, 0xAD, 0xF4],
+				personalization_string: vec![0x1E, 0x34, 0x83, 0xCF, 0x12, 0x31, 0x97, 0x68, 0x07, 0xF8, 0xE3, 0xE5, 0xED, 0xDD, 0x5C, 0x23, 0x40, 0x12, 0x5A, 0x29, 0x6A, 0x1F, 0x39, 0x38, 0x9C, 0x5A, 0xC9, 0x1E, 0x37, 0x37, 0xA6, 0xAD],
+				entropy_input_reseed: vec![0xB9, 0x4B, 0x5C, 0xE8, 0x99, 0x90, 0x16, 0x6F, 0xCE, 0x2C, 0xEA, 0x7B, 0xE2, 0x0D, 0xF1, 0x5A, 0x09, 0x14, 0xE3, 0x86, 0x8D, 0xF9, 0xF5, 0x8D, 0xCA, 0xFC, 0x1C, 0x29, 0xCE, 0x28, 0x5B, 0x85],
+				additional_input_reseed: vec![],
+				additional_input_1: vec![],
+				additional_input_2: vec![],
+				returned_bits: vec![0x86, 0x12, 0xCF, 0x34, 0x59, 0x76, 0x13, 0x46, 0xCF, 0xEF, 0x26, 0xB2, 0x7D, 0x10, 0xEE, 0xAE, 0x3E, 0xCD, 0xA7, 0xCA, 0xDE, 0xCF, 0xA6, 0x3B, 0xE0, 0x3A, 0x7B, 0x57, 0x6E, 0xE6, 0x2E, 0xBC, 0x68, 0xCA, 0xEC, 0xED, 0x27, 0x83, 0x66, 0x63, 0x6E, 0x11, 0x31, 0x6F, 0x3A, 0x34, 0x5E, 0x3B, 0x53, 0x4D, 0x54, 0x84, 0x8E, 0xCA, 0x4C, 0x27, 0xDF, 0x65, 0x88, 0x24, 0x39, 0xB6, 0xA3, 0x3D]
+			},
+			TestCase {
+				entropy_input: vec![0xC3, 0xBD, 0x9D, 0x09, 0xF0, 0x77, 0x9B, 0xE9, 0x04, 0xCA, 0x6D, 0x3D, 0xBB, 0x28, 0x98, 0x1A, 0x04, 0xDB, 0x6D, 0x79, 0x67, 0xEF, 0x54, 0x47, 0xAB, 0x65, 0x7A, 0xC4, 0xE5, 0xEB, 0x5C, 0x5E],
+				nonce: vec![0x3F, 0xC2, 0x00, 0xD7, 0xCE, 0xFC, 0xD4, 0xC9, 0xCA, 0x78, 0x25, 0x58, 0x72, 0x06, 0x77, 0xFA],
+				personalization_string: vec![],
+				entropy_input_reseed: vec![0x31, 0xEA, 0xF2, 0xC3, 0x0B, 0x6B, 0x46, 0x4D, 0xEC, 0x3A, 0x4E, 0x71, 0xE5, 0x07, 0xC1, 0xE1, 0x79, 0x12, 0x8C, 0xF7, 0xBF, 0x97, 0xCC, 0xEF, 0x72, 0xAE, 0x42, 0xAE, 0x06, 0xFD, 0x03, 0xC1],
+				additional_input_reseed: vec![0x77, 0xBC, 0xA1, 0xF1, 0xC4, 0x0D, 0xCC, 0x06, 0x53, 0x6E, 0x52, 0x08, 0x75, 0x15, 0xC6, 0x98, 0x04, 0xDD, 0x24, 0xC8, 0x49, 0xFF, 0x20, 0xBC, 0x07, 0xBB, 0x39, 0xEF, 0x7A, 0x03, 0x4B, 0x65],
+				additional_input_1: vec![0xD2, 0x9C, 0x56, 0x6B, 0x30, 0x2E, 0xC9, 0xD9, 0xEE, 0x62, 0xBE, 0x8B, 0xAA, 0x5A, 0x5D, 0x7C, 0x04, 0x55, 0xC7, 0xC4, 0xEB, 0xCD, 0xFE, 0x2F, 0xBA, 0xFF, 0x8B, 0xA5, 0xA9, 0x16, 0x6C, 0xA2],
+				additional_input_2: vec![0xF1, 0x6F, 0x0D, 0xA6, 0x22, 0x3F, 0x59, 0xAF, 0xD6, 0x38, 0x1D, 0x60, 0x64, 0x57, 0x98, 0x75, 0x5D, 0x55, 0x37, 0xDA, 0xA8, 0xDE, 0x13, 0x35, 0x8A, 0x07, 0x7C, 0x52, 0x01, 0x96, 0xFB, 0x47],
+				returned_bits: vec![0x13, 0xF3, 0x68, 0xC5, 0xF3, 0xAD, 0x6A, 0xE2, 0x1D, 0x9A, 0xB7, 0x69, 0x5B, 0xE6, 0x79, 0x86, 0xB7, 0x4C, 0xF3, 0x99, 0x16, 0xC2, 0x92, 0x27, 0x03, 0x3C, 0x30, 0xEA, 0x8E, 0xED, 0xA0, 0xFD, 0x6E, 0x2B, 0x46, 0xFB, 0xAD, 0x1F, 0x12, 0x8D, 0xE1, 0x15, 0xEB, 0xC0, 0xBE, 0x27, 0x31, 0xD3, 0xE4, 0x2E, 0xFC, 0xCF, 0x7E, 0x6C, 0xE0, 0xA3, 0x67, 0xEE, 0xB6, 0x46, 0xA6, 0x57, 0x3C, 0x80]
+			},
+			TestCase {
+				entropy_input: vec![0xB4, 0x50, 0xE9, 0xCC, 0x64, 0x18, 0x0D, 0x8A, 0x4D, 0xCD, 0xA2, 0x24, 0x4D, 0xD8, 0x68, 0x9C, 0x56, 0x4B, 0x35, 0xEF, 0x1D, 0xCE, 0x02, 0x52, 0x56, 0xAB, 0xEA, 0x9F, 0x81, 0x2E, 0x68, 0x83],
+				nonce: vec![0xE0, 0xF3, 0x04, 0xB9, 0xCA, 0x2C, 0x9C, 0x6D, 0xF2, 0xED, 0x3D, 0x4D, 0xD5, 0xE5, 0x7B, 0xBD],
+				personalization_string: vec![0x5E, 0x64, 0x17, 0x88, 0xE9, 0x8C, 0x37, 0xCA, 0xEF, 0x44, 0x68, 0xC9, 0xD9, 0x54, 0xF6, 0xCD, 0xA1, 0x33, 0x08, 0x1A, 0xFF, 0xDC, 0xDE, 0x35, 0xA0, 0x88, 0xFD, 0x41, 0xBA, 0x44, 0x99, 0xCD],
+				entropy_input_reseed: vec![0x33, 0xCE, 0x4D, 0x35, 0x4E, 0x21, 0x2E, 0x48, 0xB2, 0x46, 0x9D, 0xCA, 0xBE, 0x48, 0xFE, 0xF2, 0x13, 0x4F, 0xAD, 0x20, 0xB5, 0x4E, 0x34, 0xB1, 0xA3, 0xB0, 0x60, 0x17, 0x27, 0x53, 0xF0, 0x6D],
+				additional_input_reseed: vec![0x66, 0xF6, 0xDA, 0xEB, 0xB0, 0x09, 0x19, 0x51, 0xEE, 0x1C, 0xC8, 0x4D, 0xEB, 0xFF, 0xD8, 0xE4, 0x0F, 0xEE, 0x8A, 0x64, 0x20, 0x9A, 0xB7, 0xAC, 0xFC, 0x85, 0xF1, 0x96, 0x38, 0xE1, 0xBE, 0xA9],
+				additional_input_1: vec![0xFB, 0x02, 0x76, 0xEB, 0x6F, 0x7A, 0x21, 0x87, 0xDC, 0x3F, 0x9A, 0x3C, 0xBC, 0x32, 0x58, 0xBE, 0x21, 0x87, 0x4E, 0x98, 0xE5, 0x24, 0xBC, 0xCA, 0x12, 0x0D, 0xAA, 0x76, 0x2A, 0x0E, 0x2B, 0xFF],
+				additional_input_2: vec![0x0B, 0xA4, 0x6D, 0x03, 0xD3, 0x6F, 0xA5, 0x27, 0x2E, 0xB5, 0x2A, 0x78, 0x6D, 0x6F, 0x24, 0x43, 0x19, 0x2E, 0x8F, 0x0E, 0x70, 0x88, 0x0E, 0xA7, 0x36, 0x71, 0x29, 0x21, 0xBE, 0x2D, 0xBA, 0x42],
+				returned_bits: vec![0xF9, 0xC6, 0xA4, 0xF0, 0x05, 0x14, 0xAA, 0x54, 0x83, 0xE6, 0xB1, 0x1C, 0xD5, 0x7B, 0xEC, 0x05, 0xFA, 0xE4, 0xE3, 0x38, 0x34, 0x09, 0xCF, 0xC1, 0xBF, 0x9E, 0xB4, 0xE9, 0x1A, 0xE9, 0x6A, 0x12, 0x7D, 0xF8, 0x41, 0x77, 0x03, 0x5A, 0xA9, 0x26, 0x55, 0xA2, 0x9F, 0xC4, 0x71, 0x3E, 0x77, 0xED, 0xAA, 0x99, 0x26, 0xE7, 0xF5, 0x33, 0xFD, 0xA7, 0x8C, 0x35, 0x83, 0x03, 0x22, 0x7C, 0xAC, 0x8F]
+			}
+		];
+
+		for TestCase { entropy_input, nonce, personalization_string, entropy_input_reseed, additional_input_reseed, additional_input_1, additional_input_2, returned_bits } in test_cases {
+			let mut lea256ctrdrbg = Lea256CtrDrbg::instantiate(&entropy_input, &nonce, &personalization_string)?;
+			lea256ctrdrbg.reseed(&entropy_input_reseed, &additional_input_reseed)?;
+
+			let mut buffer = [0; 64];
+			lea256ctrdrbg.generate(&mut buffer, &additional_input_1)?;
+			lea256ctrdrbg.generate(&mut buffer, &additional_input_2)?;
+			assert_eq!(buffer[..], returned_bits);
+			assert_eq!(lea256ctrdrbg.reseed_counter(), 3);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea256ctrdrbg_no_df() -> Result<(), Error> {
+		let test_cases = [
+			TestCase {
+				entropy_input: vec![0x59, 0x02, 0xDB, 0x6A, 0x00, 0x2A, 0x88, 0xC9, 0xD8, 0x58, 0x0D, 0x04, 0xEF, 0x4A, 0x33, 0x8F, 0x68, 0x72, 0xF9, 0xB5, 0xF9, 0xF3, 0x82, 0x78, 0x61, 0x6F, 0xDD, 0xE6, 0x4A, 0xF7, 0x0B, 0xA2, 0x74, 0xE8, 0xCA, 0x3B, 0xF0, 0x31, 0xA8, 0x67, 0xFC, 0x2F, 0x16, 0x93, 0xBF, 0x98, 0xEA, 0xBE],
+				nonce: vec![],
+				personalization_string: vec![],
+				entropy_input_reseed: vec![0xD1, 0x91, 0xBA, 0xB0, 0x4D, 0x6A, 0xEA, 0xC4, 0xAB, 0xEE, 0x38, 0xB4, 0x4D, 0x3E, 0xFF, 0xB3, 0x4C, 0xB3, 0xD6, 0xE1, 0x4A, 0x08, 0x11, 0xD8, 0xEB, 0xF7, 0x6E, 0x1E, 0xAC, 0x1D, 0x21, 0x7F, 0x90, 0x84, 0xF8, 0x8C, 0xF3, 0xAD, 0xC4, 0x36, 0xE9, 0x92, 0xDE, 0x38, 0xEE, 0x7D, 0xED, 0xBB],
+				additional_input_reseed: vec![],
+				additional_input_1: vec![],
+				additional_input_2: vec![],
+				returned_bits: vec![0x59, 0xD8, 0x6A, 0x6D, 0xBA, 0x5B, 0x50, 0x88, 0x1C, 0x87, 0xB3, 0x86, 0x8B, 0xBD, 0x6C, 0xF6, 0x03, 0xF1, 0xC9, 0x8A, 0xF4, 0x77, 0x95, 0xA5, 0x13, 0xC4, 0x64, 0x5C, 0xF5, 0x8E, 0x05, 0x37, 0xA9, 0x40, 0x09, 0xDB, 0x33, 0x18, 0x8A, 0xA3, 0x8E, 0x60, 0x59, 0x66, 0x3A, 0x68, 0x07, 0xDE, 0x8F, 0x14, 0x74, 0xAA, 0x5A, 0x0B, 0x4E, 0x43, 0x10, 0x87, 0xE1, 0xF4, 0xBC, 0x5F, 0x12, 0x98]
+			},
+			TestCase {
+				entropy_input: vec![0xFD, 0x7F, 0x21, 0x49, 0x19, 0x4F, 0x01, 0xD4, 0xD4, 0xC8, 0x31, 0x2F, 0x3D, 0x5C, 0x04, 0xF7, 0xA8, 0x55, 0x10, 0x7E, 0x74, 0xA4, 0x0C, 0x80, 0xA8, 0xBE, 0x15, 0x55, 0x37, 0x78, 0xC9, 0xE0, 0x08, 0x47, 0x94, 0xDA, 0xBE, 0x1F, 0x9B, 0x73, 0x0D, 0xE5, 0x54, 0xDA, 0xF5, 0xCD, 0x34, 0x44],
+				nonce: vec![],
+				personalization_string: vec![0xB7, 0xCE, 0xCD, 0x9F, 0x64, 0x26, 0xC6, 0xD9, 0xF7, 0x88, 0x04, 0xBF, 0x54, 0x17, 0xDB, 0xEC, 0xA1, 0x7B, 0x6C, 0x9D, 0x2F, 0xE4, 0xF9, 0x07, 0x04, 0x45, 0x65, 0x10, 0x25, 0x2B, 0x42, 0xCD, 0x0B, 0xC7, 0x8D, 0xFF, 0xB3, 0x21, 0x41, 0x28, 0xAD, 0xC3, 0x5D, 0x95, 0xC4, 0xE9, 0x71, 0x97],
+				entropy_input_reseed: vec![0x38, 0x41, 0x5D, 0x34, 0xCA, 0xF7, 0xF0, 0xEC, 0x28, 0xA2, 0xFE, 0xCD, 0x57, 0x06, 0xF1, 0x55, 0x96, 0xD8, 0xDA, 0x1A, 0x5A, 0xAA, 0x36, 0x9E, 0x8C, 0xBA, 0xD2, 0x33, 0x74, 0xCF, 0x61, 0x14, 0xDF, 0x25, 0xA0, 0xE4, 0x3F, 0xB9, 0x55, 0xC4, 0xC1, 0xAE, 0x40, 0x6E, 0x32, 0x5F, 0xFA, 0x77],
+				additional_input_reseed: vec![],
+				additional_input_1: vec![],
+				additional_input_2: vec![],
+				returned_bits: vec![0xAE, 0x38, 0x84, 0x75, 0xED, 0x8A, 0x1E, 0xCB, 0x0A, 0xB4, 0x41, 0x47, 0x40, 0x52, 0xA8, 0x13, 0xE3, 0x1B, 0xBC, 0x26, 0xD9, 0x05, 0xE3, 0x08, 0x2C, 0xF5, 0xC6, 0x3C, 0x26, 0xB3, 0x24, 0xC3, 0xBB, 0xA9, 0xE3, 0x4F, 0xD7, 0x8C, 0x28, 0x31, 0x2E, 0xEA, 0x22, 0x56, 0x2B, 0x01, 0x94, 0x4A, 0xD0, 0x1E, 0x53, 0x59, 0x95, 0x05, 0x69, 0xD1, 0x0E, 0x34, 0x70, 0xDC, 0x85, 0x87, 0x4D, 0x67]
+			},
+			TestCase {
+				entropy_input: vec![0x0B, 0xA4, 0x85, 0x72, 0xD9, 0x35, 0x4D, 0xF5, 0xCB, 0xBC, 0xD4, 0xA7, 0x12, 0xA8, 0x10, 0x95, 0x8A, 0x82, 0x29, 0xE5, 0xBC, 0x6C, 0x04, 0xC7, 0x4E, 0x30, 0x9A, 0x32, 0xBC, 0x3C, 0x5A, 0x37, 0x68, 0x2C, 0x33, 0x87, 0x93, 0x57, 0x1D, 0xDC, 0x6E, 0x91, 0xEE, 0xBD, 0xC2, 0x34, 0xFE, 0xBD],
+				nonce: vec![],
+				personalization_string: vec![],
+				entropy_input_reseed: vec![0x64, 0xA1, 0x54, 0x1B, 0xF4, 0xEB, 0x13, 0x5F, 0x34, 0x55, 0x19, 0xEA, 0xBB, 0xBF, 0x0F, 0x9C, 0xB3, 0x10, 0x5F, 0x61, 0xC4, 0x2F, 0x67, 0xE1, 0xDC, 0x8C, 0x62, 0x3D, 0x6B, 0x8D, 0x7B, 0xA5, 0x48, 0x87, 0x3C, 0x04, 0x6F, 0xDA, 0xFB, 0x56, 0x22, 0x33, 0x1B, 0x7C, 0x46, 0x03, 0xB3, 0xA6],
+				additional_input_reseed: vec![0x23, 0xA7, 0x92, 0x24, 0xF7, 0xBF, 0xB4, 0x1F, 0x80, 0xE7, 0x09, 0xB1, 0xA5, 0x94, 0x76, 0x30, 0xB9, 0xFC, 0x64, 0x31, 0x8C, 0xE3, 0x37, 0x9E, 0x97, 0xE1, 0x36, 0x75, 0x77, 0xD9, 0x1B, 0x65, 0xF7, 0xEF, 0x56, 0x49, 0xAB, 0x40, 0x30, 0x6E, 0x0B, 0xAA, 0x1D, 0x82, 0xE3, 0x8F, 0x9F, 0xA9],
+				additional_input_1: vec![0xD3, 0xA5, 0xAE, 0x8D, 0x0A, 0x7A, 0xD8, 0x17, 0xEF, 0x7E, 0x88, 0x90, 0x14, 0xDE, 0x57, 0xF2, 0x38, 0xFD, 0x3F, 0x36, 0x5D, 0x25, 0x4D, 0xD5, 0x95, 0x85, 0x20, 0x45, 0xBD, 0xE6, 0xB9, 0x09, 0x37, 0x62, 0x24, 0x33, 0x2D, 0xAF, 0x1A, 0xDA, 0x10, 0xAD, 0x30, 0x56, 0x75, 0x31, 0xE1, 0x99],
+				additional_input_2: vec![0xA7, 0xC6, 0x56, 0x40, 0x64, 0x11, 0x05, 0x5B, 0xC8, 0x55, 0x2B, 0x38, 0x53, 0x8F, 0xBF, 0x35, 0xC8, 0x7E, 0xEC, 0x21, 0xFD, 0xB0, 0x5D, 0x06, 0xF1, 0xDF, 0x1D, 0xA7, 0xF5, 0x8E, 0x75, 0x85, 0xF7, 0x4B, 0x33, 0xE8, 0x59, 0x6A, 0xD0, 0x80, 0x0E, 0x27, 0xC8, 0x98, 0x46, 0xE2, 0x0F, 0xF3],
+				returned_bits: vec![0xFA, 0xD6, 0xC0, 0x60, 0x8E, 0x5B, 0x31, 0xE1, 0xEB, 0xA1, 0x34, 0x5F, 0x89, 0xD9, 0xDE, 0xA2, 0x44, 0x04, 0xE1, 0x53, 0x7A, 0x86, 0x72, 0xF8, 0x63, 0x0C, 0x9D, 0xF7, 0x80, 0xE0, 0x53, 0xC7, 0x81, 0x7A, 0x4A, 0x8B, 0xBD, 0xF5, 0x49, 0xB0, 0x53, 0x6E, 0xF3, 0x8C, 0xF7, 0xA0, 0xE0, 0xCB, 0xE9, 0x9F, 0x39, 0x49, 0x15, 0x4D, 0x68, 0x10, 0x9D, 0x26, 0xBC, 0x73, 0x9C, 0xCA, 0xBB, 0x89]
+			},
+			TestCase {
+				entropy_input: vec![0x68, 0x90, 0xA3, 0xBA, 0x9F, 0x8A, 0x2C, 0xD5, 0x16, 0xC5, 0xC6, 0xB9, 0x71, 0x5E, 0x07, 0x85, 0x3F, 0xA4, 0x5A, 0x89, 0xFA, 0xEF, 0xDE, 0x38, 0xC7, 0xA3, 0x87, 0x3E, 0x10, 0x1F, 0x55, 0x7C, 0x90, 0xFE, 0x22, 0x81, 0xCB, 0xEB, 0x54, 0x42, 0xAD, 0x61, 0x4C, 0x20, 0x27, 0xC8, 0x29, 0x63],
+				nonce: vec![],
+				personalization_string: vec![0x3E, 0x65, 0x1B, 0xE2, 0x49, 0xE2, 0xC3, 0x33, 0xAB, 0x9B, 0x32, 0xD1, 0xA0, 0x8F, 0x31, 0xE5, 0xD2, 0x70, 0x87, 0x14, 0x32, 0xA2, 0x2A, 0xE1, 0xB0, 0x44, 0x86, 0xE6, 0xB1, 0x15, 0xEB, 0xD5, 0xE1, 0x7F, 0x93, 0xEC, 0x8A, 0x56, 0xBC, 0x1B, 0xA0, 0x18, 0x38, 0xF6, 0xA1, 0x75, 0x6E, 0x30],
+				entropy_input_reseed: vec![0x27, 0x77, 0x54, 0xBD, 0x6C, 0xEA, 0x66, 0x7A, 0x15, 0x3F, 0xF5, 0xDB, 0x11, 0xAB, 0x18, 0xC8, 0x72, 0xB8, 0x34, 0x95, 0xAF, 0x45, 0x0E, 0x0E, 0x16, 0x52, 0x20, 0x36, 0x11, 0xD1, 0xF0, 0x61, 0x16, 0xB9, 0xB9, 0x63, 0x5F, 0xC3, 0x46, 0x02, 0x13, 0x6C, 0x8F, 0x69, 0x7B, 0xA0, 0x25, 0x69],
+				additional_input_reseed: vec![0x11, 0x9F, 0x89, 0x1B, 0x93, 0xFC, 0x02, 0xE5, 0xF3, 0xAC, 0xB6, 0x36, 0x3E, 0xB1, 0x8E, 0xC7, 0x20, 0x8E, 0x71, 0xC3, 0x49, 0x8B, 0x97, 0x72, 0x60, 0xC0, 0xA9, 0xDF, 0x3F, 0xB3, 0xBC, 0xE9, 0x47, 0xB5, 0x99, 0xEC, 0x31, 0x80, 0x34, 0x10, 0xC0, 0x9C, 0x7E, 0x33, 0x0E, 0x76, 0xA2, 0x0D],
+				additional_input_1: vec![0xD7, 0x03, 0xDB, 0xA7, 0xBF, 0x4A, 0x23, 0x8E, 0x55, 0xB1, 0xEA, 0x29, 0x87, 0x50, 0x41, 0x3D, 0xBA, 0x27, 0xB3, 0x69, 0x5B, 0xAF, 0x48, 0x72, 0x0C, 0x0E, 0x89, 0xA3, 0xCA, 0x35, 0x76, 0x96, 0x50, 0xDE, 0xD1, 0xE5, 0x94, 0xD5, 0xA9, 0xF2, 0xA9, 0x41, 0x71, 0xE4, 0x2E, 0x4F, 0xB2, 0x63],
+				additional_input_2: vec![0x95, 0x00, 0x19, 0x6A, 0x70, 0x08, 0x95, 0x1A, 0x86, 0x38, 0xB8, 0x08, 0xF7, 0xC9, 0x03, 0x1C, 0xA4, 0xF7, 0x0D, 0x62, 0xBF, 0xA6, 0x08, 0xDA, 0xAB, 0xB6, 0x73, 0x4A, 0x8D, 0x67, 0x39, 0xBC, 0x1F, 0xA4, 0xAF, 0xD5, 0xEE, 0x14, 0xA5, 0x6C, 0xFE, 0xCD, 0xA5, 0xDE, 0xAB, 0xE0, 0x19, 0xA5],
+				returned_bits: vec![0x6A, 0xF8, 0x9D, 0xBE, 0x26, 0x41, 0x38, 0xF0, 0xE1, 0x9E, 0xD8, 0x07, 0xCF, 0x4F, 0x15, 0x78, 0x50, 0x4B, 0xDA, 0xFC, 0x90, 0x2E, 0xAF, 0xF6, 0x94, 0xC7, 0x00, 0x36, 0xA6, 0xCF, 0x09, 0x10, 0x89, 0x29, 0xDB, 0x56, 0xD0, 0x58, 0x8F, 0xEC, 0x70, 0x34, 0x54, 0x4E, 0xE0, 0xE3, 0xF6, 0x80, 0x7C, 0x4A, 0xF4, 0x81, 0x96, 0x75, 0xD1, 0x02, 0x99, 0xBF, 0x98, 0x56, 0xFE, 0x9F, 0x57, 0xCD]
+			}
+		];
+
+		for TestCase { entropy_input, nonce: _, personalization_string, entropy_input_reseed, additional_input_reseed, additional_input_1, additional_input_2, returned_bits } in test_cases {
+			let mut lea256ctrdrbg = Lea256CtrDrbg::instantiate_without_df(&entropy_input, &personalization_string)?;
+			lea256ctrdrbg.reseed(&entropy_input_reseed, &additional_input_reseed)?;
+
+			let mut buffer = [0; 64];
+			lea256ctrdrbg.generate(&mut buffer, &additional_input_1)?;
+			lea256ctrdrbg.generate(&mut buffer, &additional_input_2)?;
+			assert_eq!(buffer[..], returned_bits);
+			assert_eq!(lea256ctrdrbg.reseed_counter(), 3);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128ctrdrbg_prediction_resistance() -> Result<(), Error> {
+		let mut lea128ctrdrbg_1 = Lea128CtrDrbg::instantiate(&[1; 16], &[2; 8], &[])?;
+		let mut lea128ctrdrbg_2 = Lea128CtrDrbg::instantiate(&[1; 16], &[2; 8], &[])?;
+
+		// Same as reseeding with the additional input, then generating without it
+		let mut buffer_1 = [0; 100];
+		let mut buffer_2 = [0; 100];
+		lea128ctrdrbg_1.generate_with_prediction_resistance(&[3; 16], &mut buffer_1, &[4; 5])?;
+		lea128ctrdrbg_2.reseed(&[3; 16], &[4; 5])?;
+		lea128ctrdrbg_2.generate(&mut buffer_2, &[])?;
+		assert_eq!(buffer_1, buffer_2);
+		assert_eq!(lea128ctrdrbg_1.reseed_counter(), 2);
+
+		// RngCore generates without additional input.
+		lea128ctrdrbg_1.fill_bytes(&mut buffer_1);
+		lea128ctrdrbg_2.generate(&mut buffer_2, &[])?;
+		assert_eq!(buffer_1, buffer_2);
+
+		// Longer requests are split.
+		let mut buffer = vec![0; MAX_REQUEST_LEN + 1];
+		lea128ctrdrbg_1.fill_bytes(&mut buffer);
+		assert_eq!(lea128ctrdrbg_1.reseed_counter(), 5);
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128ctrdrbg_rejection() -> Result<(), Error> {
+		assert_eq!(Lea128CtrDrbg::instantiate(&[0; 15], &[], &[]).err(), Some(Error::InvalidEntropyLength));
+		assert_eq!(Lea128CtrDrbg::instantiate_without_df(&[0; 31], &[]).err(), Some(Error::InvalidEntropyLength));
+		assert_eq!(Lea128CtrDrbg::instantiate_without_df(&[0; 33], &[]).err(), Some(Error::InvalidEntropyLength));
+		assert_eq!(Lea128CtrDrbg::instantiate_without_df(&[0; 32], &[0; 33]).err(), Some(Error::InvalidInputLength));
+
+		let mut lea128ctrdrbg = Lea128CtrDrbg::instantiate_without_df(&[0; 32], &[])?;
+		assert_eq!(lea128ctrdrbg.reseed(&[0; 16], &[]), Err(Error::InvalidEntropyLength));
+		assert_eq!(lea128ctrdrbg.generate(&mut [0; 16], &[0; 33]), Err(Error::InvalidInputLength));
+		assert_eq!(lea128ctrdrbg.generate(&mut vec![0; MAX_REQUEST_LEN + 1], &[]), Err(Error::RequestTooLong));
+
+		// The reseed counter may exceed the reseed interval by one.
+		lea128ctrdrbg.reseed_counter = RESEED_INTERVAL;
+		lea128ctrdrbg.generate(&mut [0; 16], &[])?;
+		assert_eq!(lea128ctrdrbg.generate(&mut [0; 16], &[]), Err(Error::ReseedRequired));
+		assert!(lea128ctrdrbg.try_fill_bytes(&mut [0; 16]).is_err());
+
+		lea128ctrdrbg.reseed(&[0; 32], &[])?;
+		lea128ctrdrbg.generate(&mut [0; 16], &[])?;
+
+		Ok(())
+	}
+}
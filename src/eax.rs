@@ -0,0 +1,444 @@
+// Copyright © 2023 남기훈 <gihunnam@proton.me>
+//
+// This file and its content are subject to the terms of the MIT License (the "License").
+// If a copy of the License was not distributed with this file, you can obtain one at https://opensource.org/licenses/MIT.
+
+//! LEA-EAX
+//!
+//! EAX as specified by Bellare, Rogaway, and Wagner, built from OMAC (CMAC) and CTR over the forward cipher only.
+//! Nonces may have any length, and tags may have 1 to 16 bytes.
+//!
+//! * Example
+//! ```
+//! use lea::{eax::aead, prelude::*, Lea128Eax};
+//!
+//! let key = arr![u8; 0x99, 0x2A, 0x10, 0x94, 0x75, 0x93, 0xA8, 0x28, 0x7C, 0xCD, 0x94, 0xDA, 0x23, 0xF4, 0x04, 0x5C];
+//! let nonce = arr![u8; 0xE4, 0x53, 0x2E, 0x1F, 0x43, 0x85, 0x18, 0x9A, 0x4A, 0xC4, 0x1C, 0x63, 0x12, 0xFA, 0x03, 0xA7];
+//! let tag = arr![u8; 0x4E, 0x37, 0xD3, 0xDE, 0xF9, 0x28, 0x6E, 0xAA, 0x4A, 0x10, 0x98, 0x02, 0xC6, 0x75, 0xB9, 0x15];
+//! let associated_data = [0xB8, 0xFB, 0xF5, 0x7F, 0xA5, 0x77, 0x73, 0xC4];
+//! let ptxt = [0xC0, 0xBD];
+//! let ctxt = [0x95, 0x8B];
+//!
+//! // Nonce size defaults to `U16`.
+//! let lea128eax = Lea128Eax::new(&key);
+//!
+//! // Encryption
+//! let mut buffer = ptxt;
+//! let calculated_tag: Result<_, aead::Error> = lea128eax.encrypt_in_place_detached(&nonce, &associated_data, &mut buffer);
+//! assert_eq!(buffer, ctxt);
+//! assert_eq!(calculated_tag.unwrap(), tag);
+//!
+//! // Decryption
+//! let mut buffer = ctxt;
+//! let _: Result<(), aead::Error> = lea128eax.decrypt_in_place_detached(&nonce, &associated_data, &mut buffer, &tag);
+//! assert_eq!(buffer, ptxt);
+//! ```
+
+pub use aead;
+
+use core::marker::PhantomData;
+
+use aead::consts::{U0, U1, U16};
+use aead::generic_array::{typenum::{IsGreaterOrEqual, IsLessOrEqual, True}, ArrayLength, GenericArray};
+use aead::{AeadCore, AeadInPlace, Error, Key, NewAead, Nonce, Tag};
+use cipher::{BlockCipher, BlockEncrypt, FromBlockCipher, NewBlockCipher, StreamCipher};
+use cmac::{Cmac, FromBlockCipher as _, Mac};
+use ctr::Ctr128BE;
+use subtle::ConstantTimeEq;
+
+use crate::{Lea128, Lea192, Lea256};
+
+pub type Lea128Eax<NonceSize = U16, TagSize = U16> = Eax<Lea128, TagSize, NonceSize>;
+pub type Lea192Eax<NonceSize = U16, TagSize = U16> = Eax<Lea192, TagSize, NonceSize>;
+pub type Lea256Eax<NonceSize = U16, TagSize = U16> = Eax<Lea256, TagSize, NonceSize>;
+
+type Block = GenericArray<u8, U16>;
+
+/// EAX
+///
+/// * `M`: Tag size
+/// * `N`: Nonce size
+///
+/// The tag is verified before the ciphertext is decrypted.
+pub struct Eax<C, M, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+M: ArrayLength<u8> + IsGreaterOrEqual<U1, Output = True> + IsLessOrEqual<U16, Output = True>,
+N: ArrayLength<u8> {
+	cipher: C,
+	_pd: PhantomData<(M, N)>
+}
+
+impl<C, M, N> From<C> for Eax<C, M, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+M: ArrayLength<u8> + IsGreaterOrEqual<U1, Output = True> + IsLessOrEqual<U16, Output = True>,
+N: ArrayLength<u8> {
+	fn from(cipher: C) -> Self {
+		Self { cipher, _pd: PhantomData }
+	}
+}
+
+impl<C, M, N> NewAead for Eax<C, M, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+M: ArrayLength<u8> + IsGreaterOrEqual<U1, Output = True> + IsLessOrEqual<U16, Output = True>,
+N: ArrayLength<u8> {
+	type KeySize = C::KeySize;
+
+	fn new(key: &Key<Self>) -> Self {
+		Self::from(C::new(key))
+	}
+}
+
+impl<C, M, N> AeadCore for Eax<C, M, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+M: ArrayLength<u8> + IsGreaterOrEqual<U1, Output = True> + IsLessOrEqual<U16, Output = True>,
+N: ArrayLength<u8> {
+	type NonceSize = N;
+	type TagSize = M;
+	type CiphertextOverhead = U0;
+}
+
+impl<C, M, N> AeadInPlace for Eax<C, M, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+M: ArrayLength<u8> + IsGreaterOrEqual<U1, Output = True> + IsLessOrEqual<U16, Output = True>,
+N: ArrayLength<u8> {
+	fn encrypt_in_place_detached(&self, nonce: &Nonce<Self>, associated_data: &[u8], buffer: &mut [u8]) -> Result<Tag<Self>, Error> {
+		let mac = Cmac::from_cipher(&self.cipher);
+		let n = omac(&mac, 0, nonce);
+		let h = omac(&mac, 1, associated_data);
+
+		Ctr128BE::from_block_cipher(&self.cipher, &n).apply_keystream(buffer);
+		let c = omac(&mac, 2, buffer);
+
+		Ok(Tag::<Self>::clone_from_slice(&tag(&n, &h, &c)[..M::USIZE]))
+	}
+
+	fn decrypt_in_place_detached(&self, nonce: &Nonce<Self>, associated_data: &[u8], buffer: &mut [u8], tag: &Tag<Self>) -> Result<(), Error> {
+		let mac = Cmac::from_cipher(&self.cipher);
+		let n = omac(&mac, 0, nonce);
+		let h = omac(&mac, 1, associated_data);
+		let c = omac(&mac, 2, buffer);
+
+		if self::tag(&n, &h, &c)[..M::USIZE].ct_eq(tag).into() {
+			Ctr128BE::from_block_cipher(&self.cipher, &n).apply_keystream(buffer);
+
+			Ok(())
+		} else {
+			Err(Error)
+		}
+	}
+}
+
+/// OMAC<sup>t</sup><sub>K</sub>
+fn omac<C>(mac: &Cmac<&C>, t: u8, data: &[u8]) -> Block where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	let mut mac = mac.clone();
+	mac.update(&[0; 15]);
+	mac.update(&[t]);
+	mac.update(data);
+
+	mac.finalize().into_bytes()
+}
+
+fn tag(n: &Block, h: &Block, c: &Block) -> Block {
+	let mut tag = *n;
+	tag.iter_mut().zip(h).zip(c).for_each(|((t, h), c)| *t ^= h ^ c);
+
+	tag
+}
+
+#[cfg(test)]
+mod tests {
+	extern crate alloc;
+
+	use alloc::{vec::Vec, vec};
+
+	use crate::{eax::aead::{consts::{U1, U33}, Error}, prelude::*, Lea128Eax, Lea192Eax, Lea256Eax};
+
+	struct TestCase<T> where
+	T: AeadInPlace + NewAead {
+		key: GenericArray<u8, <T as NewAead>::KeySize>,
+		nonce: GenericArray<u8, <T as AeadCore>::NonceSize>,
+		tag: GenericArray<u8, <T as AeadCore>::TagSize>,
+		associated_data: Vec<u8>,
+		ptxt: Vec<u8>,
+		ctxt: Vec<u8>
+	}
+
+	#[test]
+	fn lea128eax() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea128Eax>; 5] = [
+			TestCase {
+				key: arr![u8; 0x27, 0x6D, 0x4A, 0x9B, 0x79, 0xFE, 0x0C, 0x13, 0x33, 0xA6, 0xA9, 0x1D, 0xF0, 0xBD, 0x00, 0x40],
+				nonce: arr![u8; 0x04, 0xF8, 0x67, 0x93, 0x67, 0xE4, 0x29, 0xAB, 0x3D, 0x12, 0x39, 0x51, 0xE0, 0x0A, 0xDC, 0x19],
+				tag: arr![u8; 0xA0, 0xAD, 0x14, 0xC7, 0xC1, 0x24, 0xCA, 0x19, 0xA8, 0x8E, 0x53, 0xB5, 0xFA, 0xFE, 0xBD, 0xCF],
+				associated_data: vec![0x13, 0xB4, 0x74, 0x53, 0xEF, 0x9E, 0xFC, 0x55],
+				ptxt: vec![],
+				ctxt: vec![]
+			},
+			TestCase {
+				key: arr![u8; 0x99, 0x2A, 0x10, 0x94, 0x75, 0x93, 0xA8, 0x28, 0x7C, 0xCD, 0x94, 0xDA, 0x23, 0xF4, 0x04, 0x5C],
+				nonce: arr![u8; 0xE4, 0x53, 0x2E, 0x1F, 0x43, 0x85, 0x18, 0x9A, 0x4A, 0xC4, 0x1C, 0x63, 0x12, 0xFA, 0x03, 0xA7],
+				tag: arr![u8; 0x4E, 0x37, 0xD3, 0xDE, 0xF9, 0x28, 0x6E, 0xAA, 0x4A, 0x10, 0x98, 0x02, 0xC6, 0x75, 0xB9, 0x15],
+				associated_data: vec![0xB8, 0xFB, 0xF5, 0x7F, 0xA5, 0x77, 0x73, 0xC4],
+				ptxt: vec![0xC0, 0xBD],
+				ctxt: vec![0x95, 0x8B]
+			},
+			TestCase {
+				key: arr![u8; 0x63, 0x41, 0xB3, 0xBD, 0x46, 0x7E, 0x00, 0xFF, 0x0E, 0x37, 0x00, 0x55, 0xA1, 0xD5, 0x73, 0xE9],
+				nonce: arr![u8; 0xCD, 0x2A, 0xA3, 0xDE, 0x55, 0xBB, 0x9B, 0x2A, 0x6B, 0x42, 0x86, 0xF7, 0xBB, 0xC1, 0x2F, 0x11],
+				tag: arr![u8; 0x6D, 0x30, 0x56, 0xB2, 0xCD, 0xE9, 0x70, 0x1A, 0xCD, 0x7F, 0x21, 0x14, 0x95, 0x31, 0x0A, 0x89],
+				associated_data: vec![0x5D, 0x01, 0x18, 0x65, 0xA4, 0xC1, 0x70, 0x3A],
+				ptxt: vec![0xCC, 0xA2, 0x61, 0x4F, 0x75, 0xE2, 0x1D, 0xE6, 0x0D, 0x02, 0xED, 0x81, 0x08, 0x23, 0x42, 0xB2, 0x2B, 0x3D, 0x71, 0xAD, 0x26],
+				ctxt: vec![0xE6, 0x64, 0x0C, 0xFC, 0x03, 0xA9, 0x1F, 0x1B, 0xE1, 0x4C, 0x24, 0x50, 0x01, 0x16, 0xF6, 0x85, 0xA9, 0xDD, 0x08, 0x1F, 0x90]
+			},
+			TestCase {
+				key: arr![u8; 0x9C, 0x5C, 0x76, 0x21, 0x33, 0x5E, 0x09, 0x08, 0x7E, 0xA3, 0xBA, 0xB2, 0x5E, 0x04, 0xBA, 0x05],
+				nonce: arr![u8; 0x0B, 0x0D, 0x5B, 0x37, 0x2C, 0x73, 0xC0, 0xF2, 0x1B, 0x41, 0xE5, 0x8C, 0x69, 0x64, 0x9B, 0x2D],
+				tag: arr![u8; 0x96, 0xC6, 0x56, 0xED, 0x93, 0xB3, 0x78, 0x0C, 0xA3, 0x95, 0xEE, 0x64, 0x8D, 0x33, 0x0A, 0x37],
+				associated_data: vec![],
+				ptxt: vec![0xC8, 0x70, 0x13, 0xA4, 0xE4, 0x89, 0x4E, 0x6D, 0xF2, 0x9A, 0x3F, 0x0D, 0xBD, 0xE3, 0x46, 0x82, 0x99, 0x97, 0x79, 0x01, 0xE5, 0x4E, 0x4C, 0x18, 0x76, 0xD7, 0x7C, 0x2B, 0x02, 0x26, 0x90, 0x43],
+				ctxt: vec![0xC4, 0x37, 0x3A, 0xEF, 0x49, 0x32, 0xE5, 0xC1, 0x7C, 0x3B, 0xC4, 0x49, 0x0E, 0xD3, 0x40, 0xBA, 0xC2, 0x47, 0xED, 0xAA, 0x7B, 0xBD, 0x5F, 0xDA, 0xE0, 0x66, 0x43, 0x84, 0x9E, 0xB2, 0x5F, 0x41]
+			},
+			TestCase {
+				key: arr![u8; 0x9A, 0xC0, 0xFA, 0xBA, 0xF1, 0x09, 0xFC, 0xC9, 0x82, 0x4B, 0xB1, 0xB2, 0x35, 0xB2, 0xC2, 0x65],
+				nonce: arr![u8; 0x30, 0x0E, 0xBA, 0x07, 0x8D, 0x8F, 0x25, 0x75, 0xE4, 0x90, 0xD6, 0x08, 0x3E, 0xCB, 0xBC, 0x1F],
+				tag: arr![u8; 0x04, 0x44, 0x23, 0xE3, 0x39, 0x77, 0xD2, 0xAD, 0x74, 0xA6, 0x0A, 0x7B, 0x94, 0x8C, 0xEB, 0x6A],
+				associated_data: vec![0x28, 0x08, 0xB2, 0xD5, 0x64, 0x58, 0x3E, 0x27, 0x53, 0xCE, 0x28, 0x52, 0x55, 0x5A, 0x42, 0xD1, 0xE4, 0xC5, 0x5D, 0x5F],
+				ptxt: vec![0x92, 0x4D, 0xE1, 0x0C, 0x3C, 0x5B, 0x89, 0xAB, 0x3D, 0x49, 0xB0, 0xF8, 0x46, 0xDB, 0x2F, 0xB7, 0xD2, 0x34, 0x71, 0x48, 0xED, 0x71, 0xEC, 0x3E, 0x65, 0x91, 0xED, 0x6B, 0xAF, 0x7A, 0x80, 0x14, 0x77, 0xD0, 0xA5, 0x2B, 0xEC, 0x29, 0xE5, 0x61, 0x2E, 0x0F, 0x8C, 0xB6, 0xD1, 0x33, 0xDA, 0x6E, 0x20, 0xF1, 0x17, 0xC3, 0xE6, 0xD6, 0x15, 0x67, 0x9F, 0x53, 0xA6, 0xE9, 0x44, 0xF9, 0xE6, 0x89, 0xB8, 0x2A, 0x98, 0x14, 0x84, 0x1E, 0xFF, 0x4C, 0x2F, 0xCC, 0x6A],
+				ctxt: vec![0x65, 0x0B, 0x24, 0x04, 0x1E, 0xC8, 0x29, 0x8B, 0x5C, 0x2E, 0x7E, 0x97, 0x60, 0x8E, 0x3F, 0x1E, 0x46, 0xAE, 0xA1, 0xC5, 0x6A, 0xA0, 0xB1, 0x54, 0x34, 0x9B, 0xEE, 0xB4, 0xC9, 0xE2, 0xB5, 0x2E, 0x20, 0xCD, 0x2D, 0xE9, 0x9D, 0x50, 0xE1, 0x80, 0x91, 0x6B, 0x42, 0xED, 0xF4, 0xED, 0xA8, 0x5B, 0xDF, 0x0B, 0xB0, 0xA6, 0x6C, 0xA1, 0xCB, 0x2F, 0x48, 0x4A, 0x86, 0x0A, 0x7E, 0xA3, 0x14, 0x92, 0x64, 0x22, 0x11, 0xDD, 0x29, 0xA7, 0x22, 0x1B, 0x48, 0x17, 0x84]
+			}
+		];
+
+		for TestCase { key, nonce, tag, associated_data, ptxt, ctxt } in test_cases {
+			let lea128eax = Lea128Eax::new(&key);
+
+			// Encryption
+			let mut buffer = ptxt.clone();
+			let calculated_tag = lea128eax.encrypt_in_place_detached(&nonce, &associated_data, &mut buffer)?;
+			assert_eq!(buffer, ctxt);
+			assert_eq!(calculated_tag, tag);
+
+			// Decryption
+			let mut buffer = ctxt.clone();
+			lea128eax.decrypt_in_place_detached(&nonce, &associated_data, &mut buffer, &tag)?;
+			assert_eq!(buffer, ptxt);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea192eax() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea192Eax>; 5] = [
+			TestCase {
+				key: arr![u8; 0x6F, 0x16, 0x9A, 0x19, 0xE4, 0xB0, 0x27, 0x02, 0x21, 0x1F, 0x61, 0x61, 0xF5, 0x27, 0x22, 0x8A, 0x2D, 0x54, 0xC5, 0x63, 0x77, 0xE6, 0xBD, 0x6A],
+				nonce: arr![u8; 0x06, 0xE9, 0x9D, 0x08, 0xDD, 0x99, 0xA7, 0x69, 0x63, 0xE1, 0xDF, 0xD6, 0xE3, 0x66, 0x9D, 0x15],
+				tag: arr![u8; 0x3A, 0x81, 0x57, 0x84, 0x58, 0x08, 0xE0, 0x22, 0x3C, 0x53, 0xB2, 0x00, 0x77, 0x8E, 0x69, 0x0D],
+				associated_data: vec![0x24, 0x4B, 0x5E, 0x13, 0x5D, 0x6E, 0x4B, 0x9D],
+				ptxt: vec![],
+				ctxt: vec![]
+			},
+			TestCase {
+				key: arr![u8; 0x05, 0x2C, 0x23, 0x74, 0x5C, 0x2B, 0x45, 0x34, 0xE2, 0x99, 0x05, 0xBD, 0xEF, 0x4C, 0xC9, 0x7A, 0x04, 0xAC, 0xEC, 0xDB, 0xD8, 0x92, 0xF6, 0x73],
+				nonce: arr![u8; 0xAB, 0x0D, 0xE9, 0x0B, 0xDD, 0x7C, 0xD8, 0x6F, 0x16, 0x9D, 0xCB, 0x0F, 0xE8, 0x3D, 0x36, 0xA8],
+				tag: arr![u8; 0x5A, 0xED, 0xA2, 0xB8, 0xC7, 0x9B, 0x45, 0xEF, 0xB3, 0x4A, 0x80, 0x63, 0xC9, 0x23, 0xB5, 0xDC],
+				associated_data: vec![0x96, 0x97, 0xE9, 0xC5, 0x05, 0x23, 0x03, 0xF6],
+				ptxt: vec![0xFD, 0xC2],
+				ctxt: vec![0x73, 0x95]
+			},
+			TestCase {
+				key: arr![u8; 0xF1, 0x5A, 0x3B, 0xE0, 0x7D, 0x8B, 0x8D, 0x59, 0x18, 0x6F, 0xE0, 0x07, 0x6A, 0x91, 0x98, 0x8B, 0x4A, 0x7F, 0x58, 0x64, 0xD2, 0x60, 0x10, 0xC7],
+				nonce: arr![u8; 0x65, 0x1E, 0xBA, 0x9A, 0xC2, 0xF9, 0x95, 0x8C, 0xEB, 0x93, 0xFC, 0x07, 0x45, 0x3E, 0xDB, 0x7C],
+				tag: arr![u8; 0x64, 0xFC, 0xA0, 0x7E, 0xAA, 0x5A, 0x25, 0xD9, 0xF6, 0x45, 0xB6, 0x87, 0x2D, 0x36, 0x6E, 0x9F],
+				associated_data: vec![0xA6, 0xCB, 0x2E, 0xAB, 0x1F, 0xEA, 0x82, 0xB7],
+				ptxt: vec![0xAC, 0xF0, 0x4B, 0xC9, 0x6A, 0xBD, 0x77, 0x8E, 0x4F, 0x18, 0xFD, 0xEA, 0x3E, 0x4A, 0xE0, 0x81, 0x73, 0xE9, 0x7D, 0xA7, 0x7E],
+				ctxt: vec![0xC2, 0xC9, 0x3E, 0xC5, 0x17, 0xA5, 0xB8, 0x3D, 0x96, 0xFA, 0xAA, 0xD6, 0x3F, 0xCD, 0xCE, 0x9C, 0xA4, 0x1F, 0xF2, 0xC5, 0x2E]
+			},
+			TestCase {
+				key: arr![u8; 0x2C, 0x89, 0xB7, 0x11, 0xA5, 0xD6, 0x74, 0x37, 0x64, 0xD3, 0x6F, 0x5C, 0x9A, 0x1D, 0x48, 0x56, 0x12, 0xDA, 0xF2, 0xDF, 0x1E, 0xDE, 0xAB, 0xF4],
+				nonce: arr![u8; 0x8F, 0xE3, 0x61, 0x02, 0x6F, 0x7E, 0x18, 0x96, 0xC8, 0x7E, 0x61, 0xF2, 0x7A, 0xB7, 0x40, 0xA0],
+				tag: arr![u8; 0xB9, 0x41, 0x45, 0xEF, 0x9E, 0x94, 0x43, 0x6A, 0xF9, 0x47, 0x11, 0xE2, 0xDC, 0x7A, 0x54, 0xE2],
+				associated_data: vec![],
+				ptxt: vec![0x32, 0x06, 0xCB, 0xD1, 0xCC, 0x0B, 0x31, 0x27, 0x76, 0x27, 0x71, 0x1A, 0x0E, 0x73, 0xF6, 0xDC, 0x1D, 0x14, 0x1D, 0x06, 0x94, 0x67, 0x7E, 0xBB, 0x14, 0x91, 0xA5, 0x6A, 0x68, 0x3D, 0x48, 0x4F],
+				ctxt: vec![0xFB, 0xAF, 0x69, 0x6C, 0x19, 0x83, 0x75, 0x57, 0x75, 0x85, 0x4D, 0x51, 0xE7, 0xC0, 0xF8, 0x7D, 0x7A, 0xA4, 0xD3, 0xB0, 0x37, 0x19, 0x2C, 0xC6, 0xE4, 0x5D, 0x78, 0x6B, 0x14, 0xE8, 0xE0, 0x18]
+			},
+			TestCase {
+				key: arr![u8; 0xD8, 0xBC, 0x30, 0x68, 0x32, 0x7E, 0xAD, 0x4B, 0x8E, 0x71, 0xD4, 0xB9, 0xDA, 0xCE, 0x6E, 0x81, 0x2B, 0x71, 0xE7, 0x9D, 0xFD, 0x47, 0x66, 0x3B],
+				nonce: arr![u8; 0x1D, 0xDB, 0xB6, 0x52, 0x14, 0xB8, 0x6E, 0xA1, 0x36, 0xC0, 0x85, 0x5A, 0xC5, 0xA3, 0x24, 0xE9],
+				tag: arr![u8; 0xEC, 0x7E, 0x6E, 0x38, 0xA1, 0x66, 0x47, 0xB5, 0x98, 0x94, 0x68, 0xB2, 0x99, 0x72, 0xB6, 0x43],
+				associated_data: vec![0x5B, 0x3F, 0xB8, 0xB9, 0x15, 0xA9, 0x79, 0x66, 0x29, 0xD0, 0xFC, 0x07, 0xAD, 0x27, 0xAC, 0x1A, 0xD4, 0x6F, 0xA5, 0x70],
+				ptxt: vec![0x9F, 0x58, 0x23, 0x1F, 0x3B, 0x88, 0x4B, 0x2A, 0xDA, 0x28, 0x37, 0x5C, 0x19, 0x46, 0x90, 0x46, 0x3D, 0x4D, 0x9F, 0x86, 0xE2, 0x29, 0xA9, 0xEC, 0x91, 0x81, 0xC0, 0xED, 0x4B, 0xF8, 0xAD, 0x79, 0x46, 0x12, 0x96, 0xA4, 0x53, 0x9F, 0x96, 0x60, 0x38, 0xD6, 0x09, 0x0F, 0xCF, 0x65, 0x7A, 0xF2, 0x50, 0x8B, 0x51, 0x66, 0x37, 0x9D, 0x12, 0xD1, 0x16, 0x3D, 0x89, 0x79, 0x44, 0xAB, 0x9C, 0xDB, 0xA5, 0xEE, 0x00, 0xE3, 0x3B, 0xB9, 0x9E, 0xC7, 0x6F, 0x5A, 0x84],
+				ctxt: vec![0x72, 0x00, 0xD6, 0x18, 0xBF, 0x01, 0x05, 0x42, 0x6D, 0x9F, 0x07, 0x55, 0x28, 0x85, 0xB3, 0x3B, 0xE4, 0xF2, 0x14, 0x51, 0xD3, 0xE4, 0x46, 0x0F, 0xBA, 0x69, 0xB2, 0x8E, 0x73, 0x54, 0xD6, 0x62, 0xFD, 0xBE, 0x66, 0x4C, 0xA3, 0x36, 0x93, 0x6F, 0xC3, 0xCD, 0x28, 0xDB, 0xBA, 0xB5, 0xAB, 0x0C, 0xBA, 0x8F, 0xF3, 0xD9, 0x74, 0x5D, 0x00, 0x8D, 0x26, 0x17, 0x10, 0x81, 0x7E, 0xCA, 0x2D, 0xB2, 0x3B, 0xB3, 0xF4, 0xA5, 0x13, 0x42, 0x80, 0x71, 0x3B, 0xAC, 0x1D]
+			}
+		];
+
+		for TestCase { key, nonce, tag, associated_data, ptxt, ctxt } in test_cases {
+			let lea192eax = Lea192Eax::new(&key);
+
+			// Encryption
+			let mut buffer = ptxt.clone();
+			let calculated_tag = lea192eax.encrypt_in_place_detached(&nonce, &associated_data, &mut buffer)?;
+			assert_eq!(buffer, ctxt);
+			assert_eq!(calculated_tag, tag);
+
+			// Decryption
+			let mut buffer = ctxt.clone();
+			lea192eax.decrypt_in_place_detached(&nonce, &associated_data, &mut buffer, &tag)?;
+			assert_eq!(buffer, ptxt);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea256eax() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea256Eax>; 5] = [
+			TestCase {
+				key: arr![u8; 0x62, 0x16, 0xCB, 0x02, 0x6A, 0xF9, 0x80, 0xC5, 0xC6, 0x7C, 0x5E, 0x34, 0xAD, 0x63, 0x7E, 0xB4, 0xE8, 0x0D, 0x04, 0x07, 0xE0, 0x46, 0x06, 0xE9, 0xB1, 0xAE, 0x50, 0x68, 0x94, 0xFB, 0x94, 0xF8],
+				nonce: arr![u8; 0xAB, 0xC2, 0x78, 0xBA, 0xB4, 0x28, 0x6A, 0x63, 0x98, 0x0B, 0xC0, 0x68, 0xE5, 0x66, 0x1B, 0x2F],
+				tag: arr![u8; 0x4E, 0x7F, 0x96, 0x7A, 0xD8, 0x09, 0x37, 0xF0, 0x62, 0xAB, 0xB9, 0xDE, 0xAA, 0x8D, 0xB4, 0xC8],
+				associated_data: vec![0xCB, 0x41, 0x92, 0xE5, 0x52, 0xDD, 0x0C, 0x77],
+				ptxt: vec![],
+				ctxt: vec![]
+			},
+			TestCase {
+				key: arr![u8; 0x7B, 0x68, 0xB2, 0x6C, 0xAF, 0xF3, 0x98, 0x77, 0x4F, 0x78, 0xFF, 0xBB, 0x60, 0x8B, 0xB5, 0xDA, 0x8F, 0xFE, 0xA3, 0xE7, 0x34, 0xF0, 0x51, 0xE4, 0xB5, 0x5A, 0xF0, 0x0A, 0x4A, 0x8D, 0x6F, 0xC8],
+				nonce: arr![u8; 0x38, 0xFF, 0x29, 0xD4, 0xBA, 0x27, 0x01, 0x03, 0x66, 0xA0, 0xA3, 0x81, 0xD8, 0x02, 0x5E, 0x7B],
+				tag: arr![u8; 0x15, 0xC8, 0xF6, 0x9B, 0xEC, 0xFE, 0x8A, 0xDA, 0x63, 0xCE, 0xB0, 0x8A, 0x94, 0xF0, 0xFA, 0xE0],
+				associated_data: vec![0x34, 0x47, 0x8E, 0xBA, 0xE2, 0xFD, 0x50, 0xCA],
+				ptxt: vec![0xEF, 0x72],
+				ctxt: vec![0x7C, 0x10]
+			},
+			TestCase {
+				key: arr![u8; 0xFE, 0x57, 0x76, 0x8A, 0xBF, 0x0D, 0xE9, 0x56, 0x2D, 0x3E, 0xC3, 0x03, 0x90, 0xAE, 0x26, 0x68, 0xE0, 0xE1, 0x73, 0x60, 0x40, 0x02, 0xBD, 0xEC, 0x66, 0x24, 0x00, 0x71, 0x0F, 0x85, 0xBB, 0xF9],
+				nonce: arr![u8; 0xF4, 0xAA, 0xE6, 0x60, 0xDF, 0xC1, 0x95, 0x86, 0x7D, 0xDD, 0x25, 0xB2, 0x3C, 0x6B, 0xF2, 0x41],
+				tag: arr![u8; 0xCA, 0x5E, 0xD2, 0xDD, 0xE5, 0xDB, 0x76, 0xE4, 0x95, 0x5B, 0x4C, 0xBA, 0xCD, 0x70, 0x35, 0x93],
+				associated_data: vec![0x3F, 0x17, 0x20, 0x56, 0x93, 0x80, 0xDC, 0xF2],
+				ptxt: vec![0x02, 0xCA, 0x2A, 0xFD, 0xA8, 0x55, 0xEC, 0x5F, 0x8C, 0xD0, 0x35, 0x07, 0xC4, 0xD1, 0x72, 0x6F, 0xD3, 0xED, 0x94, 0xB3, 0xA8],
+				ctxt: vec![0xAE, 0x9F, 0x2F, 0x42, 0x12, 0x48, 0xC0, 0x39, 0x78, 0x8C, 0x7B, 0x5D, 0xA8, 0x39, 0xCC, 0x09, 0x9A, 0x4E, 0xCA, 0xBA, 0x17]
+			},
+			TestCase {
+				key: arr![u8; 0xA9, 0x2D, 0xE2, 0x44, 0x49, 0x00, 0x2E, 0xCD, 0x7C, 0xA2, 0xC2, 0xA0, 0x3E, 0x82, 0xBA, 0x56, 0x69, 0xB4, 0xD3, 0x91, 0xE5, 0xF3, 0xB3, 0x85, 0x3B, 0x25, 0xB4, 0x27, 0x3C, 0xD8, 0x3F, 0x0E],
+				nonce: arr![u8; 0xCA, 0x6D, 0xAA, 0x5E, 0x9E, 0xCD, 0x45, 0x62, 0x51, 0x5D, 0xDE, 0xCE, 0x78, 0x6C, 0x04, 0xD1],
+				tag: arr![u8; 0x28, 0xD3, 0x67, 0x08, 0x42, 0x59, 0xA9, 0x82, 0xB0, 0x40, 0x6F, 0xB1, 0xFC, 0x18, 0xAC, 0x1C],
+				associated_data: vec![],
+				ptxt: vec![0x0B, 0xA2, 0xDC, 0xA1, 0x80, 0x77, 0x32, 0x76, 0x74, 0xE4, 0x98, 0x04, 0xAD, 0x24, 0x0E, 0x48, 0x7B, 0x1A, 0x6A, 0x12, 0x77, 0x6F, 0x47, 0x94, 0x82, 0x18, 0x5A, 0x0A, 0x35, 0x61, 0xF1, 0x3F],
+				ctxt: vec![0x20, 0xC7, 0xE9, 0x1F, 0x8E, 0x59, 0x84, 0x89, 0xFE, 0xDB, 0x8E, 0xBB, 0x59, 0xF6, 0xF7, 0xA8, 0xCC, 0xEB, 0xD8, 0x17, 0x73, 0x8F, 0x0D, 0xD8, 0x68, 0x55, 0x21, 0xC3, 0x63, 0x48, 0x91, 0x86]
+			},
+			TestCase {
+				key: arr![u8; 0xC0, 0x1B, 0xE3, 0x9A, 0x32, 0x75, 0xB3, 0xD8, 0x85, 0xAB, 0x3E, 0x29, 0x0D, 0x59, 0xAC, 0xB0, 0xBE, 0xEF, 0x73, 0x59, 0xB7, 0x60, 0x4F, 0x70, 0x52, 0x63, 0xD6, 0xBF, 0xCE, 0x2B, 0xBE, 0x3C],
+				nonce: arr![u8; 0x79, 0x35, 0x02, 0x90, 0x33, 0x7D, 0x31, 0xE2, 0xF1, 0x9B, 0x2A, 0xE1, 0x36, 0xC1, 0x4B, 0xD5],
+				tag: arr![u8; 0xF4, 0x16, 0x3C, 0xD8, 0x43, 0x28, 0xD6, 0x42, 0x7E, 0x1B, 0xC6, 0x6D, 0xFA, 0x18, 0x85, 0x44],
+				associated_data: vec![0x5D, 0x61, 0xEB, 0xBB, 0x00, 0xC8, 0x2F, 0x8E, 0xF0, 0x8D, 0x66, 0x9D, 0xCF, 0x66, 0x17, 0xFB, 0x60, 0x7D, 0x17, 0x97],
+				ptxt: vec![0xDB, 0x47, 0x23, 0xAB, 0x86, 0x2E, 0x66, 0x40, 0x6D, 0x1D, 0x9A, 0x18, 0xA1, 0x44, 0xB2, 0x45, 0x89, 0x2E, 0x8E, 0xE9, 0xA4, 0x2B, 0xCE, 0x2F, 0x77, 0x96, 0xC0, 0x0C, 0xE0, 0x1C, 0x65, 0x88, 0x57, 0x6B, 0x78, 0x8C, 0xB5, 0xF3, 0x78, 0x79, 0xA9, 0x9B, 0xF2, 0x77, 0x37, 0x96, 0x9F, 0x83, 0x8C, 0x97, 0x07, 0x88, 0x6F, 0x18, 0x69, 0x7E, 0x74, 0x23, 0x69, 0x16, 0x55, 0x58, 0x9A, 0x97, 0x10, 0xFF, 0x37, 0xE2, 0x43, 0x56, 0xD2, 0x3F, 0x4A, 0x35, 0x96],
+				ctxt: vec![0xFB, 0xA8, 0xF3, 0x42, 0x5A, 0x63, 0x7E, 0x00, 0x2E, 0x52, 0xED, 0xFA, 0x52, 0xF2, 0x05, 0xBB, 0x7B, 0x14, 0x1F, 0xA3, 0x7E, 0x57, 0xAF, 0x90, 0xB4, 0x66, 0x67, 0x46, 0x93, 0x8C, 0x5C, 0x0B, 0xE2, 0x0A, 0xB5, 0x09, 0xD9, 0xF0, 0x7A, 0x6D, 0x2B, 0x72, 0x96, 0xA4, 0x40, 0x55, 0xA9, 0x46, 0xDF, 0xA9, 0x84, 0x62, 0xF9, 0xE7, 0x8C, 0x9A, 0xA8, 0xD2, 0xB0, 0x19, 0x6C, 0x06, 0xA9, 0x3A, 0xE6, 0x47, 0x24, 0x4F, 0xDE, 0x09, 0x24, 0x0E, 0x4B, 0x6C, 0x9F]
+			}
+		];
+
+		for TestCase { key, nonce, tag, associated_data, ptxt, ctxt } in test_cases {
+			let lea256eax = Lea256Eax::new(&key);
+
+			// Encryption
+			let mut buffer = ptxt.clone();
+			let calculated_tag = lea256eax.encrypt_in_place_detached(&nonce, &associated_data, &mut buffer)?;
+			assert_eq!(buffer, ctxt);
+			assert_eq!(calculated_tag, tag);
+
+			// Decryption
+			let mut buffer = ctxt.clone();
+			lea256eax.decrypt_in_place_detached(&nonce, &associated_data, &mut buffer, &tag)?;
+			assert_eq!(buffer, ptxt);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128eax_sizes() -> Result<(), Error> {
+		let key = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+
+		let lea128eax = Lea128Eax::<U12, U16>::new(&key);
+		let nonce = GenericArray::from_slice(&[0xA0, 0x5B, 0x54, 0xC0, 0x26, 0x26, 0x27, 0x17, 0x3F, 0x4E, 0x4F, 0xA4]);
+		let associated_data = [0xAE, 0x4A, 0x1E, 0xF0, 0x79];
+		let mut buffer = [0x34, 0x9A, 0x0B, 0xF2, 0x87, 0x89, 0x9C, 0xAC, 0x7C, 0xFC, 0x82, 0x4B, 0x70, 0xF6, 0x84, 0x4B, 0x9F, 0xDA, 0x49];
+		let tag = lea128eax.encrypt_in_place_detached(nonce, &associated_data, &mut buffer)?;
+		assert_eq!(buffer, [0x1E, 0xB4, 0x81, 0xA4, 0x0A, 0x4F, 0x71, 0xDB, 0xC6, 0x91, 0xB2, 0x5F, 0x3C, 0x1A, 0x5D, 0x66, 0xF3, 0xD2, 0x09]);
+		assert_eq!(tag.as_slice(), [0x0A, 0x44, 0xD9, 0xCC, 0x45, 0xA9, 0xAF, 0x6B, 0xB5, 0x3D, 0xE7, 0xA4, 0xD9, 0x0C, 0x9E, 0xFB]);
+		lea128eax.decrypt_in_place_detached(nonce, &associated_data, &mut buffer, &tag)?;
+		assert_eq!(buffer, [0x34, 0x9A, 0x0B, 0xF2, 0x87, 0x89, 0x9C, 0xAC, 0x7C, 0xFC, 0x82, 0x4B, 0x70, 0xF6, 0x84, 0x4B, 0x9F, 0xDA, 0x49]);
+
+		let lea128eax = Lea128Eax::<U7, U8>::new(&key);
+		let nonce = GenericArray::from_slice(&[0x53, 0x53, 0x52, 0x33, 0x14, 0x22, 0x30]);
+		let associated_data = [0xAF, 0xEE, 0x4A, 0x75, 0x90];
+		let mut buffer = [0x19, 0xB9, 0x74, 0x1C, 0x69, 0xBD, 0xD1, 0xC4, 0x3D, 0xFB, 0xFF, 0x8D, 0xBE, 0x53, 0x95, 0xC7, 0x1A, 0x42, 0x27];
+		let tag = lea128eax.encrypt_in_place_detached(nonce, &associated_data, &mut buffer)?;
+		assert_eq!(buffer, [0x4A, 0x5E, 0x79, 0xA2, 0x5B, 0xD0, 0xB4, 0x2B, 0xC1, 0x9B, 0xE7, 0x2E, 0xCC, 0x64, 0xAB, 0x3A, 0xB9, 0x5E, 0x24]);
+		assert_eq!(tag.as_slice(), [0x9F, 0xF9, 0x6C, 0xD1, 0x2E, 0xCB, 0x78, 0xAC]);
+		lea128eax.decrypt_in_place_detached(nonce, &associated_data, &mut buffer, &tag)?;
+		assert_eq!(buffer, [0x19, 0xB9, 0x74, 0x1C, 0x69, 0xBD, 0xD1, 0xC4, 0x3D, 0xFB, 0xFF, 0x8D, 0xBE, 0x53, 0x95, 0xC7, 0x1A, 0x42, 0x27]);
+
+		let lea128eax = Lea128Eax::<U0, U4>::new(&key);
+		let nonce = GenericArray::from_slice(&[]);
+		let associated_data = [0x9B, 0xFE, 0x87, 0x54, 0x38];
+		let mut buffer = [0x62, 0xF5, 0xAA, 0x4E, 0x1A, 0x13, 0x01, 0x40, 0x09, 0xD0, 0xEE, 0x78, 0x80, 0x05, 0x84, 0x60, 0xD5, 0x1F, 0x6D];
+		let tag = lea128eax.encrypt_in_place_detached(nonce, &associated_data, &mut buffer)?;
+		assert_eq!(buffer, [0x4B, 0x32, 0xE2, 0x02, 0x58, 0xE5, 0xEA, 0xE4, 0x60, 0xE1, 0xE6, 0x6C, 0x6B, 0xBF, 0x66, 0x52, 0x2A, 0xAB, 0xCF]);
+		assert_eq!(tag.as_slice(), [0xFA, 0xEA, 0x9A, 0x71]);
+		lea128eax.decrypt_in_place_detached(nonce, &associated_data, &mut buffer, &tag)?;
+		assert_eq!(buffer, [0x62, 0xF5, 0xAA, 0x4E, 0x1A, 0x13, 0x01, 0x40, 0x09, 0xD0, 0xEE, 0x78, 0x80, 0x05, 0x84, 0x60, 0xD5, 0x1F, 0x6D]);
+
+		let lea128eax = Lea128Eax::<U33, U16>::new(&key);
+		let nonce = GenericArray::from_slice(&[0x04, 0x35, 0xD4, 0x6F, 0x03, 0x25, 0xEB, 0x09, 0x44, 0x5D, 0x48, 0xC2, 0x5D, 0x4B, 0xF7, 0x59, 0x53, 0xB0, 0xCB, 0x82, 0x4B, 0x54, 0x9D, 0x8F, 0x20, 0x50, 0xA8, 0xB7, 0xA9, 0x51, 0x16, 0xC2, 0x18]);
+		let associated_data = [0x58, 0x00, 0x94, 0xE6, 0x1B];
+		let mut buffer = [0xFC, 0x48, 0x51, 0x73, 0xB6, 0x0B, 0x21, 0x96, 0x28, 0x1C, 0xD1, 0x3B, 0xA5, 0x95, 0xC1, 0x5E, 0x9C, 0xF6, 0x62];
+		let tag = lea128eax.encrypt_in_place_detached(nonce, &associated_data, &mut buffer)?;
+		assert_eq!(buffer, [0xC8, 0xC1, 0x19, 0x06, 0x70, 0x91, 0x34, 0xD1, 0x39, 0x73, 0xAD, 0xBE, 0x0E, 0x0D, 0x39, 0xE8, 0x01, 0x19, 0x92]);
+		assert_eq!(tag.as_slice(), [0xAF, 0x76, 0x85, 0x7E, 0x3F, 0x19, 0x9D, 0x0F, 0x43, 0xCC, 0x3D, 0x84, 0xFC, 0xB3, 0xB0, 0x80]);
+		lea128eax.decrypt_in_place_detached(nonce, &associated_data, &mut buffer, &tag)?;
+		assert_eq!(buffer, [0xFC, 0x48, 0x51, 0x73, 0xB6, 0x0B, 0x21, 0x96, 0x28, 0x1C, 0xD1, 0x3B, 0xA5, 0x95, 0xC1, 0x5E, 0x9C, 0xF6, 0x62]);
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128eax_rejection() -> Result<(), Error> {
+		let lea128eax = Lea128Eax::<U12>::new(&Default::default());
+		let nonce = Default::default();
+		let associated_data = [1, 2, 3];
+		let ptxt: Vec<u8> = (0..40).collect();
+
+		let mut ctxt = ptxt.clone();
+		let tag = lea128eax.encrypt_in_place_detached(&nonce, &associated_data, &mut ctxt)?;
+
+		// Tampered ciphertext; the buffer is left as it is.
+		for i in [0, 16, 39] {
+			let mut buffer = ctxt.clone();
+			buffer[i] ^= 1;
+			let tampered_ctxt = buffer.clone();
+			assert!(lea128eax.decrypt_in_place_detached(&nonce, &associated_data, &mut buffer, &tag).is_err());
+			assert_eq!(buffer, tampered_ctxt);
+		}
+
+		// Tampered tag, associated data, or nonce
+		let mut tampered_tag = tag;
+		tampered_tag[0] ^= 1;
+		assert!(lea128eax.decrypt_in_place_detached(&nonce, &associated_data, &mut ctxt.clone(), &tampered_tag).is_err());
+		assert!(lea128eax.decrypt_in_place_detached(&nonce, &[1, 2, 3, 0], &mut ctxt.clone(), &tag).is_err());
+		assert!(lea128eax.decrypt_in_place_detached(&arr![u8; 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], &associated_data, &mut ctxt.clone(), &tag).is_err());
+
+		// Truncated ciphertext
+		assert!(lea128eax.decrypt_in_place_detached(&nonce, &associated_data, &mut ctxt[..39].to_vec(), &tag).is_err());
+
+		// Forged one-byte tags; only the right one is accepted.
+		let lea128eax = Lea128Eax::<U12, U1>::new(&Default::default());
+		let mut ctxt = ptxt.clone();
+		let tag = lea128eax.encrypt_in_place_detached(&nonce, &associated_data, &mut ctxt)?;
+		for forged_tag in (0..=255).filter(|&t| t != tag[0]) {
+			assert!(lea128eax.decrypt_in_place_detached(&nonce, &associated_data, &mut ctxt.clone(), &arr![u8; forged_tag]).is_err());
+		}
+		lea128eax.decrypt_in_place_detached(&nonce, &associated_data, &mut ctxt, &tag)?;
+		assert_eq!(ctxt, ptxt);
+
+		Ok(())
+	}
+}
@@ -0,0 +1,724 @@
+// Copyright © 2023 남기훈 <gihunnam@proton.me>
+//
+// This file and its content are subject to the terms of the MIT License (the "License").
+// If a copy of the License was not distributed with this file, you can obtain one at https://opensource.org/licenses/MIT.
+
+//! `embedded-io` adapters for LEA-CTR and STREAM
+//!
+//! [`EmbeddedLeaCtrReader`] and [`EmbeddedLeaCtrWriter`] apply the keystream to the bytes read from or written to the inner stream,
+//! and [`EmbeddedLeaCtrReader`] can seek when the inner reader can.
+//!
+//! [`EmbeddedLeaStreamWriter`] splits the plaintext into chunks and seals each with a STREAM [`Encryptor`],
+//! and [`EmbeddedLeaStreamReader`] opens them with a [`Decryptor`].
+//! A chunk is held in an array of `N` bytes, so a sealed chunk is `N` bytes long and holds `N` minus the tag size bytes of plaintext,
+//! except the last one, which may be shorter.
+//! A ciphertext that is truncated, reordered, or modified fails to read with [`Error::InvalidTag`],
+//! but the plaintext of the chunks before the failing one has been returned already.
+//!
+//! Neither needs `alloc`.
+//!
+//! * Example
+//! ```
+//! use embedded_io::{Read, Write};
+//! use lea::{embedded::{EmbeddedLeaStreamReader, EmbeddedLeaStreamWriter}, prelude::*, Lea128GcmDecryptorBE32, Lea128GcmEncryptorBE32};
+//!
+//! let key = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+//! let nonce_prefix = arr![u8; 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16];
+//! let mut flash = [0; 1024];
+//!
+//! // Encryption with 240 bytes of plaintext and a 16-byte tag in each chunk
+//! let mut writer = EmbeddedLeaStreamWriter::<_, _, 256>::new(flash.as_mut_slice(), Lea128GcmEncryptorBE32::new(&key, &nonce_prefix));
+//! writer.write_all(&[0x42; 600]).unwrap();
+//! let unused = writer.finish().unwrap().len();
+//! let image = &flash[..(1024 - unused)];
+//! assert_eq!(image.len(), 600 + 3 * 16);
+//!
+//! // Decryption
+//! let mut reader = EmbeddedLeaStreamReader::<_, _, 256>::new(image, Lea128GcmDecryptorBE32::new(&key, &nonce_prefix));
+//! let mut ptxt = [0; 600];
+//! reader.read_exact(&mut ptxt).unwrap();
+//! assert_eq!(ptxt, [0x42; 600]);
+//! assert_eq!(reader.read(&mut [0; 1]).unwrap(), 0);
+//! ```
+
+use core::fmt;
+use core::ops::Sub;
+
+use aead::{generic_array::{typenum::Unsigned, ArrayLength}, stream::{Decryptor, Encryptor, NonceSize, StreamBE32, StreamPrimitive}, AeadInPlace, Buffer};
+use cipher::{StreamCipher, StreamCipherSeek};
+use embedded_io::{ErrorKind, ErrorType, Read, Seek, SeekFrom, Write};
+
+use crate::Lea128Ctr;
+
+/// Largest number of bytes encrypted by one call of [`EmbeddedLeaCtrWriter::write`]
+const CHUNK_SIZE: usize = 256;
+
+/// Error of the `embedded-io` adapters
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Error<E> {
+	/// Error of the inner reader or writer
+	Io(E),
+	/// Keystream or STREAM counter is exhausted.
+	Exhausted,
+	/// Seek is outside the keystream.
+	InvalidSeek,
+	/// Chunk does not authenticate.
+	InvalidTag,
+	/// Inner writer accepted no bytes.
+	WriteZero
+}
+
+impl<E> fmt::Display for Error<E> where
+E: fmt::Debug {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Io(e) => write!(f, "I/O error: {:?}", e),
+			Self::Exhausted => f.write_str("exhausted"),
+			Self::InvalidSeek => f.write_str("invalid seek"),
+			Self::InvalidTag => f.write_str("invalid tag"),
+			Self::WriteZero => f.write_str("write zero")
+		}
+	}
+}
+
+impl<E> embedded_io::Error for Error<E> where
+E: embedded_io::Error {
+	fn kind(&self) -> ErrorKind {
+		match self {
+			Self::Io(e) => e.kind(),
+			Self::Exhausted => ErrorKind::Other,
+			Self::InvalidSeek => ErrorKind::InvalidInput,
+			Self::InvalidTag => ErrorKind::InvalidData,
+			Self::WriteZero => ErrorKind::WriteZero
+		}
+	}
+}
+
+/// Reader that applies the keystream of `C` to the bytes read from `R`
+pub struct EmbeddedLeaCtrReader<R, C = Lea128Ctr> where
+R: Read,
+C: StreamCipher {
+	inner: R,
+	cipher: C
+}
+
+impl<R, C> EmbeddedLeaCtrReader<R, C> where
+R: Read,
+C: StreamCipher {
+	/// The keystream starts at the current position of `inner`.
+	pub fn new(inner: R, cipher: C) -> Self {
+		Self { inner, cipher }
+	}
+
+	pub fn get_ref(&self) -> &R {
+		&self.inner
+	}
+
+	pub fn into_inner(self) -> R {
+		self.inner
+	}
+}
+
+impl<R, C> ErrorType for EmbeddedLeaCtrReader<R, C> where
+R: Read,
+C: StreamCipher {
+	type Error = Error<R::Error>;
+}
+
+impl<R, C> Read for EmbeddedLeaCtrReader<R, C> where
+R: Read,
+C: StreamCipher {
+	fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+		let n = self.inner.read(buf).map_err(Error::Io)?;
+		self.cipher.try_apply_keystream(&mut buf[..n]).map_err(|_| Error::Exhausted)?;
+
+		Ok(n)
+	}
+}
+
+impl<R, C> Seek for EmbeddedLeaCtrReader<R, C> where
+R: Read + Seek,
+C: StreamCipher + StreamCipherSeek {
+	/// Seeks the inner reader and moves the keystream by the same distance.
+	/// Positions are those of the inner reader, and seeking before the start of the keystream fails.
+	fn seek(&mut self, pos: SeekFrom) -> Result<u64, Self::Error> {
+		let keystream_pos: u64 = self.cipher.try_current_pos().map_err(|_| Error::Exhausted)?;
+		let old_pos = self.inner.stream_position().map_err(Error::Io)?;
+		let new_pos = self.inner.seek(pos).map_err(Error::Io)?;
+
+		let result = if new_pos >= old_pos {
+			keystream_pos.checked_add(new_pos - old_pos)
+		} else {
+			keystream_pos.checked_sub(old_pos - new_pos)
+		}.ok_or(()).and_then(|keystream_pos| self.cipher.try_seek(keystream_pos).map_err(|_| ()));
+
+		match result {
+			Ok(()) => Ok(new_pos),
+			Err(()) => {
+				self.inner.seek(SeekFrom::Start(old_pos)).map_err(Error::Io)?;
+
+				Err(Error::InvalidSeek)
+			}
+		}
+	}
+}
+
+/// Writer that applies the keystream of `C` to the bytes written to `W`
+///
+/// Nothing is buffered: the keystream is moved back over the bytes that `W` did not accept.
+pub struct EmbeddedLeaCtrWriter<W, C = Lea128Ctr> where
+W: Write,
+C: StreamCipher + StreamCipherSeek {
+	inner: W,
+	cipher: C
+}
+
+impl<W, C> EmbeddedLeaCtrWriter<W, C> where
+W: Write,
+C: StreamCipher + StreamCipherSeek {
+	pub fn new(inner: W, cipher: C) -> Self {
+		Self { inner, cipher }
+	}
+
+	pub fn get_ref(&self) -> &W {
+		&self.inner
+	}
+
+	pub fn into_inner(self) -> W {
+		self.inner
+	}
+}
+
+impl<W, C> ErrorType for EmbeddedLeaCtrWriter<W, C> where
+W: Write,
+C: StreamCipher + StreamCipherSeek {
+	type Error = Error<W::Error>;
+}
+
+impl<W, C> Write for EmbeddedLeaCtrWriter<W, C> where
+W: Write,
+C: StreamCipher + StreamCipherSeek {
+	fn write(&mut self, data: &[u8]) -> Result<usize, Self::Error> {
+		let mut chunk = [0; CHUNK_SIZE];
+		let n = data.len().min(CHUNK_SIZE);
+		chunk[..n].copy_from_slice(&data[..n]);
+
+		let keystream_pos: u64 = self.cipher.try_current_pos().map_err(|_| Error::Exhausted)?;
+		self.cipher.try_apply_keystream(&mut chunk[..n]).map_err(|_| Error::Exhausted)?;
+
+		let result = self.inner.write(&chunk[..n]);
+		let written = *result.as_ref().unwrap_or(&0);
+		if written < n {
+			self.cipher.seek(keystream_pos + written as u64);
+		}
+
+		result.map_err(Error::Io)
+	}
+
+	fn flush(&mut self) -> Result<(), Self::Error> {
+		self.inner.flush().map_err(Error::Io)
+	}
+}
+
+/// Reader that opens the chunks sealed by [`EmbeddedLeaStreamWriter`] with the same `N`
+pub struct EmbeddedLeaStreamReader<R, A, const N: usize, S = StreamBE32<A>> where
+R: Read,
+A: AeadInPlace,
+S: StreamPrimitive<A>,
+A::NonceSize: Sub<S::NonceOverhead>,
+NonceSize<A, S>: ArrayLength<u8> {
+	inner: R,
+	/// `None` after the last chunk or a failure
+	decryptor: Option<Decryptor<A, S>>,
+	failed: bool,
+	/// Sealed chunk, which is opened in place
+	buffer: [u8; N],
+	len: usize,
+	/// Position of the plaintext to be read, or `None` before the chunk is opened
+	pos: Option<usize>,
+	/// First byte of the next chunk, which tells that the chunk is not the last one
+	next: Option<u8>
+}
+
+impl<R, A, const N: usize, S> EmbeddedLeaStreamReader<R, A, N, S> where
+R: Read,
+A: AeadInPlace,
+S: StreamPrimitive<A>,
+A::NonceSize: Sub<S::NonceOverhead>,
+NonceSize<A, S>: ArrayLength<u8> {
+	/// # Panics
+	///
+	/// Panics if `N` is not greater than the tag size.
+	pub fn new(inner: R, decryptor: Decryptor<A, S>) -> Self {
+		assert!(N > A::TagSize::USIZE);
+
+		Self { inner, decryptor: Some(decryptor), failed: false, buffer: [0; N], len: 0, pos: None, next: None }
+	}
+
+	pub fn get_ref(&self) -> &R {
+		&self.inner
+	}
+
+	pub fn into_inner(self) -> R {
+		self.inner
+	}
+
+	/// Reads and opens the next chunk.
+	fn open(&mut self) -> Result<(), Error<R::Error>> {
+		while self.len < N {
+			match self.inner.read(&mut self.buffer[self.len..]).map_err(Error::Io)? {
+				0 => break,
+				n => self.len += n
+			}
+		}
+
+		let mut last = self.len < N;
+		if !last {
+			let mut byte = [0];
+			match self.inner.read(&mut byte).map_err(Error::Io)? {
+				0 => last = true,
+				_ => self.next = Some(byte[0])
+			}
+		}
+
+		let mut chunk = ChunkBuffer { data: &mut self.buffer, len: self.len };
+		let result = if last {
+			self.decryptor.take().map_or(Ok(()), |decryptor| decryptor.decrypt_last_in_place(&[], &mut chunk))
+		} else {
+			self.decryptor.as_mut().map_or(Ok(()), |decryptor| decryptor.decrypt_next_in_place(&[], &mut chunk))
+		};
+		self.len = chunk.len;
+
+		if result.is_err() {
+			self.decryptor = None;
+			self.failed = true;
+			self.buffer.fill(0);
+			self.len = 0;
+
+			return Err(Error::InvalidTag);
+		}
+
+		self.pos = Some(0);
+
+		Ok(())
+	}
+}
+
+impl<R, A, const N: usize, S> ErrorType for EmbeddedLeaStreamReader<R, A, N, S> where
+R: Read,
+A: AeadInPlace,
+S: StreamPrimitive<A>,
+A::NonceSize: Sub<S::NonceOverhead>,
+NonceSize<A, S>: ArrayLength<u8> {
+	type Error = Error<R::Error>;
+}
+
+impl<R, A, const N: usize, S> Read for EmbeddedLeaStreamReader<R, A, N, S> where
+R: Read,
+A: AeadInPlace,
+S: StreamPrimitive<A>,
+A::NonceSize: Sub<S::NonceOverhead>,
+NonceSize<A, S>: ArrayLength<u8> {
+	fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+		loop {
+			if let Some(pos) = self.pos {
+				if pos < self.len {
+					let n = buf.len().min(self.len - pos);
+					buf[..n].copy_from_slice(&self.buffer[pos..(pos + n)]);
+					self.pos = Some(pos + n);
+
+					return Ok(n);
+				}
+
+				self.pos = None;
+				self.len = 0;
+				if let Some(byte) = self.next.take() {
+					self.buffer[0] = byte;
+					self.len = 1;
+				}
+			}
+
+			if self.failed {
+				return Err(Error::InvalidTag);
+			}
+			if self.decryptor.is_none() || buf.is_empty() {
+				return Ok(0);
+			}
+
+			self.open()?;
+		}
+	}
+}
+
+/// Writer that seals chunks of the plaintext and writes them to `W`
+///
+/// The ciphertext is complete only after [`EmbeddedLeaStreamWriter::finish`], which seals and writes the last chunk.
+pub struct EmbeddedLeaStreamWriter<W, A, const N: usize, S = StreamBE32<A>> where
+W: Write,
+A: AeadInPlace,
+S: StreamPrimitive<A>,
+A::NonceSize: Sub<S::NonceOverhead>,
+NonceSize<A, S>: ArrayLength<u8> {
+	inner: W,
+	encryptor: Encryptor<A, S>,
+	/// Plaintext of the next chunk, or the sealed chunk not written yet
+	buffer: [u8; N],
+	len: usize,
+	/// Position of the sealed chunk to be written, or `None` while `buffer` holds plaintext
+	pos: Option<usize>
+}
+
+impl<W, A, const N: usize, S> EmbeddedLeaStreamWriter<W, A, N, S> where
+W: Write,
+A: AeadInPlace,
+S: StreamPrimitive<A>,
+A::NonceSize: Sub<S::NonceOverhead>,
+NonceSize<A, S>: ArrayLength<u8> {
+	/// # Panics
+	///
+	/// Panics if `N` is not greater than the tag size.
+	pub fn new(inner: W, encryptor: Encryptor<A, S>) -> Self {
+		assert!(N > A::TagSize::USIZE);
+
+		Self { inner, encryptor, buffer: [0; N], len: 0, pos: None }
+	}
+
+	pub fn get_ref(&self) -> &W {
+		&self.inner
+	}
+
+	/// Seals and writes the last chunk, flushes, and returns the inner writer.
+	pub fn finish(mut self) -> Result<W, Error<W::Error>> {
+		self.write_pending()?;
+
+		let Self { mut inner, encryptor, mut buffer, len, .. } = self;
+		let mut chunk = ChunkBuffer { data: &mut buffer, len };
+		encryptor.encrypt_last_in_place(&[], &mut chunk).map_err(|_| Error::Exhausted)?;
+
+		let mut data = chunk.as_ref();
+		while !data.is_empty() {
+			match inner.write(data).map_err(Error::Io)? {
+				0 => return Err(Error::WriteZero),
+				n => data = &data[n..]
+			}
+		}
+		inner.flush().map_err(Error::Io)?;
+
+		Ok(inner)
+	}
+
+	fn write_pending(&mut self) -> Result<(), Error<W::Error>> {
+		if let Some(mut pos) = self.pos {
+			while pos < self.len {
+				match self.inner.write(&self.buffer[pos..self.len]).map_err(Error::Io)? {
+					0 => return Err(Error::WriteZero),
+					n => pos += n
+				}
+				self.pos = Some(pos);
+			}
+
+			self.pos = None;
+			self.len = 0;
+		}
+
+		Ok(())
+	}
+}
+
+impl<W, A, const N: usize, S> ErrorType for EmbeddedLeaStreamWriter<W, A, N, S> where
+W: Write,
+A: AeadInPlace,
+S: StreamPrimitive<A>,
+A::NonceSize: Sub<S::NonceOverhead>,
+NonceSize<A, S>: ArrayLength<u8> {
+	type Error = Error<W::Error>;
+}
+
+impl<W, A, const N: usize, S> Write for EmbeddedLeaStreamWriter<W, A, N, S> where
+W: Write,
+A: AeadInPlace,
+S: StreamPrimitive<A>,
+A::NonceSize: Sub<S::NonceOverhead>,
+NonceSize<A, S>: ArrayLength<u8> {
+	fn write(&mut self, data: &[u8]) -> Result<usize, Self::Error> {
+		self.write_pending()?;
+
+		if data.is_empty() {
+			return Ok(0);
+		}
+
+		// A full chunk is sealed only when more data arrives, as it may be the last one otherwise.
+		let chunk_size = N - A::TagSize::USIZE;
+		if self.len == chunk_size {
+			let mut chunk = ChunkBuffer { data: &mut self.buffer, len: self.len };
+			self.encryptor.encrypt_next_in_place(&[], &mut chunk).map_err(|_| Error::Exhausted)?;
+			self.len = chunk.len;
+			self.pos = Some(0);
+			self.write_pending()?;
+		}
+
+		let n = data.len().min(chunk_size - self.len);
+		self.buffer[self.len..(self.len + n)].copy_from_slice(&data[..n]);
+		self.len += n;
+
+		Ok(n)
+	}
+
+	/// Writes the chunks sealed so far and flushes `W`, leaving the buffered plaintext for the next chunk.
+	fn flush(&mut self) -> Result<(), Self::Error> {
+		self.write_pending()?;
+		self.inner.flush().map_err(Error::Io)
+	}
+}
+
+/// [`Buffer`] over the array of a chunk
+struct ChunkBuffer<'a> {
+	data: &'a mut [u8],
+	len: usize
+}
+
+impl AsRef<[u8]> for ChunkBuffer<'_> {
+	fn as_ref(&self) -> &[u8] {
+		&self.data[..self.len]
+	}
+}
+
+impl AsMut<[u8]> for ChunkBuffer<'_> {
+	fn as_mut(&mut self) -> &mut [u8] {
+		&mut self.data[..self.len]
+	}
+}
+
+impl Buffer for ChunkBuffer<'_> {
+	fn extend_from_slice(&mut self, other: &[u8]) -> aead::Result<()> {
+		let end = self.len.checked_add(other.len()).filter(|&end| end <= self.data.len()).ok_or(aead::Error)?;
+		self.data[self.len..end].copy_from_slice(other);
+		self.len = end;
+
+		Ok(())
+	}
+
+	fn truncate(&mut self, len: usize) {
+		self.len = self.len.min(len);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	extern crate alloc;
+
+	use alloc::vec::Vec;
+
+	use embedded_io::{ErrorKind, ErrorType, Read, Seek, SeekFrom, Write};
+
+	use crate::{embedded::{EmbeddedLeaCtrReader, EmbeddedLeaCtrWriter, EmbeddedLeaStreamReader, EmbeddedLeaStreamWriter, Error}, prelude::*,
+		Lea128Ctr, Lea128CcmDecryptorBE32, Lea128CcmEncryptorBE32, Lea128GcmDecryptorBE32, Lea128GcmEncryptorBE32};
+
+	/// Flash memory that reads and writes at most 5 bytes per call
+	struct Flash {
+		data: Vec<u8>,
+		pos: usize
+	}
+
+	impl ErrorType for Flash {
+		type Error = ErrorKind;
+	}
+
+	impl Read for Flash {
+		fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+			let n = buf.len().min(5).min(self.data.len() - self.pos);
+			buf[..n].copy_from_slice(&self.data[self.pos..(self.pos + n)]);
+			self.pos += n;
+
+			Ok(n)
+		}
+	}
+
+	impl Write for Flash {
+		fn write(&mut self, data: &[u8]) -> Result<usize, Self::Error> {
+			let n = data.len().min(5);
+			self.data.extend_from_slice(&data[..n]);
+
+			Ok(n)
+		}
+
+		fn flush(&mut self) -> Result<(), Self::Error> {
+			Ok(())
+		}
+	}
+
+	impl Seek for Flash {
+		fn seek(&mut self, pos: SeekFrom) -> Result<u64, Self::Error> {
+			let pos = match pos {
+				SeekFrom::Start(pos) => Some(pos),
+				SeekFrom::End(offset) => (self.data.len() as u64).checked_add_signed(offset),
+				SeekFrom::Current(offset) => (self.pos as u64).checked_add_signed(offset)
+			}.ok_or(ErrorKind::InvalidInput)?;
+			self.pos = pos as usize;
+
+			Ok(pos)
+		}
+	}
+
+	fn seal(ptxt: &[u8]) -> Result<Vec<u8>, Error<ErrorKind>> {
+		let key = arr![u8; 0x45, 0x6A, 0x7C, 0x41, 0x14, 0x4D, 0x56, 0x05, 0x12, 0x7B, 0x02, 0x1D, 0x48, 0xCE, 0x1E, 0xF4];
+		let nonce_prefix = arr![u8; 0x4F, 0xDA, 0xBE, 0xA6, 0x09, 0xE6, 0x9F];
+
+		let mut writer = EmbeddedLeaStreamWriter::<_, _, 116>::new(Flash { data: Vec::new(), pos: 0 }, Lea128GcmEncryptorBE32::new(&key, &nonce_prefix));
+		for piece in ptxt.chunks(7) {
+			writer.write_all(piece)?;
+		}
+
+		Ok(writer.finish()?.data)
+	}
+
+	fn open(ctxt: &[u8]) -> Result<Vec<u8>, Error<ErrorKind>> {
+		let key = arr![u8; 0x45, 0x6A, 0x7C, 0x41, 0x14, 0x4D, 0x56, 0x05, 0x12, 0x7B, 0x02, 0x1D, 0x48, 0xCE, 0x1E, 0xF4];
+		let nonce_prefix = arr![u8; 0x4F, 0xDA, 0xBE, 0xA6, 0x09, 0xE6, 0x9F];
+
+		let mut reader = EmbeddedLeaStreamReader::<_, _, 116>::new(Flash { data: ctxt.to_vec(), pos: 0 }, Lea128GcmDecryptorBE32::new(&key, &nonce_prefix));
+		let mut ptxt = Vec::new();
+		let mut buffer = [0; 3];
+		loop {
+			match reader.read(&mut buffer)? {
+				0 => return Ok(ptxt),
+				n => ptxt.extend_from_slice(&buffer[..n])
+			}
+		}
+	}
+
+	#[test]
+	fn lea128ctr_embedded() -> Result<(), Error<ErrorKind>> {
+		let key = arr![u8; 0x7A, 0xD3, 0x6A, 0x75, 0xD5, 0x5F, 0x30, 0x22, 0x09, 0x4E, 0x06, 0xF7, 0xC8, 0x97, 0xD8, 0xBB];
+		let nonce = arr![u8; 0x0C, 0x5F, 0x04, 0xE8, 0xB5, 0x12, 0x19, 0x5E, 0x74, 0xB3, 0xDE, 0x57, 0xE9, 0x70, 0x97, 0x9E];
+		let ptxt: Vec<u8> = (0..100).collect();
+		let mut ctxt = ptxt.clone();
+		Lea128Ctr::new(&key, &nonce).apply_keystream(&mut ctxt);
+
+		// Writing in pieces to a writer that accepts only some bytes
+		let mut writer = EmbeddedLeaCtrWriter::new(Flash { data: Vec::new(), pos: 0 }, Lea128Ctr::new(&key, &nonce));
+		for piece in ptxt.chunks(7) {
+			writer.write_all(piece)?;
+		}
+		assert_eq!(writer.into_inner().data, ctxt);
+
+		// Reading after a header, with seeking
+		let mut data = b"header".to_vec();
+		data.extend_from_slice(&ctxt);
+		let mut reader = EmbeddedLeaCtrReader::new(Flash { data, pos: 6 }, Lea128Ctr::new(&key, &nonce));
+
+		let mut buffer = [0; 10];
+		reader.read_exact(&mut buffer).unwrap();
+		assert_eq!(buffer, ptxt[..10]);
+		reader.seek(SeekFrom::Start(6 + 50))?;
+		reader.read_exact(&mut buffer).unwrap();
+		assert_eq!(buffer, ptxt[50..60]);
+		reader.seek(SeekFrom::Current(-30))?;
+		reader.read_exact(&mut buffer).unwrap();
+		assert_eq!(buffer, ptxt[30..40]);
+		reader.seek(SeekFrom::End(-5))?;
+		reader.read_exact(&mut buffer[..5]).unwrap();
+		assert_eq!(buffer[..5], ptxt[95..]);
+
+		// Before the start of the keystream
+		assert_eq!(reader.seek(SeekFrom::Start(5)), Err(Error::InvalidSeek));
+		assert_eq!(reader.get_ref().pos, 106);
+
+		// Writing and reading more than a chunk at once
+		let ptxt: Vec<u8> = (0..=255).cycle().take(1000).collect();
+		let mut ctxt = ptxt.clone();
+		Lea128Ctr::new(&key, &nonce).apply_keystream(&mut ctxt);
+
+		let mut buffer = [0; 1000];
+		let mut writer = EmbeddedLeaCtrWriter::new(buffer.as_mut_slice(), Lea128Ctr::new(&key, &nonce));
+		writer.write_all(&ptxt).unwrap();
+		assert_eq!(buffer.as_slice(), ctxt);
+
+		let mut reader = EmbeddedLeaCtrReader::new(ctxt.as_slice(), Lea128Ctr::new(&key, &nonce));
+		reader.read_exact(&mut buffer).unwrap();
+		assert_eq!(buffer.as_slice(), ptxt);
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128gcm_stream_embedded() -> Result<(), Error<ErrorKind>> {
+		let key = arr![u8; 0x45, 0x6A, 0x7C, 0x41, 0x14, 0x4D, 0x56, 0x05, 0x12, 0x7B, 0x02, 0x1D, 0x48, 0xCE, 0x1E, 0xF4];
+		let nonce_prefix = arr![u8; 0x4F, 0xDA, 0xBE, 0xA6, 0x09, 0xE6, 0x9F];
+
+		for len in [0, 1, 99, 100, 101, 250, 300] {
+			let ptxt: Vec<u8> = (0..len).map(|i| i as u8).collect();
+
+			// Chunks as sealed by the STREAM encryptor
+			let mut expected = Vec::new();
+			let mut encryptor = Lea128GcmEncryptorBE32::new(&key, &nonce_prefix);
+			let mut chunks: Vec<&[u8]> = ptxt.chunks(100).collect();
+			let last = chunks.pop().unwrap_or(&[]);
+			for chunk in chunks {
+				expected.extend(encryptor.encrypt_next(chunk).unwrap());
+			}
+			expected.extend(encryptor.encrypt_last(last).unwrap());
+
+			let ctxt = seal(&ptxt)?;
+			assert_eq!(ctxt, expected);
+			assert_eq!(open(&ctxt)?, ptxt);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128ccm_stream_embedded() -> Result<(), Error<ErrorKind>> {
+		let key = arr![u8; 0x7A, 0xD3, 0x6A, 0x75, 0xD5, 0x5F, 0x30, 0x22, 0x09, 0x4E, 0x06, 0xF7, 0xC8, 0x97, 0xD8, 0xBB];
+		let nonce_prefix = arr![u8; 0x0C, 0x5F, 0x04, 0xE8, 0xB5, 0x12, 0x19, 0x5E];
+		let ptxt: Vec<u8> = (0..=255).cycle().take(1000).collect();
+
+		let mut ctxt = [0; 1000 + 16 * 16];
+		let encryptor: Lea128CcmEncryptorBE32 = Lea128CcmEncryptorBE32::new(&key, &nonce_prefix);
+		let mut writer = EmbeddedLeaStreamWriter::<_, _, 80>::new(ctxt.as_mut_slice(), encryptor);
+		writer.write_all(&ptxt).unwrap();
+		assert!(writer.finish().unwrap().is_empty());
+
+		let mut buffer = [0; 1000];
+		let decryptor: Lea128CcmDecryptorBE32 = Lea128CcmDecryptorBE32::new(&key, &nonce_prefix);
+		let mut reader = EmbeddedLeaStreamReader::<_, _, 80>::new(ctxt.as_slice(), decryptor);
+		reader.read_exact(&mut buffer).unwrap();
+		assert_eq!(buffer.as_slice(), ptxt);
+		assert_eq!(reader.read(&mut buffer), Ok(0));
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128gcm_stream_embedded_reject() -> Result<(), Error<ErrorKind>> {
+		let ptxt: Vec<u8> = (0..250).map(|i| i as u8).collect();
+		let ctxt = seal(&ptxt)?;
+		assert_eq!(ctxt.len(), 250 + 3 * 16);
+
+		// Truncation at a chunk boundary
+		assert_eq!(open(&ctxt[..116]), Err(Error::InvalidTag));
+		assert_eq!(open(&ctxt[..232]), Err(Error::InvalidTag));
+
+		// Truncation inside a chunk
+		assert_eq!(open(&ctxt[..(ctxt.len() - 1)]), Err(Error::InvalidTag));
+		assert_eq!(open(&[]), Err(Error::InvalidTag));
+
+		// Reordering
+		let mut reordered = ctxt[116..232].to_vec();
+		reordered.extend_from_slice(&ctxt[..116]);
+		reordered.extend_from_slice(&ctxt[232..]);
+		assert_eq!(open(&reordered), Err(Error::InvalidTag));
+
+		// Modification
+		let mut modified = ctxt.clone();
+		modified[150] ^= 1;
+		assert_eq!(open(&modified), Err(Error::InvalidTag));
+
+		// Appending
+		let mut appended = ctxt.clone();
+		appended.push(0);
+		assert_eq!(open(&appended), Err(Error::InvalidTag));
+
+		Ok(())
+	}
+}
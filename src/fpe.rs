@@ -0,0 +1,924 @@
+// Copyright © 2023 남기훈 <gihunnam@proton.me>
+//
+// This file and its content are subject to the terms of the MIT License (the "License").
+// If a copy of the License was not distributed with this file, you can obtain one at https://opensource.org/licenses/MIT.
+
+//! LEA-FF1 and LEA-FF3-1
+//!
+//! Format-preserving encryption specified in NIST SP 800-38G Rev. 1.
+//! Both encrypt a string of numerals in a radix from 2 to 2<sup>16</sup> into another string of the same radix and length.
+//!
+//! The domain, i.e. radix<sup>length</sup>, must have at least 1,000,000 elements, and each half of a string must fit in 120 bits (FF1) or 96 bits (FF3-1),
+//! which allows e.g. up to 72 (FF1) or 56 (FF3-1) decimal digits. See [`Fpe::min_len`] and [`Fpe::max_len`].
+//! FF1 takes tweaks of any length, while FF3-1 takes 7-byte tweaks.
+//!
+//! Numerals are `u16`, and [`Alphabet`] maps them to and from byte symbols.
+//!
+//! * Example
+//! ```
+//! use lea::{fpe::{Alphabet, Fpe}, prelude::*, Lea128Ff1};
+//!
+//! let key = arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C];
+//! let tweak = [0x39, 0x38, 0x37, 0x36, 0x35, 0x34, 0x33, 0x32, 0x31, 0x30];
+//! let ptxt = *b"4111111111111111";
+//! let ctxt = *b"0539982863224389";
+//!
+//! let lea128ff1 = Lea128Ff1::new(&key, 10).unwrap();
+//!
+//! // Encryption
+//! let mut buffer = ptxt;
+//! lea128ff1.encrypt_text(&Alphabet::DECIMAL, &tweak, &mut buffer).unwrap();
+//! assert_eq!(buffer, ctxt);
+//!
+//! // Decryption
+//! let mut buffer = ctxt;
+//! lea128ff1.decrypt_text(&Alphabet::DECIMAL, &tweak, &mut buffer).unwrap();
+//! assert_eq!(buffer, ptxt);
+//! ```
+
+use core::fmt;
+
+use cipher::{generic_array::{typenum::U16, GenericArray}, BlockCipher, BlockCipherKey, BlockEncrypt, NewBlockCipher};
+
+use crate::{Lea128, Lea192, Lea256};
+
+pub type Lea128Ff1 = Ff1<Lea128>;
+pub type Lea192Ff1 = Ff1<Lea192>;
+pub type Lea256Ff1 = Ff1<Lea256>;
+
+pub type Lea128Ff3_1 = Ff3_1<Lea128>;
+pub type Lea192Ff3_1 = Ff3_1<Lea192>;
+pub type Lea256Ff3_1 = Ff3_1<Lea256>;
+
+type Block = GenericArray<u8, U16>;
+
+/// Maximum length of numeral strings in any radix
+pub const MAX_LEN: usize = 240;
+
+/// Minimum number of elements of a domain
+const MIN_DOMAIN_SIZE: u128 = 1_000_000;
+
+/// Error of format-preserving encryption
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Error {
+	/// Radix is not from 2 to 2<sup>16</sup>.
+	InvalidRadix,
+	/// Alphabet has fewer than 2 or duplicate symbols, or its size is not the radix.
+	InvalidAlphabet,
+	/// Numeral string is too short or too long for the radix.
+	InvalidLength,
+	/// Numeral is not less than the radix, or symbol is not in the alphabet.
+	InvalidNumeral,
+	/// Tweak length is not supported.
+	InvalidTweakLength
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::InvalidRadix => "invalid radix",
+			Self::InvalidAlphabet => "invalid alphabet",
+			Self::InvalidLength => "invalid length",
+			Self::InvalidNumeral => "invalid numeral",
+			Self::InvalidTweakLength => "invalid tweak length"
+		})
+	}
+}
+
+/// Format-preserving encryption of numeral strings
+pub trait Fpe {
+	fn radix(&self) -> u32;
+
+	/// Minimum length of numeral strings, so that the domain has at least 1,000,000 elements
+	fn min_len(&self) -> usize;
+
+	/// Maximum length of numeral strings
+	fn max_len(&self) -> usize;
+
+	/// Encrypts numerals in place.
+	fn encrypt(&self, tweak: &[u8], x: &mut [u16]) -> Result<(), Error>;
+
+	/// Decrypts numerals in place.
+	fn decrypt(&self, tweak: &[u8], x: &mut [u16]) -> Result<(), Error>;
+
+	/// Encrypts symbols of `alphabet` in place.
+	fn encrypt_text(&self, alphabet: &Alphabet<'_>, tweak: &[u8], text: &mut [u8]) -> Result<(), Error> {
+		crypt_text(alphabet, text, self.radix(), |x| self.encrypt(tweak, x))
+	}
+
+	/// Decrypts symbols of `alphabet` in place.
+	fn decrypt_text(&self, alphabet: &Alphabet<'_>, tweak: &[u8], text: &mut [u8]) -> Result<(), Error> {
+		crypt_text(alphabet, text, self.radix(), |x| self.decrypt(tweak, x))
+	}
+}
+
+/// Alphabet of distinct byte symbols, where the i-th symbol stands for the numeral i
+#[derive(Clone, Copy, Debug)]
+pub struct Alphabet<'a> {
+	symbols: &'a [u8]
+}
+
+impl Alphabet<'static> {
+	/// `0`–`9`
+	pub const DECIMAL: Self = Self { symbols: b"0123456789" };
+	/// `0`–`9`, `a`–`z`
+	pub const ALPHANUMERIC: Self = Self { symbols: b"0123456789abcdefghijklmnopqrstuvwxyz" };
+}
+
+impl<'a> Alphabet<'a> {
+	pub fn new(symbols: &'a [u8]) -> Result<Self, Error> {
+		let distinct = symbols.iter().enumerate().all(|(i, s)| !symbols[..i].contains(s));
+
+		if symbols.len() >= 2 && distinct {
+			Ok(Self { symbols })
+		} else {
+			Err(Error::InvalidAlphabet)
+		}
+	}
+
+	pub fn radix(&self) -> u32 {
+		self.symbols.len() as u32
+	}
+
+	fn numeral(&self, symbol: u8) -> Result<u16, Error> {
+		self.symbols.iter().position(|&s| s == symbol).map(|i| i as u16).ok_or(Error::InvalidNumeral)
+	}
+
+	fn symbol(&self, numeral: u16) -> u8 {
+		self.symbols[numeral as usize]
+	}
+}
+
+/// FF1
+///
+/// Ten Feistel rounds with CBC-MAC over the whole tweak as the round function.
+pub struct Ff1<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	cipher: C,
+	domain: Domain
+}
+
+impl<C> Ff1<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	pub fn new(key: &BlockCipherKey<C>, radix: u32) -> Result<Self, Error> where
+	C: NewBlockCipher {
+		Self::from_cipher(C::new(key), radix)
+	}
+
+	pub fn from_cipher(cipher: C, radix: u32) -> Result<Self, Error> {
+		Ok(Self { cipher, domain: Domain::new(radix, 120)? })
+	}
+
+	fn crypt(&self, tweak: &[u8], x: &mut [u16], decrypt: bool) -> Result<(), Error> {
+		self.domain.check(x)?;
+		let t = u32::try_from(tweak.len()).map_err(|_| Error::InvalidTweakLength)?;
+
+		let n = x.len();
+		let (u, v) = (n / 2, n - n / 2);
+		let (modulus_u, modulus_v) = (self.domain.modulus(u), self.domain.modulus(v));
+		let b_len = (128 - (modulus_v - 1).leading_zeros() as usize).div_ceil(8);
+		let d = 4 * b_len.div_ceil(4) + 4;
+
+		// P || T || 0^((-t-b-1) mod 16), common to all rounds
+		let mut prefix = CbcMac::new(&self.cipher);
+		let radix = self.domain.radix.to_be_bytes();
+		prefix.update(&[1, 2, 1, radix[1], radix[2], radix[3], 10, u as u8]);
+		prefix.update(&(n as u32).to_be_bytes());
+		prefix.update(&t.to_be_bytes());
+		prefix.update(tweak);
+		for _ in 0..(16 - (tweak.len() + b_len + 1) % 16) % 16 {
+			prefix.update(&[0]);
+		}
+
+		let round = |i: u8, x: u128, modulus: u128| {
+			let mut mac = prefix.clone();
+			mac.update(&[i]);
+			mac.update(&x.to_be_bytes()[(16 - b_len)..]);
+			let r = mac.finalize();
+
+			let s = (1..d.div_ceil(16) as u128).map(|j| {
+				let mut block = r;
+				block.iter_mut().zip(j.to_be_bytes()).for_each(|(r, j)| *r ^= j);
+				self.cipher.encrypt_block(&mut block);
+
+				block
+			});
+
+			// NUM(S) mod radix^m, where radix^m ≤ 2^120 keeps the shift from overflowing
+			core::iter::once(r).chain(s).flatten().take(d).fold(0, |y, s| ((y << 8) | s as u128) % modulus)
+		};
+
+		let (x_a, x_b) = x.split_at_mut(u);
+		let mut a = self.domain.num(x_a.iter().copied());
+		let mut b = self.domain.num(x_b.iter().copied());
+
+		if decrypt {
+			for i in (0..10).rev() {
+				let modulus = if i % 2 == 0 { modulus_u } else { modulus_v };
+				let c = (b + modulus - round(i, a, modulus)) % modulus;
+				b = a;
+				a = c;
+			}
+		} else {
+			for i in 0..10 {
+				let modulus = if i % 2 == 0 { modulus_u } else { modulus_v };
+				let c = (a + round(i, b, modulus)) % modulus;
+				a = b;
+				b = c;
+			}
+		}
+
+		self.domain.str(a, x_a.iter_mut().rev());
+		self.domain.str(b, x_b.iter_mut().rev());
+
+		Ok(())
+	}
+}
+
+impl<C> Fpe for Ff1<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	fn radix(&self) -> u32 {
+		self.domain.radix
+	}
+
+	fn min_len(&self) -> usize {
+		self.domain.min_len
+	}
+
+	fn max_len(&self) -> usize {
+		self.domain.max_len
+	}
+
+	fn encrypt(&self, tweak: &[u8], x: &mut [u16]) -> Result<(), Error> {
+		self.crypt(tweak, x, false)
+	}
+
+	fn decrypt(&self, tweak: &[u8], x: &mut [u16]) -> Result<(), Error> {
+		self.crypt(tweak, x, true)
+	}
+}
+
+/// FF3-1
+///
+/// Eight Feistel rounds with a single block encryption as the round function, and 7-byte tweaks.
+pub struct Ff3_1<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	cipher: C,
+	domain: Domain
+}
+
+impl<C> Ff3_1<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	pub fn new(key: &BlockCipherKey<C>, radix: u32) -> Result<Self, Error> where
+	C: NewBlockCipher {
+		let mut key = key.clone();
+		key.reverse();
+
+		Self::from_cipher(C::new(&key), radix)
+	}
+
+	/// Creates FF3-1 from a cipher, which must be keyed with the byte-reversed key.
+	pub fn from_cipher(cipher: C, radix: u32) -> Result<Self, Error> {
+		Ok(Self { cipher, domain: Domain::new(radix, 96)? })
+	}
+
+	fn crypt(&self, tweak: &[u8], x: &mut [u16], decrypt: bool) -> Result<(), Error> {
+		self.domain.check(x)?;
+		let tweak: &[u8; 7] = tweak.try_into().map_err(|_| Error::InvalidTweakLength)?;
+		let t_l = [tweak[0], tweak[1], tweak[2], tweak[3] & 0xF0];
+		let t_r = [tweak[4], tweak[5], tweak[6], tweak[3] << 4];
+
+		let n = x.len();
+		let (u, v) = (n - n / 2, n / 2);
+		let (modulus_u, modulus_v) = (self.domain.modulus(u), self.domain.modulus(v));
+
+		// REVB(CIPH_REVB(K)(REVB(P))), where P = (W ⊕ [i]^4) || [x]^12
+		let round = |i: u8, x: u128| {
+			let w = [t_r, t_l][i as usize % 2];
+
+			let mut block = Block::default();
+			block[..12].copy_from_slice(&x.to_le_bytes()[..12]);
+			block[12..].iter_mut().zip(w.iter().rev()).for_each(|(b, w)| *b = *w);
+			block[12] ^= i;
+			self.cipher.encrypt_block(&mut block);
+
+			u128::from_le_bytes(block.into())
+		};
+
+		// Numerals are in reverse order.
+		let (x_a, x_b) = x.split_at_mut(u);
+		let mut a = self.domain.num(x_a.iter().rev().copied());
+		let mut b = self.domain.num(x_b.iter().rev().copied());
+
+		if decrypt {
+			for i in (0..8).rev() {
+				let modulus = if i % 2 == 0 { modulus_u } else { modulus_v };
+				let c = (b + modulus - round(i, a) % modulus) % modulus;
+				b = a;
+				a = c;
+			}
+		} else {
+			for i in 0..8 {
+				let modulus = if i % 2 == 0 { modulus_u } else { modulus_v };
+				let c = (a + round(i, b) % modulus) % modulus;
+				a = b;
+				b = c;
+			}
+		}
+
+		self.domain.str(a, x_a.iter_mut());
+		self.domain.str(b, x_b.iter_mut());
+
+		Ok(())
+	}
+}
+
+impl<C> Fpe for Ff3_1<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	fn radix(&self) -> u32 {
+		self.domain.radix
+	}
+
+	fn min_len(&self) -> usize {
+		self.domain.min_len
+	}
+
+	fn max_len(&self) -> usize {
+		self.domain.max_len
+	}
+
+	fn encrypt(&self, tweak: &[u8], x: &mut [u16]) -> Result<(), Error> {
+		self.crypt(tweak, x, false)
+	}
+
+	fn decrypt(&self, tweak: &[u8], x: &mut [u16]) -> Result<(), Error> {
+		self.crypt(tweak, x, true)
+	}
+}
+
+/// Radix and numeral string lengths
+struct Domain {
+	radix: u32,
+	min_len: usize,
+	max_len: usize
+}
+
+impl Domain {
+	/// Domain whose halves are less than 2<sup>`bits`</sup>
+	fn new(radix: u32, bits: u32) -> Result<Self, Error> {
+		if !(2..=(1 << 16)).contains(&radix) {
+			return Err(Error::InvalidRadix);
+		}
+
+		let radix_u128 = radix as u128;
+		let min_len = (2..).find(|&n| radix_u128.pow(n) >= MIN_DOMAIN_SIZE).unwrap() as usize;
+		let max_half_len = (1..).find(|&n| radix_u128.pow(n) > (1 << bits) / radix_u128).unwrap() as usize;
+
+		Ok(Self { radix, min_len, max_len: 2 * max_half_len })
+	}
+
+	fn check(&self, x: &[u16]) -> Result<(), Error> {
+		if !(self.min_len..=self.max_len).contains(&x.len()) {
+			Err(Error::InvalidLength)
+		} else if x.iter().any(|&x| x as u32 >= self.radix) {
+			Err(Error::InvalidNumeral)
+		} else {
+			Ok(())
+		}
+	}
+
+	/// radix<sup>m</sup>
+	fn modulus(&self, m: usize) -> u128 {
+		(self.radix as u128).pow(m as u32)
+	}
+
+	/// NUM<sub>radix</sub>, most significant numeral first
+	fn num(&self, x: impl Iterator<Item = u16>) -> u128 {
+		x.fold(0, |num, x| num * self.radix as u128 + x as u128)
+	}
+
+	/// STR<sub>radix</sub>, least significant numeral first
+	fn str<'a>(&self, mut num: u128, x: impl Iterator<Item = &'a mut u16>) {
+		for x in x {
+			*x = (num % self.radix as u128) as u16;
+			num /= self.radix as u128;
+		}
+	}
+}
+
+/// CBC-MAC with a zero IV over whole blocks
+struct CbcMac<'c, C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	cipher: &'c C,
+	state: Block,
+	pos: usize
+}
+
+impl<C> Clone for CbcMac<'_, C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	fn clone(&self) -> Self {
+		Self { cipher: self.cipher, state: self.state, pos: self.pos }
+	}
+}
+
+impl<'c, C> CbcMac<'c, C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	fn new(cipher: &'c C) -> Self {
+		Self { cipher, state: Block::default(), pos: 0 }
+	}
+
+	fn update(&mut self, data: &[u8]) {
+		for byte in data {
+			self.state[self.pos] ^= byte;
+			self.pos += 1;
+
+			if self.pos == 16 {
+				self.cipher.encrypt_block(&mut self.state);
+				self.pos = 0;
+			}
+		}
+	}
+
+	fn finalize(self) -> Block {
+		debug_assert_eq!(self.pos, 0);
+
+		self.state
+	}
+}
+
+fn crypt_text(alphabet: &Alphabet<'_>, text: &mut [u8], radix: u32, crypt: impl FnOnce(&mut [u16]) -> Result<(), Error>) -> Result<(), Error> {
+	if alphabet.radix() != radix {
+		return Err(Error::InvalidAlphabet);
+	}
+
+	let mut buffer = [0; MAX_LEN];
+	let x = buffer.get_mut(..text.len()).ok_or(Error::InvalidLength)?;
+	for (x, &symbol) in x.iter_mut().zip(text.iter()) {
+		*x = alphabet.numeral(symbol)?;
+	}
+
+	crypt(x)?;
+	for (symbol, &x) in text.iter_mut().zip(x.iter()) {
+		*symbol = alphabet.symbol(x);
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	extern crate alloc;
+
+	use alloc::{vec::Vec, vec};
+
+	use cipher::{BlockCipher, BlockCipherKey, NewBlockCipher};
+
+	use crate::{fpe::{Alphabet, Error, Fpe}, prelude::*, Lea128, Lea192, Lea256, Lea128Ff1, Lea192Ff1, Lea256Ff1, Lea128Ff3_1, Lea192Ff3_1, Lea256Ff3_1};
+
+	struct TestCase<T> where
+	T: BlockCipher + NewBlockCipher {
+		key: BlockCipherKey<T>,
+		radix: u32,
+		tweak: Vec<u8>,
+		ptxt: Vec<u16>,
+		ctxt: Vec<u16>
+	}
+
+	#[test]
+	fn lea128ff1() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea128>; 5] = [
+			TestCase {
+				key: arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C],
+				radix: 10,
+				tweak: vec![],
+				ptxt: vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+				ctxt: vec![9, 9, 5, 4, 5, 0, 1, 4, 8, 4]
+			},
+			TestCase {
+				key: arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C],
+				radix: 10,
+				tweak: vec![0x39, 0x38, 0x37, 0x36, 0x35, 0x34, 0x33, 0x32, 0x31, 0x30],
+				ptxt: vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+				ctxt: vec![2, 9, 2, 0, 4, 4, 1, 8, 2, 7]
+			},
+			TestCase {
+				key: arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C],
+				radix: 36,
+				tweak: vec![0x37, 0x37, 0x37, 0x37, 0x70, 0x71, 0x72, 0x73, 0x37, 0x37, 0x37],
+				ptxt: vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18],
+				ctxt: vec![9, 12, 26, 10, 18, 10, 28, 7, 15, 34, 3, 24, 26, 20, 28, 7, 25, 18, 34]
+			},
+			TestCase {
+				key: arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C],
+				radix: 2,
+				tweak: vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F, 0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27],
+				ptxt: vec![1, 1, 0, 0, 1, 1, 1, 0, 0, 1, 1, 1, 1, 1, 1, 1, 0, 0, 1, 0, 1, 0, 0, 1, 1, 0, 0, 1, 0, 1, 0, 0, 1, 0, 0, 1, 1, 0, 1, 1, 1, 1, 1, 1, 0, 1, 1, 0, 1, 0, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 1, 1, 0, 0, 1, 0, 1, 1, 0, 1, 0, 0, 1, 0, 1, 0, 0, 0, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 1, 1, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 0, 0, 1, 0, 1, 1, 1, 1, 0, 1, 0, 0, 1, 1, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 0, 0, 1, 0, 1, 0, 0, 1, 0, 1, 0, 0, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 1, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 1, 1, 0, 1, 0, 1, 0, 0, 0, 1, 0, 1, 0, 0, 0, 1, 1, 1, 0, 0, 1, 1, 1, 0, 1, 1, 1],
+				ctxt: vec![0, 1, 0, 1, 1, 0, 1, 0, 1, 0, 0, 1, 1, 0, 0, 0, 1, 0, 1, 0, 1, 0, 1, 1, 1, 0, 0, 1, 0, 1, 1, 0, 1, 0, 0, 1, 1, 0, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 1, 1, 1, 0, 1, 1, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 1, 1, 0, 1, 1, 1, 1, 0, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 1, 1, 0, 1, 1, 1, 1, 0, 1, 0, 1, 0, 1, 1, 0, 1, 1, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 1, 0, 0, 1, 1, 0, 1, 1, 0, 0, 1, 0, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 0, 1, 1, 1, 0, 1, 1, 0, 1, 1, 0, 1, 0, 1, 0, 0, 1, 1, 1, 1, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 0, 0, 1, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 0, 1, 0]
+			},
+			TestCase {
+				key: arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C],
+				radix: 65536,
+				tweak: vec![0x00],
+				ptxt: vec![31947, 13475, 9946, 2649, 44649, 16388, 19115],
+				ctxt: vec![15223, 35014, 52449, 34304, 58486, 28495, 13127]
+			}
+		];
+
+		for TestCase { key, radix, tweak, ptxt, ctxt } in test_cases {
+			let lea128ff1 = Lea128Ff1::new(&key, radix)?;
+
+			// Encryption
+			let mut buffer = ptxt.clone();
+			lea128ff1.encrypt(&tweak, &mut buffer)?;
+			assert_eq!(buffer, ctxt);
+
+			// Decryption
+			let mut buffer = ctxt.clone();
+			lea128ff1.decrypt(&tweak, &mut buffer)?;
+			assert_eq!(buffer, ptxt);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea192ff1() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea192>; 5] = [
+			TestCase {
+				key: arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C, 0xEF, 0x43, 0x59, 0xD8, 0xD5, 0x80, 0xAA, 0x4F],
+				radix: 10,
+				tweak: vec![],
+				ptxt: vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+				ctxt: vec![1, 1, 1, 9, 9, 8, 7, 7, 9, 0]
+			},
+			TestCase {
+				key: arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C, 0xEF, 0x43, 0x59, 0xD8, 0xD5, 0x80, 0xAA, 0x4F],
+				radix: 10,
+				tweak: vec![0x39, 0x38, 0x37, 0x36, 0x35, 0x34, 0x33, 0x32, 0x31, 0x30],
+				ptxt: vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+				ctxt: vec![6, 8, 3, 4, 4, 3, 3, 3, 9, 8]
+			},
+			TestCase {
+				key: arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C, 0xEF, 0x43, 0x59, 0xD8, 0xD5, 0x80, 0xAA, 0x4F],
+				radix: 36,
+				tweak: vec![0x37, 0x37, 0x37, 0x37, 0x70, 0x71, 0x72, 0x73, 0x37, 0x37, 0x37],
+				ptxt: vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18],
+				ctxt: vec![1, 7, 13, 19, 20, 19, 16, 8, 11, 20, 32, 29, 4, 23, 5, 34, 26, 24, 26]
+			},
+			TestCase {
+				key: arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C, 0xEF, 0x43, 0x59, 0xD8, 0xD5, 0x80, 0xAA, 0x4F],
+				radix: 2,
+				tweak: vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F, 0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27],
+				ptxt: vec![0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 1, 1, 1, 0, 1, 0, 0, 1, 1, 1, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 1, 1, 0, 1, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 1, 0, 1, 1, 1, 1, 0, 0, 1, 1, 0, 0, 1, 0, 1, 1, 1, 0, 0, 1, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 1, 1, 0, 1, 1, 1, 1, 0, 1, 0, 1, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 1, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 0, 1, 1, 1, 1, 1, 1, 0, 0, 1, 0, 1, 1, 1, 0, 0, 1, 0, 0, 1, 1, 1, 0, 1, 0, 1, 1, 1, 0, 1, 0, 1, 1, 0, 0, 0, 1, 1, 0, 1, 1, 1, 1, 0, 0, 0, 1, 0, 1, 1, 1, 1, 1, 1, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 1, 1, 0, 1, 1, 1, 1, 1, 0, 0, 1, 1, 0, 1, 0, 0, 1, 1, 1, 0, 1, 0, 1, 1, 0, 1, 0],
+				ctxt: vec![1, 1, 1, 1, 0, 0, 1, 0, 1, 0, 0, 1, 1, 1, 0, 1, 0, 1, 1, 1, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 1, 1, 1, 0, 1, 0, 1, 1, 1, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 1, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1, 1, 0, 0, 1, 0, 1, 1, 0, 1, 1, 1, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 0, 0, 1, 1, 1, 0, 1, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 1, 1, 1, 0, 0, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 1, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 0, 1, 1, 1, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 1, 0, 1, 1, 0, 1, 1, 1, 0, 0, 0, 1, 0, 0, 0, 0, 1, 1, 1, 0, 1, 1, 1, 1, 0, 1, 1, 0, 1, 0, 1, 0, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1, 1, 0, 1, 0, 0, 1, 1, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 1, 1, 0, 1, 0]
+			},
+			TestCase {
+				key: arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C, 0xEF, 0x43, 0x59, 0xD8, 0xD5, 0x80, 0xAA, 0x4F],
+				radix: 65536,
+				tweak: vec![0x00],
+				ptxt: vec![32715, 49875, 24226, 28454, 1177, 11662, 14821],
+				ctxt: vec![35554, 4838, 23943, 21070, 60125, 58776, 30901]
+			}
+		];
+
+		for TestCase { key, radix, tweak, ptxt, ctxt } in test_cases {
+			let lea192ff1 = Lea192Ff1::new(&key, radix)?;
+
+			// Encryption
+			let mut buffer = ptxt.clone();
+			lea192ff1.encrypt(&tweak, &mut buffer)?;
+			assert_eq!(buffer, ctxt);
+
+			// Decryption
+			let mut buffer = ctxt.clone();
+			lea192ff1.decrypt(&tweak, &mut buffer)?;
+			assert_eq!(buffer, ptxt);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea256ff1() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea256>; 5] = [
+			TestCase {
+				key: arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C, 0xEF, 0x43, 0x59, 0xD8, 0xD5, 0x80, 0xAA, 0x4F, 0x7F, 0x03, 0x6D, 0x6F, 0x04, 0xFC, 0x6A, 0x94],
+				radix: 10,
+				tweak: vec![],
+				ptxt: vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+				ctxt: vec![5, 3, 7, 6, 2, 6, 6, 0, 8, 1]
+			},
+			TestCase {
+				key: arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C, 0xEF, 0x43, 0x59, 0xD8, 0xD5, 0x80, 0xAA, 0x4F, 0x7F, 0x03, 0x6D, 0x6F, 0x04, 0xFC, 0x6A, 0x94],
+				radix: 10,
+				tweak: vec![0x39, 0x38, 0x37, 0x36, 0x35, 0x34, 0x33, 0x32, 0x31, 0x30],
+				ptxt: vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+				ctxt: vec![6, 1, 7, 8, 8, 1, 6, 5, 3, 0]
+			},
+			TestCase {
+				key: arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C, 0xEF, 0x43, 0x59, 0xD8, 0xD5, 0x80, 0xAA, 0x4F, 0x7F, 0x03, 0x6D, 0x6F, 0x04, 0xFC, 0x6A, 0x94],
+				radix: 36,
+				tweak: vec![0x37, 0x37, 0x37, 0x37, 0x70, 0x71, 0x72, 0x73, 0x37, 0x37, 0x37],
+				ptxt: vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18],
+				ctxt: vec![33, 11, 22, 2, 14, 33, 11, 13, 31, 3, 23, 35, 14, 16, 4, 34, 9, 18, 23]
+			},
+			TestCase {
+				key: arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C, 0xEF, 0x43, 0x59, 0xD8, 0xD5, 0x80, 0xAA, 0x4F, 0x7F, 0x03, 0x6D, 0x6F, 0x04, 0xFC, 0x6A, 0x94],
+				radix: 2,
+				tweak: vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F, 0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27],
+				ptxt: vec![0, 1, 0, 0, 1, 0, 1, 1, 0, 0, 0, 1, 1, 1, 1, 0, 1, 1, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 1, 1, 1, 0, 1, 1, 0, 1, 1, 1, 1, 1, 0, 1, 0, 0, 0, 0, 1, 0, 1, 1, 1, 0, 1, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 1, 0, 0, 1, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 1, 1, 1, 0, 0, 0, 1, 0, 1, 1, 0, 1, 1, 0, 1, 1, 0, 1, 1, 0, 0, 1, 1, 1, 1, 0, 1, 0, 0, 0, 1, 1, 1, 0, 1, 1, 0, 0, 1, 0, 1, 0, 1, 0, 1, 0, 0, 1, 1, 1, 0, 1, 0, 1, 1, 0, 1, 0, 1, 1, 1, 1, 1, 0, 1, 0, 1, 1, 1, 0, 1, 0, 1, 1, 1, 0, 1, 1, 1, 1, 0, 1, 1, 0, 1, 1, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 1, 0, 1, 1, 0, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 1, 1, 0, 1, 0, 0, 1, 0, 1, 1, 0, 0, 1, 1, 0, 1, 0, 1, 1, 0, 0, 1, 1, 0, 1, 0, 1, 1, 0, 1, 0, 1, 1, 0, 1],
+				ctxt: vec![0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 1, 0, 0, 0, 1, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 1, 1, 1, 1, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 1, 1, 0, 1, 0, 0, 1, 1, 1, 0, 0, 0, 1, 0, 1, 1, 0, 0, 1, 1, 1, 1, 1, 0, 0, 1, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 1, 1, 0, 1, 1, 1, 0, 1, 1, 1, 1, 1, 0, 1, 1, 0, 0, 1, 0, 1, 1, 0, 1, 1, 0, 1, 0, 0, 1, 1, 0, 0, 1, 1, 1, 1, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 1, 0, 1, 1, 1, 1, 1, 1, 0, 0, 1, 0, 1, 1, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 0, 0, 1, 1, 0, 0, 1, 0, 1, 0, 1, 1, 1, 0, 1, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 1, 0, 1, 1, 0, 0, 1, 1, 1, 0]
+			},
+			TestCase {
+				key: arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C, 0xEF, 0x43, 0x59, 0xD8, 0xD5, 0x80, 0xAA, 0x4F, 0x7F, 0x03, 0x6D, 0x6F, 0x04, 0xFC, 0x6A, 0x94],
+				radix: 65536,
+				tweak: vec![0x00],
+				ptxt: vec![3799, 25507, 22572, 5620, 49397, 41762, 24882],
+				ctxt: vec![9780, 47297, 23550, 54860, 41081, 26784, 14789]
+			}
+		];
+
+		for TestCase { key, radix, tweak, ptxt, ctxt } in test_cases {
+			let lea256ff1 = Lea256Ff1::new(&key, radix)?;
+
+			// Encryption
+			let mut buffer = ptxt.clone();
+			lea256ff1.encrypt(&tweak, &mut buffer)?;
+			assert_eq!(buffer, ctxt);
+
+			// Decryption
+			let mut buffer = ctxt.clone();
+			lea256ff1.decrypt(&tweak, &mut buffer)?;
+			assert_eq!(buffer, ptxt);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128ff3_1() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea128>; 5] = [
+			TestCase {
+				key: arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C],
+				radix: 10,
+				tweak: vec![0xD8, 0xE7, 0x92, 0x0A, 0xFA, 0x33, 0x0A],
+				ptxt: vec![8, 9, 0, 1, 2, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0, 0, 0],
+				ctxt: vec![7, 9, 2, 7, 7, 4, 8, 7, 7, 7, 1, 4, 0, 9, 1, 0, 7, 2]
+			},
+			TestCase {
+				key: arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C],
+				radix: 10,
+				tweak: vec![0x9A, 0x76, 0x8A, 0x92, 0xF6, 0x0E, 0x12],
+				ptxt: vec![8, 9, 0, 1, 2, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0, 0, 0],
+				ctxt: vec![6, 2, 8, 5, 8, 8, 3, 7, 0, 3, 4, 5, 7, 2, 3, 8, 5, 4]
+			},
+			TestCase {
+				key: arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C],
+				radix: 26,
+				tweak: vec![0xD8, 0xE7, 0x92, 0x0A, 0xFA, 0x33, 0x0A],
+				ptxt: vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18],
+				ctxt: vec![4, 12, 20, 9, 2, 0, 14, 17, 3, 16, 1, 10, 14, 1, 17, 7, 17, 13, 25]
+			},
+			TestCase {
+				key: arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C],
+				radix: 2,
+				tweak: vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x0F],
+				ptxt: vec![0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 1, 0, 0, 0, 1, 1, 0, 1, 0, 1, 0, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 0, 1, 1, 0, 1, 1, 1, 0, 1, 1, 0, 0, 0, 1, 1, 1, 0, 1, 0, 1, 1, 0, 0, 1, 0, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 0, 0, 1, 1, 1, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 1, 0, 1, 1, 0, 0, 1, 1, 1, 0, 0, 0, 1, 0, 0, 1, 0, 1, 0, 1, 1, 1, 1, 0, 0, 0, 1, 1, 1, 0, 1, 1, 1, 0, 0, 1, 1, 0, 1, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 1, 0, 1, 0, 1, 1, 0, 1, 0, 1, 0, 1, 1, 0, 1, 0, 1, 1, 0, 1, 1, 1, 0, 0, 1, 0, 1, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0],
+				ctxt: vec![1, 0, 1, 0, 1, 1, 1, 0, 0, 1, 1, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 1, 0, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0, 1, 0, 1, 0, 1, 1, 1, 0, 0, 1, 0, 1, 0, 0, 1, 0, 1, 1, 1, 0, 0, 1, 1, 0, 1, 0, 1, 1, 1, 0, 1, 0, 0, 1, 0, 1, 0, 0, 0, 0, 1, 1, 0, 1, 0, 1, 1, 1, 1, 0, 0, 0, 1, 0, 0, 1, 1, 1, 0, 0, 0, 0, 1, 1, 1, 0, 1, 1, 1, 1, 0, 0, 1, 0, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 1, 1, 0, 1, 0, 0, 1, 0, 1, 0, 0, 1, 1, 1, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 1, 1, 1, 0, 1, 1, 1, 0, 0, 1, 0, 1, 1, 1, 0, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0]
+			},
+			TestCase {
+				key: arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C],
+				radix: 65536,
+				tweak: vec![0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF],
+				ptxt: vec![44197, 42428, 158, 4294, 35487, 51256, 15174],
+				ctxt: vec![63122, 22446, 48594, 36344, 64807, 31858, 23307]
+			}
+		];
+
+		for TestCase { key, radix, tweak, ptxt, ctxt } in test_cases {
+			let lea128ff3_1 = Lea128Ff3_1::new(&key, radix)?;
+
+			// Encryption
+			let mut buffer = ptxt.clone();
+			lea128ff3_1.encrypt(&tweak, &mut buffer)?;
+			assert_eq!(buffer, ctxt);
+
+			// Decryption
+			let mut buffer = ctxt.clone();
+			lea128ff3_1.decrypt(&tweak, &mut buffer)?;
+			assert_eq!(buffer, ptxt);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea192ff3_1() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea192>; 5] = [
+			TestCase {
+				key: arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C, 0xEF, 0x43, 0x59, 0xD8, 0xD5, 0x80, 0xAA, 0x4F],
+				radix: 10,
+				tweak: vec![0xD8, 0xE7, 0x92, 0x0A, 0xFA, 0x33, 0x0A],
+				ptxt: vec![8, 9, 0, 1, 2, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0, 0, 0],
+				ctxt: vec![2, 6, 8, 8, 3, 0, 7, 6, 2, 5, 5, 3, 3, 5, 4, 7, 6, 5]
+			},
+			TestCase {
+				key: arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C, 0xEF, 0x43, 0x59, 0xD8, 0xD5, 0x80, 0xAA, 0x4F],
+				radix: 10,
+				tweak: vec![0x9A, 0x76, 0x8A, 0x92, 0xF6, 0x0E, 0x12],
+				ptxt: vec![8, 9, 0, 1, 2, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0, 0, 0],
+				ctxt: vec![6, 0, 3, 8, 8, 3, 2, 6, 5, 0, 6, 4, 5, 7, 6, 5, 0, 5]
+			},
+			TestCase {
+				key: arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C, 0xEF, 0x43, 0x59, 0xD8, 0xD5, 0x80, 0xAA, 0x4F],
+				radix: 26,
+				tweak: vec![0xD8, 0xE7, 0x92, 0x0A, 0xFA, 0x33, 0x0A],
+				ptxt: vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18],
+				ctxt: vec![19, 5, 18, 11, 4, 24, 12, 3, 9, 3, 5, 1, 21, 7, 14, 8, 18, 25, 15]
+			},
+			TestCase {
+				key: arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C, 0xEF, 0x43, 0x59, 0xD8, 0xD5, 0x80, 0xAA, 0x4F],
+				radix: 2,
+				tweak: vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x0F],
+				ptxt: vec![1, 1, 0, 0, 0, 1, 0, 0, 1, 1, 1, 0, 1, 0, 1, 0, 0, 0, 1, 1, 1, 1, 0, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 1, 1, 1, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 0, 1, 0, 1, 1, 1, 1, 1, 0, 1, 0, 1, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 1, 0, 1, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 0, 0, 1, 1, 0, 1, 0, 1, 0, 0, 1, 0, 1, 0, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 1, 1, 1, 0, 0],
+				ctxt: vec![1, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 1, 1, 0, 1, 0, 1, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 1, 1, 0, 1, 1, 1, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 0, 0, 1, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 1, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 1, 0, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 0, 0, 1, 0, 1, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 1, 1, 0, 0, 1, 1, 1, 1, 0, 0, 1, 0, 1, 1, 1, 0, 1, 0, 1, 1, 1, 1, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 0, 1, 1, 0, 0, 1, 0, 1, 1, 0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 0, 0, 1, 1, 1, 1, 0, 1, 0, 1, 1, 0, 0, 1, 0, 0, 0, 1, 1, 0, 1, 0, 1, 1, 1, 1, 0]
+			},
+			TestCase {
+				key: arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C, 0xEF, 0x43, 0x59, 0xD8, 0xD5, 0x80, 0xAA, 0x4F],
+				radix: 65536,
+				tweak: vec![0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF],
+				ptxt: vec![58470, 26434, 43711, 16262, 40776, 32880, 27115],
+				ctxt: vec![31123, 32005, 36212, 50703, 36344, 45500, 44921]
+			}
+		];
+
+		for TestCase { key, radix, tweak, ptxt, ctxt } in test_cases {
+			let lea192ff3_1 = Lea192Ff3_1::new(&key, radix)?;
+
+			// Encryption
+			let mut buffer = ptxt.clone();
+			lea192ff3_1.encrypt(&tweak, &mut buffer)?;
+			assert_eq!(buffer, ctxt);
+
+			// Decryption
+			let mut buffer = ctxt.clone();
+			lea192ff3_1.decrypt(&tweak, &mut buffer)?;
+			assert_eq!(buffer, ptxt);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea256ff3_1() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea256>; 5] = [
+			TestCase {
+				key: arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C, 0xEF, 0x43, 0x59, 0xD8, 0xD5, 0x80, 0xAA, 0x4F, 0x7F, 0x03, 0x6D, 0x6F, 0x04, 0xFC, 0x6A, 0x94],
+				radix: 10,
+				tweak: vec![0xD8, 0xE7, 0x92, 0x0A, 0xFA, 0x33, 0x0A],
+				ptxt: vec![8, 9, 0, 1, 2, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0, 0, 0],
+				ctxt: vec![2, 7, 4, 0, 4, 8, 4, 0, 0, 5, 1, 2, 7, 3, 0, 6, 4, 7]
+			},
+			TestCase {
+				key: arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C, 0xEF, 0x43, 0x59, 0xD8, 0xD5, 0x80, 0xAA, 0x4F, 0x7F, 0x03, 0x6D, 0x6F, 0x04, 0xFC, 0x6A, 0x94],
+				radix: 10,
+				tweak: vec![0x9A, 0x76, 0x8A, 0x92, 0xF6, 0x0E, 0x12],
+				ptxt: vec![8, 9, 0, 1, 2, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0, 0, 0],
+				ctxt: vec![9, 2, 4, 3, 1, 8, 4, 9, 3, 7, 2, 4, 2, 1, 1, 6, 9, 5]
+			},
+			TestCase {
+				key: arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C, 0xEF, 0x43, 0x59, 0xD8, 0xD5, 0x80, 0xAA, 0x4F, 0x7F, 0x03, 0x6D, 0x6F, 0x04, 0xFC, 0x6A, 0x94],
+				radix: 26,
+				tweak: vec![0xD8, 0xE7, 0x92, 0x0A, 0xFA, 0x33, 0x0A],
+				ptxt: vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18],
+				ctxt: vec![2, 12, 15, 0, 15, 25, 11, 19, 0, 10, 21, 4, 16, 19, 9, 19, 14, 22, 19]
+			},
+			TestCase {
+				key: arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C, 0xEF, 0x43, 0x59, 0xD8, 0xD5, 0x80, 0xAA, 0x4F, 0x7F, 0x03, 0x6D, 0x6F, 0x04, 0xFC, 0x6A, 0x94],
+				radix: 2,
+				tweak: vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x0F],
+				ptxt: vec![1, 1, 0, 1, 1, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 1, 1, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 1, 1, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 1, 0, 1, 1, 0, 1, 0, 1, 1, 0, 0, 1, 0, 1, 1, 0, 0, 0, 1, 1, 1, 0, 1, 1, 0, 1, 0, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 0, 0, 1, 1, 1, 0, 1, 1, 0, 0, 1, 1, 1, 1, 0, 1, 0, 1, 1, 0, 1, 1, 1, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 1, 0, 0, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 1, 1, 1, 0, 0, 1, 0, 1, 1, 1, 0, 1, 1, 1, 1, 0, 1, 1, 1, 0, 0, 1, 0, 0, 0, 0, 0, 1, 1, 1, 1, 0, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 1, 0, 0],
+				ctxt: vec![0, 1, 0, 1, 1, 1, 0, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 1, 0, 1, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 1, 1, 1, 1, 1, 0, 1, 0, 0, 0, 1, 0, 0, 1, 1, 1, 1, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 1, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 1, 0, 1, 1, 0, 0, 1, 1, 1, 1, 0, 0, 0, 1, 0, 0, 1, 0, 1, 1, 1, 0, 1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 1, 1, 1, 0, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 1, 1, 1, 1, 0, 1, 1, 0, 1, 0, 0, 0, 1, 0, 1, 1, 0, 1, 1, 0, 0, 1, 1, 1, 1, 1, 1, 0, 0, 1, 0, 0, 0, 1, 1, 1, 0, 1]
+			},
+			TestCase {
+				key: arr![u8; 0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C, 0xEF, 0x43, 0x59, 0xD8, 0xD5, 0x80, 0xAA, 0x4F, 0x7F, 0x03, 0x6D, 0x6F, 0x04, 0xFC, 0x6A, 0x94],
+				radix: 65536,
+				tweak: vec![0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF],
+				ptxt: vec![29742, 46122, 8495, 2208, 41454, 51659, 34955],
+				ctxt: vec![59002, 31785, 780, 8148, 37345, 53872, 30717]
+			}
+		];
+
+		for TestCase { key, radix, tweak, ptxt, ctxt } in test_cases {
+			let lea256ff3_1 = Lea256Ff3_1::new(&key, radix)?;
+
+			// Encryption
+			let mut buffer = ptxt.clone();
+			lea256ff3_1.encrypt(&tweak, &mut buffer)?;
+			assert_eq!(buffer, ctxt);
+
+			// Decryption
+			let mut buffer = ctxt.clone();
+			lea256ff3_1.decrypt(&tweak, &mut buffer)?;
+			assert_eq!(buffer, ptxt);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128fpe_round_trip() -> Result<(), Error> {
+		let key = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+		let tweak = [0xFF; 7];
+
+		for radix in [2, 3, 10, 26, 36, 62, 255, 256, 1000, 65535, 65536] {
+			let lea128ff1 = Lea128Ff1::new(&key, radix)?;
+			let lea128ff3_1 = Lea128Ff3_1::new(&key, radix)?;
+			let fpes: [&dyn Fpe; 2] = [&lea128ff1, &lea128ff3_1];
+
+			for fpe in fpes {
+				for len in fpe.min_len()..=fpe.max_len() {
+					let ptxt: Vec<u16> = (0..len).map(|i| ((i * 7919) as u32 % radix) as u16).collect();
+
+					let mut buffer = ptxt.clone();
+					fpe.encrypt(&tweak, &mut buffer)?;
+					assert_ne!(buffer, ptxt);
+					assert!(buffer.iter().all(|&x| (x as u32) < radix));
+
+					fpe.decrypt(&tweak, &mut buffer)?;
+					assert_eq!(buffer, ptxt);
+				}
+			}
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128fpe_domain() -> Result<(), Error> {
+		let key = Default::default();
+
+		assert_eq!(Lea128Ff1::new(&key, 1).err(), Some(Error::InvalidRadix));
+		assert_eq!(Lea128Ff1::new(&key, 65537).err(), Some(Error::InvalidRadix));
+		assert_eq!(Lea128Ff3_1::new(&key, 0).err(), Some(Error::InvalidRadix));
+
+		// radix^min_len ≥ 1,000,000, and each half is at most 2^120 (FF1) or 2^96 (FF3-1).
+		let lea128ff1 = Lea128Ff1::new(&key, 10)?;
+		assert_eq!((lea128ff1.min_len(), lea128ff1.max_len()), (6, 72));
+		assert_eq!(lea128ff1.encrypt(&[], &mut [0; 5]), Err(Error::InvalidLength));
+		assert_eq!(lea128ff1.encrypt(&[], &mut [0; 73]), Err(Error::InvalidLength));
+		assert_eq!(lea128ff1.encrypt(&[], &mut [0, 1, 2, 3, 4, 10]), Err(Error::InvalidNumeral));
+
+		let lea128ff3_1 = Lea128Ff3_1::new(&key, 10)?;
+		assert_eq!((lea128ff3_1.min_len(), lea128ff3_1.max_len()), (6, 56));
+		assert_eq!(lea128ff3_1.encrypt(&[0; 7], &mut [0; 57]), Err(Error::InvalidLength));
+		assert_eq!(lea128ff3_1.encrypt(&[0; 8], &mut [0; 6]), Err(Error::InvalidTweakLength));
+		assert_eq!(lea128ff3_1.encrypt(&[0; 6], &mut [0; 6]), Err(Error::InvalidTweakLength));
+
+		let lea128ff1 = Lea128Ff1::new(&key, 2)?;
+		assert_eq!((lea128ff1.min_len(), lea128ff1.max_len()), (20, 240));
+		let lea128ff1 = Lea128Ff1::new(&key, 65536)?;
+		assert_eq!((lea128ff1.min_len(), lea128ff1.max_len()), (2, 14));
+		let lea128ff3_1 = Lea128Ff3_1::new(&key, 65536)?;
+		assert_eq!((lea128ff3_1.min_len(), lea128ff3_1.max_len()), (2, 12));
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128fpe_alphabet() -> Result<(), Error> {
+		let key = Default::default();
+		let tweak = *b"RRN";
+
+		// Korean resident registration number without the hyphen
+		let lea128ff1 = Lea128Ff1::new(&key, 10)?;
+		let mut buffer = *b"9001011234567";
+		lea128ff1.encrypt_text(&Alphabet::DECIMAL, &tweak, &mut buffer)?;
+		assert!(buffer.iter().all(u8::is_ascii_digit));
+		assert_ne!(&buffer, b"9001011234567");
+
+		// Same as encrypting numerals
+		let mut numerals: Vec<u16> = b"9001011234567".iter().map(|x| (x - b'0') as u16).collect();
+		lea128ff1.encrypt(&tweak, &mut numerals)?;
+		assert_eq!(numerals, buffer.iter().map(|x| (x - b'0') as u16).collect::<Vec<_>>());
+
+		lea128ff1.decrypt_text(&Alphabet::DECIMAL, &tweak, &mut buffer)?;
+		assert_eq!(&buffer, b"9001011234567");
+
+		// Custom alphabet
+		let hex = Alphabet::new(b"0123456789ABCDEF")?;
+		let lea128ff3_1 = Lea128Ff3_1::new(&key, 16)?;
+		let mut buffer = *b"DEADBEEF";
+		lea128ff3_1.encrypt_text(&hex, &[0; 7], &mut buffer)?;
+		lea128ff3_1.decrypt_text(&hex, &[0; 7], &mut buffer)?;
+		assert_eq!(&buffer, b"DEADBEEF");
+
+		assert_eq!(Alphabet::new(b"0").err(), Some(Error::InvalidAlphabet));
+		assert_eq!(Alphabet::new(b"0120").err(), Some(Error::InvalidAlphabet));
+		assert_eq!(lea128ff3_1.encrypt_text(&Alphabet::DECIMAL, &[0; 7], &mut b"012345".clone()), Err(Error::InvalidAlphabet));
+		assert_eq!(lea128ff1.encrypt_text(&Alphabet::DECIMAL, &[], &mut b"01234-6789".clone()), Err(Error::InvalidNumeral));
+		assert_eq!(lea128ff1.encrypt_text(&Alphabet::DECIMAL, &[], &mut [b'0'; 241]), Err(Error::InvalidLength));
+
+		Ok(())
+	}
+}
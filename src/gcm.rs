@@ -0,0 +1,1005 @@
+// Copyright © 2023 남기훈 <gihunnam@proton.me>
+//
+// This file and its content are subject to the terms of the MIT License (the "License").
+// If a copy of the License was not distributed with this file, you can obtain one at https://opensource.org/licenses/MIT.
+
+//! LEA-GCM
+//!
+//! * Example
+//! ```
+//! use lea::{gcm::aead, prelude::*, Lea128Gcm};
+//!
+//! let key = arr![u8; 0x26, 0x37, 0xE9, 0x77, 0xDA, 0xF5, 0x36, 0x13, 0xB4, 0x8A, 0x56, 0x5E, 0x92, 0x60, 0x05, 0x94];
+//! let nonce = arr![u8; 0x37, 0xB4, 0xA4, 0xDD, 0x75, 0xA8, 0xA9, 0xF1, 0x6B, 0x7E, 0x02, 0x70];
+//! let tag = arr![u8; 0x2D, 0x0D, 0x06, 0x5B, 0xA8, 0xA8, 0x42, 0x32, 0x45, 0x0B, 0x08, 0x79, 0xE2, 0x58, 0x84, 0x12];
+//! let associated_data = [];
+//! let ptxt = [0x47, 0xA0, 0x20, 0x9C, 0x50, 0x61, 0x7C, 0x63, 0xFF, 0x59, 0xE7, 0x74, 0xE2, 0x82, 0xD2, 0x3E];
+//! let ctxt = [0xBA, 0x1C, 0xBA, 0xE2, 0xD6, 0x46, 0x09, 0x98, 0x4B, 0x7A, 0x8D, 0xE0, 0xF0, 0xC9, 0xCA, 0xA4];
+//!
+//! // Nonce size defaults to `U12`.
+//! let lea128gcm = Lea128Gcm::new(&key);
+//!
+//! // Encryption
+//! let mut buffer = ptxt;
+//! let calculated_tag: Result<_, aead::Error> = lea128gcm.encrypt_in_place_detached(&nonce, &associated_data, &mut buffer);
+//! assert_eq!(buffer, ctxt);
+//! assert_eq!(calculated_tag.unwrap(), tag);
+//!
+//! // Decryption
+//! let mut buffer = ctxt;
+//! let _: Result<(), aead::Error> = lea128gcm.decrypt_in_place_detached(&nonce, &associated_data, &mut buffer, &tag);
+//! assert_eq!(buffer, ptxt);
+//! ```
+
+pub use aes_gcm::aead;
+
+use core::marker::PhantomData;
+
+use aead::consts::{U1, U12, U16};
+use aead::generic_array::{typenum::{IsGreaterOrEqual, True}, ArrayLength, GenericArray};
+use aead::Error;
+use aes_gcm::{AesGcm, Nonce, Tag};
+use cipher::{BlockCipher, BlockEncrypt, FromBlockCipher, StreamCipher, StreamCipherSeek};
+use ctr::Ctr32BE;
+use ghash::{universal_hash::{NewUniversalHash, UniversalHash}, GHash};
+use subtle::ConstantTimeEq;
+
+use crate::{Lea128, Lea192, Lea256};
+
+pub type Lea128Gcm<NonceSize = U12> = AesGcm<Lea128, NonceSize>;
+pub type Lea192Gcm<NonceSize = U12> = AesGcm<Lea192, NonceSize>;
+pub type Lea256Gcm<NonceSize = U12> = AesGcm<Lea256, NonceSize>;
+
+pub type Lea128GcmEncryptor<'a, NonceSize = U12> = GcmEncryptor<'a, Lea128, NonceSize>;
+pub type Lea192GcmEncryptor<'a, NonceSize = U12> = GcmEncryptor<'a, Lea192, NonceSize>;
+pub type Lea256GcmEncryptor<'a, NonceSize = U12> = GcmEncryptor<'a, Lea256, NonceSize>;
+
+pub type Lea128GcmDecryptor<'a, NonceSize = U12> = GcmDecryptor<'a, Lea128, NonceSize>;
+pub type Lea192GcmDecryptor<'a, NonceSize = U12> = GcmDecryptor<'a, Lea192, NonceSize>;
+pub type Lea256GcmDecryptor<'a, NonceSize = U12> = GcmDecryptor<'a, Lea256, NonceSize>;
+
+pub type Lea128Gmac<NonceSize = U12> = Gmac<Lea128, NonceSize>;
+pub type Lea192Gmac<NonceSize = U12> = Gmac<Lea192, NonceSize>;
+pub type Lea256Gmac<NonceSize = U12> = Gmac<Lea256, NonceSize>;
+
+type Block = GenericArray<u8, U16>;
+
+/// Maximum length of associated data in bytes
+const A_MAX: u64 = (1 << 61) - 1;
+/// Maximum length of plaintext in bytes
+const P_MAX: u64 = (1 << 36) - 32;
+
+/// Incremental GCM encryption
+///
+/// All associated data must be supplied before any plaintext.
+///
+/// * Example
+/// ```
+/// use lea::{gcm::{aead, GcmEncryptor}, prelude::*, Lea128};
+///
+/// let key = arr![u8; 0x26, 0x37, 0xE9, 0x77, 0xDA, 0xF5, 0x36, 0x13, 0xB4, 0x8A, 0x56, 0x5E, 0x92, 0x60, 0x05, 0x94];
+/// let nonce = arr![u8; 0x37, 0xB4, 0xA4, 0xDD, 0x75, 0xA8, 0xA9, 0xF1, 0x6B, 0x7E, 0x02, 0x70];
+/// let tag = arr![u8; 0x2D, 0x0D, 0x06, 0x5B, 0xA8, 0xA8, 0x42, 0x32, 0x45, 0x0B, 0x08, 0x79, 0xE2, 0x58, 0x84, 0x12];
+/// let ptxt = [0x47, 0xA0, 0x20, 0x9C, 0x50, 0x61, 0x7C, 0x63, 0xFF, 0x59, 0xE7, 0x74, 0xE2, 0x82, 0xD2, 0x3E];
+/// let ctxt = [0xBA, 0x1C, 0xBA, 0xE2, 0xD6, 0x46, 0x09, 0x98, 0x4B, 0x7A, 0x8D, 0xE0, 0xF0, 0xC9, 0xCA, 0xA4];
+///
+/// let lea128 = Lea128::new(&key);
+/// let mut encryptor = GcmEncryptor::new(&lea128, &nonce);
+///
+/// let mut buffer = ptxt;
+/// for chunk in buffer.chunks_mut(3) {
+///     encryptor.encrypt(chunk)?;
+/// }
+/// assert_eq!(buffer, ctxt);
+/// assert_eq!(encryptor.finalize(), tag);
+/// # Ok::<(), aead::Error>(())
+/// ```
+pub struct GcmEncryptor<'a, C, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+N: ArrayLength<u8> + IsGreaterOrEqual<U1, Output = True> {
+	state: GcmState<'a, C>,
+	_pd: PhantomData<N>
+}
+
+impl<'a, C, N> GcmEncryptor<'a, C, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+N: ArrayLength<u8> + IsGreaterOrEqual<U1, Output = True> {
+	pub fn new(cipher: &'a C, nonce: &Nonce<N>) -> Self {
+		Self { state: GcmState::new(cipher, nonce), _pd: PhantomData }
+	}
+
+	/// Fails if plaintext has already been supplied, or if associated data exceeds 2<sup>61</sup> - 1 bytes.
+	pub fn update_associated_data(&mut self, associated_data: &[u8]) -> Result<(), Error> {
+		self.state.update_associated_data(associated_data)
+	}
+
+	/// Fails if plaintext exceeds 2<sup>36</sup> - 32 bytes.
+	pub fn encrypt(&mut self, buffer: &mut [u8]) -> Result<(), Error> {
+		self.state.encrypt(buffer)
+	}
+
+	pub fn finalize(mut self) -> Tag {
+		self.state.tag()
+	}
+}
+
+/// Incremental GCM decryption
+///
+/// * [`GcmDecryptor::verify`] authenticates ciphertext without releasing plaintext.
+///   Once [`GcmDecryptor::finalize`] succeeds, the ciphertext can be decrypted in a second pass with [`GcmVerifiedDecryptor`],
+///   which must also be finalized, as it authenticates the ciphertext again.
+/// * [`GcmDecryptor::decrypt_unverified`] releases plaintext immediately.
+///   The plaintext must be discarded unless [`GcmDecryptor::finalize`] succeeds.
+///
+/// * Example
+/// ```
+/// use lea::{gcm::{aead, GcmDecryptor}, prelude::*, Lea128};
+///
+/// let key = arr![u8; 0x26, 0x37, 0xE9, 0x77, 0xDA, 0xF5, 0x36, 0x13, 0xB4, 0x8A, 0x56, 0x5E, 0x92, 0x60, 0x05, 0x94];
+/// let nonce = arr![u8; 0x37, 0xB4, 0xA4, 0xDD, 0x75, 0xA8, 0xA9, 0xF1, 0x6B, 0x7E, 0x02, 0x70];
+/// let tag = arr![u8; 0x2D, 0x0D, 0x06, 0x5B, 0xA8, 0xA8, 0x42, 0x32, 0x45, 0x0B, 0x08, 0x79, 0xE2, 0x58, 0x84, 0x12];
+/// let ptxt = [0x47, 0xA0, 0x20, 0x9C, 0x50, 0x61, 0x7C, 0x63, 0xFF, 0x59, 0xE7, 0x74, 0xE2, 0x82, 0xD2, 0x3E];
+/// let ctxt = [0xBA, 0x1C, 0xBA, 0xE2, 0xD6, 0x46, 0x09, 0x98, 0x4B, 0x7A, 0x8D, 0xE0, 0xF0, 0xC9, 0xCA, 0xA4];
+///
+/// let lea128 = Lea128::new(&key);
+/// let mut decryptor = GcmDecryptor::new(&lea128, &nonce);
+///
+/// // First pass
+/// for chunk in ctxt.chunks(3) {
+///     decryptor.verify(chunk)?;
+/// }
+/// let mut decryptor = decryptor.finalize(&tag)?;
+///
+/// // Second pass
+/// let mut buffer = ctxt;
+/// for chunk in buffer.chunks_mut(3) {
+///     decryptor.decrypt(chunk)?;
+/// }
+/// decryptor.finalize()?;
+/// assert_eq!(buffer, ptxt);
+/// # Ok::<(), aead::Error>(())
+/// ```
+pub struct GcmDecryptor<'a, C, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+N: ArrayLength<u8> + IsGreaterOrEqual<U1, Output = True> {
+	state: GcmState<'a, C>,
+	/// GHASH before the ciphertext, for the second pass
+	ghash_start: Option<GhashState>,
+	_pd: PhantomData<N>
+}
+
+impl<'a, C, N> GcmDecryptor<'a, C, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+N: ArrayLength<u8> + IsGreaterOrEqual<U1, Output = True> {
+	pub fn new(cipher: &'a C, nonce: &Nonce<N>) -> Self {
+		Self { state: GcmState::new(cipher, nonce), ghash_start: None, _pd: PhantomData }
+	}
+
+	/// Fails if ciphertext has already been supplied, or if associated data exceeds 2<sup>61</sup> - 1 bytes.
+	pub fn update_associated_data(&mut self, associated_data: &[u8]) -> Result<(), Error> {
+		self.state.update_associated_data(associated_data)
+	}
+
+	/// Authenticates ciphertext without decrypting it.
+	///
+	/// Fails if ciphertext exceeds 2<sup>36</sup> - 32 bytes.
+	pub fn verify(&mut self, buffer: &[u8]) -> Result<(), Error> {
+		self.start();
+		self.state.verify(buffer)
+	}
+
+	/// Decrypts ciphertext in place before it is authenticated.
+	///
+	/// Fails if ciphertext exceeds 2<sup>36</sup> - 32 bytes.
+	pub fn decrypt_unverified(&mut self, buffer: &mut [u8]) -> Result<(), Error> {
+		self.start();
+		self.state.decrypt(buffer)
+	}
+
+	/// Fails if the tag does not match.
+	pub fn finalize(mut self, tag: &Tag) -> Result<GcmVerifiedDecryptor<'a, C>, Error> {
+		self.start();
+		let calculated_tag = self.state.tag();
+		if calculated_tag.ct_eq(tag).into() {
+			let len = self.state.len;
+			self.state.ghash = self.ghash_start.take().unwrap();
+			self.state.len = 0;
+			self.state.ctr.seek(16u64);
+
+			Ok(GcmVerifiedDecryptor { state: self.state, len, tag: calculated_tag })
+		} else {
+			Err(Error)
+		}
+	}
+
+	/// Keeps the GHASH state once associated data is complete, i.e. before the first ciphertext.
+	fn start(&mut self) {
+		if self.ghash_start.is_none() {
+			self.state.start();
+			self.ghash_start = Some(self.state.ghash.clone());
+		}
+	}
+}
+
+/// Decryption of authenticated ciphertext
+///
+/// Returned by [`GcmDecryptor::finalize`].
+///
+/// # Security
+///
+/// Nothing but GHASH ties the second pass to the ciphertext authenticated in the first,
+/// so the ciphertext is authenticated again as it is decrypted.
+/// The plaintext must be discarded unless [`GcmVerifiedDecryptor::finalize`] succeeds,
+/// e.g. if the ciphertext was modified between the passes.
+pub struct GcmVerifiedDecryptor<'a, C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	state: GcmState<'a, C>,
+	/// Length of the ciphertext authenticated
+	len: u64,
+	/// Tag calculated in the first pass
+	tag: Tag
+}
+
+impl<'a, C> GcmVerifiedDecryptor<'a, C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	/// Decrypts the ciphertext passed to [`GcmDecryptor`] in place, from the beginning.
+	///
+	/// Fails if more ciphertext is supplied than authenticated.
+	pub fn decrypt(&mut self, buffer: &mut [u8]) -> Result<(), Error> {
+		if buffer.len() as u64 > self.len - self.state.len {
+			return Err(Error);
+		}
+
+		self.state.decrypt(buffer)
+	}
+
+	/// Fails if less ciphertext has been supplied than authenticated, or if it differs from the ciphertext authenticated.
+	pub fn finalize(mut self) -> Result<(), Error> {
+		if self.state.len == self.len && bool::from(self.state.tag().ct_eq(&self.tag)) {
+			Ok(())
+		} else {
+			Err(Error)
+		}
+	}
+}
+
+/// GMAC
+///
+/// GCM authentication of data without encryption, i.e. GCM with associated data only.
+/// A nonce must never be reused with the same key, as with GCM, and must be at least 1 byte long as SP 800-38D requires.
+///
+/// * Example
+/// ```
+/// use lea::{gcm::{aead, Gmac}, prelude::*, Lea128};
+///
+/// let key = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+/// let nonce = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+/// let tag = arr![u8; 0xC0, 0x7D, 0xFD, 0x2C, 0x54, 0x56, 0x35, 0x45, 0x57, 0xF8, 0x6E, 0x18, 0xA1, 0x8C, 0xCE, 0x05];
+///
+/// let lea128 = Lea128::new(&key);
+///
+/// // Authentication
+/// let mut gmac = Gmac::new(&lea128, &nonce);
+/// gmac.update(b"head")?;
+/// gmac.update(b"er")?;
+/// assert_eq!(gmac.finalize(), tag);
+///
+/// // Verification
+/// let mut gmac = Gmac::new(&lea128, &nonce);
+/// gmac.update(b"header")?;
+/// gmac.verify(&tag)?;
+/// # Ok::<(), aead::Error>(())
+/// ```
+pub struct Gmac<C, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+N: ArrayLength<u8> + IsGreaterOrEqual<U1, Output = True> {
+	ghash: GhashState,
+	len: u64,
+	_pd: PhantomData<(C, N)>
+}
+
+impl<C, N> Gmac<C, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+N: ArrayLength<u8> + IsGreaterOrEqual<U1, Output = True> {
+	pub fn new(cipher: &C, nonce: &Nonce<N>) -> Self {
+		let (ghash, _) = GhashState::new(cipher, nonce);
+
+		Self { ghash, len: 0, _pd: PhantomData }
+	}
+
+	/// Fails if data exceeds 2<sup>61</sup> - 1 bytes.
+	pub fn update(&mut self, data: &[u8]) -> Result<(), Error> {
+		if data.len() as u64 > A_MAX - self.len {
+			return Err(Error);
+		}
+
+		self.ghash.absorb(data);
+		self.len += data.len() as u64;
+
+		Ok(())
+	}
+
+	pub fn finalize(mut self) -> Tag {
+		self.ghash.tag(self.len, 0)
+	}
+
+	/// Fails if the tag does not match. Tags are compared in constant time.
+	pub fn verify(self, tag: &Tag) -> Result<(), Error> {
+		if self.finalize().ct_eq(tag).into() {
+			Ok(())
+		} else {
+			Err(Error)
+		}
+	}
+}
+
+struct GcmState<'a, C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	ctr: Ctr32BE<&'a C>,
+	ghash: GhashState,
+	associated_data_len: u64,
+	len: u64,
+	/// Whether plaintext or ciphertext has been supplied
+	started: bool
+}
+
+impl<'a, C> GcmState<'a, C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	fn new(cipher: &'a C, nonce: &[u8]) -> Self {
+		let (ghash, j0) = GhashState::new(cipher, nonce);
+
+		let mut ctr = Ctr32BE::from_block_cipher(cipher, &j0);
+		ctr.seek_block(1);
+
+		Self { ctr, ghash, associated_data_len: 0, len: 0, started: false }
+	}
+
+	fn update_associated_data(&mut self, associated_data: &[u8]) -> Result<(), Error> {
+		if self.started || associated_data.len() as u64 > A_MAX - self.associated_data_len {
+			return Err(Error);
+		}
+
+		self.ghash.absorb(associated_data);
+		self.associated_data_len += associated_data.len() as u64;
+
+		Ok(())
+	}
+
+	fn update(&mut self, len: usize) -> Result<(), Error> {
+		if len as u64 > P_MAX - self.len {
+			return Err(Error);
+		}
+
+		self.start();
+		self.len += len as u64;
+
+		Ok(())
+	}
+
+	/// Pads the associated data before the first plaintext or ciphertext.
+	fn start(&mut self) {
+		if !self.started {
+			self.ghash.pad();
+			self.started = true;
+		}
+	}
+
+	fn encrypt(&mut self, buffer: &mut [u8]) -> Result<(), Error> {
+		self.update(buffer.len())?;
+		self.ctr.apply_keystream(buffer);
+		self.ghash.absorb(buffer);
+
+		Ok(())
+	}
+
+	fn decrypt(&mut self, buffer: &mut [u8]) -> Result<(), Error> {
+		self.update(buffer.len())?;
+		self.ghash.absorb(buffer);
+		self.ctr.apply_keystream(buffer);
+
+		Ok(())
+	}
+
+	fn verify(&mut self, buffer: &[u8]) -> Result<(), Error> {
+		self.update(buffer.len())?;
+		self.ghash.absorb(buffer);
+
+		Ok(())
+	}
+
+	fn tag(&mut self) -> Tag {
+		self.ghash.tag(self.associated_data_len, self.len)
+	}
+}
+
+/// GHASH keyed with the hash subkey H = E<sub>K</sub>(0<sup>128</sup>), shared by GCM and GMAC
+#[derive(Clone)]
+struct GhashState {
+	ghash: GHash,
+	ghash_block: Block,
+	ghash_pos: usize,
+	tag_mask: Block
+}
+
+impl GhashState {
+	/// Returns the state along with the pre-counter block J<sub>0</sub> derived from `nonce`.
+	fn new<C>(cipher: &C, nonce: &[u8]) -> (Self, Block) where
+	C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+		let mut h = Block::default();
+		cipher.encrypt_block(&mut h);
+		let ghash = GHash::new(&h);
+
+		let mut j0 = Block::default();
+		if nonce.len() == 12 {
+			j0[..12].copy_from_slice(nonce);
+			j0[15] = 1;
+		} else {
+			let mut ghash = ghash.clone();
+			ghash.update_padded(nonce);
+			j0[8..].copy_from_slice(&(nonce.len() as u64 * 8).to_be_bytes());
+			ghash.update(&j0);
+			j0 = ghash.finalize().into_bytes();
+		}
+
+		let mut tag_mask = j0;
+		cipher.encrypt_block(&mut tag_mask);
+
+		(Self { ghash, ghash_block: Block::default(), ghash_pos: 0, tag_mask }, j0)
+	}
+
+	/// GHASH over bytes, zero-padded by [`GhashState::pad`]
+	fn absorb(&mut self, mut data: &[u8]) {
+		while !data.is_empty() {
+			let n = data.len().min(16 - self.ghash_pos);
+			self.ghash_block[self.ghash_pos..(self.ghash_pos + n)].copy_from_slice(&data[..n]);
+			self.ghash_pos += n;
+			data = &data[n..];
+
+			if self.ghash_pos == 16 {
+				self.ghash.update(&self.ghash_block);
+				self.ghash_pos = 0;
+			}
+		}
+	}
+
+	fn pad(&mut self) {
+		if self.ghash_pos != 0 {
+			self.ghash_block[self.ghash_pos..].iter_mut().for_each(|b| *b = 0);
+			self.ghash.update(&self.ghash_block);
+			self.ghash_pos = 0;
+		}
+	}
+
+	fn tag(&mut self, associated_data_len: u64, len: u64) -> Tag {
+		self.pad();
+
+		let mut len_block = Block::default();
+		len_block[..8].copy_from_slice(&(associated_data_len * 8).to_be_bytes());
+		len_block[8..].copy_from_slice(&(len * 8).to_be_bytes());
+		self.ghash.update(&len_block);
+
+		let mut tag = self.ghash.clone().finalize().into_bytes();
+		tag.iter_mut().zip(self.tag_mask).for_each(|(t, m)| *t ^= m);
+
+		tag
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	extern crate alloc;
+
+	use alloc::{vec::Vec, vec};
+
+	use crate::{gcm::{aead::Error, GcmDecryptor, GcmEncryptor, Lea128GcmDecryptor, Lea128GcmEncryptor, Lea192GcmDecryptor, Lea192GcmEncryptor, Lea256GcmDecryptor, Lea256GcmEncryptor, Lea128Gmac, Lea192Gmac, Lea256Gmac}, prelude::*, Lea128, Lea128Gcm, Lea192, Lea192Gcm, Lea256, Lea256Gcm};
+
+	struct TestCase<T> where
+	T: AeadInPlace + NewAead {
+		key: GenericArray<u8, <T as NewAead>::KeySize>,
+		nonce: GenericArray<u8, <T as AeadCore>::NonceSize>,
+		tag: GenericArray<u8, <T as AeadCore>::TagSize>,
+		associated_data: Vec<u8>,
+		ptxt: Vec<u8>,
+		ctxt: Vec<u8>
+	}
+
+	struct GmacTestCase<T> where
+	T: BlockCipher + NewBlockCipher {
+		key: GenericArray<u8, T::KeySize>,
+		nonce: GenericArray<u8, U12>,
+		tag: GenericArray<u8, U16>,
+		message: Vec<u8>
+	}
+
+	#[test]
+	fn lea128gcm() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea128Gcm>; 3] = [
+			TestCase {
+				key: arr![u8; 0x26, 0x37, 0xE9, 0x77, 0xDA, 0xF5, 0x36, 0x13, 0xB4, 0x8A, 0x56, 0x5E, 0x92, 0x60, 0x05, 0x94],
+				nonce: arr![u8; 0x37, 0xB4, 0xA4, 0xDD, 0x75, 0xA8, 0xA9, 0xF1, 0x6B, 0x7E, 0x02, 0x70],
+				tag: arr![u8; 0x2D, 0x0D, 0x06, 0x5B, 0xA8, 0xA8, 0x42, 0x32, 0x45, 0x0B, 0x08, 0x79, 0xE2, 0x58, 0x84, 0x12],
+				associated_data: vec![],
+				ptxt: vec![0x47, 0xA0, 0x20, 0x9C, 0x50, 0x61, 0x7C, 0x63, 0xFF, 0x59, 0xE7, 0x74, 0xE2, 0x82, 0xD2, 0x3E],
+				ctxt: vec![0xBA, 0x1C, 0xBA, 0xE2, 0xD6, 0x46, 0x09, 0x98, 0x4B, 0x7A, 0x8D, 0xE0, 0xF0, 0xC9, 0xCA, 0xA4]
+			},
+			TestCase {
+				key: arr![u8; 0xFE, 0x05, 0x72, 0x6A, 0x26, 0xE4, 0x99, 0x40, 0xFF, 0x0C, 0xA8, 0xAA, 0x0B, 0x51, 0x7F, 0x79],
+				nonce: arr![u8; 0x7E, 0xDE, 0x8A, 0xCD, 0x3F, 0x74, 0xF2, 0xC6, 0x96, 0x6F, 0xBE, 0xC7],
+				tag: arr![u8; 0x7B, 0xC8, 0xB1, 0xB6, 0x9B, 0x35, 0xB9, 0x8E, 0x0E, 0x56, 0x1A, 0xDB, 0x4F, 0x8D, 0x9D, 0xCD],
+				associated_data: vec![0x5E, 0xCF, 0xDA, 0xE2, 0x1F, 0x8C, 0xD7, 0x5B, 0x85, 0x63, 0xF3, 0x0B, 0x95, 0xE7, 0xFC, 0xF6, 0xDE, 0x7E, 0xFE, 0xAC],
+				ptxt: vec![0x77, 0x9A, 0xA7, 0xC2, 0xCE, 0x81, 0xD4, 0x3E, 0xC1, 0xD7, 0xC1, 0xEB, 0xA1, 0xF8, 0xF3, 0x03, 0xFB, 0xBC, 0x28, 0xC3, 0xCE, 0x87, 0x72, 0x1E, 0xBC, 0x93, 0x11, 0x82, 0x1B, 0x7C, 0x68, 0xEC, 0xAC, 0x80, 0x30, 0xF5, 0xAB, 0xBA, 0xFE, 0x2B],
+				ctxt: vec![0x6A, 0xB0, 0xD4, 0x00, 0xCC, 0xE5, 0x67, 0x75, 0x3C, 0x1D, 0xA8, 0x31, 0x07, 0x8D, 0xD7, 0x30, 0xBB, 0x62, 0xA4, 0x99, 0xE9, 0xBB, 0x91, 0x8F, 0x4B, 0x01, 0x6D, 0xAF, 0xEF, 0x53, 0x1B, 0xFE, 0x46, 0x27, 0x7B, 0x32, 0x29, 0xBF, 0x63, 0x93]
+			},
+			TestCase {
+				key: arr![u8; 0x55, 0x1D, 0x59, 0xFB, 0xF6, 0xE6, 0x2D, 0x63, 0xC3, 0x9E, 0xC9, 0x1D, 0xD1, 0xC9, 0xD6, 0x4F],
+				nonce: arr![u8; 0x00, 0xFE, 0x57, 0x38, 0xC3, 0x12, 0x30, 0x72, 0x78, 0x20, 0xF8, 0x84],
+				tag: arr![u8; 0x1C, 0x07, 0xA2, 0xE8, 0xE5, 0x8D, 0xE1, 0x85, 0x40, 0xF1, 0xF8, 0x35, 0xA5, 0x53, 0xAF, 0xE7],
+				associated_data: vec![0x71, 0x42, 0x50, 0xC7, 0xDB, 0xF3, 0x9F, 0xC4, 0x61, 0xEB, 0xA6, 0x69, 0xA4, 0x0D, 0x6E, 0x6B],
+				ptxt: vec![0x06, 0xC3, 0x61, 0xDC, 0x7A, 0xE3, 0xBC, 0xB7, 0x7A, 0x0E, 0xEE, 0xC1, 0x41, 0x87, 0xE4, 0x09, 0x15, 0x0E, 0x16, 0x74, 0x4D, 0x14, 0x29, 0xCE, 0xD4, 0x58, 0x1E, 0xE4, 0x6C, 0x70, 0x72, 0x1D, 0x73, 0xEA, 0xF2, 0xE4, 0x33, 0x11, 0x0E, 0xD2, 0x48, 0xA5, 0x78, 0xC8, 0x22, 0xC8, 0x3F, 0x59, 0x02, 0x05, 0x57, 0xB1, 0x49, 0xFA, 0xC7, 0x6E, 0xF4, 0x60, 0xCA, 0xE6, 0x59, 0x2D, 0x98, 0x68],
+				ctxt: vec![0xFC, 0x37, 0xD6, 0x21, 0x68, 0xF0, 0xBD, 0xA7, 0x16, 0x1E, 0x40, 0xC0, 0x8A, 0xBF, 0xAB, 0x09, 0xB5, 0x42, 0xD6, 0x69, 0xFC, 0xED, 0xC2, 0x5E, 0x2C, 0x3A, 0x5C, 0x3B, 0xEA, 0x8D, 0x79, 0xF2, 0xA3, 0xFF, 0x4F, 0xE6, 0x88, 0x0C, 0x64, 0x48, 0xDD, 0x08, 0x1F, 0x57, 0x15, 0x40, 0x32, 0xE2, 0xC3, 0x06, 0x86, 0xFB, 0x2B, 0xB6, 0xBA, 0x5B, 0xFB, 0x47, 0xBF, 0x3B, 0x03, 0xA3, 0x63, 0xD3]
+			}
+		];
+
+		for TestCase { key, nonce, tag, associated_data, ptxt, ctxt } in test_cases {
+			let lea128gcm = Lea128Gcm::new(&key);
+
+			// Encryption
+			let mut buffer = ptxt.clone();
+			let calculated_tag = lea128gcm.encrypt_in_place_detached(&nonce, &associated_data, &mut buffer)?;
+			assert_eq!(buffer, ctxt);
+			assert_eq!(calculated_tag, tag);
+
+			// Decryption
+			let mut buffer = ctxt.clone();
+			lea128gcm.decrypt_in_place_detached(&nonce, &associated_data, &mut buffer, &tag)?;
+			assert_eq!(buffer, ptxt);
+
+			// Incremental encryption
+			let lea128 = Lea128::new(&key);
+			let mut encryptor = Lea128GcmEncryptor::new(&lea128, &nonce);
+			for chunk in associated_data.chunks(5) {
+				encryptor.update_associated_data(chunk)?;
+			}
+			let mut buffer = ptxt.clone();
+			for chunk in buffer.chunks_mut(7) {
+				encryptor.encrypt(chunk)?;
+			}
+			assert_eq!(buffer, ctxt);
+			assert_eq!(encryptor.finalize(), tag);
+
+			// Incremental decryption
+			let mut decryptor = Lea128GcmDecryptor::new(&lea128, &nonce);
+			decryptor.update_associated_data(&associated_data)?;
+			for chunk in ctxt.chunks(9) {
+				decryptor.verify(chunk)?;
+			}
+			let mut decryptor = decryptor.finalize(&tag)?;
+			let mut buffer = ctxt.clone();
+			for chunk in buffer.chunks_mut(11) {
+				decryptor.decrypt(chunk)?;
+			}
+			decryptor.finalize()?;
+			assert_eq!(buffer, ptxt);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea192gcm() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea192Gcm>; 3] = [
+			TestCase {
+				key: arr![u8; 0x8D, 0x43, 0x1C, 0x91, 0x6B, 0xF6, 0xE6, 0x88, 0xAA, 0x48, 0xF0, 0xF3, 0xE5, 0x32, 0x38, 0x6E, 0xCE, 0x3A, 0xD7, 0xE6, 0x6B, 0x0C, 0xE0, 0x2E],
+				nonce: arr![u8; 0x73, 0xE0, 0x2E, 0xAA, 0x15, 0x73, 0x08, 0x08, 0xF6, 0x2D, 0xE7, 0x69],
+				tag: arr![u8; 0x2F, 0x30, 0xC4, 0xD0, 0x27, 0x24, 0xED, 0xC3, 0x8A, 0xF3, 0x17, 0x21, 0x99, 0x7B, 0x74, 0x23],
+				associated_data: vec![],
+				ptxt: vec![0x23, 0xB9, 0x65, 0x5C, 0x9C, 0x79, 0x03, 0xB7, 0xBA, 0x7D, 0x2B, 0xF6, 0xF0, 0x09, 0x4D, 0x75],
+				ctxt: vec![0xCA, 0x50, 0x28, 0xEA, 0x5B, 0x38, 0x09, 0x4E, 0xFD, 0x2A, 0x8E, 0x1A, 0x50, 0x2F, 0x2F, 0x50]
+			},
+			TestCase {
+				key: arr![u8; 0xFD, 0x07, 0x30, 0x98, 0x16, 0x6C, 0xEC, 0xC2, 0xF0, 0xB3, 0xD6, 0xE4, 0x11, 0x5E, 0x2C, 0xB4, 0xA6, 0x2E, 0x1D, 0x67, 0x90, 0x6C, 0x5E, 0x81],
+				nonce: arr![u8; 0xC7, 0xD6, 0x52, 0x7D, 0x26, 0x57, 0x9F, 0xFF, 0x04, 0xE7, 0x62, 0x8D],
+				tag: arr![u8; 0x71, 0x96, 0xFC, 0x80, 0xF8, 0x00, 0x85, 0xBB, 0x1F, 0xF8, 0x47, 0x68, 0x0F, 0x7B, 0x7E, 0xCA],
+				associated_data: vec![0xAF, 0xA0, 0x45, 0x30, 0x30, 0x4E, 0x45, 0xE4, 0x50, 0x15, 0xE7, 0x98, 0xE3, 0x49, 0x46, 0x83],
+				ptxt: vec![0x0D, 0x01, 0x79, 0xF6, 0x58, 0x31, 0x4C, 0x68, 0x55, 0x7F, 0x55, 0x00, 0x94, 0x31, 0x30, 0x0D, 0xF5, 0xB1, 0x1D, 0xEF, 0x9C, 0x2E, 0xF0, 0x91, 0x8F, 0xA9, 0x6C, 0xA9, 0x14, 0x44, 0xCF, 0x9A, 0xC8],
+				ctxt: vec![0x0A, 0x07, 0x41, 0xEC, 0x12, 0x42, 0x4E, 0x1B, 0x3D, 0xE5, 0xD1, 0x05, 0x45, 0xC6, 0x88, 0xB1, 0xFB, 0x82, 0x16, 0xE9, 0x76, 0xE4, 0xE9, 0x73, 0xFE, 0xF1, 0x04, 0xF3, 0x9B, 0xA2, 0x16, 0x8A, 0x37]
+			},
+			TestCase {
+				key: arr![u8; 0xED, 0xE9, 0xAF, 0x2A, 0xEF, 0xF9, 0x73, 0x24, 0x59, 0x9D, 0xAD, 0xD9, 0x66, 0x3C, 0xAF, 0x18, 0xD1, 0xFB, 0x63, 0x06, 0x72, 0xC2, 0xBA, 0xA0],
+				nonce: arr![u8; 0xED, 0xEB, 0x7F, 0xD1, 0xBD, 0x6B, 0x79, 0xE3, 0x0B, 0xEF, 0xD9, 0x1B],
+				tag: arr![u8; 0xF1, 0x52, 0xD8, 0x18, 0x5E, 0xBD, 0xDE, 0x13, 0xB3, 0x5F, 0x3E, 0x53, 0x58, 0xA2, 0x27, 0x3F],
+				associated_data: vec![0x6D, 0x50, 0x6F, 0x65, 0x61, 0x94, 0x99],
+				ptxt: vec![0x4E, 0xD5, 0x54, 0x1A, 0xE6, 0xB0, 0x20, 0x05, 0x08, 0x57, 0x26, 0x47, 0x28, 0x32, 0x80, 0x50, 0x31, 0x61, 0x5B, 0xEF, 0x9C, 0x47, 0xDE, 0xCF, 0xA6, 0x07, 0x67, 0xCE, 0xFD, 0xEE, 0xF3, 0xE0, 0x42, 0x46, 0xF8, 0xEF, 0x8F, 0xF1, 0x6B, 0xDB, 0x78, 0x76, 0x67, 0x86, 0xDA, 0xAB, 0xF0, 0x68, 0xFA, 0x44, 0x2B, 0xE7, 0x5D, 0xA2, 0x2E, 0x42, 0x18, 0x33, 0x9D, 0xEF, 0x9E, 0x6F, 0x02, 0x71, 0x50, 0x44, 0x9E, 0x90, 0x13, 0xB8, 0x0A, 0xB1, 0xF8, 0x48, 0x0A, 0xAB, 0xB1, 0x7D, 0x03, 0x49],
+				ctxt: vec![0xF8, 0xE4, 0x5E, 0xF7, 0xFE, 0x60, 0x45, 0x70, 0x39, 0xD1, 0xD6, 0x22, 0x70, 0x81, 0x36, 0xBB, 0x56, 0xF5, 0xC8, 0x8C, 0x69, 0x26, 0x05, 0x0A, 0xE0, 0x6F, 0xE6, 0x85, 0x7C, 0x56, 0x3A, 0x9F, 0x35, 0xCB, 0xBB, 0xEF, 0x14, 0x5C, 0x29, 0x29, 0x72, 0xA1, 0xF8, 0x51, 0x69, 0xF1, 0x05, 0x97, 0x35, 0x74, 0xD8, 0x67, 0xEB, 0x83, 0x21, 0xD1, 0x82, 0x86, 0xED, 0x92, 0xA7, 0xAE, 0x90, 0xA6, 0xFD, 0x23, 0xE2, 0xF7, 0x4F, 0x57, 0x55, 0x65, 0xD0, 0xDB, 0x8D, 0x12, 0xC3, 0x7A, 0x38, 0x6A]
+			}
+		];
+
+		for TestCase { key, nonce, tag, associated_data, ptxt, ctxt } in test_cases {
+			let lea192gcm = Lea192Gcm::new(&key);
+
+			// Encryption
+			let mut buffer = ptxt.clone();
+			let calculated_tag = lea192gcm.encrypt_in_place_detached(&nonce, &associated_data, &mut buffer)?;
+			assert_eq!(buffer, ctxt);
+			assert_eq!(calculated_tag, tag);
+
+			// Decryption
+			let mut buffer = ctxt.clone();
+			lea192gcm.decrypt_in_place_detached(&nonce, &associated_data, &mut buffer, &tag)?;
+			assert_eq!(buffer, ptxt);
+
+			// Incremental encryption
+			let lea192 = Lea192::new(&key);
+			let mut encryptor = Lea192GcmEncryptor::new(&lea192, &nonce);
+			for chunk in associated_data.chunks(5) {
+				encryptor.update_associated_data(chunk)?;
+			}
+			let mut buffer = ptxt.clone();
+			for chunk in buffer.chunks_mut(7) {
+				encryptor.encrypt(chunk)?;
+			}
+			assert_eq!(buffer, ctxt);
+			assert_eq!(encryptor.finalize(), tag);
+
+			// Incremental decryption
+			let mut decryptor = Lea192GcmDecryptor::new(&lea192, &nonce);
+			decryptor.update_associated_data(&associated_data)?;
+			for chunk in ctxt.chunks(9) {
+				decryptor.verify(chunk)?;
+			}
+			let mut decryptor = decryptor.finalize(&tag)?;
+			let mut buffer = ctxt.clone();
+			for chunk in buffer.chunks_mut(11) {
+				decryptor.decrypt(chunk)?;
+			}
+			decryptor.finalize()?;
+			assert_eq!(buffer, ptxt);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea256gcm() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea256Gcm>; 3] = [
+			TestCase {
+				key: arr![u8; 0xF9, 0xFF, 0x15, 0x6B, 0x10, 0x45, 0x20, 0xAF, 0x11, 0x0B, 0x5E, 0x30, 0x25, 0xEA, 0x85, 0x34, 0x0E, 0x85, 0x12, 0xE7, 0x10, 0x5A, 0xEB, 0x9F, 0x75, 0xFD, 0xDE, 0x63, 0x55, 0xDC, 0xFC, 0x5E],
+				nonce: arr![u8; 0xA4, 0x90, 0x48, 0xD1, 0x2F, 0x72, 0x58, 0xA6, 0xD7, 0xE8, 0xAB, 0x56],
+				tag: arr![u8; 0xA0, 0x98, 0x0F, 0xFF, 0x78, 0xA9, 0xBD, 0xB4, 0xE6, 0x5F, 0x0A, 0x9E, 0x1C, 0xED, 0x88, 0x9D],
+				associated_data: vec![],
+				ptxt: vec![0xDC, 0x2A, 0xD6, 0xDD, 0x05, 0xA0, 0xCA, 0x9E, 0x10, 0x07, 0xBE, 0x24, 0x44, 0xD6, 0x75, 0x86],
+				ctxt: vec![0x08, 0x33, 0xE4, 0xE1, 0xFF, 0x3B, 0x9C, 0x9F, 0x24, 0xFC, 0x8D, 0x2A, 0x47, 0x6D, 0xEC, 0x49]
+			},
+			TestCase {
+				key: arr![u8; 0x30, 0x8A, 0x04, 0xF6, 0xA8, 0xAD, 0xC9, 0xCB, 0x6E, 0x43, 0xCE, 0xE7, 0x32, 0x1E, 0x1A, 0x5D, 0x0B, 0x48, 0xE8, 0xED, 0x98, 0x58, 0xFE, 0xE0, 0x6A, 0xCE, 0x40, 0xF6, 0x7C, 0xEB, 0x2A, 0xF8],
+				nonce: arr![u8; 0xAE, 0xD7, 0x1A, 0x5A, 0x05, 0x22, 0x42, 0xD5, 0xAD, 0x2C, 0xF7, 0x7F],
+				tag: arr![u8; 0xDF, 0xAE, 0x38, 0xD6, 0x89, 0xE8, 0x6F, 0xFD, 0xC9, 0x60, 0x29, 0x2D, 0x80, 0x7D, 0x39, 0x02],
+				associated_data: vec![0xF9, 0xD5, 0x27, 0x78, 0x00, 0xB6, 0xEA],
+				ptxt: vec![0xDA, 0x74, 0x9F, 0x20, 0x28, 0x66, 0xCD, 0x61, 0xA4, 0xBE, 0x85, 0xF3, 0x69, 0x0C, 0xE4, 0x4B, 0xE6, 0x2B, 0x9E, 0xF1, 0xB0, 0x73, 0xB9, 0x5F, 0xBC, 0x97, 0xBC, 0x4B, 0xC8, 0x6B, 0xB6, 0x34, 0x9B, 0x0E, 0x80, 0xEC, 0x7B, 0x29, 0x2F, 0x71, 0x8B, 0xB5, 0x79, 0x89, 0x92, 0x5E, 0xCC, 0x95, 0xD9, 0x1E, 0x2E, 0xDA, 0x78, 0xA8, 0x8B, 0x4D, 0xE9, 0x24, 0xDB, 0x16, 0x06, 0x87, 0x5F, 0x45],
+				ctxt: vec![0x90, 0x54, 0x2E, 0x14, 0xA3, 0x11, 0x9D, 0x37, 0x3D, 0xFE, 0xB5, 0x0C, 0x1F, 0x5A, 0xFA, 0xB7, 0x32, 0xFD, 0x91, 0x77, 0x0F, 0x3A, 0x3C, 0xA0, 0x06, 0x8C, 0xE0, 0x60, 0xAC, 0x52, 0x10, 0x8C, 0x34, 0xE2, 0x58, 0x90, 0x23, 0xCC, 0x4C, 0xA4, 0xE0, 0x17, 0x33, 0x54, 0xBE, 0x6A, 0x62, 0x8B, 0xD2, 0xF3, 0x3A, 0xE9, 0x2A, 0x93, 0x04, 0x41, 0x84, 0x5A, 0x9D, 0xDB, 0x74, 0xCE, 0x1C, 0xFB]
+			},
+			TestCase {
+				key: arr![u8; 0x90, 0xB1, 0x2E, 0x7E, 0xD5, 0x87, 0x3F, 0xEF, 0x2E, 0xED, 0x62, 0x24, 0x83, 0xFC, 0x70, 0xDA, 0x51, 0x54, 0xFA, 0xEF, 0x32, 0x5C, 0xED, 0x9D, 0x1C, 0xC5, 0xBD, 0x44, 0x37, 0xC0, 0x98, 0xCE],
+				nonce: arr![u8; 0xD0, 0x7A, 0xCD, 0xBD, 0xD9, 0xFA, 0xD9, 0x52, 0x47, 0x1F, 0xDB, 0x14],
+				tag: arr![u8; 0xB4, 0x4A, 0x5F, 0x76, 0x61, 0xE3, 0xBC, 0x89, 0x8C, 0x64, 0xE7, 0x95, 0xB2, 0x20, 0x08, 0xF1],
+				associated_data: vec![0xBD, 0x00, 0x9F, 0xE9, 0xE8, 0x29, 0xD8, 0x05, 0x73, 0x35, 0xC0, 0xD0, 0x93, 0x03, 0x10, 0xFE, 0x3C, 0x1C, 0xD2, 0x9E, 0xAB, 0x89, 0x7C, 0x29, 0x5A, 0xDD, 0x72, 0x49, 0xD9, 0x9C, 0x8A],
+				ptxt: vec![0x9D, 0x5E, 0x23, 0xD1, 0xE1, 0x5D, 0x56, 0x4E, 0x85, 0x2D, 0xDB, 0xCD, 0xB5, 0x30, 0xD7, 0x83, 0xBA, 0x79, 0xF0, 0xF4, 0xFA, 0x18, 0x7F, 0x70, 0xC9, 0xE6, 0x62, 0xAB, 0x0C, 0x24, 0xB8, 0x0F, 0x39, 0x12, 0x81, 0x70, 0x44, 0x1B, 0xF3, 0x40, 0xEA, 0x34, 0x06, 0x18, 0x0B, 0x35, 0x28, 0x86, 0x5D, 0xB0],
+				ctxt: vec![0x8B, 0xD5, 0x6C, 0xEC, 0xED, 0x35, 0xE0, 0xD9, 0x74, 0xB5, 0x56, 0x8E, 0x92, 0x95, 0x33, 0x66, 0xC4, 0x72, 0x64, 0xF1, 0x4C, 0xB3, 0x16, 0xDD, 0xF8, 0x4D, 0x6D, 0xD4, 0x4A, 0xF5, 0xF4, 0x4C, 0x87, 0xBD, 0x6C, 0xD4, 0x41, 0x4A, 0x03, 0x53, 0x15, 0xA4, 0x63, 0x0B, 0xB0, 0xD0, 0x9D, 0xF1, 0x2E, 0xDA]
+			}
+		];
+
+		for TestCase { key, nonce, tag, associated_data, ptxt, ctxt } in test_cases {
+			let lea256gcm = Lea256Gcm::new(&key);
+
+			// Encryption
+			let mut buffer = ptxt.clone();
+			let calculated_tag = lea256gcm.encrypt_in_place_detached(&nonce, &associated_data, &mut buffer)?;
+			assert_eq!(buffer, ctxt);
+			assert_eq!(calculated_tag, tag);
+
+			// Decryption
+			let mut buffer = ctxt.clone();
+			lea256gcm.decrypt_in_place_detached(&nonce, &associated_data, &mut buffer, &tag)?;
+			assert_eq!(buffer, ptxt);
+
+			// Incremental encryption
+			let lea256 = Lea256::new(&key);
+			let mut encryptor = Lea256GcmEncryptor::new(&lea256, &nonce);
+			for chunk in associated_data.chunks(5) {
+				encryptor.update_associated_data(chunk)?;
+			}
+			let mut buffer = ptxt.clone();
+			for chunk in buffer.chunks_mut(7) {
+				encryptor.encrypt(chunk)?;
+			}
+			assert_eq!(buffer, ctxt);
+			assert_eq!(encryptor.finalize(), tag);
+
+			// Incremental decryption
+			let mut decryptor = Lea256GcmDecryptor::new(&lea256, &nonce);
+			decryptor.update_associated_data(&associated_data)?;
+			for chunk in ctxt.chunks(9) {
+				decryptor.verify(chunk)?;
+			}
+			let mut decryptor = decryptor.finalize(&tag)?;
+			let mut buffer = ctxt.clone();
+			for chunk in buffer.chunks_mut(11) {
+				decryptor.decrypt(chunk)?;
+			}
+			decryptor.finalize()?;
+			assert_eq!(buffer, ptxt);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128gcm_nonce_size() -> Result<(), Error> {
+		let key = arr![u8; 0x26, 0x37, 0xE9, 0x77, 0xDA, 0xF5, 0x36, 0x13, 0xB4, 0x8A, 0x56, 0x5E, 0x92, 0x60, 0x05, 0x94];
+		let nonce = arr![u8; 0x37, 0xB4, 0xA4, 0xDD, 0x75, 0xA8, 0xA9, 0xF1, 0x6B, 0x7E, 0x02, 0x70, 0x00, 0x01, 0x02, 0x03];
+		let associated_data = [0x5E, 0xCF, 0xDA, 0xE2, 0x1F];
+		let ptxt: Vec<u8> = (0..50).collect();
+
+		let lea128 = Lea128::new(&key);
+		let lea128gcm = Lea128Gcm::<U16>::new(&key);
+
+		let mut ctxt = ptxt.clone();
+		let tag = lea128gcm.encrypt_in_place_detached(&nonce, &associated_data, &mut ctxt)?;
+
+		let mut encryptor = GcmEncryptor::new(&lea128, &nonce);
+		encryptor.update_associated_data(&associated_data)?;
+		let mut buffer = ptxt.clone();
+		encryptor.encrypt(&mut buffer)?;
+		assert_eq!(buffer, ctxt);
+		assert_eq!(encryptor.finalize(), tag);
+
+		let mut decryptor = GcmDecryptor::new(&lea128, &nonce);
+		decryptor.update_associated_data(&associated_data)?;
+		decryptor.decrypt_unverified(&mut buffer)?;
+		decryptor.finalize(&tag)?;
+		assert_eq!(buffer, ptxt);
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128gcm_stream_rejection() -> Result<(), Error> {
+		let lea128 = Lea128::new(&Default::default());
+		let nonce = GenericArray::<u8, U12>::default();
+		let ptxt = [0; 40];
+
+		let mut encryptor = Lea128GcmEncryptor::new(&lea128, &nonce);
+		encryptor.update_associated_data(&[1, 2, 3])?;
+		let mut ctxt = ptxt;
+		encryptor.encrypt(&mut ctxt)?;
+
+		// Associated data after plaintext
+		assert!(encryptor.update_associated_data(&[4]).is_err());
+		let tag = encryptor.finalize();
+
+		// Tampered ciphertext
+		let mut decryptor = Lea128GcmDecryptor::new(&lea128, &nonce);
+		decryptor.update_associated_data(&[1, 2, 3])?;
+		let mut buffer = ctxt;
+		buffer[39] ^= 1;
+		decryptor.verify(&buffer)?;
+		assert!(decryptor.finalize(&tag).is_err());
+
+		// Truncated ciphertext
+		let mut decryptor = Lea128GcmDecryptor::new(&lea128, &nonce);
+		decryptor.update_associated_data(&[1, 2, 3])?;
+		decryptor.verify(&ctxt[..32])?;
+		assert!(decryptor.finalize(&tag).is_err());
+
+		// Verified decryption does not go past authenticated ciphertext
+		let mut decryptor = Lea128GcmDecryptor::new(&lea128, &nonce);
+		decryptor.update_associated_data(&[1, 2, 3])?;
+		decryptor.verify(&ctxt)?;
+		let mut decryptor = decryptor.finalize(&tag)?;
+		let mut buffer = [ctxt.as_slice(), &[0]].concat();
+		assert!(decryptor.decrypt(&mut buffer).is_err());
+		decryptor.decrypt(&mut buffer[..40])?;
+		decryptor.finalize()?;
+		assert_eq!(buffer[..40], ptxt);
+
+		// Ciphertext modified or truncated between the passes
+		let mut decryptor = Lea128GcmDecryptor::new(&lea128, &nonce);
+		decryptor.update_associated_data(&[1, 2, 3])?;
+		decryptor.verify(&ctxt)?;
+		let mut decryptor = decryptor.finalize(&tag)?;
+		let mut buffer = ctxt;
+		buffer[0] ^= 1;
+		decryptor.decrypt(&mut buffer)?;
+		assert!(decryptor.finalize().is_err());
+
+		let mut decryptor = Lea128GcmDecryptor::new(&lea128, &nonce);
+		decryptor.update_associated_data(&[1, 2, 3])?;
+		decryptor.verify(&ctxt)?;
+		let mut decryptor = decryptor.finalize(&tag)?;
+		let mut buffer = ctxt;
+		decryptor.decrypt(&mut buffer[..39])?;
+		assert!(decryptor.finalize().is_err());
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128gmac() -> Result<(), Error> {
+		let test_cases: [GmacTestCase<Lea128>; 3] = [
+			GmacTestCase {
+				key: arr![u8; 0x6B, 0x84, 0xC5, 0x0D, 0x63, 0x70, 0xCA, 0x02, 0x82, 0xB6, 0x5B, 0x0D, 0x96, 0x25, 0xBB, 0x04],
+				nonce: arr![u8; 0x37, 0xD9, 0xB2, 0xD8, 0x31, 0xC8, 0x99, 0x88, 0x22, 0xEB, 0xDC, 0x83],
+				tag: arr![u8; 0xA6, 0x76, 0x7A, 0xF2, 0x2B, 0x0C, 0x48, 0xCE, 0x9F, 0xDC, 0x87, 0x35, 0xA4, 0xD8, 0xB8, 0x1D],
+				message: vec![]
+			},
+			GmacTestCase {
+				key: arr![u8; 0xA4, 0x00, 0x17, 0x60, 0xF6, 0xC3, 0xA0, 0x90, 0xC6, 0xB3, 0xF5, 0x45, 0xDD, 0x24, 0x58, 0x98],
+				nonce: arr![u8; 0x93, 0x78, 0x32, 0xCB, 0xF1, 0x2D, 0xD5, 0x11, 0x08, 0xD6, 0x02, 0xC1],
+				tag: arr![u8; 0x9C, 0x20, 0xF1, 0xBB, 0xF9, 0x40, 0x45, 0x9F, 0x52, 0x49, 0x0A, 0x37, 0x10, 0xCB, 0x62, 0x5B],
+				message: vec![0x40, 0x71, 0x3C, 0xE1, 0x67, 0x74, 0x7B, 0x43, 0x2D, 0xFA, 0xA9, 0x8F, 0x6F, 0x60, 0x48, 0x5B, 0x57, 0xB9, 0xA4, 0x92]
+			},
+			GmacTestCase {
+				key: arr![u8; 0x5B, 0x14, 0x3E, 0x6C, 0x20, 0x71, 0x18, 0x34, 0x3B, 0x36, 0xEB, 0xE8, 0xB6, 0x88, 0x68, 0x40],
+				nonce: arr![u8; 0xA2, 0x5E, 0x97, 0x22, 0x2C, 0xEC, 0x22, 0x94, 0x0D, 0x31, 0x5F, 0x6A],
+				tag: arr![u8; 0xD1, 0xF3, 0xA2, 0x25, 0x32, 0xA8, 0x3E, 0x7A, 0x25, 0xB9, 0x1A, 0x89, 0x58, 0xDE, 0x3A, 0xD4],
+				message: vec![0xC2, 0x4C, 0xFF, 0x40, 0xAE, 0x4A, 0x6C, 0xE3, 0x67, 0xA8, 0xAD, 0xE5, 0xBE, 0x1C, 0xF5, 0x79, 0xEC, 0xAE, 0x09, 0xC6, 0x27, 0x8E, 0x6F, 0x01, 0xB5, 0x0E, 0xBC, 0xD3, 0x74, 0x2D, 0xF5, 0x1E, 0x44, 0x14, 0x8A, 0x8F, 0x2B, 0xE2, 0x1E, 0x3F, 0x42, 0xA4, 0x7E, 0x9D, 0xA5, 0x36, 0x99, 0x90, 0xF6, 0x3D, 0x4B, 0x12, 0x27, 0xB0, 0x67, 0x75, 0x56, 0x8A, 0xC1, 0x00, 0x08, 0x09, 0x95, 0xF6]
+			}
+		];
+
+		for GmacTestCase { key, nonce, tag, message } in test_cases {
+			let lea128 = Lea128::new(&key);
+
+			// Authentication
+			let mut gmac = Lea128Gmac::new(&lea128, &nonce);
+			for chunk in message.chunks(7) {
+				gmac.update(chunk)?;
+			}
+			assert_eq!(gmac.finalize(), tag);
+
+			// Verification
+			let mut gmac = Lea128Gmac::new(&lea128, &nonce);
+			gmac.update(&message)?;
+			gmac.verify(&tag)?;
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea192gmac() -> Result<(), Error> {
+		let test_cases: [GmacTestCase<Lea192>; 3] = [
+			GmacTestCase {
+				key: arr![u8; 0x69, 0xBE, 0x52, 0xEA, 0x46, 0xA4, 0xB6, 0x6A, 0xE4, 0xBF, 0xB0, 0x26, 0xFC, 0x86, 0x08, 0x05, 0xDE, 0xF1, 0xBE, 0xDD, 0x0E, 0xF6, 0xE9, 0x6A],
+				nonce: arr![u8; 0xFA, 0xD8, 0x02, 0x49, 0xB3, 0x2E, 0xBD, 0x8B, 0x61, 0xED, 0xB5, 0x87],
+				tag: arr![u8; 0x08, 0xF5, 0xF5, 0x5E, 0x57, 0xEF, 0x1F, 0x31, 0xB2, 0x31, 0x34, 0x83, 0x4F, 0x10, 0xC4, 0x33],
+				message: vec![]
+			},
+			GmacTestCase {
+				key: arr![u8; 0x3D, 0x2E, 0x57, 0x8B, 0x64, 0xF1, 0x79, 0xEF, 0xEA, 0x2F, 0x49, 0xC0, 0x2C, 0x06, 0x29, 0xFC, 0x63, 0xB8, 0xAD, 0xF3, 0xF6, 0x3B, 0xC1, 0xDC],
+				nonce: arr![u8; 0x32, 0x78, 0xCE, 0x94, 0xB0, 0xA2, 0xFC, 0x21, 0x6C, 0x98, 0x24, 0x58],
+				tag: arr![u8; 0x69, 0x52, 0x0B, 0x79, 0x5A, 0xE7, 0xEC, 0x2E, 0x2E, 0x53, 0xD6, 0x08, 0x75, 0x6F, 0x28, 0x78],
+				message: vec![0x30, 0x50, 0x76, 0x14, 0xAD, 0x83, 0x99, 0x1D, 0x43, 0x39, 0x89, 0x76, 0xFC, 0x76, 0x4C, 0x16, 0xFB, 0xDE, 0x10, 0xC1]
+			},
+			GmacTestCase {
+				key: arr![u8; 0x97, 0xEE, 0x02, 0x33, 0xD8, 0x9C, 0x1B, 0x96, 0x04, 0x53, 0x99, 0xB0, 0xF6, 0xFD, 0x15, 0x0D, 0x19, 0x02, 0xE3, 0x9F, 0x89, 0x36, 0x74, 0x00],
+				nonce: arr![u8; 0x81, 0xB5, 0x90, 0x8D, 0x2B, 0xD3, 0x91, 0xBC, 0xB6, 0xD9, 0x40, 0xDA],
+				tag: arr![u8; 0xE2, 0x72, 0x5F, 0x22, 0xE5, 0x62, 0x30, 0x05, 0x1D, 0xC2, 0x60, 0xB2, 0x82, 0x39, 0xBE, 0x4C],
+				message: vec![0x16, 0x82, 0xB6, 0xD8, 0x29, 0x69, 0x75, 0x2E, 0x02, 0x27, 0xC1, 0x79, 0x8E, 0xA0, 0x32, 0x89, 0x6A, 0x15, 0x88, 0x2A, 0x3B, 0x3F, 0x6D, 0xDF, 0x39, 0x70, 0x87, 0x7F, 0xE6, 0xA0, 0x65, 0xCD, 0x32, 0x8C, 0x55, 0xFA, 0x33, 0x91, 0xA9, 0x3C, 0x2E, 0xE8, 0x1C, 0xC7, 0xD5, 0x80, 0xF7, 0xD8, 0x1E, 0xB8, 0xB2, 0x8F, 0x8B, 0x13, 0xE4, 0x01, 0x4C, 0x9F, 0x7F, 0xFE, 0x83, 0x7D, 0x91, 0x65]
+			}
+		];
+
+		for GmacTestCase { key, nonce, tag, message } in test_cases {
+			let lea192 = Lea192::new(&key);
+
+			// Authentication
+			let mut gmac = Lea192Gmac::new(&lea192, &nonce);
+			for chunk in message.chunks(7) {
+				gmac.update(chunk)?;
+			}
+			assert_eq!(gmac.finalize(), tag);
+
+			// Verification
+			let mut gmac = Lea192Gmac::new(&lea192, &nonce);
+			gmac.update(&message)?;
+			gmac.verify(&tag)?;
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea256gmac() -> Result<(), Error> {
+		let test_cases: [GmacTestCase<Lea256>; 3] = [
+			GmacTestCase {
+				key: arr![u8; 0x9C, 0x74, 0x70, 0x92, 0x4F, 0x8D, 0x55, 0x35, 0x30, 0xF3, 0x8C, 0xEB, 0x00, 0x44, 0x37, 0x86, 0xEF, 0x0B, 0x78, 0xC6, 0x23, 0x45, 0xBB, 0x62, 0x6E, 0x75, 0x02, 0xC5, 0x0E, 0xBC, 0x18, 0x8E],
+				nonce: arr![u8; 0xE9, 0x46, 0x23, 0xA2, 0xD9, 0x2F, 0xA2, 0x5E, 0x35, 0xA1, 0xDB, 0xB5],
+				tag: arr![u8; 0xDE, 0xD8, 0xEF, 0xAF, 0x31, 0xF9, 0xBC, 0x28, 0xBB, 0x07, 0xD7, 0x62, 0x43, 0x4B, 0x1B, 0xEF],
+				message: vec![]
+			},
+			GmacTestCase {
+				key: arr![u8; 0x20, 0xF6, 0x5A, 0x43, 0x91, 0x79, 0x01, 0xAC, 0x49, 0x2E, 0x7A, 0xB3, 0x46, 0x59, 0xEF, 0x19, 0xE8, 0x66, 0x88, 0xB1, 0xE2, 0x6A, 0x34, 0xC5, 0x26, 0x9C, 0xDF, 0xFA, 0x25, 0xBD, 0x68, 0xB0],
+				nonce: arr![u8; 0xC6, 0x7B, 0x5E, 0xD5, 0xB3, 0x0C, 0x9D, 0x12, 0xC2, 0x59, 0x63, 0xCA],
+				tag: arr![u8; 0xEA, 0x15, 0xB8, 0x85, 0x57, 0x1B, 0x8A, 0x37, 0x09, 0xF2, 0x61, 0x50, 0x77, 0x53, 0x19, 0x9A],
+				message: vec![0x80, 0x24, 0xB9, 0x62, 0x40, 0x87, 0xDD, 0xD7, 0x9E, 0xC8, 0x14, 0x23, 0x2D, 0xE5, 0x98, 0x99, 0x48, 0xAF, 0xA3, 0x7B]
+			},
+			GmacTestCase {
+				key: arr![u8; 0xC4, 0xDA, 0x0E, 0xD3, 0x39, 0x83, 0x56, 0xC0, 0x78, 0xC0, 0xA0, 0x46, 0x98, 0xE9, 0xBB, 0xB5, 0x49, 0xAE, 0x88, 0xBF, 0x00, 0x46, 0x14, 0xC0, 0xDF, 0xCF, 0x66, 0xA2, 0xE5, 0x5A, 0x92, 0xF1],
+				nonce: arr![u8; 0x2F, 0x6F, 0x66, 0xCD, 0xAC, 0x04, 0x44, 0x33, 0xBF, 0x06, 0x7C, 0x91],
+				tag: arr![u8; 0x30, 0xD6, 0x99, 0x02, 0xC8, 0x9B, 0xED, 0x70, 0x5C, 0x07, 0xC5, 0x16, 0x1D, 0x57, 0xB1, 0x0D],
+				message: vec![0xCA, 0x05, 0xFB, 0x53, 0x7D, 0xCC, 0x30, 0x1B, 0x17, 0x1F, 0x19, 0xD2, 0x17, 0xE1, 0xCE, 0x2D, 0x7F, 0x33, 0x54, 0xD2, 0x8D, 0x9E, 0xBB, 0x93, 0x21, 0x5A, 0x02, 0xC6, 0x37, 0x5A, 0x3F, 0x70, 0x1E, 0x5F, 0x1C, 0x76, 0xAB, 0x48, 0xE5, 0x45, 0xAD, 0xEB, 0x18, 0x0F, 0x43, 0x14, 0xDE, 0xE4, 0xC7, 0x3E, 0x1C, 0x25, 0xCE, 0x0D, 0x1A, 0xDD, 0x40, 0x3F, 0x93, 0xC9, 0xF1, 0xEF, 0x1A, 0xEC]
+			}
+		];
+
+		for GmacTestCase { key, nonce, tag, message } in test_cases {
+			let lea256 = Lea256::new(&key);
+
+			// Authentication
+			let mut gmac = Lea256Gmac::new(&lea256, &nonce);
+			for chunk in message.chunks(7) {
+				gmac.update(chunk)?;
+			}
+			assert_eq!(gmac.finalize(), tag);
+
+			// Verification
+			let mut gmac = Lea256Gmac::new(&lea256, &nonce);
+			gmac.update(&message)?;
+			gmac.verify(&tag)?;
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128gmac_gcm() -> Result<(), Error> {
+		let key = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+		let nonce = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+		let message: Vec<u8> = (0..50).collect();
+
+		// GMAC is GCM without plaintext.
+		let lea128 = Lea128::new(&key);
+		let lea128gcm = Lea128Gcm::<U16>::new(&key);
+		let tag = lea128gcm.encrypt_in_place_detached(&nonce, &message, &mut [])?;
+
+		let mut gmac = Lea128Gmac::<U16>::new(&lea128, &nonce);
+		gmac.update(&message)?;
+		assert_eq!(gmac.finalize(), tag);
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128gmac_rejection() -> Result<(), Error> {
+		let lea128 = Lea128::new(&Default::default());
+		let nonce = GenericArray::<u8, U12>::default();
+		let message = [0; 40];
+
+		let mut gmac = Lea128Gmac::new(&lea128, &nonce);
+		gmac.update(&message)?;
+		let tag = gmac.finalize();
+
+		// Tampered message
+		let mut gmac = Lea128Gmac::new(&lea128, &nonce);
+		gmac.update(&message[..39])?;
+		gmac.update(&[1])?;
+		assert!(gmac.verify(&tag).is_err());
+
+		// Truncated message
+		let mut gmac = Lea128Gmac::new(&lea128, &nonce);
+		gmac.update(&message[..32])?;
+		assert!(gmac.verify(&tag).is_err());
+
+		// Tampered tag
+		let mut tampered_tag = tag;
+		tampered_tag[15] ^= 1;
+		let mut gmac = Lea128Gmac::new(&lea128, &nonce);
+		gmac.update(&message)?;
+		assert!(gmac.verify(&tampered_tag).is_err());
+
+		// Another nonce
+		let mut gmac = Lea128Gmac::new(&lea128, &arr![u8; 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+		gmac.update(&message)?;
+		assert!(gmac.verify(&tag).is_err());
+
+		Ok(())
+	}
+}
@@ -0,0 +1,401 @@
+// Copyright © 2023 남기훈 <gihunnam@proton.me>
+//
+// This file and its content are subject to the terms of the MIT License (the "License").
+// If a copy of the License was not distributed with this file, you can obtain one at https://opensource.org/licenses/MIT.
+
+//! LEA-HCTR2
+//!
+//! HCTR2 as specified by Crowley, Huckleberry, and Biggers, a length-preserving wide-block cipher built from XCTR and POLYVAL.
+//! Changing any bit of the plaintext or the tweak changes the whole ciphertext, unlike XTS, which only changes one block.
+//! Inputs must be at least 16 bytes long, and tweaks may have any length.
+//! XCTR blocks are encrypted in batches of [`BlockCipher::ParBlocks`].
+//!
+//! HCTR2 is deterministic and unauthenticated: equal plaintexts under equal tweaks give equal ciphertexts.
+//!
+//! * Example
+//! ```
+//! use lea::{prelude::*, Lea128Hctr2};
+//!
+//! let key = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+//! let tweak = b"/home/user";
+//! let ptxt = *b"annual-report-2023.pdf";
+//! let ctxt = [
+//!     0x8D, 0x50, 0x08, 0x14, 0x67, 0x96, 0x41, 0xE2, 0x5F, 0x28, 0xDE, 0x41, 0x28, 0xBF, 0x58, 0xC0,
+//!     0xCA, 0x3B, 0x7B, 0x91, 0x77, 0xB2
+//! ];
+//!
+//! let lea128hctr2 = Lea128Hctr2::new(&key);
+//!
+//! // Encryption
+//! let mut buffer = ptxt;
+//! lea128hctr2.encrypt(tweak, &mut buffer).unwrap();
+//! assert_eq!(buffer, ctxt);
+//!
+//! // Decryption
+//! let mut buffer = ctxt;
+//! lea128hctr2.decrypt(tweak, &mut buffer).unwrap();
+//! assert_eq!(buffer, ptxt);
+//! ```
+
+use core::fmt;
+
+use cipher::{generic_array::{typenum::{U16, Unsigned}, GenericArray}, BlockCipher, BlockCipherKey, BlockDecrypt, BlockEncrypt, NewBlockCipher, ParBlocks};
+use polyval::{universal_hash::{NewUniversalHash, UniversalHash}, Polyval};
+
+use crate::{Lea128, Lea192, Lea256};
+
+pub type Lea128Hctr2 = Hctr2<Lea128>;
+pub type Lea192Hctr2 = Hctr2<Lea192>;
+pub type Lea256Hctr2 = Hctr2<Lea256>;
+
+type Block = GenericArray<u8, U16>;
+
+/// Error of HCTR2
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Error {
+	/// Input is shorter than 16 bytes.
+	InvalidLength
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::InvalidLength => "invalid length"
+		})
+	}
+}
+
+/// HCTR2
+pub struct Hctr2<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt {
+	cipher: C,
+	/// POLYVAL keyed with h = E<sub>K</sub>(bin(0))
+	polyval: Polyval,
+	/// L = E<sub>K</sub>(bin(1))
+	l: Block
+}
+
+impl<C> Hctr2<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt + NewBlockCipher {
+	pub fn new(key: &BlockCipherKey<C>) -> Self {
+		Self::from(C::new(key))
+	}
+}
+
+impl<C> From<C> for Hctr2<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt {
+	fn from(cipher: C) -> Self {
+		let mut h = Block::default();
+		cipher.encrypt_block(&mut h);
+
+		let mut l = Block::default();
+		l[0] = 1;
+		cipher.encrypt_block(&mut l);
+
+		Self { cipher, polyval: Polyval::new(&h), l }
+	}
+}
+
+impl<C> Hctr2<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt {
+	/// Encrypts `buffer`, at least 16 bytes long, in place.
+	pub fn encrypt(&self, tweak: &[u8], buffer: &mut [u8]) -> Result<(), Error> {
+		self.crypt(tweak, buffer, false)
+	}
+
+	/// Decrypts `buffer`, at least 16 bytes long, in place.
+	pub fn decrypt(&self, tweak: &[u8], buffer: &mut [u8]) -> Result<(), Error> {
+		self.crypt(tweak, buffer, true)
+	}
+
+	fn crypt(&self, tweak: &[u8], buffer: &mut [u8], decrypt: bool) -> Result<(), Error> {
+		if buffer.len() < 16 {
+			return Err(Error::InvalidLength);
+		}
+
+		let (m, n) = buffer.split_at_mut(16);
+
+		// bin(2|T| + 2) or bin(2|T| + 3) || pad(T), common to both hashes
+		let mut tweak_hash = self.polyval.clone();
+		let mut block = Block::default();
+		block[..8].copy_from_slice(&((tweak.len() as u64 * 16) + 2 + (n.len() % 16 != 0) as u64).to_le_bytes());
+		tweak_hash.update(&block);
+		tweak_hash.update_padded(tweak);
+
+		// MM ← M ⊕ H(T, N)
+		let mut mm = hash(tweak_hash.clone(), n);
+		mm.iter_mut().zip(m.iter()).for_each(|(mm, m)| *mm ^= m);
+
+		// UU ← E(MM), or D(MM) when decrypting
+		let mut uu = mm;
+		if decrypt {
+			self.cipher.decrypt_block(&mut uu);
+		} else {
+			self.cipher.encrypt_block(&mut uu);
+		}
+
+		// S ← MM ⊕ UU ⊕ L, V ← N ⊕ XCTR(S)
+		let mut s = self.l;
+		s.iter_mut().zip(mm).zip(uu).for_each(|((s, mm), uu)| *s ^= mm ^ uu);
+		self.xctr(&s, n);
+
+		// U ← UU ⊕ H(T, V)
+		let u = hash(tweak_hash, n);
+		m.iter_mut().zip(uu).zip(u).for_each(|((m, uu), u)| *m = uu ^ u);
+
+		Ok(())
+	}
+
+	/// XCTR, i.e. CTR with the little-endian counter, starting from 1, XORed with `s`
+	fn xctr(&self, s: &Block, buffer: &mut [u8]) {
+		let s = u128::from_le_bytes((*s).into());
+		let mut i = 0u128;
+
+		let mut blocks = ParBlocks::<C>::default();
+		for chunk in buffer.chunks_mut(16 * C::ParBlocks::USIZE) {
+			let n = chunk.len().div_ceil(16);
+			for block in blocks[..n].iter_mut() {
+				i += 1;
+				*block = Block::from((s ^ i).to_le_bytes());
+			}
+
+			self.cipher.encrypt_blocks(&mut blocks[..n]);
+			chunk.iter_mut().zip(blocks.iter().flatten()).for_each(|(x, k)| *x ^= k);
+		}
+	}
+}
+
+/// Hashes the message part with a one byte appended if it is not a multiple of 16 bytes.
+fn hash(mut polyval: Polyval, data: &[u8]) -> Block {
+	let (full, partial) = data.split_at(data.len() / 16 * 16);
+	polyval.update_padded(full);
+
+	if !partial.is_empty() {
+		let mut block = Block::default();
+		block[..partial.len()].copy_from_slice(partial);
+		block[partial.len()] = 1;
+		polyval.update(&block);
+	}
+
+	polyval.finalize().into_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+	extern crate alloc;
+
+	use alloc::{vec::Vec, vec};
+
+	use cipher::{BlockCipher, BlockCipherKey, NewBlockCipher};
+
+	use crate::{hctr2::Error, prelude::*, Lea128, Lea192, Lea256, Lea128Hctr2, Lea192Hctr2, Lea256Hctr2};
+
+	struct TestCase<T> where
+	T: BlockCipher + NewBlockCipher {
+		key: BlockCipherKey<T>,
+		tweak: Vec<u8>,
+		ptxt: Vec<u8>,
+		ctxt: Vec<u8>
+	}
+
+	#[test]
+	fn lea128hctr2() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea128>; 6] = [
+			TestCase {
+				key: arr![u8; 0xD6, 0x67, 0xDB, 0xA1, 0x8B, 0x46, 0x3F, 0xE0, 0xA2, 0x08, 0xF9, 0x71, 0x79, 0x93, 0x10, 0x50],
+				tweak: vec![],
+				ptxt: vec![0x45, 0x4B, 0x79, 0x13, 0xE9, 0xE3, 0x29, 0x2A, 0xD4, 0x0D, 0xBE, 0x1A, 0xAC, 0xB7, 0x8C, 0x4B],
+				ctxt: vec![0x2D, 0x6C, 0x96, 0x5D, 0x97, 0x19, 0xFC, 0x07, 0xDD, 0xB9, 0xE4, 0x81, 0x03, 0xEB, 0x22, 0x3B]
+			},
+			TestCase {
+				key: arr![u8; 0x0D, 0x88, 0xD3, 0xAE, 0x77, 0x1D, 0x33, 0x9A, 0xEA, 0xA2, 0x70, 0xA3, 0xFF, 0xF5, 0xD5, 0xF5],
+				tweak: vec![0x47],
+				ptxt: vec![0x86, 0x56, 0xAB, 0x5F, 0xCC, 0x65, 0xB1, 0x1C, 0xFB, 0x38, 0x3E, 0xFB, 0x76, 0x71, 0x78, 0x1D, 0xA5],
+				ctxt: vec![0x83, 0x83, 0x6D, 0xB7, 0xCF, 0x78, 0x5D, 0x58, 0x62, 0x81, 0xFB, 0x89, 0x5D, 0xB9, 0x99, 0x09, 0x01]
+			},
+			TestCase {
+				key: arr![u8; 0x8A, 0x38, 0x76, 0x79, 0x48, 0xE7, 0x22, 0x47, 0x01, 0x05, 0x89, 0xC1, 0xE2, 0x3A, 0x31, 0xC1],
+				tweak: vec![0x2B, 0xD4, 0xCF, 0x3F, 0x66, 0x6D, 0x94, 0xA7, 0x5E, 0xB1, 0xD2, 0x42, 0x0D, 0x46, 0x13, 0x15],
+				ptxt: vec![0x2C, 0xE4, 0x95, 0x73, 0xB2, 0x38, 0x94, 0x23, 0xA1, 0xF6, 0xEE, 0x6D, 0x65, 0xC6, 0x44, 0x6F, 0x2D, 0xA1, 0x6D, 0x22, 0x9D, 0xD7, 0xB1, 0x79, 0xED, 0x48, 0x1B, 0x58, 0x02, 0x73, 0xEF, 0x8F],
+				ctxt: vec![0xC0, 0x5F, 0x19, 0x04, 0xB0, 0x40, 0x98, 0x94, 0x88, 0x88, 0x5A, 0x2E, 0xF3, 0x1D, 0x41, 0x7A, 0xF9, 0x07, 0x7B, 0xCC, 0x00, 0xC3, 0xC5, 0xD3, 0x39, 0x24, 0xB2, 0xCC, 0x14, 0x47, 0xB0, 0x26]
+			},
+			TestCase {
+				key: arr![u8; 0x4B, 0x5A, 0xA1, 0x1C, 0x13, 0x81, 0xF0, 0x17, 0x6E, 0x53, 0x95, 0x18, 0xB8, 0xE6, 0x19, 0x29],
+				tweak: vec![0x65, 0x28, 0xCA, 0x5D, 0x34, 0x23, 0x14],
+				ptxt: vec![0x1D, 0x07, 0x57, 0xBD, 0x56, 0x8A, 0xFC, 0x0B, 0x8F, 0xC0, 0xE5, 0x46, 0xEC, 0xB9, 0xF7, 0x06, 0x8C, 0x95, 0xBC, 0xAF, 0x6A, 0x2D, 0xBC, 0xED, 0x1C, 0x09, 0x01, 0x6A, 0x56, 0x09, 0x99, 0x66, 0x82, 0xF5, 0x7A, 0x5B, 0x8B, 0x3D, 0x40, 0x99, 0xF3, 0x83, 0x45, 0x55, 0x2E, 0xC9, 0x6E],
+				ctxt: vec![0xBE, 0xAA, 0x60, 0x85, 0x08, 0xB9, 0x72, 0xC8, 0xD1, 0x28, 0x55, 0xE2, 0xF5, 0x59, 0xD2, 0x08, 0x55, 0xE3, 0xD7, 0x38, 0x42, 0x3C, 0x66, 0xB4, 0x51, 0xDA, 0x91, 0xB0, 0x0D, 0xF9, 0x9B, 0x05, 0x72, 0xAE, 0xEB, 0x11, 0x13, 0xDE, 0xB6, 0x6E, 0x1A, 0xF0, 0xD6, 0x8D, 0x29, 0x92, 0xF7]
+			},
+			TestCase {
+				key: arr![u8; 0x96, 0x59, 0x87, 0x60, 0x2B, 0x89, 0x52, 0x10, 0x2A, 0x3A, 0x07, 0x9A, 0x7D, 0x81, 0x26, 0x95],
+				tweak: vec![0x75, 0xA9, 0x39, 0x56, 0xA4, 0xD9, 0x8D, 0xFB, 0x8E, 0x29, 0x0C, 0x57, 0xF8, 0x82, 0x2F, 0xDC, 0x48, 0x2F, 0xB5, 0x5B, 0x34, 0xB1, 0x03, 0x5E, 0x46, 0xE9, 0x0F, 0x33, 0x68, 0x92, 0x14, 0x4D],
+				ptxt: vec![0x2A, 0x69, 0x83, 0x7B, 0x0F, 0x39, 0xC6, 0xA6, 0xCA, 0x51, 0xB8, 0xBF, 0xDC, 0xEC, 0x1D, 0x75, 0xF3, 0xB0, 0x27, 0x44, 0x54, 0xF1, 0x1D, 0x68, 0xDE, 0x44, 0xBD, 0xD9, 0xC1, 0x39, 0xAC, 0x96, 0xB7, 0xA5, 0x87, 0x0A, 0xAB, 0x4C, 0x77, 0xC7, 0xF9, 0x7E, 0x48, 0x73, 0x66, 0x76, 0xDB, 0x4A, 0x6E, 0xB4, 0xAA, 0xE1, 0x87, 0xF5, 0xCF, 0xBF, 0x88, 0x63, 0x03, 0x8A, 0x22, 0xD3, 0x67, 0x89, 0xB5, 0x41, 0xF4, 0x4C, 0x5D, 0x40, 0x45, 0x98, 0x3A, 0x0F, 0xD9, 0x45, 0xBE, 0xC2, 0x90, 0x40, 0x60, 0x1B, 0xC7, 0x19, 0xBF, 0x05, 0xC2, 0xA1, 0x3D, 0x67, 0x83, 0x47, 0xBD, 0xA4, 0xE7, 0x28, 0x73, 0x93, 0x95, 0x8E, 0xCD, 0x3B, 0x15, 0xA2, 0x20, 0x47, 0xC0, 0x91, 0x45, 0x79, 0xD0, 0xC6, 0xAC, 0xF5, 0x8F, 0x46, 0xC5, 0xD1, 0x68, 0xE8, 0xC8, 0x7B, 0xC6, 0xB3, 0x97, 0x1C, 0x80, 0xB9, 0xC3, 0xD9, 0xDE, 0x25, 0xEC, 0xDA, 0x22, 0x2B, 0xFF, 0x54, 0xD7, 0xF8, 0x54, 0x2C, 0xCA, 0xBC, 0x36, 0x75, 0x58, 0x77, 0xBC],
+				ctxt: vec![0x1B, 0x4F, 0x9A, 0xD8, 0xCC, 0x71, 0x61, 0xFC, 0xB9, 0x70, 0x66, 0xEF, 0xB6, 0xBF, 0xF9, 0x33, 0x36, 0x4E, 0x43, 0x73, 0x26, 0xB4, 0x86, 0x30, 0x1F, 0xEA, 0xF7, 0x6C, 0x01, 0xBB, 0xC2, 0xD3, 0xA3, 0x58, 0xBF, 0xCE, 0xF7, 0xFC, 0xE9, 0x5F, 0xDC, 0xBB, 0x1B, 0xEA, 0x1B, 0x9B, 0x64, 0xC0, 0xF9, 0x0F, 0x2C, 0xB4, 0xB9, 0xBB, 0x34, 0xCE, 0x9F, 0x63, 0x8C, 0xDB, 0xFB, 0xBC, 0xC5, 0x76, 0xBB, 0xF7, 0x86, 0x80, 0x05, 0xB1, 0x7F, 0x83, 0xDA, 0x85, 0xB1, 0x94, 0xC6, 0x6C, 0x1B, 0xDF, 0x38, 0xAD, 0xDC, 0xF8, 0x06, 0x2B, 0x1E, 0x82, 0x87, 0x19, 0xEF, 0x25, 0xCE, 0x66, 0xAD, 0x1E, 0x7D, 0x7B, 0x65, 0x72, 0x5E, 0x68, 0x69, 0x90, 0x63, 0x56, 0x9E, 0x50, 0xE6, 0xE4, 0x25, 0x55, 0x3D, 0xB7, 0xC5, 0x63, 0x71, 0xC0, 0xE2, 0xA3, 0xBA, 0x22, 0x70, 0x63, 0x33, 0x0A, 0xE9, 0xF1, 0x21, 0x5E, 0x12, 0x5D, 0xC4, 0xD8, 0xB3, 0x91, 0x6D, 0x9E, 0xC6, 0xD6, 0x84, 0x1E, 0x63, 0xE7, 0x85, 0xF9, 0x73, 0x7E, 0xB8]
+			},
+			TestCase {
+				key: arr![u8; 0xF9, 0xFC, 0x0B, 0x20, 0xEE, 0x88, 0x27, 0x33, 0x5C, 0xAF, 0xE7, 0xCD, 0x5C, 0x27, 0xB8, 0x78],
+				tweak: vec![0x39, 0x77, 0xD5, 0xC9, 0x20, 0xD6, 0xFD, 0x22, 0x05, 0xDF, 0xF1, 0xC4, 0x75, 0x7B, 0x2C, 0xD2, 0x01, 0x01, 0x38, 0x71, 0xF0, 0xF6, 0x24, 0x80, 0xFE, 0x63, 0x65, 0x5D, 0xA2, 0x9E, 0xFA, 0xA7, 0x71, 0xF6, 0x0D, 0xAF, 0x14, 0xF0, 0x27, 0x31],
+				ptxt: vec![0x95, 0xFE, 0x3A, 0xE0, 0xAF, 0xC9, 0x05, 0x59, 0x0F, 0x9E, 0x35, 0x55, 0x23, 0x4C, 0xB6, 0xFE, 0xF6, 0xBB, 0x3D, 0xDF, 0x92, 0x4C, 0x7A, 0xC9, 0x62, 0x34, 0x21, 0x6F, 0xC7, 0xA0, 0xD8, 0x83, 0xEE, 0xD0, 0x00, 0x80, 0x9A, 0xDB, 0x0B, 0x73, 0xFD, 0xE1, 0x20, 0xDA, 0xE3, 0x9F, 0x3C, 0x7F, 0x7B, 0x82, 0x4D, 0x23, 0x3F, 0xE5, 0xB2, 0xDC, 0x88, 0x0D, 0xDA, 0xA8, 0x03, 0x48, 0x22, 0x51, 0xCA, 0xBC, 0x15, 0xA5, 0x90, 0x50, 0xFF, 0x24, 0x3D, 0x9A, 0x48, 0xBA, 0x22, 0x0A, 0x77, 0xBD, 0x1A, 0xE5, 0xEE, 0x43, 0x6C, 0x71, 0x1E, 0x96, 0xDC, 0x10, 0x81, 0x0B, 0xDF, 0x5B, 0xD8, 0xAC, 0xAE, 0xC8, 0xDE, 0x30, 0xC2, 0x99, 0xAC, 0xD7, 0x1B, 0xB1, 0x8A, 0xF4, 0x1F, 0xFA, 0x44, 0xEB, 0xD2, 0xC0, 0x6E, 0xBB, 0xF1, 0xE8, 0x02, 0x40, 0x4B, 0xF0, 0x5C, 0x35, 0xC5, 0x85, 0x95, 0x33, 0x14, 0x99, 0xAE, 0xDF, 0xC0, 0x81, 0x77, 0x19, 0xB2, 0x7E, 0x9B, 0x67, 0xC1, 0x1B, 0x3E, 0x50, 0xB1, 0x78, 0xF2, 0xEB, 0x08, 0xE1, 0xDB, 0x50, 0x0D, 0xA4, 0x8B, 0xC4, 0xD7, 0xC1, 0x5E, 0xF2, 0x6B, 0xA3, 0xFB, 0xCC, 0x9C, 0x8A, 0x82, 0x9F, 0xC5, 0x68, 0x42, 0x55, 0x15, 0x60, 0x26, 0xD1, 0xF4, 0x2F, 0x7F, 0xA0, 0x48, 0x60, 0x63, 0xCE, 0xE4, 0x6A, 0xCA, 0xD9, 0x23, 0x62, 0x1A, 0x71, 0x09, 0xEA, 0x23, 0xC4, 0xAC, 0x4B, 0xCB, 0xEC, 0x7A, 0x0C, 0xE8, 0xD2, 0x0F, 0x1F, 0x2F, 0x83, 0xD6, 0x8A, 0xAC, 0x5A, 0x36, 0xA6, 0xE4, 0x2A, 0x35, 0x6E, 0xBF, 0x71, 0xC1, 0x5E, 0xE6, 0x7B, 0xEF, 0x52, 0x61, 0x0B, 0xB6, 0x85, 0x26, 0xCB, 0x37, 0x3D, 0xC9, 0x30, 0xB7, 0x0D, 0x22, 0x0E, 0x02, 0xE5, 0x67, 0x0A, 0x7E, 0xDD, 0x6A, 0x18, 0x9C, 0xAD, 0x9D, 0x93, 0x5A, 0x1F, 0x6B, 0xFF, 0xBC, 0x1E, 0x25, 0x82, 0x82, 0xFF, 0x9F, 0xFF, 0x29, 0x9F, 0x31, 0x07, 0x44, 0x22, 0xE8, 0xCF],
+				ctxt: vec![0x6E, 0x60, 0x9B, 0xCD, 0xCE, 0xBB, 0x73, 0x6A, 0x76, 0x6B, 0xA0, 0xB4, 0xA3, 0x5D, 0x81, 0xBF, 0x86, 0x2F, 0x25, 0x67, 0x98, 0x5C, 0x5B, 0xAD, 0xE5, 0x74, 0xF0, 0x59, 0x86, 0x13, 0xD1, 0x16, 0xB8, 0x67, 0x7B, 0xF5, 0x04, 0xC7, 0xA1, 0x54, 0x45, 0x51, 0x27, 0x1D, 0x87, 0x7C, 0x60, 0x0B, 0x7D, 0x0F, 0xBB, 0xEB, 0x25, 0x26, 0xE0, 0x22, 0x90, 0x17, 0x9A, 0x12, 0x77, 0x20, 0x78, 0x81, 0x73, 0x41, 0x11, 0x4A, 0x3F, 0xE3, 0x26, 0xEE, 0xF2, 0x75, 0xBD, 0xD8, 0xD8, 0x40, 0x17, 0xCD, 0xAC, 0x83, 0x52, 0x0E, 0x0E, 0x04, 0xDC, 0xDE, 0xD1, 0x06, 0xA2, 0xB1, 0xC5, 0x2C, 0xFF, 0x3E, 0x20, 0xFB, 0xC1, 0x71, 0x86, 0x81, 0xDE, 0x42, 0x4E, 0xD4, 0xE8, 0xB2, 0x4F, 0x2A, 0x34, 0x8D, 0xF7, 0xC3, 0x45, 0xC1, 0xBF, 0xB4, 0xC6, 0x0C, 0x0C, 0x70, 0x41, 0x27, 0x02, 0x7B, 0x2B, 0xDA, 0xF2, 0xED, 0xF4, 0xB2, 0xD2, 0x0B, 0x24, 0xE9, 0xE5, 0xDD, 0x17, 0x0D, 0x04, 0x60, 0x6D, 0xFB, 0xD0, 0xC2, 0x4D, 0xFA, 0xB0, 0xC9, 0x17, 0xC5, 0xE2, 0x82, 0xAB, 0x37, 0x21, 0xF2, 0xC3, 0x28, 0x3A, 0x36, 0x97, 0xF1, 0x2C, 0x81, 0xC6, 0xC5, 0xBB, 0x4F, 0xF3, 0x44, 0x9B, 0xD6, 0x92, 0xE4, 0x7C, 0xA9, 0x5A, 0x66, 0x5F, 0x9A, 0xBD, 0x4D, 0x60, 0xB0, 0xAD, 0xC1, 0x68, 0x1E, 0x7B, 0x47, 0x38, 0x64, 0xD8, 0x4C, 0x13, 0x7C, 0xF8, 0x3B, 0xE2, 0xCC, 0x92, 0x1D, 0x98, 0x01, 0xC1, 0x26, 0x79, 0xC2, 0xBB, 0xB1, 0xAF, 0x13, 0xD8, 0x88, 0x9D, 0x85, 0x42, 0x52, 0xCB, 0xD2, 0xA1, 0x51, 0xB4, 0x4A, 0xC1, 0x80, 0x4D, 0xD0, 0x7B, 0x6E, 0x39, 0x1B, 0x5C, 0xC3, 0x24, 0x3E, 0x51, 0x39, 0x8E, 0xE1, 0x8E, 0xEC, 0x6C, 0xFF, 0x98, 0xD0, 0x34, 0x28, 0xFA, 0xD1, 0xE6, 0x0D, 0xCA, 0xCA, 0x38, 0x40, 0xD7, 0xB0, 0x6A, 0x86, 0x46, 0xFC, 0xE5, 0x42, 0x0C, 0x9D, 0x20, 0x3E, 0x93, 0xDB]
+			}
+		];
+
+		for TestCase { key, tweak, ptxt, ctxt } in test_cases {
+			let lea128hctr2 = Lea128Hctr2::new(&key);
+
+			// Encryption
+			let mut buffer = ptxt.clone();
+			lea128hctr2.encrypt(&tweak, &mut buffer)?;
+			assert_eq!(buffer, ctxt);
+
+			// Decryption
+			let mut buffer = ctxt.clone();
+			lea128hctr2.decrypt(&tweak, &mut buffer)?;
+			assert_eq!(buffer, ptxt);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea192hctr2() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea192>; 6] = [
+			TestCase {
+				key: arr![u8; 0x19, 0xFA, 0x6D, 0x8F, 0x82, 0x1A, 0xC5, 0x9E, 0xDB, 0x6E, 0x60, 0x51, 0x18, 0x87, 0x58, 0x8A, 0x9C, 0xB2, 0xDA, 0xFB, 0x98, 0x3E, 0x8C, 0xBF],
+				tweak: vec![],
+				ptxt: vec![0xC3, 0x22, 0x5B, 0x77, 0xA2, 0xC8, 0xC0, 0xCC, 0x9B, 0xF5, 0xFB, 0x98, 0x89, 0xBA, 0xF3, 0x0C],
+				ctxt: vec![0x14, 0x86, 0x0B, 0x8A, 0xD1, 0xC8, 0x3A, 0x9A, 0x04, 0x96, 0x93, 0x86, 0x1C, 0x7C, 0xD0, 0x75]
+			},
+			TestCase {
+				key: arr![u8; 0xA0, 0x02, 0x72, 0x8F, 0x62, 0x79, 0x81, 0xE8, 0x13, 0x79, 0x1E, 0x17, 0x72, 0x72, 0x46, 0xCF, 0x1B, 0xD8, 0x20, 0x81, 0xF5, 0x95, 0x44, 0x7E],
+				tweak: vec![0x50],
+				ptxt: vec![0x8C, 0xC2, 0xE2, 0x9A, 0x24, 0xAE, 0x95, 0x14, 0xCC, 0xF9, 0x1A, 0xDB, 0xE8, 0xA5, 0x88, 0x33, 0xBC],
+				ctxt: vec![0x0C, 0xC4, 0x04, 0x30, 0xEF, 0xD2, 0x45, 0xC7, 0x42, 0x78, 0xF9, 0xEA, 0x02, 0x03, 0x4C, 0x27, 0x34]
+			},
+			TestCase {
+				key: arr![u8; 0x5D, 0x94, 0x1D, 0x6D, 0x3B, 0x14, 0xF0, 0x80, 0x12, 0x3A, 0xA7, 0x3A, 0x89, 0x15, 0x47, 0x16, 0x2A, 0x24, 0x64, 0x6B, 0x1C, 0x2D, 0x4F, 0xF8],
+				tweak: vec![0xF1, 0x62, 0xED, 0x95, 0x27, 0xC9, 0x74, 0xDE, 0xD9, 0xC9, 0xF5, 0xB5, 0x47, 0x74, 0x4E, 0x90],
+				ptxt: vec![0xB4, 0x9B, 0x7F, 0x76, 0xB6, 0x37, 0x67, 0x41, 0x9A, 0xFA, 0xD8, 0x38, 0x15, 0x77, 0x5A, 0x59, 0x95, 0xA6, 0xF3, 0x2D, 0x1C, 0x24, 0x81, 0xD8, 0xF7, 0x24, 0x18, 0x01, 0x22, 0x2E, 0xDF, 0x7E],
+				ctxt: vec![0x9F, 0xDF, 0xD5, 0x09, 0x5C, 0x25, 0xE7, 0x1F, 0x2F, 0x3D, 0x90, 0xDE, 0xF6, 0x9C, 0x11, 0x75, 0xEC, 0x4F, 0x99, 0x77, 0xEF, 0xF9, 0x20, 0x3A, 0xAA, 0xEC, 0xC9, 0xBA, 0x2E, 0xF9, 0x6B, 0xED]
+			},
+			TestCase {
+				key: arr![u8; 0x55, 0x82, 0x16, 0xEE, 0x55, 0x0E, 0x73, 0x11, 0x68, 0xCD, 0x58, 0x5E, 0x4B, 0x20, 0xF1, 0x1C, 0xEF, 0xCC, 0x43, 0xD7, 0x40, 0xD1, 0x73, 0x29],
+				tweak: vec![0xD0, 0x1B, 0x67, 0xA0, 0x98, 0xD2, 0xAA],
+				ptxt: vec![0x33, 0x24, 0x02, 0xA9, 0xE8, 0xD4, 0xF1, 0x29, 0x1E, 0xC8, 0xF6, 0x71, 0x47, 0x6C, 0x3F, 0x25, 0xC2, 0xCE, 0xC6, 0x5B, 0x6B, 0x66, 0xC9, 0xD2, 0x31, 0xB7, 0xBC, 0x0D, 0x2D, 0x49, 0xD2, 0x3F, 0x20, 0x65, 0xE5, 0x1B, 0x40, 0x5D, 0x88, 0x81, 0x0F, 0xF9, 0x35, 0xA4, 0xA0, 0x65, 0x5A],
+				ctxt: vec![0x5A, 0x7C, 0x81, 0xF2, 0x60, 0x1B, 0x36, 0x5C, 0x14, 0xBF, 0xD0, 0xBB, 0x85, 0x39, 0xDE, 0x11, 0xF5, 0xCC, 0x19, 0x68, 0x1C, 0xB6, 0x4F, 0xE8, 0x9E, 0x8E, 0xD3, 0xC2, 0x3E, 0x94, 0x66, 0x9B, 0x3A, 0xC2, 0x9D, 0xB7, 0xAD, 0x61, 0xD6, 0xAB, 0x32, 0x46, 0x6A, 0xC5, 0x27, 0x9E, 0xA7]
+			},
+			TestCase {
+				key: arr![u8; 0x26, 0xC3, 0xF5, 0x7F, 0x3A, 0x61, 0x0E, 0xE0, 0x92, 0x44, 0x58, 0xFB, 0xDE, 0x7A, 0x65, 0xED, 0xE8, 0x37, 0xED, 0x07, 0x1E, 0xFA, 0x05, 0x55],
+				tweak: vec![0xF9, 0x9B, 0x9A, 0x2A, 0x5B, 0x65, 0x12, 0x43, 0x1D, 0xE1, 0x7E, 0xD1, 0x98, 0xBB, 0x13, 0x72, 0xEA, 0x08, 0x94, 0xCA, 0x36, 0x8A, 0x98, 0xA7, 0x42, 0xB2, 0x5D, 0x25, 0x59, 0x9D, 0xAF, 0x88],
+				ptxt: vec![0x89, 0x33, 0x53, 0x94, 0x68, 0x68, 0xA2, 0x0F, 0xDE, 0x51, 0xA3, 0x67, 0xC7, 0x86, 0xA2, 0x60, 0x57, 0xC5, 0x0C, 0xDD, 0x42, 0x2B, 0xA1, 0xC0, 0x1E, 0x80, 0x46, 0xE8, 0xFF, 0xA2, 0xDB, 0x28, 0x46, 0x41, 0xB0, 0x67, 0x8E, 0xFD, 0xE3, 0xFC, 0xA4, 0x67, 0x4C, 0xD0, 0x45, 0x2A, 0xB0, 0x6B, 0xE4, 0x74, 0xF5, 0xEE, 0x11, 0x37, 0x3D, 0xF5, 0xE1, 0x3C, 0x94, 0x56, 0x13, 0x23, 0xAE, 0xB1, 0x14, 0xA9, 0xE7, 0xEE, 0xA4, 0x1D, 0xB2, 0x7E, 0xD9, 0x89, 0x46, 0xB0, 0x1D, 0x22, 0xB9, 0xA9, 0xB3, 0x90, 0x51, 0x6E, 0xAD, 0x6F, 0x85, 0xE0, 0x77, 0x77, 0x82, 0xFD, 0xA4, 0xC7, 0x00, 0x5E, 0x0B, 0xC3, 0x2B, 0x94, 0x19, 0x12, 0xE0, 0xDF, 0x50, 0xE6, 0x11, 0xD6, 0x37, 0xBB, 0xF8, 0xB8, 0x52, 0x53, 0x64, 0x57, 0xA6, 0x9C, 0x33, 0x77, 0x95, 0xFA, 0xA2, 0x04, 0x47, 0x87, 0x22, 0x63, 0xEC, 0xB9, 0xD8, 0x95, 0x4B, 0x1F, 0x7C, 0x06, 0xFA, 0x48, 0xE9, 0x62, 0x35, 0x62, 0x8C, 0x70, 0xE2, 0xE6, 0x8C, 0xD8, 0x50],
+				ctxt: vec![0xE9, 0x07, 0x22, 0x3E, 0x69, 0x06, 0x5E, 0xBE, 0xDC, 0xC9, 0x90, 0xAB, 0x92, 0xD3, 0x43, 0xEE, 0x11, 0xB9, 0x19, 0x15, 0xF0, 0xFB, 0xD3, 0x7A, 0xAF, 0xBC, 0x49, 0x6F, 0xC3, 0x87, 0x44, 0xA7, 0xC9, 0x34, 0xCE, 0xD9, 0x6C, 0x0C, 0xD0, 0x6B, 0xA7, 0xA4, 0x9E, 0x09, 0xFA, 0xDB, 0xE7, 0x3D, 0xF1, 0x9D, 0x20, 0xA7, 0x01, 0x4B, 0xE3, 0x2F, 0xBD, 0x0F, 0x06, 0x14, 0x54, 0x35, 0xAE, 0x73, 0x51, 0xDF, 0xC4, 0xB1, 0xE8, 0x6C, 0x9C, 0x7E, 0xCA, 0x8A, 0xA9, 0xCF, 0xC6, 0x79, 0x1E, 0xCA, 0xB2, 0xB6, 0xBB, 0x82, 0x94, 0x8C, 0x0C, 0xE9, 0xC8, 0x46, 0x9F, 0xD0, 0x14, 0x5A, 0x04, 0xDC, 0x22, 0x98, 0x31, 0xB8, 0x28, 0xAC, 0xDD, 0xB6, 0x74, 0xC0, 0xB6, 0xC2, 0xCC, 0x5E, 0x0B, 0x17, 0x3A, 0xC6, 0xB9, 0x01, 0xE9, 0x65, 0xE6, 0xB9, 0x74, 0xF0, 0xDE, 0x04, 0xCE, 0x74, 0xD6, 0x48, 0xFD, 0x91, 0x7C, 0x71, 0x83, 0x23, 0xB0, 0x6D, 0x54, 0x87, 0xFC, 0xA9, 0xE5, 0xBF, 0xAD, 0x28, 0x56, 0x17, 0x0A, 0xA8, 0xDA]
+			},
+			TestCase {
+				key: arr![u8; 0xCC, 0x72, 0x54, 0x1E, 0x38, 0xB3, 0xAB, 0x12, 0x0D, 0xBE, 0x01, 0x72, 0x87, 0x88, 0x15, 0xE8, 0x07, 0x18, 0x13, 0xD2, 0x1B, 0x32, 0x5F, 0x92],
+				tweak: vec![0x70, 0x88, 0x40, 0xEE, 0x31, 0x2F, 0x8E, 0x5C, 0xDC, 0x8D, 0x0D, 0x2C, 0xAE, 0x20, 0x37, 0xF9, 0x31, 0xFE, 0x6F, 0xA4, 0x50, 0x6D, 0xD8, 0x36, 0x4E, 0x01, 0xC4, 0xBE, 0x65, 0xF8, 0x7C, 0x4C, 0x80, 0x52, 0x44, 0x5C, 0x14, 0xD7, 0x99, 0xD2],
+				ptxt: vec![0x75, 0x55, 0xF1, 0x77, 0xCF, 0x3E, 0x9C, 0x0F, 0x23, 0xF3, 0x14, 0x85, 0xCB, 0x05, 0xA6, 0x22, 0x97, 0x1C, 0x17, 0x48, 0xFF, 0xCB, 0x8A, 0x3A, 0x94, 0x8E, 0x13, 0xD8, 0xDF, 0x06, 0xE2, 0x2D, 0xAE, 0x6A, 0x0E, 0x50, 0x6B, 0x5C, 0xF7, 0xC2, 0x9C, 0x71, 0xCB, 0x6C, 0xCB, 0x58, 0xD6, 0xC4, 0x56, 0x45, 0x44, 0xB8, 0xB3, 0x99, 0x05, 0xA3, 0xE3, 0x32, 0x5A, 0xF0, 0x6B, 0x0E, 0xBA, 0x4D, 0xF8, 0x70, 0x57, 0xBE, 0xAA, 0x9F, 0x32, 0x28, 0xC4, 0x0E, 0x5A, 0x2D, 0xBC, 0x53, 0x1F, 0x5B, 0x2F, 0xA5, 0x78, 0xF1, 0xEB, 0x1E, 0xA6, 0xD7, 0x97, 0x72, 0xCB, 0x04, 0x95, 0xBD, 0xB1, 0x67, 0xE0, 0x79, 0x01, 0x5D, 0x6A, 0x9C, 0xEE, 0x69, 0xC1, 0x14, 0xED, 0xF8, 0xB0, 0xCC, 0x87, 0x1C, 0x73, 0x30, 0xF7, 0x56, 0x3C, 0x1B, 0x97, 0x94, 0x02, 0x0F, 0xDA, 0x1D, 0xEE, 0x42, 0xAF, 0xE4, 0xF8, 0x86, 0x3B, 0x37, 0xDA, 0x8F, 0x4D, 0x09, 0x8A, 0x9F, 0xBA, 0x8E, 0x9F, 0xB2, 0xE5, 0xAC, 0x8D, 0x17, 0x2F, 0xDF, 0xCB, 0x43, 0xDE, 0xA6, 0x34, 0xFD, 0xEA, 0x8D, 0x0E, 0x1C, 0xCF, 0x97, 0x65, 0xBC, 0x29, 0x94, 0xB4, 0x37, 0x61, 0x87, 0x11, 0xF8, 0xEC, 0x8A, 0xE1, 0xB8, 0x70, 0x78, 0xB4, 0x6B, 0xD9, 0xB3, 0x7C, 0x14, 0x53, 0xE3, 0xBE, 0x3B, 0x32, 0x21, 0x41, 0x9F, 0x42, 0xAB, 0x5A, 0x6B, 0x22, 0x78, 0xA0, 0x6F, 0xE8, 0xC1, 0xD6, 0xE7, 0x1D, 0x77, 0x94, 0xCF, 0x13, 0x26, 0x0C, 0x82, 0x8E, 0xED, 0x7C, 0x56, 0xEC, 0x09, 0x67, 0x54, 0xF1, 0x2A, 0x70, 0x6F, 0x46, 0x6D, 0x60, 0xD2, 0xEA, 0xD6, 0x25, 0xCC, 0xEC, 0x84, 0xEE, 0xEB, 0xF1, 0xB7, 0xA9, 0x5D, 0x1D, 0x3F, 0x03, 0x23, 0xCE, 0xB5, 0x4C, 0x8A, 0x70, 0xED, 0x64, 0x3C, 0x72, 0x30, 0x8C, 0xE4, 0x90, 0x03, 0x2E, 0xA6, 0x99, 0x82, 0x53, 0xF6, 0x6B, 0x43, 0xA3, 0x0A, 0x09, 0x2C, 0x26, 0x17, 0x51, 0x79],
+				ctxt: vec![0x58, 0x99, 0x8F, 0x8A, 0x93, 0x98, 0x32, 0x37, 0x94, 0x7A, 0x8D, 0x57, 0x2B, 0x30, 0x42, 0x62, 0xBE, 0xF2, 0x8E, 0x7B, 0xAD, 0xC4, 0xED, 0xA2, 0x9E, 0x34, 0x5A, 0xAF, 0xAC, 0xAD, 0x17, 0x05, 0xF4, 0xF5, 0x1F, 0x2C, 0x70, 0x71, 0xB4, 0x09, 0x47, 0xA9, 0xDA, 0x64, 0x9D, 0x5C, 0xAC, 0x22, 0x03, 0xE8, 0x44, 0x92, 0x14, 0x29, 0x0F, 0xC0, 0x66, 0xD4, 0xE6, 0x5F, 0xF6, 0x2B, 0xDD, 0x2B, 0xF6, 0x4E, 0x1D, 0x9B, 0x62, 0x59, 0x95, 0xE8, 0xE6, 0x8C, 0x77, 0xF5, 0x5F, 0xF9, 0x3A, 0x33, 0xDF, 0xF0, 0x1E, 0x3E, 0x62, 0xA0, 0x07, 0xE7, 0x31, 0x23, 0x2A, 0xBF, 0x86, 0x16, 0xB5, 0x66, 0x73, 0x6E, 0xC3, 0x19, 0xD5, 0x11, 0x10, 0xC9, 0x9C, 0x22, 0x8A, 0xD3, 0x75, 0x56, 0xCD, 0x55, 0x70, 0x23, 0xE5, 0x37, 0x81, 0xC0, 0x6F, 0xEB, 0x07, 0x33, 0x03, 0x33, 0x92, 0xA1, 0xDF, 0x1B, 0x3E, 0x4E, 0xE5, 0x41, 0xFE, 0x73, 0xB1, 0xF2, 0x87, 0xA1, 0x57, 0x59, 0xE4, 0x88, 0x00, 0xB2, 0x37, 0xF4, 0xA7, 0x50, 0x43, 0x94, 0x94, 0x2C, 0xEE, 0xAB, 0x71, 0x86, 0xAF, 0x5E, 0xF0, 0xBB, 0xAE, 0x06, 0xAE, 0x95, 0xB4, 0x28, 0x1B, 0xD6, 0x73, 0x94, 0xF6, 0x2F, 0xF1, 0xAF, 0x60, 0x56, 0x09, 0x5B, 0x4E, 0xBC, 0x95, 0x99, 0x35, 0xA8, 0x2D, 0x16, 0x26, 0xF4, 0x47, 0x67, 0xF5, 0xEE, 0x72, 0x46, 0x88, 0x00, 0x19, 0x4C, 0x4B, 0xC9, 0x81, 0xEB, 0x38, 0xF6, 0x68, 0xDA, 0xD1, 0x5C, 0x3E, 0x0D, 0xB9, 0x93, 0x9F, 0x8E, 0xC9, 0x52, 0x5A, 0xBB, 0x17, 0x2C, 0x3E, 0x36, 0x75, 0x5D, 0xC9, 0x88, 0xAE, 0xC2, 0x74, 0xBB, 0x8A, 0x04, 0xF0, 0xC6, 0x20, 0x70, 0x51, 0x96, 0x01, 0x7D, 0x44, 0x58, 0x09, 0x40, 0x08, 0xEF, 0xE4, 0x69, 0xFC, 0xBB, 0x3C, 0x5E, 0xF2, 0x20, 0x22, 0x0B, 0x5B, 0x56, 0x24, 0xC6, 0xCB, 0xBD, 0x03, 0xB3, 0x41, 0x36, 0xC7, 0xD1, 0x22, 0xE7, 0x7F, 0x33]
+			}
+		];
+
+		for TestCase { key, tweak, ptxt, ctxt } in test_cases {
+			let lea192hctr2 = Lea192Hctr2::new(&key);
+
+			// Encryption
+			let mut buffer = ptxt.clone();
+			lea192hctr2.encrypt(&tweak, &mut buffer)?;
+			assert_eq!(buffer, ctxt);
+
+			// Decryption
+			let mut buffer = ctxt.clone();
+			lea192hctr2.decrypt(&tweak, &mut buffer)?;
+			assert_eq!(buffer, ptxt);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea256hctr2() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea256>; 6] = [
+			TestCase {
+				key: arr![u8; 0x44, 0x78, 0x44, 0x0C, 0xF8, 0x29, 0xBF, 0xD2, 0x6C, 0xFF, 0x4F, 0x05, 0x85, 0x3E, 0x5B, 0x1F, 0x58, 0x42, 0x43, 0x0D, 0x81, 0x14, 0x4A, 0x80, 0x8B, 0x82, 0x8E, 0x42, 0x39, 0x13, 0x64, 0x4D],
+				tweak: vec![],
+				ptxt: vec![0x4F, 0x5D, 0xCE, 0xD8, 0x01, 0x40, 0xA9, 0x0A, 0x9F, 0xBA, 0x49, 0xCC, 0x9B, 0x24, 0xCD, 0xA7],
+				ctxt: vec![0xB2, 0x11, 0x98, 0x3F, 0x6A, 0x10, 0xF5, 0xDD, 0x57, 0x04, 0x49, 0xD6, 0x06, 0x20, 0x92, 0xB9]
+			},
+			TestCase {
+				key: arr![u8; 0xFE, 0x96, 0x14, 0x73, 0xFD, 0x26, 0x49, 0xA1, 0xD3, 0xA2, 0x40, 0xFE, 0x5F, 0xCB, 0x1C, 0x87, 0xB4, 0xFB, 0xFE, 0x48, 0x24, 0xB6, 0x30, 0x10, 0x9B, 0xEE, 0xA6, 0xDD, 0x91, 0x88, 0xBB, 0x99],
+				tweak: vec![0x33],
+				ptxt: vec![0x62, 0xB3, 0x1D, 0x8D, 0x08, 0xEB, 0x87, 0x6C, 0xCF, 0xEE, 0x45, 0x26, 0xD6, 0x9A, 0xDE, 0x0D, 0x41],
+				ctxt: vec![0x9D, 0x00, 0x64, 0x69, 0x55, 0x88, 0xBB, 0x2C, 0xDE, 0x17, 0xA0, 0x32, 0x1A, 0xCD, 0xAB, 0xD7, 0x38]
+			},
+			TestCase {
+				key: arr![u8; 0x19, 0xFC, 0xCC, 0x29, 0xF0, 0xAA, 0x1C, 0x6D, 0x6B, 0xD7, 0x77, 0xE1, 0xFB, 0xE6, 0xA7, 0x80, 0x67, 0xBD, 0x37, 0x2C, 0x40, 0x93, 0xA5, 0xD3, 0x09, 0xA8, 0x85, 0xEF, 0x85, 0x08, 0x60, 0x9B],
+				tweak: vec![0xC8, 0xB8, 0xEF, 0x59, 0x3A, 0x82, 0xA1, 0x1C, 0x3B, 0x19, 0x43, 0x27, 0xD0, 0xC7, 0xF8, 0x03],
+				ptxt: vec![0xBB, 0xAF, 0xF0, 0x1F, 0x5D, 0x6C, 0x4D, 0xBE, 0xD6, 0x7B, 0x12, 0x33, 0x5B, 0x5C, 0xA9, 0x7B, 0x3C, 0x55, 0xD2, 0x5A, 0x31, 0x7F, 0x96, 0x11, 0x60, 0xDF, 0xFF, 0x34, 0x39, 0x6B, 0xA2, 0xF1],
+				ctxt: vec![0x3B, 0xF8, 0xD1, 0x03, 0x60, 0x22, 0xC1, 0x42, 0xB8, 0x09, 0xF2, 0x77, 0x72, 0x8C, 0x67, 0x90, 0xCA, 0x56, 0x49, 0xC8, 0xB8, 0x2A, 0x8C, 0x7F, 0xFB, 0xD1, 0x32, 0x80, 0x73, 0x8B, 0xB8, 0x21]
+			},
+			TestCase {
+				key: arr![u8; 0x4D, 0x8C, 0xA4, 0xB8, 0xAD, 0x8A, 0xA0, 0x07, 0x77, 0xEB, 0x55, 0x17, 0x27, 0x09, 0x37, 0x84, 0x86, 0x48, 0xF6, 0x56, 0x8A, 0x9B, 0xB5, 0x12, 0xBC, 0xEA, 0x65, 0xF5, 0xD4, 0x3D, 0xBC, 0x53],
+				tweak: vec![0x71, 0xD6, 0x84, 0x0F, 0x1B, 0x97, 0xFC],
+				ptxt: vec![0xE0, 0x9E, 0xA6, 0xB3, 0xD7, 0x2F, 0x51, 0x7F, 0x83, 0xD5, 0x4B, 0xD3, 0xCA, 0x17, 0x39, 0x64, 0x11, 0xB7, 0x9E, 0xCD, 0x79, 0x43, 0x88, 0x79, 0x33, 0x75, 0x47, 0x5D, 0xDF, 0xF1, 0x5A, 0xDA, 0x8F, 0x24, 0x5D, 0xEE, 0x85, 0x40, 0x6B, 0xE4, 0xD7, 0x01, 0xF3, 0x1B, 0xAA, 0x24, 0xE3],
+				ctxt: vec![0xFC, 0xEB, 0x4C, 0xAA, 0xD8, 0x02, 0x60, 0xD7, 0x6D, 0xE0, 0x39, 0x95, 0xC8, 0x77, 0xF8, 0x93, 0x38, 0x5A, 0x57, 0x4A, 0x53, 0x93, 0xC7, 0x96, 0x01, 0x70, 0xDE, 0x20, 0x71, 0x5F, 0xF5, 0x7E, 0x7C, 0x30, 0x38, 0x5A, 0x7A, 0x8B, 0x75, 0x02, 0x79, 0xA7, 0x44, 0x85, 0xC9, 0xD5, 0x75]
+			},
+			TestCase {
+				key: arr![u8; 0x2C, 0xBE, 0x02, 0x31, 0x36, 0x05, 0x5C, 0x38, 0x0F, 0xDB, 0xA2, 0x49, 0x4D, 0xB7, 0xF1, 0x96, 0x13, 0x47, 0x4B, 0x5C, 0x5A, 0x9C, 0x2B, 0x0F, 0xC7, 0xA2, 0x0B, 0x5D, 0xB4, 0xC5, 0xB4, 0xE9],
+				tweak: vec![0x74, 0x94, 0xA5, 0x30, 0xEA, 0xA5, 0x57, 0x25, 0xA8, 0x30, 0x3A, 0x52, 0x03, 0x06, 0x10, 0x50, 0xFD, 0xD8, 0x3B, 0x9D, 0x8C, 0x8F, 0x7F, 0xC3, 0x43, 0x2D, 0x5E, 0x3E, 0x2F, 0x9F, 0xBF, 0xAA],
+				ptxt: vec![0x21, 0xAA, 0xF3, 0x9A, 0x29, 0xE6, 0x58, 0x4D, 0x1E, 0x9B, 0xF3, 0x73, 0xC7, 0xDB, 0x9B, 0x74, 0x81, 0x1A, 0x4F, 0xC3, 0x5F, 0xB4, 0xC9, 0x3D, 0xFD, 0xA5, 0xA3, 0xA9, 0x76, 0x24, 0x5D, 0x34, 0x7C, 0x4B, 0x47, 0xF1, 0xE1, 0xC1, 0x0A, 0x79, 0xF4, 0xEC, 0x88, 0x64, 0x60, 0x7B, 0x67, 0x3F, 0x92, 0xA4, 0x2B, 0x1B, 0xE8, 0x58, 0x39, 0x4B, 0xE9, 0xB1, 0xA7, 0xC0, 0x07, 0x13, 0x6B, 0xBC, 0x8B, 0x18, 0x4C, 0xC4, 0xE7, 0x40, 0x91, 0x3C, 0xAF, 0x1A, 0x1F, 0x08, 0x32, 0x2D, 0x1E, 0xF4, 0xF6, 0x13, 0xC1, 0xC0, 0xD0, 0x4C, 0xDE, 0x0A, 0x6A, 0x0B, 0xCB, 0x10, 0xD4, 0xAC, 0xF1, 0x7D, 0x5B, 0x47, 0x5D, 0x77, 0xFE, 0x9E, 0x15, 0x08, 0x95, 0x0C, 0x1C, 0x46, 0x3E, 0xD9, 0xC5, 0x82, 0x1E, 0x66, 0x74, 0x77, 0x68, 0xBB, 0x6D, 0xD7, 0xC7, 0x5C, 0x16, 0x50, 0x08, 0x62, 0xD2, 0xAD, 0x77, 0x4B, 0x88, 0x07, 0x17, 0xBA, 0x30, 0x7C, 0x17, 0xF1, 0x43, 0x2E, 0x14, 0xB2, 0x2D, 0x42, 0x00, 0xC7, 0x19, 0x5F, 0x7A],
+				ctxt: vec![0xAA, 0x23, 0xD7, 0xA1, 0x95, 0xC2, 0xB2, 0x7E, 0x8B, 0x90, 0x33, 0x10, 0xE6, 0xA0, 0xA3, 0x3F, 0x98, 0xE4, 0x53, 0x69, 0x7F, 0xDC, 0xCE, 0xEA, 0xB3, 0xB7, 0x13, 0x59, 0xC7, 0x49, 0xA1, 0xF6, 0x4A, 0x9F, 0x79, 0xFC, 0x1C, 0x89, 0xEA, 0xEB, 0x63, 0x5A, 0x09, 0x01, 0x81, 0xE2, 0x76, 0x69, 0x8A, 0x4F, 0x7E, 0xDC, 0x9C, 0x1C, 0xB9, 0x3D, 0x32, 0x1C, 0x75, 0x64, 0xD3, 0xA3, 0x45, 0xCE, 0x5E, 0x1A, 0x6D, 0x8B, 0xF3, 0xED, 0xD1, 0x88, 0xFB, 0x4D, 0x96, 0x47, 0x62, 0xB6, 0x4B, 0x22, 0x63, 0xB1, 0x84, 0x05, 0xB3, 0x03, 0x7F, 0x93, 0x4A, 0x01, 0x84, 0x00, 0xBE, 0x04, 0x36, 0x44, 0xED, 0x8E, 0x72, 0x3F, 0x50, 0x5F, 0x9C, 0xE9, 0x58, 0xB4, 0xC7, 0xE6, 0xB6, 0x5A, 0xE3, 0x9A, 0xF8, 0x73, 0xE3, 0x62, 0x9F, 0x1D, 0xE7, 0x67, 0x4A, 0x24, 0x62, 0xCB, 0x3F, 0xA3, 0x0C, 0x2F, 0x67, 0x94, 0x5B, 0x49, 0x8C, 0x7B, 0x74, 0x5C, 0x35, 0x11, 0xB3, 0xE5, 0x69, 0xB8, 0xF2, 0x9E, 0x7A, 0x97, 0xF9, 0xC6, 0x34]
+			},
+			TestCase {
+				key: arr![u8; 0xB7, 0xC1, 0xB5, 0x75, 0xC8, 0x2E, 0xA6, 0xBA, 0xD1, 0xA7, 0x8D, 0xD5, 0x50, 0xF1, 0xCE, 0x35, 0x8B, 0xE5, 0xFF, 0x76, 0xF7, 0x25, 0x98, 0xC3, 0xD7, 0x63, 0x37, 0x87, 0xCC, 0x4A, 0xA7, 0xEC],
+				tweak: vec![0x59, 0x4C, 0x7E, 0x44, 0xC8, 0xFB, 0x77, 0xDD, 0x12, 0x87, 0xE7, 0xCD, 0x53, 0xEC, 0x25, 0xB0, 0x80, 0x22, 0x36, 0x4D, 0x62, 0x78, 0x1B, 0x87, 0x24, 0x11, 0x5F, 0x25, 0x07, 0x9B, 0x53, 0xE5, 0xA1, 0x24, 0xC7, 0x6F, 0x06, 0x09, 0x2B, 0xD4],
+				ptxt: vec![0x08, 0xF4, 0xA6, 0xB7, 0xAB, 0xC8, 0x71, 0x2B, 0xD5, 0x1F, 0x91, 0x47, 0x9D, 0x62, 0xB1, 0xF7, 0x11, 0x8D, 0xD9, 0x39, 0xC1, 0x9B, 0x17, 0x53, 0x20, 0x32, 0xDB, 0x59, 0xC2, 0x3D, 0x5A, 0x42, 0xB2, 0x1E, 0x9E, 0x61, 0x3E, 0x19, 0xB1, 0x4F, 0xC6, 0x2A, 0x0F, 0xF9, 0x6E, 0x85, 0x78, 0x25, 0x47, 0xAE, 0x01, 0x2C, 0xFB, 0xD9, 0xDD, 0xE4, 0x0D, 0x26, 0x3A, 0x96, 0x85, 0xD8, 0x32, 0x08, 0x15, 0xAE, 0xD4, 0xF9, 0x53, 0x5A, 0x32, 0xA4, 0x42, 0x76, 0x15, 0x7C, 0x80, 0x4B, 0xCB, 0x5F, 0xDD, 0x33, 0x42, 0xD7, 0xA3, 0x6D, 0x7D, 0xFB, 0xBD, 0x3C, 0x6A, 0x54, 0x64, 0x92, 0x35, 0x32, 0x6A, 0x36, 0xF0, 0xD9, 0x1E, 0x10, 0xFD, 0x5C, 0xDE, 0x6F, 0x33, 0xF6, 0x63, 0xD3, 0xCE, 0xDC, 0x57, 0x5A, 0x23, 0xE5, 0x4B, 0xF5, 0x75, 0xAA, 0x8C, 0x5F, 0xB3, 0x10, 0x82, 0xDC, 0xEE, 0xA8, 0x38, 0xDC, 0x7E, 0xD1, 0xDC, 0x5A, 0x14, 0x8D, 0x14, 0xEC, 0x56, 0x4E, 0x6A, 0x5A, 0x73, 0xF3, 0x95, 0x44, 0x76, 0xEE, 0x3C, 0xCC, 0xB6, 0xAC, 0xE0, 0x76, 0x5F, 0xE3, 0xE9, 0xE1, 0xCE, 0x0A, 0x7A, 0x23, 0x62, 0xDD, 0x7A, 0x0A, 0x50, 0xDE, 0x19, 0x48, 0x70, 0x9F, 0x15, 0x7E, 0xBE, 0x2C, 0x0A, 0x85, 0xAA, 0xB0, 0x48, 0x2F, 0x6F, 0xDF, 0xBD, 0x5B, 0x9B, 0xAE, 0xF4, 0x17, 0xBC, 0x35, 0x24, 0xFA, 0x51, 0x24, 0x75, 0xCA, 0xDC, 0xE4, 0x8B, 0x93, 0x7C, 0x80, 0xD9, 0xEF, 0xF5, 0x06, 0x5F, 0x8B, 0x95, 0x95, 0x17, 0x44, 0x07, 0x03, 0xE2, 0x95, 0x85, 0xAF, 0x8A, 0xAA, 0xF3, 0x8E, 0x92, 0x0E, 0x60, 0xCB, 0x94, 0x77, 0x2C, 0xA7, 0x29, 0xD5, 0x02, 0xFE, 0xFB, 0xB5, 0xBD, 0x4C, 0x14, 0x49, 0x6E, 0x05, 0xF9, 0x7C, 0xD1, 0xF8, 0x25, 0x07, 0xE1, 0x2B, 0xB5, 0x5F, 0x04, 0xEC, 0xEB, 0xDA, 0xEC, 0x16, 0x3F, 0xAA, 0x08, 0x23, 0x93, 0x97, 0x06, 0x92, 0x09, 0xAC, 0xA3, 0xE8],
+				ctxt: vec![0xB4, 0x33, 0xAF, 0x26, 0x55, 0xAC, 0x08, 0x8B, 0xEB, 0xEF, 0x34, 0x78, 0x2A, 0xB2, 0xAE, 0xE6, 0xA9, 0xA1, 0x2D, 0xB3, 0x14, 0x64, 0x00, 0x48, 0x22, 0xBF, 0xA4, 0x72, 0xE7, 0x47, 0x8E, 0x5E, 0xFC, 0x8B, 0x2E, 0xA1, 0xAF, 0x85, 0x0D, 0xBB, 0x3D, 0x19, 0xD2, 0x5A, 0xB0, 0xEB, 0x1A, 0x14, 0xDD, 0xF1, 0x26, 0x8C, 0xF9, 0x28, 0xAA, 0x9D, 0x59, 0xFE, 0xAA, 0x34, 0x78, 0xAD, 0x92, 0xF2, 0x03, 0x26, 0xCA, 0xBF, 0x5B, 0xE2, 0x56, 0x9F, 0xEE, 0xF4, 0xEE, 0x5C, 0xD3, 0x89, 0xEE, 0xFE, 0xC1, 0xED, 0x3C, 0xFD, 0xD4, 0x31, 0x06, 0x3D, 0x27, 0xB4, 0xB9, 0x67, 0x47, 0xF0, 0xBA, 0x1D, 0x76, 0x54, 0x1C, 0xA4, 0xE6, 0x0D, 0x34, 0xE3, 0x04, 0xFE, 0xFE, 0x6A, 0x15, 0x02, 0x96, 0xC7, 0x20, 0xB7, 0x40, 0x77, 0x88, 0x34, 0x77, 0xAF, 0x95, 0x79, 0xBA, 0x25, 0xA8, 0x53, 0xA1, 0xE5, 0xE6, 0xE4, 0x2E, 0x44, 0xB3, 0x08, 0x6D, 0x0D, 0xA4, 0xAF, 0x22, 0x27, 0xF4, 0x5A, 0xE5, 0x47, 0xFA, 0x4A, 0x6D, 0xAB, 0xB5, 0x3D, 0x77, 0x1C, 0x8B, 0xE4, 0x8E, 0x4F, 0x05, 0x63, 0xA1, 0x23, 0x03, 0x69, 0xA6, 0xEE, 0xEC, 0xAC, 0x65, 0x50, 0x4F, 0x28, 0xF6, 0x26, 0xE5, 0xFB, 0x03, 0x9C, 0xA4, 0x6F, 0xF8, 0x7A, 0x9B, 0xD1, 0x89, 0xE7, 0x7A, 0x56, 0x6F, 0x3C, 0x23, 0xCF, 0x67, 0x92, 0x5D, 0xA0, 0x83, 0x4D, 0x0E, 0xF3, 0x5A, 0xBC, 0xED, 0xC8, 0x08, 0xBF, 0x4E, 0x50, 0xE6, 0xA9, 0x85, 0x54, 0x71, 0x57, 0xB3, 0xB8, 0x43, 0x05, 0x60, 0x63, 0x9A, 0xBC, 0x7D, 0xB5, 0xA3, 0x98, 0x1C, 0x6A, 0xC8, 0x87, 0x8C, 0x94, 0x7D, 0x37, 0x37, 0x97, 0xFD, 0x8E, 0xA4, 0xD8, 0x20, 0xAB, 0x32, 0x7C, 0x5A, 0x47, 0x3D, 0xBB, 0xD3, 0x37, 0x2A, 0x36, 0x6C, 0x6F, 0x02, 0xC3, 0xA6, 0xEA, 0x9F, 0x8B, 0x2C, 0xAD, 0x86, 0x1E, 0xE1, 0xB6, 0xD1, 0x02, 0x33, 0xA8, 0xA5, 0x79, 0x2E, 0x9F]
+			}
+		];
+
+		for TestCase { key, tweak, ptxt, ctxt } in test_cases {
+			let lea256hctr2 = Lea256Hctr2::new(&key);
+
+			// Encryption
+			let mut buffer = ptxt.clone();
+			lea256hctr2.encrypt(&tweak, &mut buffer)?;
+			assert_eq!(buffer, ctxt);
+
+			// Decryption
+			let mut buffer = ctxt.clone();
+			lea256hctr2.decrypt(&tweak, &mut buffer)?;
+			assert_eq!(buffer, ptxt);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128hctr2_wide_block() -> Result<(), Error> {
+		let lea128hctr2 = Lea128Hctr2::new(&Default::default());
+		let ptxt: Vec<u8> = (0..200).collect();
+
+		let mut ctxt = ptxt.clone();
+		lea128hctr2.encrypt(&[], &mut ctxt)?;
+
+		// Changing any byte of the plaintext or the tweak changes every block of the ciphertext.
+		for i in [0, 15, 16, 199] {
+			let mut buffer = ptxt.clone();
+			buffer[i] ^= 1;
+			lea128hctr2.encrypt(&[], &mut buffer)?;
+			assert!(buffer.chunks(16).zip(ctxt.chunks(16)).all(|(x, y)| x != y));
+		}
+
+		let mut buffer = ptxt.clone();
+		lea128hctr2.encrypt(&[0], &mut buffer)?;
+		assert!(buffer.chunks(16).zip(ctxt.chunks(16)).all(|(x, y)| x != y));
+
+		// Too short
+		assert_eq!(lea128hctr2.encrypt(&[], &mut [0; 15]), Err(Error::InvalidLength));
+		assert_eq!(lea128hctr2.decrypt(&[], &mut []), Err(Error::InvalidLength));
+
+		Ok(())
+	}
+}
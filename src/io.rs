@@ -0,0 +1,556 @@
+// Copyright © 2023 남기훈 <gihunnam@proton.me>
+//
+// This file and its content are subject to the terms of the MIT License (the "License").
+// If a copy of the License was not distributed with this file, you can obtain one at https://opensource.org/licenses/MIT.
+
+//! `std::io` adapters for LEA-CTR and LEA-CBC
+//!
+//! [`LeaCtrReader`] and [`LeaCtrWriter`] apply the keystream to the bytes read from or written to the inner stream,
+//! and [`LeaCtrReader`] can seek when the inner reader can.
+//! [`LeaCbcWriter`] encrypts with CBC and PKCS #7 padding, and [`LeaCbcReader`] decrypts and removes the padding.
+//! Partial blocks are carried over between calls, so data can be read and written in pieces of any size.
+//!
+//! CTR and CBC are not authenticated, so the ciphertext should be protected with a MAC,
+//! and a padding error from [`LeaCbcReader`] must not be revealed to an attacker.
+//!
+//! * Example
+//! ```
+//! use std::io::{Cursor, Read, Write};
+//!
+//! use lea::{io::{LeaCbcReader, LeaCbcWriter}, prelude::*, Lea128};
+//!
+//! let key = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+//! let iv = arr![u8; 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F];
+//! let ctxt = [0x6B, 0xFC, 0xB5, 0x54, 0xB2, 0x6F, 0xE0, 0x77, 0xD4, 0x50, 0x27, 0x7A, 0x1D, 0xEF, 0x40, 0x0C];
+//!
+//! // Encryption
+//! let mut writer = LeaCbcWriter::new(Vec::new(), Lea128::new(&key), &iv);
+//! writer.write_all(b"plain")?;
+//! writer.write_all(b"text")?;
+//! assert_eq!(writer.finish()?, ctxt);
+//!
+//! // Decryption
+//! let mut reader = LeaCbcReader::new(Cursor::new(ctxt), Lea128::new(&key), &iv);
+//! let mut ptxt = Vec::new();
+//! reader.read_to_end(&mut ptxt)?;
+//! assert_eq!(ptxt, b"plaintext");
+//! # Ok::<(), std::io::Error>(())
+//! ```
+//!
+//! ```
+//! use std::io::{Cursor, Read, Seek, SeekFrom, Write};
+//!
+//! use lea::{io::{LeaCtrReader, LeaCtrWriter}, prelude::*, Lea128Ctr};
+//!
+//! let key = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+//! let nonce = arr![u8; 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F];
+//!
+//! // Encryption
+//! let mut writer = LeaCtrWriter::new(Vec::new(), Lea128Ctr::new(&key, &nonce));
+//! writer.write_all(b"0123456789")?;
+//! let ctxt = writer.into_inner();
+//!
+//! // Decryption from an offset
+//! let mut reader = LeaCtrReader::new(Cursor::new(ctxt), Lea128Ctr::new(&key, &nonce));
+//! reader.seek(SeekFrom::Start(4))?;
+//! let mut ptxt = String::new();
+//! reader.read_to_string(&mut ptxt)?;
+//! assert_eq!(ptxt, "456789");
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::vec::Vec;
+
+use cipher::generic_array::{typenum::U16, GenericArray};
+use cipher::{BlockCipher, BlockDecrypt, BlockEncrypt, StreamCipher, StreamCipherSeek};
+
+use crate::{Lea128, Lea128Ctr};
+
+type Block = GenericArray<u8, U16>;
+
+/// Largest number of bytes encrypted by one call of [`LeaCtrWriter::write`]
+const CHUNK_SIZE: usize = 4096;
+
+/// Reader that applies the keystream of `C` to the bytes read from `R`
+pub struct LeaCtrReader<R, C = Lea128Ctr> where
+R: Read,
+C: StreamCipher {
+	inner: R,
+	cipher: C
+}
+
+impl<R, C> LeaCtrReader<R, C> where
+R: Read,
+C: StreamCipher {
+	/// The keystream starts at the current position of `inner`.
+	pub fn new(inner: R, cipher: C) -> Self {
+		Self { inner, cipher }
+	}
+
+	pub fn get_ref(&self) -> &R {
+		&self.inner
+	}
+
+	pub fn into_inner(self) -> R {
+		self.inner
+	}
+}
+
+impl<R, C> Read for LeaCtrReader<R, C> where
+R: Read,
+C: StreamCipher {
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		let n = self.inner.read(buf)?;
+		self.cipher.try_apply_keystream(&mut buf[..n]).map_err(|_| keystream_exhausted())?;
+
+		Ok(n)
+	}
+}
+
+impl<R, C> Seek for LeaCtrReader<R, C> where
+R: Read + Seek,
+C: StreamCipher + StreamCipherSeek {
+	/// Seeks the inner reader and moves the keystream by the same distance.
+	/// Positions are those of the inner reader, and seeking before the start of the keystream fails.
+	fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+		let keystream_pos: u64 = self.cipher.try_current_pos().map_err(|_| keystream_exhausted())?;
+		let old_pos = self.inner.stream_position()?;
+		let new_pos = self.inner.seek(pos)?;
+
+		let result = if new_pos >= old_pos {
+			keystream_pos.checked_add(new_pos - old_pos)
+		} else {
+			keystream_pos.checked_sub(old_pos - new_pos)
+		}.ok_or(()).and_then(|keystream_pos| self.cipher.try_seek(keystream_pos).map_err(|_| ()));
+
+		match result {
+			Ok(()) => Ok(new_pos),
+			Err(()) => {
+				self.inner.seek(SeekFrom::Start(old_pos))?;
+
+				Err(io::Error::new(io::ErrorKind::InvalidInput, "seek outside the keystream"))
+			}
+		}
+	}
+}
+
+/// Writer that applies the keystream of `C` to the bytes written to `W`
+///
+/// Nothing is buffered: the keystream is moved back over the bytes that `W` did not accept.
+pub struct LeaCtrWriter<W, C = Lea128Ctr> where
+W: Write,
+C: StreamCipher + StreamCipherSeek {
+	inner: W,
+	cipher: C
+}
+
+impl<W, C> LeaCtrWriter<W, C> where
+W: Write,
+C: StreamCipher + StreamCipherSeek {
+	pub fn new(inner: W, cipher: C) -> Self {
+		Self { inner, cipher }
+	}
+
+	pub fn get_ref(&self) -> &W {
+		&self.inner
+	}
+
+	pub fn into_inner(self) -> W {
+		self.inner
+	}
+}
+
+impl<W, C> Write for LeaCtrWriter<W, C> where
+W: Write,
+C: StreamCipher + StreamCipherSeek {
+	fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+		let mut chunk = [0; CHUNK_SIZE];
+		let n = data.len().min(CHUNK_SIZE);
+		chunk[..n].copy_from_slice(&data[..n]);
+
+		let keystream_pos: u64 = self.cipher.try_current_pos().map_err(|_| keystream_exhausted())?;
+		self.cipher.try_apply_keystream(&mut chunk[..n]).map_err(|_| keystream_exhausted())?;
+
+		let result = self.inner.write(&chunk[..n]);
+		let written = *result.as_ref().unwrap_or(&0);
+		if written < n {
+			self.cipher.seek(keystream_pos + written as u64);
+		}
+
+		result
+	}
+
+	fn flush(&mut self) -> io::Result<()> {
+		self.inner.flush()
+	}
+}
+
+/// Reader that decrypts the CBC ciphertext read from `R` and removes the PKCS #7 padding
+///
+/// The last block is held back until the end of `R`, where its padding is checked.
+/// Reading from `R` one block at a time, [`LeaCbcReader`] is best used with a buffered reader.
+pub struct LeaCbcReader<R, C = Lea128> where
+R: Read,
+C: BlockCipher<BlockSize = U16> + BlockDecrypt {
+	inner: R,
+	cipher: C,
+	/// Previous ciphertext block
+	chain: Block,
+	/// Partial ciphertext block
+	ctxt: Block,
+	ctxt_len: usize,
+	/// Decrypted block which may be the last one
+	held: Option<Block>,
+	/// Plaintext ready to be read
+	ptxt: Block,
+	ptxt_pos: usize,
+	ptxt_len: usize,
+	eof: bool
+}
+
+impl<R, C> LeaCbcReader<R, C> where
+R: Read,
+C: BlockCipher<BlockSize = U16> + BlockDecrypt {
+	pub fn new(inner: R, cipher: C, iv: &Block) -> Self {
+		Self {
+			inner,
+			cipher,
+			chain: *iv,
+			ctxt: Block::default(),
+			ctxt_len: 0,
+			held: None,
+			ptxt: Block::default(),
+			ptxt_pos: 0,
+			ptxt_len: 0,
+			eof: false
+		}
+	}
+
+	pub fn get_ref(&self) -> &R {
+		&self.inner
+	}
+
+	pub fn into_inner(self) -> R {
+		self.inner
+	}
+
+	fn finish_block(&mut self) -> io::Result<()> {
+		if self.ctxt_len != 0 {
+			return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "ciphertext is not a multiple of the block size"));
+		}
+
+		let block = self.held.take().ok_or_else(|| io::Error::new(io::ErrorKind::UnexpectedEof, "empty ciphertext"))?;
+		let pad = block[15];
+		if !(1..=16).contains(&pad) || block[(16 - pad as usize)..].iter().any(|&x| x != pad) {
+			return Err(io::Error::new(io::ErrorKind::InvalidData, "invalid padding"));
+		}
+
+		self.ptxt = block;
+		self.ptxt_pos = 0;
+		self.ptxt_len = 16 - pad as usize;
+		self.eof = true;
+
+		Ok(())
+	}
+}
+
+impl<R, C> Read for LeaCbcReader<R, C> where
+R: Read,
+C: BlockCipher<BlockSize = U16> + BlockDecrypt {
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		while self.ptxt_pos == self.ptxt_len {
+			if self.eof || buf.is_empty() {
+				return Ok(0);
+			}
+
+			let n = match self.inner.read(&mut self.ctxt[self.ctxt_len..]) {
+				Ok(n) => n,
+				Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+				Err(e) => return Err(e)
+			};
+			if n == 0 {
+				self.finish_block()?;
+				continue;
+			}
+
+			self.ctxt_len += n;
+			if self.ctxt_len == 16 {
+				let mut block = self.ctxt;
+				self.cipher.decrypt_block(&mut block);
+				xor(&mut block, &self.chain);
+				self.chain = self.ctxt;
+				self.ctxt_len = 0;
+
+				if let Some(block) = self.held.replace(block) {
+					self.ptxt = block;
+					self.ptxt_pos = 0;
+					self.ptxt_len = 16;
+				}
+			}
+		}
+
+		let n = buf.len().min(self.ptxt_len - self.ptxt_pos);
+		buf[..n].copy_from_slice(&self.ptxt[self.ptxt_pos..(self.ptxt_pos + n)]);
+		self.ptxt_pos += n;
+
+		Ok(n)
+	}
+}
+
+/// Writer that encrypts with CBC and writes the ciphertext to `W`
+///
+/// The ciphertext is complete only after [`LeaCbcWriter::finish`], which pads and writes the last block.
+pub struct LeaCbcWriter<W, C = Lea128> where
+W: Write,
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	inner: W,
+	cipher: C,
+	/// Previous ciphertext block
+	chain: Block,
+	/// Partial plaintext block
+	ptxt: Block,
+	ptxt_len: usize,
+	/// Ciphertext not written yet
+	pending: Vec<u8>
+}
+
+impl<W, C> LeaCbcWriter<W, C> where
+W: Write,
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	pub fn new(inner: W, cipher: C, iv: &Block) -> Self {
+		Self { inner, cipher, chain: *iv, ptxt: Block::default(), ptxt_len: 0, pending: Vec::new() }
+	}
+
+	pub fn get_ref(&self) -> &W {
+		&self.inner
+	}
+
+	/// Pads and encrypts the last block, writes all of the ciphertext, and returns the inner writer.
+	pub fn finish(mut self) -> io::Result<W> {
+		let pad = 16 - self.ptxt_len;
+		self.ptxt[self.ptxt_len..].fill(pad as u8);
+		self.encrypt_block();
+		self.write_pending()?;
+		self.inner.flush()?;
+
+		Ok(self.inner)
+	}
+
+	fn encrypt_block(&mut self) {
+		xor(&mut self.chain, &self.ptxt);
+		self.cipher.encrypt_block(&mut self.chain);
+		self.pending.extend_from_slice(&self.chain);
+		self.ptxt_len = 0;
+	}
+
+	fn write_pending(&mut self) -> io::Result<()> {
+		while !self.pending.is_empty() {
+			match self.inner.write(&self.pending) {
+				Ok(0) => return Err(io::Error::new(io::ErrorKind::WriteZero, "failed to write the ciphertext")),
+				Ok(n) => drop(self.pending.drain(..n)),
+				Err(e) if e.kind() == io::ErrorKind::Interrupted => {},
+				Err(e) => return Err(e)
+			}
+		}
+
+		Ok(())
+	}
+}
+
+impl<W, C> Write for LeaCbcWriter<W, C> where
+W: Write,
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+		self.write_pending()?;
+
+		let data = &data[..data.len().min(CHUNK_SIZE)];
+		for &byte in data {
+			self.ptxt[self.ptxt_len] = byte;
+			self.ptxt_len += 1;
+			if self.ptxt_len == 16 {
+				self.encrypt_block();
+			}
+		}
+
+		// `data` has been consumed, so an error is reported by the next call instead.
+		let _ = self.write_pending();
+
+		Ok(data.len())
+	}
+
+	fn flush(&mut self) -> io::Result<()> {
+		self.write_pending()?;
+		self.inner.flush()
+	}
+}
+
+fn xor(x: &mut Block, y: &Block) {
+	x.iter_mut().zip(y).for_each(|(x, y)| *x ^= y);
+}
+
+fn keystream_exhausted() -> io::Error {
+	io::Error::new(io::ErrorKind::Other, "keystream exhausted")
+}
+
+#[cfg(test)]
+mod tests {
+	use std::io::{self, Cursor, Read, Seek, SeekFrom, Write};
+	use std::{vec::Vec, vec};
+
+	use cipher::{consts::U16, BlockCipher, BlockCipherKey, NewBlockCipher};
+
+	use crate::{io::{LeaCbcReader, LeaCbcWriter, LeaCtrReader, LeaCtrWriter}, prelude::*, Lea128, Lea128Ctr};
+
+	struct TestCase<T> where
+	T: BlockCipher + NewBlockCipher {
+		key: BlockCipherKey<T>,
+		iv: GenericArray<u8, U16>,
+		ptxt: Vec<u8>,
+		ctxt: Vec<u8>
+	}
+
+	/// Writer that accepts at most 5 bytes per call
+	struct ShortWriter(Vec<u8>);
+
+	impl Write for ShortWriter {
+		fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+			let n = data.len().min(5);
+			self.0.extend_from_slice(&data[..n]);
+
+			Ok(n)
+		}
+
+		fn flush(&mut self) -> io::Result<()> {
+			Ok(())
+		}
+	}
+
+	#[test]
+	fn lea128cbc_io() -> io::Result<()> {
+		let test_cases = [
+			TestCase::<Lea128> {
+				key: arr![u8; 0x5A, 0x10, 0x6E, 0x8D, 0x74, 0x92, 0x57, 0x41, 0x83, 0x62, 0x64, 0x9D, 0x0A, 0xCB, 0x6B, 0xA8],
+				iv: arr![u8; 0x06, 0xF5, 0x1A, 0x00, 0x43, 0xF1, 0xF7, 0xCA, 0x7B, 0xB9, 0x8B, 0x02, 0xEA, 0xD0, 0xE6, 0x50],
+				ptxt: vec![],
+				ctxt: vec![0x18, 0x78, 0xC3, 0x92, 0xB4, 0x51, 0x7D, 0x52, 0x0F, 0x4F, 0x76, 0x5D, 0xBF, 0xB6, 0x41, 0x08]
+			},
+			TestCase::<Lea128> {
+				key: arr![u8; 0x91, 0x6D, 0x3A, 0x3C, 0x85, 0x83, 0x5B, 0x71, 0xF5, 0x3D, 0x08, 0x8F, 0x5F, 0xB7, 0xB0, 0x5A],
+				iv: arr![u8; 0x99, 0x57, 0x8E, 0x93, 0xC3, 0x32, 0x3D, 0xC9, 0xD7, 0xBA, 0xEA, 0xEF, 0x09, 0x20, 0x55, 0x19],
+				ptxt: vec![0x5C, 0x94, 0x62, 0x82, 0x8A, 0xD0, 0x40, 0x18, 0xA6, 0xDF, 0xE1, 0x3A, 0xED, 0xB2, 0xD8, 0x55],
+				ctxt: vec![0x1A, 0xCE, 0xD0, 0x65, 0x7E, 0x54, 0xBF, 0xAA, 0x20, 0x08, 0x7F, 0xE3, 0xE1, 0xE6, 0x41, 0x5F, 0xF5, 0xC6, 0x63, 0x5A, 0x79, 0xE1, 0xD0, 0xB9, 0x4A, 0x1B, 0xAA, 0x98, 0xEF, 0x28, 0xDE, 0x09]
+			},
+			TestCase::<Lea128> {
+				key: arr![u8; 0x37, 0x78, 0xBE, 0x1C, 0xC5, 0x6A, 0x9A, 0x5A, 0xAC, 0xDF, 0x49, 0xE1, 0xC5, 0xCF, 0x3D, 0xCF],
+				iv: arr![u8; 0x8E, 0xEC, 0x42, 0x5B, 0x88, 0x90, 0x0A, 0x7B, 0x4F, 0x90, 0xC7, 0x65, 0x42, 0xF1, 0xBA, 0x84],
+				ptxt: vec![0xCC, 0x38, 0x9B, 0x1D, 0xA0, 0xD7, 0x35, 0x61, 0x7C, 0x40, 0xF6, 0x9A, 0xFC, 0xEC, 0x8E, 0x26, 0x71, 0x03, 0xFB, 0x2B, 0x8C, 0xA0, 0xCD, 0xC2, 0x15, 0x13, 0x44, 0x82, 0xD0, 0x9F, 0x8B, 0x79, 0xC8, 0xCD, 0x6F, 0x5D, 0xEB],
+				ctxt: vec![0x7F, 0xF7, 0x28, 0xE3, 0xEA, 0x93, 0x5B, 0x84, 0xD8, 0x58, 0xFA, 0xAF, 0x9F, 0xAC, 0x8D, 0xFF, 0x24, 0x14, 0x61, 0x54, 0x1C, 0x34, 0x51, 0xF6, 0xF6, 0x51, 0x16, 0x78, 0xFF, 0xED, 0x3B, 0x94, 0x09, 0x2C, 0x45, 0x67, 0x7C, 0x5C, 0x0B, 0xD0, 0xFB, 0xD9, 0xDA, 0x00, 0xC3, 0x9E, 0x5B, 0x3D]
+			}
+		];
+
+		for test_case in test_cases.iter() {
+			let mut writer = LeaCbcWriter::new(ShortWriter(Vec::new()), Lea128::new(&test_case.key), &test_case.iv);
+			for chunk in test_case.ptxt.chunks(7) {
+				writer.write_all(chunk)?;
+			}
+			assert_eq!(writer.finish()?.0, test_case.ctxt);
+
+			let mut reader = LeaCbcReader::new(Cursor::new(&test_case.ctxt), Lea128::new(&test_case.key), &test_case.iv);
+			let mut ptxt = Vec::new();
+			let mut buffer = [0; 3];
+			loop {
+				let n = reader.read(&mut buffer)?;
+				if n == 0 {
+					break;
+				}
+				ptxt.extend_from_slice(&buffer[..n]);
+			}
+			assert_eq!(ptxt, test_case.ptxt);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128cbc_io_reject() -> io::Result<()> {
+		let key = GenericArray::default();
+		let iv = GenericArray::default();
+
+		let mut writer = LeaCbcWriter::new(Vec::new(), Lea128::new(&key), &iv);
+		writer.write_all(&[0x42; 20])?;
+		let ctxt = writer.finish()?;
+		assert_eq!(ctxt.len(), 32);
+
+		// Truncation inside a block
+		let mut reader = LeaCbcReader::new(Cursor::new(&ctxt[..31]), Lea128::new(&key), &iv);
+		assert_eq!(reader.read_to_end(&mut Vec::new()).unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+
+		// Empty ciphertext
+		let mut reader = LeaCbcReader::new(Cursor::new(&[]), Lea128::new(&key), &iv);
+		assert_eq!(reader.read_to_end(&mut Vec::new()).unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+
+		// Padding
+		let mut reader = LeaCbcReader::new(Cursor::new(&ctxt[..16]), Lea128::new(&key), &iv);
+		assert_eq!(reader.read_to_end(&mut Vec::new()).unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128ctr_io() -> io::Result<()> {
+		let key = arr![u8; 0x7A, 0xD3, 0x6A, 0x75, 0xD5, 0x5F, 0x30, 0x22, 0x09, 0x4E, 0x06, 0xF7, 0xC8, 0x97, 0xD8, 0xBB];
+		let nonce = arr![u8; 0x0C, 0x5F, 0x04, 0xE8, 0xB5, 0x12, 0x19, 0x5E, 0x74, 0xB3, 0xDE, 0x57, 0xE9, 0x70, 0x97, 0x9E];
+		let ptxt: Vec<u8> = (0..100).collect();
+		let mut ctxt = ptxt.clone();
+		Lea128Ctr::new(&key, &nonce).apply_keystream(&mut ctxt);
+
+		// Writing in pieces to a writer that accepts only some bytes
+		let mut writer = LeaCtrWriter::new(ShortWriter(Vec::new()), Lea128Ctr::new(&key, &nonce));
+		for chunk in ptxt.chunks(7) {
+			writer.write_all(chunk)?;
+		}
+		assert_eq!(writer.into_inner().0, ctxt);
+
+		// Reading after a header, with seeking
+		let mut file = b"header".to_vec();
+		file.extend_from_slice(&ctxt);
+		let mut inner = Cursor::new(file);
+		inner.seek(SeekFrom::Start(6))?;
+		let mut reader = LeaCtrReader::new(inner, Lea128Ctr::new(&key, &nonce));
+
+		let mut buffer = [0; 10];
+		reader.read_exact(&mut buffer)?;
+		assert_eq!(buffer, ptxt[..10]);
+		reader.seek(SeekFrom::Start(6 + 50))?;
+		reader.read_exact(&mut buffer)?;
+		assert_eq!(buffer, ptxt[50..60]);
+		reader.seek(SeekFrom::Current(-30))?;
+		reader.read_exact(&mut buffer)?;
+		assert_eq!(buffer, ptxt[30..40]);
+		reader.seek(SeekFrom::End(-5))?;
+		let mut buffer = Vec::new();
+		reader.read_to_end(&mut buffer)?;
+		assert_eq!(buffer, ptxt[95..]);
+
+		// Before the start of the keystream
+		assert!(reader.seek(SeekFrom::Start(5)).is_err());
+		assert_eq!(reader.get_ref().position(), 106);
+
+		// Writing and reading more than a chunk at once
+		let ptxt: Vec<u8> = (0..=255).cycle().take(10000).collect();
+		let mut ctxt = ptxt.clone();
+		Lea128Ctr::new(&key, &nonce).apply_keystream(&mut ctxt);
+
+		let mut writer = LeaCtrWriter::new(Vec::new(), Lea128Ctr::new(&key, &nonce));
+		writer.write_all(&ptxt)?;
+		assert_eq!(writer.into_inner(), ctxt);
+
+		let mut reader = LeaCtrReader::new(Cursor::new(ctxt), Lea128Ctr::new(&key, &nonce));
+		let mut buffer = Vec::new();
+		reader.read_to_end(&mut buffer)?;
+		assert_eq!(buffer, ptxt);
+
+		Ok(())
+	}
+}
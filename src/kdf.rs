@@ -0,0 +1,297 @@
+// Copyright © 2023 남기훈 <gihunnam@proton.me>
+//
+// This file and its content are subject to the terms of the MIT License (the "License").
+// If a copy of the License was not distributed with this file, you can obtain one at https://opensource.org/licenses/MIT.
+
+//! LEA-CMAC key derivation functions
+//!
+//! KDFs in counter, feedback, and double-pipeline mode as specified in NIST SP 800-108 Rev. 1, with LEA-CMAC as the PRF.
+//! Each PRF input is made of the 32-bit big-endian counter i and the fixed input data Label || 0x00 || Context || \[L\]<sub>32</sub>,
+//! where L is the output length in bits.
+//!
+//! | Mode            | K(i)                                                                 |
+//! | --------------- | -------------------------------------------------------------------- |
+//! | Counter         | PRF(\[i\]<sub>32</sub> \|\| fixed input data)                        |
+//! | Feedback        | PRF(K(i - 1) \|\| \[i\]<sub>32</sub> \|\| fixed input data), K(0) = IV |
+//! | Double-pipeline | PRF(A(i) \|\| \[i\]<sub>32</sub> \|\| fixed input data), A(i) = PRF(A(i - 1)), A(0) = fixed input data |
+//!
+//! Derived keys can be written to a buffer, returned in a buffer zeroized on drop, or turned into a cipher directly.
+//!
+//! * Example
+//! ```
+//! use lea::{kdf::Mode, prelude::*, Lea128, Lea128Kdf};
+//!
+//! let key = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+//! let encryption_key = arr![u8; 0x22, 0xD3, 0x2C, 0x84, 0x79, 0xF5, 0x2B, 0xBF, 0x2F, 0xC8, 0x1C, 0x14, 0xCE, 0xC9, 0xAC, 0xCF];
+//! let authentication_key = [
+//!     0xF7, 0xE0, 0xF5, 0x21, 0xD7, 0x74, 0xFA, 0x07, 0x94, 0x23, 0xEA, 0xE5, 0x25, 0x42, 0xF2, 0xA5,
+//!     0x3B, 0x37, 0xCA, 0x14, 0xC7, 0xCD, 0xA5, 0x17, 0xE9, 0xB1, 0x65, 0x86, 0xCA, 0x58, 0x11, 0xDD
+//! ];
+//!
+//! let lea128kdf = Lea128Kdf::new(&key);
+//!
+//! // Cipher
+//! let lea128: Lea128 = lea128kdf.derive_cipher(Mode::Counter, b"encryption", b"session 1").unwrap();
+//! let mut block = GenericArray::default();
+//! lea128.encrypt_block(&mut block);
+//! let mut expected = GenericArray::default();
+//! Lea128::new(&encryption_key).encrypt_block(&mut expected);
+//! assert_eq!(block, expected);
+//!
+//! // Zeroizing buffer
+//! let buffer = lea128kdf.derive_array::<32>(Mode::Counter, b"authentication", b"session 1").unwrap();
+//! assert_eq!(*buffer, authentication_key);
+//! ```
+
+pub use zeroize;
+
+use core::fmt;
+
+use cipher::{generic_array::{typenum::U16, GenericArray}, BlockCipher, BlockCipherKey, BlockEncrypt, NewBlockCipher};
+use cmac::{Cmac, FromBlockCipher as _, Mac};
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::{Lea128, Lea192, Lea256};
+
+pub type Lea128Kdf = Kdf<Lea128>;
+pub type Lea192Kdf = Kdf<Lea192>;
+pub type Lea256Kdf = Kdf<Lea256>;
+
+type Block = GenericArray<u8, U16>;
+
+/// Maximum number of bytes per derivation, so that L in bits fits in 32 bits
+pub const MAX_OUTPUT_LEN: usize = (u32::MAX / 8) as usize;
+
+/// Error of KDF
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Error {
+	/// Output is empty or longer than [`MAX_OUTPUT_LEN`].
+	InvalidLength
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::InvalidLength => "invalid length"
+		})
+	}
+}
+
+/// Mode of iteration
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Mode<'a> {
+	/// Counter mode
+	Counter,
+	/// Feedback mode with the IV, which may be empty
+	Feedback(&'a [u8]),
+	/// Double-pipeline mode
+	DoublePipeline
+}
+
+/// KDF with CMAC as the PRF
+pub struct Kdf<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	/// Cipher keyed with K<sub>IN</sub>
+	cipher: C
+}
+
+impl<C> Kdf<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher {
+	pub fn new(key: &BlockCipherKey<C>) -> Self {
+		Self::from(C::new(key))
+	}
+}
+
+impl<C> From<C> for Kdf<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	fn from(cipher: C) -> Self {
+		Self { cipher }
+	}
+}
+
+impl<C> Kdf<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	/// Derives `out.len()` bytes of keying material into `out`.
+	pub fn derive(&self, mode: Mode<'_>, label: &[u8], context: &[u8], out: &mut [u8]) -> Result<(), Error> {
+		if out.is_empty() || out.len() > MAX_OUTPUT_LEN {
+			return Err(Error::InvalidLength);
+		}
+
+		let l = (out.len() as u32 * 8).to_be_bytes();
+		let fixed_input_data: [&[u8]; 4] = [label, &[0], context, &l];
+
+		let mac = Cmac::from_cipher(&self.cipher);
+		let mut k = Block::default();
+		let mut a = Block::default();
+
+		for (i, out) in (1u32..).zip(out.chunks_mut(16)) {
+			let chaining_value: &[u8] = match mode {
+				Mode::Counter => &[],
+				Mode::Feedback(iv) => if i == 1 { iv } else { &k },
+				Mode::DoublePipeline => {
+					a = if i == 1 { prf(&mac, &fixed_input_data) } else { prf(&mac, &[&a]) };
+
+					&a
+				}
+			};
+
+			let i = i.to_be_bytes();
+			let [label, separator, context, l] = fixed_input_data;
+			k = prf(&mac, &[chaining_value, &i, label, separator, context, l]);
+			out.copy_from_slice(&k[..out.len()]);
+		}
+
+		k.zeroize();
+		a.zeroize();
+
+		Ok(())
+	}
+
+	/// Derives `N` bytes of keying material into a buffer zeroized on drop.
+	pub fn derive_array<const N: usize>(&self, mode: Mode<'_>, label: &[u8], context: &[u8]) -> Result<Zeroizing<[u8; N]>, Error> {
+		let mut out = Zeroizing::new([0; N]);
+		self.derive(mode, label, context, out.as_mut())?;
+
+		Ok(out)
+	}
+
+	/// Derives a key of `D` and returns `D` keyed with it, e.g. [`Lea128`] or [`Lea256`].
+	pub fn derive_cipher<D>(&self, mode: Mode<'_>, label: &[u8], context: &[u8]) -> Result<D, Error> where
+	D: NewBlockCipher {
+		let mut key = BlockCipherKey::<D>::default();
+		let result = self.derive(mode, label, context, &mut key).map(|()| D::new(&key));
+		key.zeroize();
+
+		result
+	}
+}
+
+/// PRF(K<sub>IN</sub>, x) over the concatenation of `input`
+fn prf<C>(mac: &Cmac<&C>, input: &[&[u8]]) -> Block where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	let mut mac = mac.clone();
+	input.iter().for_each(|input| mac.update(input));
+
+	mac.finalize().into_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+	extern crate alloc;
+
+	use alloc::{vec::Vec, vec};
+
+	use cipher::{BlockCipher, BlockCipherKey, NewBlockCipher};
+
+	use crate::{kdf::{Error, Mode}, prelude::*, Lea128, Lea192, Lea256, Lea128Kdf, Lea192Kdf, Lea256Kdf};
+
+	struct TestCase<T> where
+	T: BlockCipher + NewBlockCipher {
+		key: BlockCipherKey<T>,
+		counter: Vec<u8>,
+		feedback: Vec<u8>,
+		double_pipeline: Vec<u8>
+	}
+
+	const LABEL: &[u8] = b"encryption";
+	const CONTEXT: &[u8] = b"session 1";
+	const IV: [u8; 16] = [0xF0, 0xF1, 0xF2, 0xF3, 0xF4, 0xF5, 0xF6, 0xF7, 0xF8, 0xF9, 0xFA, 0xFB, 0xFC, 0xFD, 0xFE, 0xFF];
+
+	#[test]
+	fn lea128kdf() -> Result<(), Error> {
+		let TestCase { key, counter, feedback, double_pipeline } = TestCase::<Lea128> {
+			key: arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F],
+			counter: vec![0xC4, 0x8E, 0xE9, 0xCF, 0x73, 0x6E, 0xCD, 0xA0, 0x2B, 0xC7, 0xC3, 0x79, 0xD7, 0x23, 0x66, 0x16, 0xB0, 0x70, 0xA8, 0x5D, 0x4E, 0xEE, 0x8D, 0xE3, 0x8E, 0xEC, 0x73, 0x7D, 0xA5, 0xE4, 0x14, 0x28, 0x4F, 0x5E, 0xB8, 0xD9, 0x12, 0x39, 0x90, 0xD4],
+			feedback: vec![0x8F, 0x57, 0x6E, 0x42, 0x7A, 0x57, 0x0F, 0xD4, 0x7C, 0x16, 0xA4, 0x03, 0x82, 0x22, 0xF9, 0xB1, 0x1F, 0x11, 0x14, 0x99, 0x51, 0xDF, 0x14, 0x11, 0xEC, 0xE7, 0x72, 0x52, 0x52, 0x18, 0x38, 0x36, 0x8E, 0x87, 0x57, 0xE2, 0xE9, 0x16, 0xB0, 0x0E],
+			double_pipeline: vec![0xF1, 0x36, 0x80, 0xE9, 0x6C, 0xB3, 0x17, 0x20, 0xD0, 0x6C, 0x61, 0x32, 0xF4, 0x2D, 0xAF, 0xF3, 0xBE, 0xDB, 0xD5, 0x10, 0x4C, 0xDC, 0xE5, 0xD8, 0x0C, 0xC2, 0xBF, 0x97, 0x16, 0x36, 0xF9, 0xB7, 0x23, 0xB4, 0xA7, 0x23, 0xA0, 0x7F, 0x69, 0xD0]
+		};
+
+		let lea128kdf = Lea128Kdf::new(&key);
+
+		let mut buffer = [0; 40];
+		lea128kdf.derive(Mode::Counter, LABEL, CONTEXT, &mut buffer)?;
+		assert_eq!(buffer[..], counter);
+		lea128kdf.derive(Mode::Feedback(&IV), LABEL, CONTEXT, &mut buffer)?;
+		assert_eq!(buffer[..], feedback);
+		lea128kdf.derive(Mode::DoublePipeline, LABEL, CONTEXT, &mut buffer)?;
+		assert_eq!(buffer[..], double_pipeline);
+
+		// Feedback mode without the IV starts like counter mode.
+		lea128kdf.derive(Mode::Feedback(&[]), LABEL, CONTEXT, &mut buffer)?;
+		assert_eq!(buffer[..16], counter[..16]);
+		assert_eq!(buffer[16..], [0xFD, 0x27, 0x06, 0xB5, 0xAB, 0x57, 0x51, 0xD4, 0x87, 0x28, 0x9A, 0x26, 0x59, 0x4A, 0xEF, 0x39, 0x1C, 0x11, 0x20, 0x6E, 0xA0, 0x91, 0xA2, 0xFF]);
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea192kdf() -> Result<(), Error> {
+		let TestCase { key, counter, feedback, double_pipeline } = TestCase::<Lea192> {
+			key: arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17],
+			counter: vec![0x0F, 0xA0, 0x3A, 0xC4, 0x9F, 0x15, 0xA9, 0x81, 0xC8, 0x46, 0x16, 0x1C, 0x05, 0xEA, 0xE6, 0xEC, 0x06, 0x87, 0xD6, 0x60, 0x79, 0x06, 0xD3, 0xE9, 0xDE, 0xAC, 0x26, 0x0E, 0xC1, 0xDA, 0x69, 0xD0, 0x60, 0xB9, 0x0B, 0xED, 0x6B, 0x5D, 0x12, 0xB6],
+			feedback: vec![0xD2, 0x24, 0x65, 0x20, 0x30, 0xAD, 0xCB, 0xB8, 0x2A, 0x52, 0xE9, 0x45, 0xA5, 0xAD, 0x0F, 0x38, 0x45, 0x83, 0x89, 0x5E, 0xD7, 0x49, 0x58, 0xCC, 0x2F, 0xF8, 0xF9, 0x01, 0x4E, 0x68, 0xA3, 0x24, 0xE7, 0x98, 0x74, 0x18, 0x19, 0x11, 0x6E, 0x83],
+			double_pipeline: vec![0xDC, 0x81, 0xA4, 0x04, 0x3A, 0xA2, 0x01, 0x13, 0x85, 0x84, 0xB7, 0x03, 0xD3, 0x51, 0xF7, 0x90, 0xBC, 0xA6, 0x3E, 0x59, 0x5B, 0x13, 0xF5, 0xEE, 0x66, 0xE3, 0x9F, 0xC1, 0x4F, 0xC0, 0xAE, 0xAA, 0x88, 0x31, 0xA5, 0x3A, 0xDA, 0xB3, 0x9D, 0xC6]
+		};
+
+		let lea192kdf = Lea192Kdf::new(&key);
+
+		let mut buffer = [0; 40];
+		lea192kdf.derive(Mode::Counter, LABEL, CONTEXT, &mut buffer)?;
+		assert_eq!(buffer[..], counter);
+		lea192kdf.derive(Mode::Feedback(&IV), LABEL, CONTEXT, &mut buffer)?;
+		assert_eq!(buffer[..], feedback);
+		lea192kdf.derive(Mode::DoublePipeline, LABEL, CONTEXT, &mut buffer)?;
+		assert_eq!(buffer[..], double_pipeline);
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea256kdf() -> Result<(), Error> {
+		let TestCase { key, counter, feedback, double_pipeline } = TestCase::<Lea256> {
+			key: arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F],
+			counter: vec![0x3C, 0xCB, 0x48, 0x10, 0x5D, 0xB5, 0x68, 0x01, 0x0B, 0x8E, 0xEB, 0xEB, 0x7B, 0x06, 0xF6, 0xB0, 0x8D, 0x60, 0x34, 0x04, 0x5A, 0x3B, 0x7F, 0x53, 0xB2, 0x3D, 0x16, 0x91, 0x72, 0xC8, 0xE8, 0x8F, 0x49, 0x5B, 0x89, 0x31, 0x53, 0x98, 0xB7, 0x64],
+			feedback: vec![0x29, 0xA2, 0x0E, 0x6B, 0x56, 0xA3, 0x60, 0x77, 0xF7, 0x7E, 0x17, 0x7D, 0x4A, 0x5C, 0x95, 0xE5, 0x85, 0x39, 0xF8, 0xF8, 0x3F, 0x3C, 0xD2, 0x68, 0xEC, 0x36, 0x81, 0x0F, 0xE4, 0x64, 0xAC, 0x45, 0xF4, 0x5D, 0x28, 0x9B, 0x85, 0x99, 0xAE, 0x0C],
+			double_pipeline: vec![0x14, 0x75, 0x6E, 0xD0, 0xF5, 0xB1, 0x89, 0x09, 0xF2, 0xBD, 0x27, 0xDA, 0xC2, 0xA9, 0x07, 0x1C, 0x85, 0x3E, 0x6C, 0xF7, 0x31, 0x96, 0xE2, 0xE4, 0xA9, 0x20, 0xAB, 0x5D, 0xED, 0x17, 0x6F, 0x73, 0x01, 0x78, 0xB0, 0xB9, 0x03, 0x01, 0xA2, 0xA8]
+		};
+
+		let lea256kdf = Lea256Kdf::new(&key);
+
+		let mut buffer = [0; 40];
+		lea256kdf.derive(Mode::Counter, LABEL, CONTEXT, &mut buffer)?;
+		assert_eq!(buffer[..], counter);
+		lea256kdf.derive(Mode::Feedback(&IV), LABEL, CONTEXT, &mut buffer)?;
+		assert_eq!(buffer[..], feedback);
+		lea256kdf.derive(Mode::DoublePipeline, LABEL, CONTEXT, &mut buffer)?;
+		assert_eq!(buffer[..], double_pipeline);
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128kdf_outputs() -> Result<(), Error> {
+		let lea128kdf = Lea128Kdf::new(&Default::default());
+
+		// L is part of the input, so shorter outputs are not prefixes of longer ones.
+		let short = lea128kdf.derive_array::<16>(Mode::Counter, LABEL, CONTEXT)?;
+		let long = lea128kdf.derive_array::<32>(Mode::Counter, LABEL, CONTEXT)?;
+		assert_ne!(short[..], long[..16]);
+
+		let lea256: Lea256 = lea128kdf.derive_cipher(Mode::Counter, LABEL, CONTEXT)?;
+		let mut block = GenericArray::default();
+		lea256.encrypt_block(&mut block);
+		let mut expected = GenericArray::default();
+		Lea256::new(GenericArray::from_slice(&*long)).encrypt_block(&mut expected);
+		assert_eq!(block, expected);
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128kdf_rejection() {
+		let lea128kdf = Lea128Kdf::new(&Default::default());
+
+		assert_eq!(lea128kdf.derive(Mode::Counter, LABEL, CONTEXT, &mut []), Err(Error::InvalidLength));
+		assert_eq!(lea128kdf.derive_array::<0>(Mode::DoublePipeline, LABEL, CONTEXT).map(|_| ()), Err(Error::InvalidLength));
+	}
+}
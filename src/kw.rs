@@ -0,0 +1,539 @@
+// Copyright © 2023 남기훈 <gihunnam@proton.me>
+//
+// This file and its content are subject to the terms of the MIT License (the "License").
+// If a copy of the License was not distributed with this file, you can obtain one at https://opensource.org/licenses/MIT.
+
+//! LEA-KW and LEA-KWP
+//!
+//! Key wrapping with ([`Kw`]) and without ([`Kwp`]) padding, specified in RFC 3394, RFC 5649, and NIST SP 800-38F.
+//! Neither allocates; the output is written to a buffer of exact length.
+//!
+//! * Example
+//! ```
+//! use lea::{prelude::*, Lea128Kw};
+//!
+//! let kek = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+//! let key = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF];
+//! let wrapped_key = [0xE8, 0xFF, 0x73, 0xB9, 0x60, 0xEB, 0x2C, 0x63, 0x30, 0xA2, 0x19, 0x0A, 0x26, 0x9D, 0x12, 0x14, 0xE2, 0x2A, 0x62, 0xE9, 0x96, 0x0F, 0xA6, 0x46];
+//!
+//! let lea128kw = Lea128Kw::new(&kek);
+//!
+//! // Wrapping
+//! let mut buffer = [0; 24];
+//! lea128kw.wrap(&key, &mut buffer).unwrap();
+//! assert_eq!(buffer, wrapped_key);
+//!
+//! // Unwrapping
+//! let mut buffer = [0; 16];
+//! lea128kw.unwrap(&wrapped_key, &mut buffer).unwrap();
+//! assert_eq!(buffer, key);
+//! ```
+
+use core::fmt;
+
+use cipher::{generic_array::{typenum::U16, GenericArray}, BlockCipher, BlockCipherKey, BlockDecrypt, BlockEncrypt, NewBlockCipher};
+use subtle::{ConstantTimeEq, ConstantTimeLess};
+
+use crate::{Lea128, Lea192, Lea256};
+
+pub type Lea128Kw = Kw<Lea128>;
+pub type Lea192Kw = Kw<Lea192>;
+pub type Lea256Kw = Kw<Lea256>;
+
+pub type Lea128Kwp = Kwp<Lea128>;
+pub type Lea192Kwp = Kwp<Lea192>;
+pub type Lea256Kwp = Kwp<Lea256>;
+
+type Block = GenericArray<u8, U16>;
+
+/// Default initial value of KW
+const IV: [u8; 8] = [0xA6; 8];
+/// Prefix of alternative initial value of KWP
+const AIV: [u8; 4] = [0xA6, 0x59, 0x59, 0xA6];
+
+/// Error of key wrapping and unwrapping
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Error {
+	/// Input length is not supported.
+	InvalidInputLength,
+	/// Output buffer does not have the required length.
+	InvalidOutputLength,
+	/// Wrapped key has been tampered with, or the key encryption key is wrong.
+	IntegrityCheckFailed
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::InvalidInputLength => "invalid input length",
+			Self::InvalidOutputLength => "invalid output length",
+			Self::IntegrityCheckFailed => "integrity check failed"
+		})
+	}
+}
+
+/// Key wrapping (KW)
+///
+/// Wraps keys of a multiple of 8 bytes, at least 16 bytes, adding 8 bytes.
+#[derive(Clone)]
+pub struct Kw<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt {
+	cipher: C
+}
+
+impl<C> Kw<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt + NewBlockCipher {
+	pub fn new(kek: &BlockCipherKey<C>) -> Self {
+		Self::from(C::new(kek))
+	}
+}
+
+impl<C> From<C> for Kw<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt {
+	fn from(cipher: C) -> Self {
+		Self { cipher }
+	}
+}
+
+impl<C> Kw<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt {
+	/// Wraps `key` into `out`, which must be 8 bytes longer than `key`.
+	pub fn wrap(&self, key: &[u8], out: &mut [u8]) -> Result<(), Error> {
+		if key.len() < 16 || key.len() % 8 != 0 {
+			return Err(Error::InvalidInputLength);
+		}
+		if out.len() != key.len() + 8 {
+			return Err(Error::InvalidOutputLength);
+		}
+
+		out[..8].copy_from_slice(&IV);
+		out[8..].copy_from_slice(key);
+		wrap(&self.cipher, out);
+
+		Ok(())
+	}
+
+	/// Unwraps `wrapped_key` into `out`, which must be 8 bytes shorter than `wrapped_key`.
+	///
+	/// `out` is zeroed if the integrity check fails.
+	pub fn unwrap(&self, wrapped_key: &[u8], out: &mut [u8]) -> Result<(), Error> {
+		if wrapped_key.len() < 24 || wrapped_key.len() % 8 != 0 {
+			return Err(Error::InvalidInputLength);
+		}
+		if out.len() != wrapped_key.len() - 8 {
+			return Err(Error::InvalidOutputLength);
+		}
+
+		out.copy_from_slice(&wrapped_key[8..]);
+		let a = unwrap(&self.cipher, wrapped_key[..8].try_into().unwrap(), out);
+
+		if a.ct_eq(&IV).into() {
+			Ok(())
+		} else {
+			out.fill(0);
+			Err(Error::IntegrityCheckFailed)
+		}
+	}
+}
+
+/// Key wrapping with padding (KWP)
+///
+/// Wraps keys of 1 to 2<sup>32</sup> - 1 bytes, padding them to a multiple of 8 bytes and adding 8 bytes.
+#[derive(Clone)]
+pub struct Kwp<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt {
+	cipher: C
+}
+
+impl<C> Kwp<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt + NewBlockCipher {
+	pub fn new(kek: &BlockCipherKey<C>) -> Self {
+		Self::from(C::new(kek))
+	}
+}
+
+impl<C> From<C> for Kwp<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt {
+	fn from(cipher: C) -> Self {
+		Self { cipher }
+	}
+}
+
+impl<C> Kwp<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt {
+	/// Length of the wrapped key for a key of `len` bytes
+	pub const fn wrapped_len(len: usize) -> usize {
+		len.div_ceil(8) * 8 + 8
+	}
+
+	/// Wraps `key` into `out`, which must be [`Kwp::wrapped_len`] bytes long.
+	pub fn wrap(&self, key: &[u8], out: &mut [u8]) -> Result<(), Error> {
+		if key.is_empty() || key.len() as u64 > u32::MAX as u64 {
+			return Err(Error::InvalidInputLength);
+		}
+		if out.len() != Self::wrapped_len(key.len()) {
+			return Err(Error::InvalidOutputLength);
+		}
+
+		out[..4].copy_from_slice(&AIV);
+		out[4..8].copy_from_slice(&(key.len() as u32).to_be_bytes());
+		out[8..(8 + key.len())].copy_from_slice(key);
+		out[(8 + key.len())..].fill(0);
+
+		if out.len() == 16 {
+			self.cipher.encrypt_block(Block::from_mut_slice(out));
+		} else {
+			wrap(&self.cipher, out);
+		}
+
+		Ok(())
+	}
+
+	/// Unwraps `wrapped_key` into `out`, which must be 8 bytes shorter than `wrapped_key`.
+	///
+	/// Returns the unwrapped key, without padding, from the beginning of `out`.
+	/// `out` is zeroed if the integrity check fails.
+	pub fn unwrap<'o>(&self, wrapped_key: &[u8], out: &'o mut [u8]) -> Result<&'o [u8], Error> {
+		if wrapped_key.len() < 16 || wrapped_key.len() % 8 != 0 {
+			return Err(Error::InvalidInputLength);
+		}
+		if out.len() != wrapped_key.len() - 8 {
+			return Err(Error::InvalidOutputLength);
+		}
+
+		let a = if wrapped_key.len() == 16 {
+			let mut block = Block::clone_from_slice(wrapped_key);
+			self.cipher.decrypt_block(&mut block);
+			out.copy_from_slice(&block[8..]);
+			block[..8].try_into().unwrap()
+		} else {
+			out.copy_from_slice(&wrapped_key[8..]);
+			unwrap(&self.cipher, wrapped_key[..8].try_into().unwrap(), out)
+		};
+
+		// Length and padding are checked without branching on secret data.
+		let len = u32::from_be_bytes(a[4..].try_into().unwrap()) as usize;
+		let pad_len = out.len().wrapping_sub(len);
+		let mut valid = a[..4].ct_eq(&AIV) & (pad_len as u64).ct_lt(&8);
+		for (i, b) in out.iter().enumerate() {
+			valid &= (i as u64).ct_lt(&(len as u64)) | b.ct_eq(&0);
+		}
+
+		if valid.into() {
+			Ok(&out[..len])
+		} else {
+			out.fill(0);
+			Err(Error::IntegrityCheckFailed)
+		}
+	}
+}
+
+/// Wrapping function W, over `data` whose first 8 bytes are the initial value
+fn wrap<C>(cipher: &C, data: &mut [u8]) where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	let (a, r) = data.split_at_mut(8);
+	let n = r.len() / 8;
+	let mut block = Block::default();
+	block[..8].copy_from_slice(a);
+
+	for j in 0..6 {
+		for (i, r) in r.chunks_exact_mut(8).enumerate() {
+			block[8..].copy_from_slice(r);
+			cipher.encrypt_block(&mut block);
+			let t = (n * j + i + 1) as u64;
+			block[..8].iter_mut().zip(t.to_be_bytes()).for_each(|(b, t)| *b ^= t);
+			r.copy_from_slice(&block[8..]);
+		}
+	}
+
+	a.copy_from_slice(&block[..8]);
+}
+
+/// Unwrapping function W<sup>-1</sup>, over the semiblocks `r` after the first semiblock `a`
+///
+/// Returns the initial value to be checked.
+fn unwrap<C>(cipher: &C, a: [u8; 8], r: &mut [u8]) -> [u8; 8] where
+C: BlockCipher<BlockSize = U16> + BlockDecrypt {
+	let n = r.len() / 8;
+	let mut block = Block::default();
+	block[..8].copy_from_slice(&a);
+
+	for j in (0..6).rev() {
+		for (i, r) in r.chunks_exact_mut(8).enumerate().rev() {
+			let t = (n * j + i + 1) as u64;
+			block[..8].iter_mut().zip(t.to_be_bytes()).for_each(|(b, t)| *b ^= t);
+			block[8..].copy_from_slice(r);
+			cipher.decrypt_block(&mut block);
+			r.copy_from_slice(&block[8..]);
+		}
+	}
+
+	block[..8].try_into().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+	extern crate alloc;
+
+	use alloc::{vec::Vec, vec};
+
+	use crate::{kw::Error, prelude::*, Lea128, Lea128Kw, Lea128Kwp, Lea192, Lea192Kw, Lea192Kwp, Lea256, Lea256Kw, Lea256Kwp};
+
+	struct TestCase<T> where
+	T: NewBlockCipher {
+		kek: GenericArray<u8, T::KeySize>,
+		key: Vec<u8>,
+		wrapped_key: Vec<u8>
+	}
+
+	#[test]
+	fn lea128kw() {
+		let test_cases: [TestCase<Lea128>; 1] = [
+			TestCase {
+				kek: arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F],
+				key: vec![0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF],
+				wrapped_key: vec![0xE8, 0xFF, 0x73, 0xB9, 0x60, 0xEB, 0x2C, 0x63, 0x30, 0xA2, 0x19, 0x0A, 0x26, 0x9D, 0x12, 0x14, 0xE2, 0x2A, 0x62, 0xE9, 0x96, 0x0F, 0xA6, 0x46]
+			}
+		];
+
+		for TestCase { kek, key, wrapped_key } in test_cases {
+			let lea128kw = Lea128Kw::new(&kek);
+
+			// Wrapping
+			let mut buffer = vec![0; key.len() + 8];
+			lea128kw.wrap(&key, &mut buffer).unwrap();
+			assert_eq!(buffer, wrapped_key);
+
+			// Unwrapping
+			let mut buffer = vec![0; key.len()];
+			lea128kw.unwrap(&wrapped_key, &mut buffer).unwrap();
+			assert_eq!(buffer, key);
+		}
+	}
+
+	#[test]
+	fn lea128kwp() {
+		let test_cases: [TestCase<Lea128>; 2] = [
+			TestCase {
+				kek: arr![u8; 0x58, 0x40, 0xDF, 0x6E, 0x29, 0xB0, 0x2A, 0xF1, 0xAB, 0x49, 0x3B, 0x70, 0x5B, 0xF1, 0x6E, 0xA1],
+				key: vec![0xC3, 0x7B, 0x7E, 0x64, 0x92, 0x58, 0x43, 0x40, 0xBE, 0xD1, 0x22, 0x07, 0x80, 0x89, 0x41, 0x15, 0x50, 0x68, 0xF7, 0x38],
+				wrapped_key: vec![0xC2, 0x84, 0x82, 0xB4, 0xA8, 0x53, 0x65, 0x49, 0x04, 0x59, 0x23, 0xF4, 0xF6, 0x09, 0x53, 0x0C, 0xF1, 0xCF, 0x41, 0xA2, 0x71, 0x41, 0x5B, 0xB0, 0x2C, 0x9D, 0xE5, 0xE7, 0x3A, 0x27, 0xAA, 0x0F]
+			},
+			TestCase {
+				kek: arr![u8; 0x58, 0x40, 0xDF, 0x6E, 0x29, 0xB0, 0x2A, 0xF1, 0xAB, 0x49, 0x3B, 0x70, 0x5B, 0xF1, 0x6E, 0xA1],
+				key: vec![0x46, 0x6F, 0x72, 0x50, 0x61, 0x73, 0x69],
+				wrapped_key: vec![0x5E, 0xFA, 0x0C, 0x9B, 0xCC, 0x7F, 0x3C, 0xC1, 0x32, 0xDB, 0x02, 0x6B, 0x87, 0x5F, 0xFF, 0x6E]
+			}
+		];
+
+		for TestCase { kek, key, wrapped_key } in test_cases {
+			let lea128kwp = Lea128Kwp::new(&kek);
+
+			// Wrapping
+			let mut buffer = vec![0; Lea128Kwp::wrapped_len(key.len())];
+			lea128kwp.wrap(&key, &mut buffer).unwrap();
+			assert_eq!(buffer, wrapped_key);
+
+			// Unwrapping
+			let mut buffer = vec![0; wrapped_key.len() - 8];
+			assert_eq!(lea128kwp.unwrap(&wrapped_key, &mut buffer).unwrap(), key);
+		}
+	}
+
+	#[test]
+	fn lea192kw() {
+		let test_cases: [TestCase<Lea192>; 2] = [
+			TestCase {
+				kek: arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17],
+				key: vec![0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF],
+				wrapped_key: vec![0xD4, 0x69, 0xAC, 0x8F, 0xD7, 0xB0, 0x54, 0xE3, 0x49, 0x70, 0xED, 0x65, 0x42, 0x26, 0x53, 0xE2, 0x73, 0x96, 0x3D, 0xA6, 0x19, 0x91, 0x1B, 0x8C]
+			},
+			TestCase {
+				kek: arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17],
+				key: vec![0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07],
+				wrapped_key: vec![0x58, 0x3A, 0x13, 0xA6, 0x8B, 0x91, 0x17, 0xE1, 0x82, 0x4F, 0x44, 0x99, 0x10, 0xDA, 0x12, 0x7C, 0x10, 0xBD, 0x0B, 0xDA, 0x1D, 0x45, 0x4D, 0x89, 0x00, 0xB3, 0xFE, 0x2B, 0x6B, 0x91, 0x48, 0x7D]
+			}
+		];
+
+		for TestCase { kek, key, wrapped_key } in test_cases {
+			let lea192kw = Lea192Kw::new(&kek);
+
+			// Wrapping
+			let mut buffer = vec![0; key.len() + 8];
+			lea192kw.wrap(&key, &mut buffer).unwrap();
+			assert_eq!(buffer, wrapped_key);
+
+			// Unwrapping
+			let mut buffer = vec![0; key.len()];
+			lea192kw.unwrap(&wrapped_key, &mut buffer).unwrap();
+			assert_eq!(buffer, key);
+		}
+	}
+
+	#[test]
+	fn lea192kwp() {
+		let test_cases: [TestCase<Lea192>; 2] = [
+			TestCase {
+				kek: arr![u8; 0x58, 0x40, 0xDF, 0x6E, 0x29, 0xB0, 0x2A, 0xF1, 0xAB, 0x49, 0x3B, 0x70, 0x5B, 0xF1, 0x6E, 0xA1, 0xAE, 0x83, 0x38, 0xF4, 0xDC, 0xC1, 0x76, 0xA8],
+				key: vec![0xC3, 0x7B, 0x7E, 0x64, 0x92, 0x58, 0x43, 0x40, 0xBE, 0xD1, 0x22, 0x07, 0x80, 0x89, 0x41, 0x15, 0x50, 0x68, 0xF7, 0x38],
+				wrapped_key: vec![0xBE, 0x97, 0x14, 0x6C, 0xCD, 0x5A, 0x37, 0x3C, 0x14, 0x16, 0x58, 0xF1, 0x85, 0x77, 0xC2, 0xCE, 0xB6, 0xC5, 0xD9, 0xB8, 0xE7, 0x89, 0x58, 0x7D, 0x7C, 0xF0, 0xBE, 0x17, 0x06, 0x34, 0x2A, 0x3F]
+			},
+			TestCase {
+				kek: arr![u8; 0x58, 0x40, 0xDF, 0x6E, 0x29, 0xB0, 0x2A, 0xF1, 0xAB, 0x49, 0x3B, 0x70, 0x5B, 0xF1, 0x6E, 0xA1, 0xAE, 0x83, 0x38, 0xF4, 0xDC, 0xC1, 0x76, 0xA8],
+				key: vec![0x46, 0x6F, 0x72, 0x50, 0x61, 0x73, 0x69],
+				wrapped_key: vec![0x8D, 0x04, 0xAD, 0xB7, 0x01, 0xE6, 0x14, 0x19, 0xA2, 0xC9, 0xE2, 0x63, 0x8A, 0x32, 0x04, 0x1A]
+			}
+		];
+
+		for TestCase { kek, key, wrapped_key } in test_cases {
+			let lea192kwp = Lea192Kwp::new(&kek);
+
+			// Wrapping
+			let mut buffer = vec![0; Lea192Kwp::wrapped_len(key.len())];
+			lea192kwp.wrap(&key, &mut buffer).unwrap();
+			assert_eq!(buffer, wrapped_key);
+
+			// Unwrapping
+			let mut buffer = vec![0; wrapped_key.len() - 8];
+			assert_eq!(lea192kwp.unwrap(&wrapped_key, &mut buffer).unwrap(), key);
+		}
+	}
+
+	#[test]
+	fn lea256kw() {
+		let test_cases: [TestCase<Lea256>; 3] = [
+			TestCase {
+				kek: arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F],
+				key: vec![0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF],
+				wrapped_key: vec![0xD3, 0xA7, 0xF6, 0x27, 0x26, 0x7E, 0x8D, 0xA4, 0xE1, 0x58, 0xCD, 0x83, 0xE5, 0xB2, 0xE1, 0x16, 0x01, 0xAD, 0x98, 0x5A, 0xEF, 0x16, 0xAB, 0x62]
+			},
+			TestCase {
+				kek: arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F],
+				key: vec![0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07],
+				wrapped_key: vec![0x7B, 0xB5, 0x57, 0x17, 0x1A, 0xAD, 0x88, 0x55, 0xDD, 0xC7, 0xC1, 0x68, 0x2F, 0x98, 0x16, 0xC1, 0x74, 0x2C, 0xE5, 0xA0, 0x4F, 0x87, 0x12, 0x07, 0x64, 0x68, 0xC0, 0xE3, 0x39, 0x36, 0x30, 0x5A]
+			},
+			TestCase {
+				kek: arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F],
+				key: vec![0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F],
+				wrapped_key: vec![0x5B, 0xD8, 0x3C, 0xDD, 0xA3, 0xE8, 0x87, 0x9A, 0x77, 0x59, 0x37, 0x50, 0xBA, 0xC8, 0xDD, 0x9D, 0x19, 0xFF, 0x58, 0x00, 0xC4, 0xD2, 0x80, 0x9B, 0x6F, 0x62, 0xEE, 0x37, 0xEB, 0x12, 0xC4, 0xC3, 0x2F, 0x35, 0x3D, 0x4D, 0x35, 0xD6, 0x56, 0x81]
+			}
+		];
+
+		for TestCase { kek, key, wrapped_key } in test_cases {
+			let lea256kw = Lea256Kw::new(&kek);
+
+			// Wrapping
+			let mut buffer = vec![0; key.len() + 8];
+			lea256kw.wrap(&key, &mut buffer).unwrap();
+			assert_eq!(buffer, wrapped_key);
+
+			// Unwrapping
+			let mut buffer = vec![0; key.len()];
+			lea256kw.unwrap(&wrapped_key, &mut buffer).unwrap();
+			assert_eq!(buffer, key);
+		}
+	}
+
+	#[test]
+	fn lea256kwp() {
+		let test_cases: [TestCase<Lea256>; 2] = [
+			TestCase {
+				kek: arr![u8; 0x58, 0x40, 0xDF, 0x6E, 0x29, 0xB0, 0x2A, 0xF1, 0xAB, 0x49, 0x3B, 0x70, 0x5B, 0xF1, 0x6E, 0xA1, 0xAE, 0x83, 0x38, 0xF4, 0xDC, 0xC1, 0x76, 0xA8, 0x58, 0x40, 0xDF, 0x6E, 0x29, 0xB0, 0x2A, 0xF1],
+				key: vec![0xC3, 0x7B, 0x7E, 0x64, 0x92, 0x58, 0x43, 0x40, 0xBE, 0xD1, 0x22, 0x07, 0x80, 0x89, 0x41, 0x15, 0x50, 0x68, 0xF7, 0x38],
+				wrapped_key: vec![0x00, 0x23, 0x90, 0x44, 0x9A, 0xE7, 0xB7, 0xE7, 0x5B, 0x4D, 0x78, 0xF1, 0x2E, 0x4B, 0xE2, 0x2B, 0x29, 0xAB, 0xC6, 0x13, 0x20, 0xB7, 0x2A, 0xBC, 0xC3, 0xD5, 0x5E, 0x3F, 0x6F, 0xFD, 0x9B, 0xB1]
+			},
+			TestCase {
+				kek: arr![u8; 0x58, 0x40, 0xDF, 0x6E, 0x29, 0xB0, 0x2A, 0xF1, 0xAB, 0x49, 0x3B, 0x70, 0x5B, 0xF1, 0x6E, 0xA1, 0xAE, 0x83, 0x38, 0xF4, 0xDC, 0xC1, 0x76, 0xA8, 0x58, 0x40, 0xDF, 0x6E, 0x29, 0xB0, 0x2A, 0xF1],
+				key: vec![0x46, 0x6F, 0x72, 0x50, 0x61, 0x73, 0x69],
+				wrapped_key: vec![0xA8, 0x1D, 0x72, 0x1F, 0x3E, 0x03, 0x1C, 0x16, 0xDE, 0x4C, 0x52, 0xC4, 0x33, 0x49, 0xC3, 0xEB]
+			}
+		];
+
+		for TestCase { kek, key, wrapped_key } in test_cases {
+			let lea256kwp = Lea256Kwp::new(&kek);
+
+			// Wrapping
+			let mut buffer = vec![0; Lea256Kwp::wrapped_len(key.len())];
+			lea256kwp.wrap(&key, &mut buffer).unwrap();
+			assert_eq!(buffer, wrapped_key);
+
+			// Unwrapping
+			let mut buffer = vec![0; wrapped_key.len() - 8];
+			assert_eq!(lea256kwp.unwrap(&wrapped_key, &mut buffer).unwrap(), key);
+		}
+	}
+
+	#[test]
+	fn lea128kw_rejection() {
+		let lea128kw = Lea128Kw::new(&Default::default());
+		let key = [0x5A; 24];
+		let mut wrapped_key = [0; 32];
+		lea128kw.wrap(&key, &mut wrapped_key).unwrap();
+
+		// Lengths
+		assert_eq!(lea128kw.wrap(&key[..8], &mut [0; 16]), Err(Error::InvalidInputLength));
+		assert_eq!(lea128kw.wrap(&key[..20], &mut [0; 28]), Err(Error::InvalidInputLength));
+		assert_eq!(lea128kw.wrap(&key, &mut [0; 24]), Err(Error::InvalidOutputLength));
+		assert_eq!(lea128kw.unwrap(&wrapped_key[..16], &mut [0; 8]), Err(Error::InvalidInputLength));
+		assert_eq!(lea128kw.unwrap(&wrapped_key, &mut [0; 32]), Err(Error::InvalidOutputLength));
+
+		// Tampered wrapped key
+		for i in 0..wrapped_key.len() {
+			let mut tampered_key = wrapped_key;
+			tampered_key[i] ^= 0x80;
+			let mut buffer = [0xFF; 24];
+			assert_eq!(lea128kw.unwrap(&tampered_key, &mut buffer), Err(Error::IntegrityCheckFailed));
+			assert_eq!(buffer, [0; 24]);
+		}
+
+		// Wrong key encryption key
+		let mut buffer = [0; 24];
+		assert_eq!(Lea128Kw::new(&GenericArray::from([1; 16])).unwrap(&wrapped_key, &mut buffer), Err(Error::IntegrityCheckFailed));
+	}
+
+	#[test]
+	fn lea128kwp_rejection() {
+		let lea128 = Lea128::new(&Default::default());
+		let lea128kwp = Lea128Kwp::from(Lea128::new(&Default::default()));
+
+		// Lengths
+		assert_eq!(lea128kwp.wrap(&[], &mut [0; 8]), Err(Error::InvalidInputLength));
+		assert_eq!(lea128kwp.wrap(&[0; 9], &mut [0; 16]), Err(Error::InvalidOutputLength));
+		assert_eq!(lea128kwp.unwrap(&[0; 8], &mut []), Err(Error::InvalidInputLength));
+		assert_eq!(lea128kwp.unwrap(&[0; 20], &mut [0; 12]), Err(Error::InvalidInputLength));
+		assert_eq!(lea128kwp.unwrap(&[0; 16], &mut [0; 16]), Err(Error::InvalidOutputLength));
+
+		// Tampered wrapped key
+		for len in [1, 8, 9, 20] {
+			let key: Vec<u8> = (1..=len).collect();
+			let mut wrapped_key = vec![0; Lea128Kwp::wrapped_len(key.len())];
+			lea128kwp.wrap(&key, &mut wrapped_key).unwrap();
+
+			for i in 0..wrapped_key.len() {
+				let mut tampered_key = wrapped_key.clone();
+				tampered_key[i] ^= 0x01;
+				let mut buffer = vec![0xFF; wrapped_key.len() - 8];
+				assert_eq!(lea128kwp.unwrap(&tampered_key, &mut buffer), Err(Error::IntegrityCheckFailed));
+				assert!(buffer.iter().all(|&b| b == 0));
+			}
+		}
+
+		// Length or padding inconsistent with the wrapped key, but correctly encrypted
+		for (len, key) in [(0, [0; 8]), (9, [0; 8]), (7, [0, 0, 0, 0, 0, 0, 0, 1])] {
+			let mut block = GenericArray::default();
+			block[..4].copy_from_slice(&[0xA6, 0x59, 0x59, 0xA6]);
+			block[4..8].copy_from_slice(&(len as u32).to_be_bytes());
+			block[8..].copy_from_slice(&key);
+			lea128.encrypt_block(&mut block);
+			assert_eq!(lea128kwp.unwrap(&block, &mut [0; 8]), Err(Error::IntegrityCheckFailed));
+		}
+		for (len, last) in [(8, 0), (12, 1)] {
+			let mut data = [0; 24];
+			data[..4].copy_from_slice(&[0xA6, 0x59, 0x59, 0xA6]);
+			data[4..8].copy_from_slice(&(len as u32).to_be_bytes());
+			data[23] = last;
+			super::wrap(&lea128, &mut data);
+			assert_eq!(lea128kwp.unwrap(&data, &mut [0; 16]), Err(Error::IntegrityCheckFailed));
+		}
+	}
+}
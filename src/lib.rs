@@ -29,29 +29,112 @@
 #![allow(mixed_script_confusables)]
 #![no_std]
 
+#[cfg(feature = "std")]
+extern crate std;
+
 pub mod prelude {
 	pub use crate::cipher::{generic_array::{GenericArray, arr}, BlockCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher};
 
-	#[cfg(feature = "ccm")]
-	pub use crate::ccm::aead::{consts::{U7, U8, U9, U10, U11, U12, U13}, AeadCore, AeadInPlace, NewAead};
+	#[cfg(any(feature = "ccm", feature = "eax", feature = "gcm", feature = "ocb3", feature = "siv"))]
+	pub use aead::{consts::{U0, U4, U6, U7, U8, U9, U10, U11, U12, U13, U14, U16}, AeadCore, AeadInPlace, NewAead};
 
 	#[cfg(feature = "ctr")]
 	pub use crate::ctr::cipher::{NewCipher, StreamCipher, StreamCipherSeek};
+
+	#[cfg(any(feature = "drbg", feature = "rng"))]
+	pub use rand_core::{CryptoRng, RngCore, SeedableRng};
 }
 
+#[cfg(feature = "async")]
+pub mod async_io;
+#[cfg(feature = "cbcmac")]
+pub mod cbcmac;
 #[cfg(feature = "ccm")]
 pub mod ccm;
+#[cfg(feature = "codec")]
+pub mod codec;
+#[cfg(feature = "committing")]
+pub mod committing;
 #[cfg(feature = "ctr")]
 pub mod ctr;
+#[cfg(feature = "drbg")]
+pub mod drbg;
+#[cfg(feature = "eax")]
+pub mod eax;
+#[cfg(feature = "embedded")]
+pub mod embedded;
+#[cfg(feature = "fpe")]
+pub mod fpe;
+#[cfg(feature = "gcm")]
+pub mod gcm;
+#[cfg(feature = "hctr2")]
+pub mod hctr2;
+#[cfg(feature = "io")]
+pub mod io;
+#[cfg(feature = "kdf")]
+pub mod kdf;
+#[cfg(feature = "kw")]
+pub mod kw;
+#[cfg(feature = "ocb3")]
+pub mod ocb3;
+#[cfg(feature = "pmac")]
+pub mod pmac;
+#[cfg(feature = "poly1305")]
+pub mod poly1305;
+#[cfg(feature = "rng")]
+pub mod rng;
+#[cfg(feature = "segmented")]
+pub mod segmented;
+#[cfg(feature = "siv")]
+pub mod siv;
+#[cfg(feature = "stream")]
+pub mod stream;
+#[cfg(feature = "xaead")]
+pub mod xaead;
 
 mod round_key;
 
 pub use cipher;
 
+#[cfg(feature = "cbcmac")]
+pub use crate::cbcmac::{Lea128CbcMac, Lea192CbcMac, Lea256CbcMac, Lea128RetailMac, Lea192RetailMac, Lea256RetailMac};
 #[cfg(feature = "ccm")]
-pub use crate::ccm::{Lea128Ccm, Lea192Ccm, Lea256Ccm};
+pub use crate::ccm::{Lea128Ccm, Lea192Ccm, Lea256Ccm, Lea128CcmStar, Lea192CcmStar, Lea256CcmStar};
+#[cfg(feature = "committing")]
+pub use crate::committing::{Lea128CommittingCcm, Lea192CommittingCcm, Lea256CommittingCcm, Lea128CommittingGcm, Lea192CommittingGcm, Lea256CommittingGcm};
 #[cfg(feature = "ctr")]
 pub use crate::ctr::{Lea128Ctr, Lea192Ctr, Lea256Ctr};
+#[cfg(feature = "drbg")]
+pub use crate::drbg::{Lea128CtrDrbg, Lea192CtrDrbg, Lea256CtrDrbg};
+#[cfg(feature = "eax")]
+pub use crate::eax::{Lea128Eax, Lea192Eax, Lea256Eax};
+#[cfg(feature = "fpe")]
+pub use crate::fpe::{Lea128Ff1, Lea192Ff1, Lea256Ff1, Lea128Ff3_1, Lea192Ff3_1, Lea256Ff3_1};
+#[cfg(feature = "gcm")]
+pub use crate::gcm::{Lea128Gcm, Lea192Gcm, Lea256Gcm, Lea128Gmac, Lea192Gmac, Lea256Gmac};
+#[cfg(feature = "hctr2")]
+pub use crate::hctr2::{Lea128Hctr2, Lea192Hctr2, Lea256Hctr2};
+#[cfg(feature = "kdf")]
+pub use crate::kdf::{Lea128Kdf, Lea192Kdf, Lea256Kdf};
+#[cfg(feature = "kw")]
+pub use crate::kw::{Lea128Kw, Lea192Kw, Lea256Kw, Lea128Kwp, Lea192Kwp, Lea256Kwp};
+#[cfg(feature = "ocb3")]
+pub use crate::ocb3::{Lea128Ocb3, Lea192Ocb3, Lea256Ocb3};
+#[cfg(feature = "pmac")]
+pub use crate::pmac::{Lea128Pmac, Lea192Pmac, Lea256Pmac};
+#[cfg(feature = "poly1305")]
+pub use crate::poly1305::{Lea128Poly1305, Lea192Poly1305, Lea256Poly1305};
+#[cfg(feature = "rng")]
+pub use crate::rng::{Lea128CtrRng, Lea192CtrRng, Lea256CtrRng};
+#[cfg(feature = "siv")]
+pub use crate::siv::{Lea128Siv, Lea192Siv, Lea256Siv, Lea128SivAead, Lea192SivAead, Lea256SivAead};
+#[cfg(feature = "stream")]
+pub use crate::stream::{
+	Lea128CcmEncryptorBE32, Lea192CcmEncryptorBE32, Lea256CcmEncryptorBE32, Lea128CcmDecryptorBE32, Lea192CcmDecryptorBE32, Lea256CcmDecryptorBE32,
+	Lea128GcmEncryptorBE32, Lea192GcmEncryptorBE32, Lea256GcmEncryptorBE32, Lea128GcmDecryptorBE32, Lea192GcmDecryptorBE32, Lea256GcmDecryptorBE32
+};
+#[cfg(feature = "xaead")]
+pub use crate::xaead::{Lea128XCcm, Lea192XCcm, Lea256XCcm, Lea128XGcm, Lea192XGcm, Lea256XGcm};
 
 use core::mem;
 use core::ptr;
@@ -71,6 +154,13 @@ Rk: RoundKey {
 	rk: GenericArray<u32, Rk::RkSize>
 }
 
+impl<Rk> Clone for Lea<Rk> where
+Rk: RoundKey {
+	fn clone(&self) -> Self {
+		Self { rk: self.rk.clone() }
+	}
+}
+
 impl<Rk> BlockCipher for Lea<Rk> where
 Rk: RoundKey {
 	type BlockSize = U16;
@@ -0,0 +1,690 @@
+// Copyright © 2023 남기훈 <gihunnam@proton.me>
+//
+// This file and its content are subject to the terms of the MIT License (the "License").
+// If a copy of the License was not distributed with this file, you can obtain one at https://opensource.org/licenses/MIT.
+
+//! LEA-OCB3
+//!
+//! OCB as specified in RFC 7253, with 64-, 96-, or 128-bit tags and nonces of 1 to 15 bytes.
+//! Blocks are encrypted in batches of [`BlockCipher::ParBlocks`].
+//!
+//! * Example
+//! ```
+//! use lea::{ocb3::aead, prelude::*, Lea128Ocb3};
+//!
+//! let key = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+//! let nonce = arr![u8; 0xBB, 0xAA, 0x99, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x01];
+//! let tag = arr![u8; 0xA4, 0x81, 0x92, 0x3F, 0x9C, 0xF1, 0xED, 0x7A, 0x4A, 0x88, 0x51, 0xFA, 0xAA, 0xC7, 0x12, 0xD8];
+//! let associated_data = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
+//! let ptxt = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
+//! let ctxt = [0x14, 0x85, 0x3F, 0x19, 0x8E, 0x82, 0x27, 0xF9];
+//!
+//! let lea128ocb3 = Lea128Ocb3::<U12>::new(&key);
+//!
+//! // Encryption
+//! let mut buffer = ptxt;
+//! let calculated_tag: Result<_, aead::Error> = lea128ocb3.encrypt_in_place_detached(&nonce, &associated_data, &mut buffer);
+//! assert_eq!(buffer, ctxt);
+//! assert_eq!(calculated_tag.unwrap(), tag);
+//!
+//! // Decryption
+//! let mut buffer = ctxt;
+//! let _: Result<(), aead::Error> = lea128ocb3.decrypt_in_place_detached(&nonce, &associated_data, &mut buffer, &tag);
+//! assert_eq!(buffer, ptxt);
+//! ```
+
+pub use aead;
+
+use core::marker::PhantomData;
+
+use aead::consts::{U0, U12, U16};
+use aead::generic_array::{typenum::Unsigned, ArrayLength, GenericArray};
+use aead::{AeadCore, AeadInPlace, Error, Key, NewAead, Nonce, Tag};
+use cipher::{BlockCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher, ParBlocks};
+use subtle::ConstantTimeEq;
+
+use crate::{Lea128, Lea192, Lea256};
+
+pub type Lea128Ocb3<NonceSize = U12, TagSize = U16> = Ocb3<Lea128, TagSize, NonceSize>;
+pub type Lea192Ocb3<NonceSize = U12, TagSize = U16> = Ocb3<Lea192, TagSize, NonceSize>;
+pub type Lea256Ocb3<NonceSize = U12, TagSize = U16> = Ocb3<Lea256, TagSize, NonceSize>;
+
+type Block = GenericArray<u8, U16>;
+
+/// Number of precomputed L<sub>i</sub>, enough for 2<sup>32</sup> - 1 blocks
+const L_LEN: usize = 32;
+
+/// Valid OCB3 tag sizes, i.e. `U8`, `U12`, `U16`
+pub trait TagSize: private::SealedTagSize {}
+
+impl<T> TagSize for T where
+T: private::SealedTagSize {}
+
+/// Valid OCB3 nonce sizes, i.e. `U1` to `U15`
+pub trait NonceSize: private::SealedNonceSize {}
+
+impl<T> NonceSize for T where
+T: private::SealedNonceSize {}
+
+mod private {
+	use crate::ocb3::aead::consts::{U1, U2, U3, U4, U5, U6, U7, U8, U9, U10, U11, U12, U13, U14, U15, U16};
+
+	pub trait SealedTagSize {}
+
+	impl SealedTagSize for U8 {}
+	impl SealedTagSize for U12 {}
+	impl SealedTagSize for U16 {}
+
+	pub trait SealedNonceSize {}
+
+	impl SealedNonceSize for U1 {}
+	impl SealedNonceSize for U2 {}
+	impl SealedNonceSize for U3 {}
+	impl SealedNonceSize for U4 {}
+	impl SealedNonceSize for U5 {}
+	impl SealedNonceSize for U6 {}
+	impl SealedNonceSize for U7 {}
+	impl SealedNonceSize for U8 {}
+	impl SealedNonceSize for U9 {}
+	impl SealedNonceSize for U10 {}
+	impl SealedNonceSize for U11 {}
+	impl SealedNonceSize for U12 {}
+	impl SealedNonceSize for U13 {}
+	impl SealedNonceSize for U14 {}
+	impl SealedNonceSize for U15 {}
+}
+
+/// OCB3 as specified in RFC 7253
+///
+/// * `M`: Tag size
+/// * `N`: Nonce size
+///
+/// On decryption failure, the buffer is zeroed.
+pub struct Ocb3<C, M, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+M: ArrayLength<u8> + TagSize,
+N: ArrayLength<u8> + NonceSize {
+	cipher: C,
+	l_star: u128,
+	l_dollar: u128,
+	/// L<sub>0</sub>, L<sub>1</sub>, ...
+	l: [u128; L_LEN],
+	_pd: PhantomData<(M, N)>
+}
+
+impl<C, M, N> From<C> for Ocb3<C, M, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+M: ArrayLength<u8> + TagSize,
+N: ArrayLength<u8> + NonceSize {
+	fn from(cipher: C) -> Self {
+		let mut block = Block::default();
+		cipher.encrypt_block(&mut block);
+
+		let l_star = to_u128(&block);
+		let l_dollar = double(l_star);
+		let mut l = [double(l_dollar); L_LEN];
+		for i in 1..L_LEN {
+			l[i] = double(l[i - 1]);
+		}
+
+		Self { cipher, l_star, l_dollar, l, _pd: PhantomData }
+	}
+}
+
+impl<C, M, N> NewAead for Ocb3<C, M, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt + NewBlockCipher,
+M: ArrayLength<u8> + TagSize,
+N: ArrayLength<u8> + NonceSize {
+	type KeySize = C::KeySize;
+
+	fn new(key: &Key<Self>) -> Self {
+		Self::from(C::new(key))
+	}
+}
+
+impl<C, M, N> AeadCore for Ocb3<C, M, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+M: ArrayLength<u8> + TagSize,
+N: ArrayLength<u8> + NonceSize {
+	type NonceSize = N;
+	type TagSize = M;
+	type CiphertextOverhead = U0;
+}
+
+impl<C, M, N> AeadInPlace for Ocb3<C, M, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+M: ArrayLength<u8> + TagSize,
+N: ArrayLength<u8> + NonceSize {
+	fn encrypt_in_place_detached(&self, nonce: &Nonce<Self>, associated_data: &[u8], buffer: &mut [u8]) -> Result<Tag<Self>, Error> {
+		let tag = self.crypt(nonce, associated_data, buffer, false);
+
+		Ok(Tag::<Self>::clone_from_slice(&tag[..M::USIZE]))
+	}
+
+	fn decrypt_in_place_detached(&self, nonce: &Nonce<Self>, associated_data: &[u8], buffer: &mut [u8], tag: &Tag<Self>) -> Result<(), Error> {
+		let calculated_tag = self.crypt(nonce, associated_data, buffer, true);
+
+		if calculated_tag[..M::USIZE].ct_eq(tag).into() {
+			Ok(())
+		} else {
+			buffer.fill(0);
+			Err(Error)
+		}
+	}
+}
+
+impl<C, M, N> Ocb3<C, M, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+M: ArrayLength<u8> + TagSize,
+N: ArrayLength<u8> + NonceSize {
+	/// L<sub>i</sub>
+	fn l(&self, i: u32) -> u128 {
+		match self.l.get(i as usize) {
+			Some(&l) => l,
+			None => (L_LEN as u32..=i).fold(self.l[L_LEN - 1], |l, _| double(l))
+		}
+	}
+
+	/// Offset<sub>0</sub>
+	fn initial_offset(&self, nonce: &Nonce<Self>) -> u128 {
+		let mut block = Block::default();
+		block[0] = (M::U8 * 8 % 128) << 1;
+		block[15 - N::USIZE] |= 1;
+		block[(16 - N::USIZE)..].copy_from_slice(nonce);
+
+		let bottom = (block[15] & 0x3F) as u32;
+		block[15] &= 0xC0;
+		self.cipher.encrypt_block(&mut block);
+
+		let ktop = to_u128(&block);
+		let stretch = (ktop >> 64) as u64 ^ (ktop >> 56) as u64;
+
+		if bottom == 0 {
+			ktop
+		} else {
+			(ktop << bottom) | (stretch >> (64 - bottom)) as u128
+		}
+	}
+
+	/// HASH
+	fn hash(&self, associated_data: &[u8]) -> u128 {
+		let mut sum = 0;
+		let mut offset = 0;
+		let mut i = 0;
+
+		let (full, partial) = associated_data.split_at(associated_data.len() / 16 * 16);
+
+		let mut blocks = ParBlocks::<C>::default();
+		for chunk in full.chunks(16 * C::ParBlocks::USIZE) {
+			let n = chunk.len() / 16;
+			for (block, a) in blocks.iter_mut().zip(chunk.chunks_exact(16)) {
+				i += 1;
+				offset ^= self.l(u64::trailing_zeros(i));
+				*block = to_block(to_u128(a) ^ offset);
+			}
+
+			self.cipher.encrypt_blocks(&mut blocks[..n]);
+			sum = blocks[..n].iter().fold(sum, |sum, block| sum ^ to_u128(block));
+		}
+
+		if !partial.is_empty() {
+			offset ^= self.l_star;
+			let mut block = to_block(pad(partial) ^ offset);
+			self.cipher.encrypt_block(&mut block);
+			sum ^= to_u128(&block);
+		}
+
+		sum
+	}
+
+	/// Encrypts or decrypts `buffer` in place, and returns the full tag.
+	fn crypt(&self, nonce: &Nonce<Self>, associated_data: &[u8], buffer: &mut [u8], decrypt: bool) -> Block {
+		let mut checksum = 0;
+		let mut offset = self.initial_offset(nonce);
+		let mut i = 0;
+
+		let len = buffer.len() / 16 * 16;
+		let (full, partial) = buffer.split_at_mut(len);
+
+		// Offsets of a batch are computed serially, then the batch is encrypted at once.
+		let mut blocks = ParBlocks::<C>::default();
+		let mut offsets = ParBlocks::<C>::default();
+		for chunk in full.chunks_mut(16 * C::ParBlocks::USIZE) {
+			let n = chunk.len() / 16;
+			for ((block, block_offset), x) in blocks.iter_mut().zip(offsets.iter_mut()).zip(chunk.chunks_exact(16)) {
+				i += 1;
+				offset ^= self.l(u64::trailing_zeros(i));
+				*block_offset = to_block(offset);
+
+				let x = to_u128(x);
+				if !decrypt {
+					checksum ^= x;
+				}
+				*block = to_block(x ^ offset);
+			}
+
+			if decrypt {
+				self.cipher.decrypt_blocks(&mut blocks[..n]);
+			} else {
+				self.cipher.encrypt_blocks(&mut blocks[..n]);
+			}
+
+			for ((block, block_offset), y) in blocks.iter().zip(offsets.iter()).zip(chunk.chunks_exact_mut(16)) {
+				let x = to_u128(block) ^ to_u128(block_offset);
+				if decrypt {
+					checksum ^= x;
+				}
+				y.copy_from_slice(&x.to_be_bytes());
+			}
+		}
+
+		if !partial.is_empty() {
+			offset ^= self.l_star;
+			let mut pad_block = to_block(offset);
+			self.cipher.encrypt_block(&mut pad_block);
+
+			if !decrypt {
+				checksum ^= pad(partial);
+			}
+			partial.iter_mut().zip(pad_block).for_each(|(b, p)| *b ^= p);
+			if decrypt {
+				checksum ^= pad(partial);
+			}
+		}
+
+		let mut tag = to_block(checksum ^ offset ^ self.l_dollar);
+		self.cipher.encrypt_block(&mut tag);
+
+		to_block(to_u128(&tag) ^ self.hash(associated_data))
+	}
+}
+
+/// Multiplication by x in GF(2<sup>128</sup>)
+fn double(x: u128) -> u128 {
+	(x << 1) ^ ((x >> 127) * 0x87)
+}
+
+/// Partial block followed by a one bit and zero bits
+fn pad(data: &[u8]) -> u128 {
+	let mut block = Block::default();
+	block[..data.len()].copy_from_slice(data);
+	block[data.len()] = 0x80;
+
+	to_u128(&block)
+}
+
+fn to_block(x: u128) -> Block {
+	Block::from(x.to_be_bytes())
+}
+
+fn to_u128(block: &[u8]) -> u128 {
+	u128::from_be_bytes(block.try_into().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+	extern crate alloc;
+
+	use alloc::{vec::Vec, vec};
+
+	use crate::{ocb3::aead::{consts::{U1, U15}, generic_array::typenum::Unsigned, Error}, prelude::*, Lea128Ocb3, Lea192Ocb3, Lea256Ocb3};
+
+	struct TestCase<T> where
+	T: AeadInPlace + NewAead {
+		key: GenericArray<u8, <T as NewAead>::KeySize>,
+		nonce: GenericArray<u8, <T as AeadCore>::NonceSize>,
+		tag: GenericArray<u8, <T as AeadCore>::TagSize>,
+		associated_data: Vec<u8>,
+		ptxt: Vec<u8>,
+		ctxt: Vec<u8>
+	}
+
+	#[test]
+	fn lea128ocb3() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea128Ocb3>; 7] = [
+			TestCase {
+				key: arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F],
+				nonce: arr![u8; 0xBB, 0xAA, 0x99, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x00],
+				tag: arr![u8; 0xE2, 0x28, 0x9B, 0x75, 0x69, 0xB9, 0xD1, 0xC4, 0x9A, 0x6E, 0x88, 0xBA, 0xBE, 0x90, 0xC4, 0xED],
+				associated_data: vec![],
+				ptxt: vec![],
+				ctxt: vec![]
+			},
+			TestCase {
+				key: arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F],
+				nonce: arr![u8; 0xBB, 0xAA, 0x99, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x01],
+				tag: arr![u8; 0xA4, 0x81, 0x92, 0x3F, 0x9C, 0xF1, 0xED, 0x7A, 0x4A, 0x88, 0x51, 0xFA, 0xAA, 0xC7, 0x12, 0xD8],
+				associated_data: vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07],
+				ptxt: vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07],
+				ctxt: vec![0x14, 0x85, 0x3F, 0x19, 0x8E, 0x82, 0x27, 0xF9]
+			},
+			TestCase {
+				key: arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F],
+				nonce: arr![u8; 0xBB, 0xAA, 0x99, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x02],
+				tag: arr![u8; 0x4C, 0xCA, 0x00, 0x90, 0x0A, 0x79, 0xEB, 0x76, 0x71, 0x42, 0xDF, 0xDF, 0x8D, 0xA2, 0x13, 0xEC],
+				associated_data: vec![],
+				ptxt: vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07],
+				ctxt: vec![0x80, 0x5A, 0xF9, 0x74, 0x0B, 0x7A, 0x42, 0x01]
+			},
+			TestCase {
+				key: arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F],
+				nonce: arr![u8; 0xBB, 0xAA, 0x99, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x03],
+				tag: arr![u8; 0x16, 0x61, 0x56, 0x19, 0x02, 0x3D, 0xEA, 0x80, 0x8A, 0xFB, 0xB7, 0x72, 0xCB, 0xA0, 0x4D, 0x49],
+				associated_data: vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07],
+				ptxt: vec![],
+				ctxt: vec![]
+			},
+			TestCase {
+				key: arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F],
+				nonce: arr![u8; 0xBB, 0xAA, 0x99, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x04],
+				tag: arr![u8; 0xE7, 0x0C, 0x2C, 0xE1, 0x9D, 0x81, 0x1C, 0xD8, 0xF1, 0x42, 0xEE, 0xB5, 0xBA, 0x25, 0x38, 0xF4],
+				associated_data: vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F],
+				ptxt: vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F],
+				ctxt: vec![0x9B, 0x12, 0xE2, 0x89, 0xF0, 0x7E, 0x32, 0x58, 0xC4, 0x4E, 0xFE, 0xCE, 0x6D, 0x42, 0x98, 0x27]
+			},
+			TestCase {
+				key: arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F],
+				nonce: arr![u8; 0xBB, 0xAA, 0x99, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x05],
+				tag: arr![u8; 0x9C, 0x23, 0xE4, 0x68, 0x76, 0x27, 0xE2, 0x50, 0x12, 0xEF, 0x51, 0xBD, 0x41, 0x9C, 0x1F, 0x5A],
+				associated_data: vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17],
+				ptxt: vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F, 0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27],
+				ctxt: vec![0x4A, 0x64, 0xA0, 0x62, 0xE3, 0x59, 0x4A, 0x16, 0x61, 0x10, 0x69, 0xBD, 0x95, 0x2C, 0x09, 0x51, 0x7E, 0xA0, 0xED, 0xCE, 0x74, 0xBE, 0x47, 0xA9, 0x85, 0x15, 0xDB, 0x92, 0xF7, 0x59, 0x9C, 0x09, 0x86, 0xAC, 0x66, 0xAC, 0xD7, 0x14, 0xA0, 0xBE]
+			},
+			TestCase {
+				key: arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F],
+				nonce: arr![u8; 0xBB, 0xAA, 0x99, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x06],
+				tag: arr![u8; 0x29, 0xE1, 0x90, 0x66, 0xC5, 0x44, 0xA6, 0x23, 0xEF, 0x6A, 0x5F, 0x5F, 0x79, 0xDB, 0xC2, 0xD9],
+				associated_data: vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F, 0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27],
+				ptxt: vec![0x00, 0x03, 0x06, 0x09, 0x0C, 0x0F, 0x12, 0x15, 0x18, 0x1B, 0x1E, 0x21, 0x24, 0x27, 0x2A, 0x2D, 0x30, 0x33, 0x36, 0x39, 0x3C, 0x3F, 0x42, 0x45, 0x48, 0x4B, 0x4E, 0x51, 0x54, 0x57, 0x5A, 0x5D, 0x60, 0x63, 0x66, 0x69, 0x6C, 0x6F, 0x72, 0x75, 0x78, 0x7B, 0x7E, 0x81, 0x84, 0x87, 0x8A, 0x8D, 0x90, 0x93, 0x96, 0x99, 0x9C, 0x9F, 0xA2, 0xA5, 0xA8, 0xAB, 0xAE, 0xB1, 0xB4, 0xB7, 0xBA, 0xBD, 0xC0, 0xC3, 0xC6, 0xC9, 0xCC, 0xCF, 0xD2, 0xD5, 0xD8, 0xDB, 0xDE, 0xE1, 0xE4, 0xE7, 0xEA, 0xED, 0xF0, 0xF3, 0xF6, 0xF9, 0xFC, 0xFF, 0x02, 0x05, 0x08, 0x0B, 0x0E, 0x11, 0x14, 0x17, 0x1A, 0x1D, 0x20, 0x23, 0x26, 0x29, 0x2C, 0x2F, 0x32, 0x35, 0x38, 0x3B, 0x3E, 0x41, 0x44, 0x47, 0x4A, 0x4D, 0x50, 0x53, 0x56, 0x59, 0x5C, 0x5F, 0x62, 0x65, 0x68, 0x6B, 0x6E, 0x71, 0x74, 0x77, 0x7A, 0x7D, 0x80, 0x83, 0x86, 0x89, 0x8C, 0x8F, 0x92, 0x95, 0x98, 0x9B, 0x9E, 0xA1, 0xA4, 0xA7, 0xAA, 0xAD, 0xB0, 0xB3, 0xB6, 0xB9, 0xBC, 0xBF],
+				ctxt: vec![0x8D, 0xD0, 0xCE, 0x42, 0x92, 0xF6, 0x53, 0xC5, 0x9B, 0x00, 0x4C, 0x4B, 0x62, 0x94, 0x5C, 0x5A, 0xB4, 0xD4, 0x1B, 0x1F, 0x68, 0xA5, 0x9B, 0x5C, 0xCC, 0xC5, 0xA3, 0x86, 0x3C, 0xFD, 0xBC, 0x1B, 0xA3, 0x46, 0x3A, 0x35, 0xB1, 0x47, 0xA5, 0xB6, 0xF2, 0xA4, 0x31, 0x6E, 0x1C, 0x34, 0xD9, 0xB2, 0x3A, 0x65, 0x35, 0x68, 0x4E, 0x52, 0x7B, 0xDD, 0x01, 0xE6, 0xB1, 0xBC, 0x8E, 0x81, 0xF3, 0xBA, 0x01, 0x12, 0x69, 0x1E, 0x70, 0xF1, 0xB1, 0xF7, 0x7E, 0x0F, 0x6A, 0x36, 0xD0, 0x1E, 0x82, 0x95, 0xFF, 0x58, 0x2A, 0x48, 0x4C, 0x15, 0x55, 0xBD, 0xE3, 0xAF, 0x18, 0x2D, 0xB6, 0x42, 0x89, 0xC0, 0x29, 0x00, 0xA3, 0xEC, 0x3E, 0xC1, 0x0E, 0x05, 0xD0, 0x31, 0xBE, 0x0A, 0x9A, 0xE5, 0x11, 0x8F, 0x20, 0x6B, 0xC7, 0xF6, 0x9B, 0x18, 0x18, 0x0B, 0x38, 0x35, 0x31, 0xD3, 0x99, 0x0B, 0xB4, 0x32, 0x6A, 0x6B, 0x8C, 0x04, 0x15, 0xEC, 0xF4, 0x3B, 0xD3, 0xFE, 0xDF, 0xC3, 0xEA, 0x42, 0xBD, 0x4E, 0x6B, 0x59, 0xC7, 0xC0, 0x05, 0x34]
+			}
+		];
+
+		for TestCase { key, nonce, tag, associated_data, ptxt, ctxt } in test_cases {
+			let lea128ocb3 = Lea128Ocb3::new(&key);
+
+			// Encryption
+			let mut buffer = ptxt.clone();
+			let calculated_tag = lea128ocb3.encrypt_in_place_detached(&nonce, &associated_data, &mut buffer)?;
+			assert_eq!(buffer, ctxt);
+			assert_eq!(calculated_tag, tag);
+
+			// Decryption
+			let mut buffer = ctxt.clone();
+			lea128ocb3.decrypt_in_place_detached(&nonce, &associated_data, &mut buffer, &tag)?;
+			assert_eq!(buffer, ptxt);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea192ocb3() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea192Ocb3>; 7] = [
+			TestCase {
+				key: arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17],
+				nonce: arr![u8; 0xBB, 0xAA, 0x99, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x00],
+				tag: arr![u8; 0xEF, 0x09, 0xF9, 0x1F, 0xFA, 0x8E, 0x7B, 0xE0, 0xF9, 0x10, 0x0A, 0x30, 0xC2, 0xA4, 0xCB, 0xAF],
+				associated_data: vec![],
+				ptxt: vec![],
+				ctxt: vec![]
+			},
+			TestCase {
+				key: arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17],
+				nonce: arr![u8; 0xBB, 0xAA, 0x99, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x01],
+				tag: arr![u8; 0x88, 0x6E, 0x6A, 0x0B, 0x46, 0x3B, 0x7D, 0xBB, 0xD1, 0xE2, 0xDC, 0xDD, 0xBF, 0xAC, 0x8B, 0x98],
+				associated_data: vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07],
+				ptxt: vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07],
+				ctxt: vec![0xD7, 0x86, 0xD7, 0x80, 0x0A, 0x76, 0x61, 0x56]
+			},
+			TestCase {
+				key: arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17],
+				nonce: arr![u8; 0xBB, 0xAA, 0x99, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x02],
+				tag: arr![u8; 0xCD, 0xDF, 0x12, 0x2B, 0xCA, 0x1A, 0x4B, 0x93, 0x54, 0x9D, 0x3A, 0x17, 0x9E, 0x51, 0x78, 0x46],
+				associated_data: vec![],
+				ptxt: vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07],
+				ctxt: vec![0x7C, 0x12, 0xB8, 0x47, 0x91, 0xAF, 0x49, 0x44]
+			},
+			TestCase {
+				key: arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17],
+				nonce: arr![u8; 0xBB, 0xAA, 0x99, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x03],
+				tag: arr![u8; 0x15, 0x11, 0xBE, 0x9B, 0x95, 0x64, 0x39, 0xD9, 0xFC, 0x32, 0xB5, 0xAE, 0x6E, 0x8E, 0xAB, 0xB1],
+				associated_data: vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07],
+				ptxt: vec![],
+				ctxt: vec![]
+			},
+			TestCase {
+				key: arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17],
+				nonce: arr![u8; 0xBB, 0xAA, 0x99, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x04],
+				tag: arr![u8; 0x54, 0xB3, 0xEF, 0xF3, 0xB0, 0x86, 0xBF, 0x56, 0x51, 0x9D, 0xF6, 0xD3, 0x19, 0xF9, 0x5A, 0x83],
+				associated_data: vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F],
+				ptxt: vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F],
+				ctxt: vec![0xB3, 0xD3, 0x67, 0x1F, 0xD3, 0xB3, 0x03, 0x2E, 0x35, 0xF3, 0x84, 0x18, 0x05, 0x7A, 0xF0, 0x72]
+			},
+			TestCase {
+				key: arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17],
+				nonce: arr![u8; 0xBB, 0xAA, 0x99, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x05],
+				tag: arr![u8; 0xDE, 0xF4, 0x2D, 0x06, 0x5F, 0xAA, 0xE2, 0x2A, 0x94, 0x56, 0xD6, 0x05, 0xBF, 0x4A, 0x22, 0xAF],
+				associated_data: vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17],
+				ptxt: vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F, 0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27],
+				ctxt: vec![0x65, 0x08, 0x55, 0x85, 0xD4, 0x38, 0xE3, 0x80, 0x5A, 0xAA, 0xAE, 0x18, 0x7D, 0x31, 0x37, 0x9B, 0x0C, 0xB3, 0xE9, 0xAE, 0x02, 0xB9, 0xAA, 0xBF, 0xFD, 0xEA, 0xDD, 0x27, 0xD7, 0x43, 0xCF, 0xA6, 0x82, 0xFA, 0x88, 0xF0, 0xF7, 0xEF, 0x10, 0xFE]
+			},
+			TestCase {
+				key: arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17],
+				nonce: arr![u8; 0xBB, 0xAA, 0x99, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x06],
+				tag: arr![u8; 0xD0, 0x61, 0xFE, 0xBF, 0xD1, 0x72, 0xA1, 0xD2, 0xBF, 0xDD, 0xB9, 0xA1, 0x19, 0xE8, 0xFC, 0x20],
+				associated_data: vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F, 0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27],
+				ptxt: vec![0x00, 0x03, 0x06, 0x09, 0x0C, 0x0F, 0x12, 0x15, 0x18, 0x1B, 0x1E, 0x21, 0x24, 0x27, 0x2A, 0x2D, 0x30, 0x33, 0x36, 0x39, 0x3C, 0x3F, 0x42, 0x45, 0x48, 0x4B, 0x4E, 0x51, 0x54, 0x57, 0x5A, 0x5D, 0x60, 0x63, 0x66, 0x69, 0x6C, 0x6F, 0x72, 0x75, 0x78, 0x7B, 0x7E, 0x81, 0x84, 0x87, 0x8A, 0x8D, 0x90, 0x93, 0x96, 0x99, 0x9C, 0x9F, 0xA2, 0xA5, 0xA8, 0xAB, 0xAE, 0xB1, 0xB4, 0xB7, 0xBA, 0xBD, 0xC0, 0xC3, 0xC6, 0xC9, 0xCC, 0xCF, 0xD2, 0xD5, 0xD8, 0xDB, 0xDE, 0xE1, 0xE4, 0xE7, 0xEA, 0xED, 0xF0, 0xF3, 0xF6, 0xF9, 0xFC, 0xFF, 0x02, 0x05, 0x08, 0x0B, 0x0E, 0x11, 0x14, 0x17, 0x1A, 0x1D, 0x20, 0x23, 0x26, 0x29, 0x2C, 0x2F, 0x32, 0x35, 0x38, 0x3B, 0x3E, 0x41, 0x44, 0x47, 0x4A, 0x4D, 0x50, 0x53, 0x56, 0x59, 0x5C, 0x5F, 0x62, 0x65, 0x68, 0x6B, 0x6E, 0x71, 0x74, 0x77, 0x7A, 0x7D, 0x80, 0x83, 0x86, 0x89, 0x8C, 0x8F, 0x92, 0x95, 0x98, 0x9B, 0x9E, 0xA1, 0xA4, 0xA7, 0xAA, 0xAD, 0xB0, 0xB3, 0xB6, 0xB9, 0xBC, 0xBF],
+				ctxt: vec![0x7B, 0x0A, 0x62, 0x4D, 0x57, 0xF1, 0xA0, 0x93, 0xFC, 0x89, 0x49, 0x67, 0xE9, 0xFC, 0xE1, 0xC3, 0xF7, 0x11, 0xEF, 0x2B, 0x9E, 0x7E, 0x56, 0xA5, 0x43, 0x54, 0x72, 0xD6, 0xB4, 0xA2, 0x69, 0xCF, 0xDE, 0xD3, 0x81, 0x55, 0x29, 0xCB, 0xF0, 0xC8, 0xA1, 0x5F, 0x43, 0x05, 0xFB, 0xC2, 0x2E, 0x2A, 0x49, 0x34, 0x5B, 0xAD, 0x78, 0xC5, 0xB9, 0x11, 0x1F, 0xFD, 0xBB, 0x7B, 0x6D, 0x74, 0x01, 0x6B, 0x8D, 0x83, 0xD1, 0x38, 0xEB, 0x37, 0x01, 0x02, 0xED, 0x86, 0xA8, 0xF5, 0xAF, 0x3E, 0x7B, 0x41, 0x94, 0x5C, 0x0D, 0xB8, 0x3E, 0x7E, 0x3A, 0xF4, 0xEB, 0x42, 0xA1, 0x02, 0x52, 0x61, 0xE2, 0x49, 0x99, 0x56, 0xBC, 0xEB, 0xC0, 0x99, 0x32, 0x8E, 0x45, 0xB3, 0x6C, 0xD1, 0xB1, 0xD0, 0x71, 0xDA, 0xD7, 0x10, 0x96, 0x42, 0x56, 0xFC, 0x9E, 0xA4, 0x2C, 0x6D, 0xAF, 0x62, 0xFB, 0x88, 0xF1, 0x06, 0x0A, 0x39, 0xC7, 0xD8, 0x58, 0x40, 0x90, 0xFA, 0xBA, 0xD4, 0xD7, 0x7D, 0x35, 0x9E, 0x32, 0xED, 0x6C, 0x2E, 0x4E, 0x37, 0x1F, 0x9A]
+			}
+		];
+
+		for TestCase { key, nonce, tag, associated_data, ptxt, ctxt } in test_cases {
+			let lea192ocb3 = Lea192Ocb3::new(&key);
+
+			// Encryption
+			let mut buffer = ptxt.clone();
+			let calculated_tag = lea192ocb3.encrypt_in_place_detached(&nonce, &associated_data, &mut buffer)?;
+			assert_eq!(buffer, ctxt);
+			assert_eq!(calculated_tag, tag);
+
+			// Decryption
+			let mut buffer = ctxt.clone();
+			lea192ocb3.decrypt_in_place_detached(&nonce, &associated_data, &mut buffer, &tag)?;
+			assert_eq!(buffer, ptxt);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea256ocb3() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea256Ocb3>; 7] = [
+			TestCase {
+				key: arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F],
+				nonce: arr![u8; 0xBB, 0xAA, 0x99, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x00],
+				tag: arr![u8; 0xD6, 0xB2, 0xA5, 0x33, 0xE9, 0x1F, 0xED, 0xDF, 0xDA, 0xED, 0x33, 0x12, 0x59, 0x41, 0x32, 0x46],
+				associated_data: vec![],
+				ptxt: vec![],
+				ctxt: vec![]
+			},
+			TestCase {
+				key: arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F],
+				nonce: arr![u8; 0xBB, 0xAA, 0x99, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x01],
+				tag: arr![u8; 0x6E, 0x09, 0xA4, 0x0A, 0xEC, 0xE8, 0x38, 0x0E, 0xCB, 0xA2, 0x43, 0xC9, 0x96, 0x86, 0xE6, 0x17],
+				associated_data: vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07],
+				ptxt: vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07],
+				ctxt: vec![0xFC, 0x23, 0x43, 0xF8, 0x66, 0x81, 0x83, 0x67]
+			},
+			TestCase {
+				key: arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F],
+				nonce: arr![u8; 0xBB, 0xAA, 0x99, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x02],
+				tag: arr![u8; 0x36, 0xB8, 0xF4, 0xB5, 0xF2, 0xEE, 0x9D, 0x51, 0x68, 0xFB, 0x1C, 0xFC, 0x49, 0xB0, 0x93, 0xBA],
+				associated_data: vec![],
+				ptxt: vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07],
+				ctxt: vec![0x8F, 0x2E, 0x50, 0xD8, 0x29, 0x0D, 0x60, 0x25]
+			},
+			TestCase {
+				key: arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F],
+				nonce: arr![u8; 0xBB, 0xAA, 0x99, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x03],
+				tag: arr![u8; 0x9C, 0x5B, 0xAD, 0x08, 0x49, 0x81, 0x13, 0xFD, 0x0E, 0x3F, 0xE6, 0xC3, 0x10, 0xF3, 0x5F, 0x3C],
+				associated_data: vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07],
+				ptxt: vec![],
+				ctxt: vec![]
+			},
+			TestCase {
+				key: arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F],
+				nonce: arr![u8; 0xBB, 0xAA, 0x99, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x04],
+				tag: arr![u8; 0x8B, 0xD7, 0xD2, 0x7F, 0x28, 0x5D, 0xED, 0xEE, 0xBB, 0xBE, 0x1B, 0x91, 0x26, 0xBA, 0x70, 0xE4],
+				associated_data: vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F],
+				ptxt: vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F],
+				ctxt: vec![0xAA, 0x62, 0x84, 0xC7, 0x5C, 0x2D, 0x4D, 0xDF, 0x4E, 0xEC, 0xE0, 0x5D, 0xC7, 0x5D, 0x96, 0x00]
+			},
+			TestCase {
+				key: arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F],
+				nonce: arr![u8; 0xBB, 0xAA, 0x99, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x05],
+				tag: arr![u8; 0x94, 0x76, 0xDC, 0xDC, 0x7D, 0x78, 0x9A, 0x86, 0x7C, 0x32, 0xA2, 0xB9, 0x69, 0xBC, 0xE4, 0x42],
+				associated_data: vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17],
+				ptxt: vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F, 0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27],
+				ctxt: vec![0x17, 0x4D, 0x2F, 0xEB, 0x98, 0x33, 0x9A, 0xB2, 0xCC, 0xD9, 0x79, 0x13, 0x24, 0x53, 0xD5, 0xA2, 0xE2, 0xF3, 0xD2, 0xA9, 0x83, 0x91, 0x95, 0x2B, 0x25, 0xA5, 0xE4, 0xC5, 0xF1, 0xD3, 0x3A, 0x42, 0x01, 0x55, 0x5D, 0xAB, 0xBC, 0x53, 0x80, 0x3C]
+			},
+			TestCase {
+				key: arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F],
+				nonce: arr![u8; 0xBB, 0xAA, 0x99, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x06],
+				tag: arr![u8; 0xA0, 0x10, 0xD0, 0xCF, 0x8B, 0xEE, 0x8B, 0xB3, 0x71, 0x9F, 0xAF, 0x84, 0x11, 0xF9, 0xD4, 0x50],
+				associated_data: vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F, 0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27],
+				ptxt: vec![0x00, 0x03, 0x06, 0x09, 0x0C, 0x0F, 0x12, 0x15, 0x18, 0x1B, 0x1E, 0x21, 0x24, 0x27, 0x2A, 0x2D, 0x30, 0x33, 0x36, 0x39, 0x3C, 0x3F, 0x42, 0x45, 0x48, 0x4B, 0x4E, 0x51, 0x54, 0x57, 0x5A, 0x5D, 0x60, 0x63, 0x66, 0x69, 0x6C, 0x6F, 0x72, 0x75, 0x78, 0x7B, 0x7E, 0x81, 0x84, 0x87, 0x8A, 0x8D, 0x90, 0x93, 0x96, 0x99, 0x9C, 0x9F, 0xA2, 0xA5, 0xA8, 0xAB, 0xAE, 0xB1, 0xB4, 0xB7, 0xBA, 0xBD, 0xC0, 0xC3, 0xC6, 0xC9, 0xCC, 0xCF, 0xD2, 0xD5, 0xD8, 0xDB, 0xDE, 0xE1, 0xE4, 0xE7, 0xEA, 0xED, 0xF0, 0xF3, 0xF6, 0xF9, 0xFC, 0xFF, 0x02, 0x05, 0x08, 0x0B, 0x0E, 0x11, 0x14, 0x17, 0x1A, 0x1D, 0x20, 0x23, 0x26, 0x29, 0x2C, 0x2F, 0x32, 0x35, 0x38, 0x3B, 0x3E, 0x41, 0x44, 0x47, 0x4A, 0x4D, 0x50, 0x53, 0x56, 0x59, 0x5C, 0x5F, 0x62, 0x65, 0x68, 0x6B, 0x6E, 0x71, 0x74, 0x77, 0x7A, 0x7D, 0x80, 0x83, 0x86, 0x89, 0x8C, 0x8F, 0x92, 0x95, 0x98, 0x9B, 0x9E, 0xA1, 0xA4, 0xA7, 0xAA, 0xAD, 0xB0, 0xB3, 0xB6, 0xB9, 0xBC, 0xBF],
+				ctxt: vec![0x3B, 0xFA, 0xB7, 0xC1, 0x80, 0xC0, 0x6F, 0xF3, 0x24, 0x07, 0xC0, 0x4D, 0x06, 0x8B, 0xFD, 0xB0, 0x74, 0x48, 0x8D, 0xF8, 0xF8, 0x29, 0x8A, 0xDC, 0x74, 0x37, 0xE3, 0x1D, 0x3C, 0x01, 0x53, 0xBB, 0xC6, 0x62, 0x9C, 0x5E, 0x82, 0xAC, 0x77, 0x9A, 0x12, 0x3B, 0x06, 0x0B, 0x78, 0x8C, 0x3A, 0x43, 0x37, 0xE2, 0x2C, 0x5D, 0x45, 0x06, 0x70, 0x12, 0x0F, 0xA3, 0xDC, 0xCB, 0x7E, 0xFC, 0xC9, 0xDD, 0xCB, 0x66, 0x14, 0x38, 0x9D, 0x92, 0xF2, 0x52, 0x97, 0x00, 0x11, 0x0C, 0x0A, 0xDB, 0x5A, 0x4B, 0xDE, 0x67, 0xF2, 0x39, 0xC0, 0x07, 0xB2, 0x79, 0x9E, 0xC8, 0x3D, 0x27, 0xD1, 0x07, 0x2D, 0x3C, 0xB4, 0xE8, 0xAF, 0x3D, 0xFA, 0x3C, 0x15, 0x4E, 0xA6, 0xEF, 0xDF, 0x13, 0x86, 0xC8, 0x6E, 0x78, 0x4D, 0xE0, 0x5F, 0x3C, 0x4B, 0x64, 0xFF, 0x95, 0x20, 0xFB, 0x2A, 0xA8, 0x23, 0xCA, 0x7C, 0xF0, 0x0D, 0x81, 0xEA, 0x5E, 0x1D, 0xA9, 0x20, 0xA9, 0xA3, 0x5F, 0xFC, 0x9F, 0xE6, 0x2D, 0x47, 0x63, 0xBE, 0x62, 0x4E, 0x6A, 0xEC, 0xBE]
+			}
+		];
+
+		for TestCase { key, nonce, tag, associated_data, ptxt, ctxt } in test_cases {
+			let lea256ocb3 = Lea256Ocb3::new(&key);
+
+			// Encryption
+			let mut buffer = ptxt.clone();
+			let calculated_tag = lea256ocb3.encrypt_in_place_detached(&nonce, &associated_data, &mut buffer)?;
+			assert_eq!(buffer, ctxt);
+			assert_eq!(calculated_tag, tag);
+
+			// Decryption
+			let mut buffer = ctxt.clone();
+			lea256ocb3.decrypt_in_place_detached(&nonce, &associated_data, &mut buffer, &tag)?;
+			assert_eq!(buffer, ptxt);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128ocb3_sizes() -> Result<(), Error> {
+		let key = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+		let associated_data: Vec<u8> = (0..20).collect();
+		let ptxt: Vec<u8> = (0..33).collect();
+
+		let lea128ocb3 = Lea128Ocb3::<U15, U8>::new(&key);
+		let nonce = arr![u8; 0xF0, 0xF1, 0xF2, 0xF3, 0xF4, 0xF5, 0xF6, 0xF7, 0xF8, 0xF9, 0xFA, 0xFB, 0xFC, 0xFD, 0xFE];
+		let mut buffer = ptxt.clone();
+		let tag = lea128ocb3.encrypt_in_place_detached(&nonce, &associated_data, &mut buffer)?;
+		assert_eq!(buffer, [0xFF, 0x56, 0x61, 0x96, 0xB3, 0x2A, 0x27, 0x33, 0x08, 0xF3, 0x7C, 0x86, 0x28, 0xD6, 0x16, 0x32, 0xCD, 0x01, 0xB5, 0xB0, 0x6B, 0xD3, 0x64, 0x6E, 0xBB, 0x95, 0xB2, 0x3B, 0x25, 0x98, 0x9B, 0x7A, 0x4F]);
+		assert_eq!(tag, arr![u8; 0xBC, 0x38, 0x1F, 0x3E, 0xC6, 0x66, 0xC1, 0x21]);
+		lea128ocb3.decrypt_in_place_detached(&nonce, &associated_data, &mut buffer, &tag)?;
+		assert_eq!(buffer, ptxt);
+
+		let lea128ocb3 = Lea128Ocb3::<U1, U12>::new(&key);
+		let nonce = arr![u8; 0x2A];
+		let mut buffer = ptxt.clone();
+		let tag = lea128ocb3.encrypt_in_place_detached(&nonce, &associated_data, &mut buffer)?;
+		assert_eq!(buffer, [0x50, 0xC2, 0x0D, 0xEF, 0xD5, 0x81, 0xE6, 0x07, 0x3F, 0xFE, 0x2C, 0x03, 0x2B, 0xEC, 0xB4, 0x46, 0xC6, 0x39, 0xBC, 0xAD, 0x2F, 0x11, 0xA2, 0x97, 0x51, 0xF0, 0x0C, 0x1F, 0xDA, 0xB8, 0x5B, 0x6F, 0x11]);
+		assert_eq!(tag, arr![u8; 0x22, 0x60, 0xD5, 0x03, 0x9F, 0x62, 0x2C, 0x6E, 0x9F, 0x0C, 0x97, 0x97]);
+		lea128ocb3.decrypt_in_place_detached(&nonce, &associated_data, &mut buffer, &tag)?;
+		assert_eq!(buffer, ptxt);
+
+		let lea128ocb3 = Lea128Ocb3::<U7>::new(&key);
+		let nonce = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+		let mut buffer = ptxt.clone();
+		let tag = lea128ocb3.encrypt_in_place_detached(&nonce, &associated_data, &mut buffer)?;
+		assert_eq!(buffer, [0x02, 0xD8, 0xDB, 0x14, 0x5A, 0x32, 0xD2, 0x14, 0xE2, 0x37, 0x93, 0xF9, 0x02, 0x42, 0x48, 0xAA, 0xAF, 0x66, 0x3F, 0x76, 0x31, 0x4C, 0xA3, 0x5D, 0x47, 0x93, 0x46, 0x7C, 0x1E, 0x7F, 0x67, 0x8D, 0x73]);
+		assert_eq!(tag, arr![u8; 0xB2, 0xFF, 0x79, 0xD5, 0x25, 0x0D, 0xF9, 0x91, 0x2D, 0xE9, 0x9B, 0x16, 0x4C, 0x50, 0x47, 0x3E]);
+		lea128ocb3.decrypt_in_place_detached(&nonce, &associated_data, &mut buffer, &tag)?;
+		assert_eq!(buffer, ptxt);
+
+		Ok(())
+	}
+
+	/// Iterated test of RFC 7253 Appendix A, with the final tag as `expected_tag`
+	fn iterated<T>(expected_tag: &[u8]) where
+	T: AeadInPlace + NewAead + AeadCore<NonceSize = U12> {
+		let mut key = GenericArray::<u8, <T as NewAead>::KeySize>::default();
+		*key.last_mut().unwrap() = 8 * <T as AeadCore>::TagSize::U8;
+		let ocb3 = T::new(&key);
+
+		let nonce = |n: u32| {
+			let mut nonce = GenericArray::<u8, U12>::default();
+			nonce[8..].copy_from_slice(&n.to_be_bytes());
+			nonce
+		};
+
+		let mut ctxt = Vec::new();
+		for i in 0..128 {
+			let s = vec![0; i as usize];
+			for (n, associated_data, ptxt) in [(3 * i + 1, &s[..], &s[..]), (3 * i + 2, &[], &s[..]), (3 * i + 3, &s[..], &[])] {
+				let mut buffer = ptxt.to_vec();
+				let tag = ocb3.encrypt_in_place_detached(&nonce(n), associated_data, &mut buffer).unwrap();
+				ctxt.extend_from_slice(&buffer);
+				ctxt.extend_from_slice(&tag);
+			}
+		}
+
+		let tag = ocb3.encrypt_in_place_detached(&nonce(385), &ctxt, &mut []).unwrap();
+		assert_eq!(tag.as_slice(), expected_tag);
+	}
+
+	#[test]
+	fn lea128ocb3_iterated() {
+		iterated::<Lea128Ocb3<U12, U8>>(&[0xBE, 0x63, 0x13, 0x2D, 0x90, 0x43, 0x53, 0x95]);
+		iterated::<Lea128Ocb3<U12, U12>>(&[0xB2, 0xAC, 0xBA, 0xE2, 0xB9, 0x29, 0x97, 0xA9, 0x83, 0x7E, 0x0F, 0xB9]);
+		iterated::<Lea128Ocb3<U12, U16>>(&[0xAF, 0x30, 0xC7, 0x68, 0xD5, 0xE0, 0x73, 0x2A, 0x0A, 0x02, 0x0F, 0x16, 0x56, 0x17, 0xA6, 0x96]);
+		iterated::<Lea192Ocb3<U12, U8>>(&[0x01, 0x67, 0x4E, 0x2E, 0xE0, 0xC1, 0x18, 0xD8]);
+		iterated::<Lea192Ocb3<U12, U12>>(&[0x6F, 0xE3, 0x45, 0x37, 0xD1, 0x6F, 0x60, 0x1D, 0xD6, 0x6C, 0x8F, 0xE8]);
+		iterated::<Lea192Ocb3<U12, U16>>(&[0xF1, 0x37, 0xDA, 0xFF, 0xC3, 0x2C, 0x37, 0x03, 0xE7, 0x91, 0xBB, 0x82, 0x80, 0xF8, 0xD4, 0x5A]);
+		iterated::<Lea256Ocb3<U12, U8>>(&[0x37, 0x97, 0x79, 0x7B, 0x0C, 0x0D, 0x92, 0x83]);
+		iterated::<Lea256Ocb3<U12, U12>>(&[0xE5, 0x2F, 0x41, 0x0D, 0x2C, 0x42, 0x4C, 0x10, 0x9E, 0x62, 0x56, 0x02]);
+		iterated::<Lea256Ocb3<U12, U16>>(&[0x05, 0xE4, 0x87, 0xB4, 0xA9, 0xF0, 0x1F, 0x3D, 0x0E, 0x23, 0x07, 0x28, 0x1B, 0x03, 0x1E, 0x36]);
+	}
+
+	#[test]
+	fn lea128ocb3_rejection() -> Result<(), Error> {
+		let lea128ocb3 = Lea128Ocb3::<U12>::new(&Default::default());
+		let nonce = Default::default();
+		let associated_data = [1, 2, 3];
+		let ptxt: Vec<u8> = (0..150).collect();
+
+		let mut ctxt = ptxt.clone();
+		let tag = lea128ocb3.encrypt_in_place_detached(&nonce, &associated_data, &mut ctxt)?;
+
+		// Tampered ciphertext
+		for i in [0, 16, 127, 128, 149] {
+			let mut buffer = ctxt.clone();
+			buffer[i] ^= 1;
+			assert!(lea128ocb3.decrypt_in_place_detached(&nonce, &associated_data, &mut buffer, &tag).is_err());
+			assert!(buffer.iter().all(|&b| b == 0));
+		}
+
+		// Tampered tag, associated data, or nonce
+		let mut buffer = ctxt.clone();
+		let mut tampered_tag = tag;
+		tampered_tag[15] ^= 1;
+		assert!(lea128ocb3.decrypt_in_place_detached(&nonce, &associated_data, &mut buffer, &tampered_tag).is_err());
+		let mut buffer = ctxt.clone();
+		assert!(lea128ocb3.decrypt_in_place_detached(&nonce, &[1, 2], &mut buffer, &tag).is_err());
+		let mut buffer = ctxt.clone();
+		assert!(lea128ocb3.decrypt_in_place_detached(&arr![u8; 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1], &associated_data, &mut buffer, &tag).is_err());
+
+		// Truncated ciphertext
+		let mut buffer = ctxt[..144].to_vec();
+		assert!(lea128ocb3.decrypt_in_place_detached(&nonce, &associated_data, &mut buffer, &tag).is_err());
+
+		Ok(())
+	}
+}
@@ -0,0 +1,180 @@
+// Copyright © 2023 남기훈 <gihunnam@proton.me>
+//
+// This file and its content are subject to the terms of the MIT License (the "License").
+// If a copy of the License was not distributed with this file, you can obtain one at https://opensource.org/licenses/MIT.
+
+//! LEA-PMAC
+//!
+//! PMAC1 as specified by Rogaway, with LEA as the block cipher.
+//! Unlike CMAC and CBC-MAC, each block is masked with its own offset and encrypted independently,
+//! so full groups of eight blocks are encrypted together through `encrypt_par_blocks`.
+//!
+//! [`Lea128Pmac`], [`Lea192Pmac`], and [`Lea256Pmac`] implement [`Mac`], which is incremental:
+//! data can be fed in any number of `update` calls before finalizing or verifying the tag.
+//!
+//! * Example
+//! ```
+//! use lea::{pmac::{Mac, NewMac}, prelude::*, Lea128Pmac};
+//!
+//! let key = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+//! let tag = arr![u8; 0x2E, 0xA1, 0x14, 0x17, 0x95, 0xA3, 0x4B, 0x6A, 0x34, 0x8B, 0xF8, 0x4B, 0xE7, 0x27, 0x76, 0xF9];
+//!
+//! // Authentication
+//! let mut lea128pmac = Lea128Pmac::new(&key);
+//! lea128pmac.update(b"head");
+//! lea128pmac.update(b"er");
+//! assert_eq!(lea128pmac.finalize().into_bytes(), tag);
+//!
+//! // Verification
+//! let mut lea128pmac = Lea128Pmac::new(&key);
+//! lea128pmac.update(b"header");
+//! assert!(lea128pmac.verify(&tag).is_ok());
+//! ```
+
+pub use pmac::{self, crypto_mac::MacError, FromBlockCipher, Mac, NewMac};
+
+use pmac::Pmac;
+
+use crate::{Lea128, Lea192, Lea256};
+
+pub type Lea128Pmac = Pmac<Lea128>;
+pub type Lea192Pmac = Pmac<Lea192>;
+pub type Lea256Pmac = Pmac<Lea256>;
+
+#[cfg(test)]
+mod tests {
+	extern crate alloc;
+
+	use alloc::{vec::Vec, vec};
+
+	use cipher::{consts::U16, BlockCipher, BlockCipherKey, NewBlockCipher};
+
+	use crate::{pmac::{FromBlockCipher, Mac, MacError, NewMac}, prelude::*, Lea128, Lea192, Lea256, Lea128Pmac, Lea192Pmac, Lea256Pmac};
+
+	struct TestCase<T> where
+	T: BlockCipher + NewBlockCipher {
+		key: BlockCipherKey<T>,
+		tag: GenericArray<u8, U16>,
+		message: Vec<u8>
+	}
+
+	#[test]
+	fn lea128pmac() -> Result<(), MacError> {
+		let test_cases: [TestCase<Lea128>; 3] = [
+			TestCase {
+				key: arr![u8; 0xA3, 0x1C, 0x06, 0xBD, 0x46, 0x3E, 0x39, 0x23, 0xBC, 0x1A, 0xAD, 0xBD, 0xE4, 0x8B, 0x16, 0x97],
+				tag: arr![u8; 0xE5, 0xA0, 0x1B, 0xD3, 0x25, 0xD8, 0x10, 0x41, 0x32, 0x86, 0x89, 0x28, 0x88, 0x24, 0xF3, 0x23],
+				message: vec![]
+			},
+			TestCase {
+				key: arr![u8; 0x6C, 0x08, 0x07, 0x17, 0x37, 0x3B, 0x81, 0x9A, 0x06, 0x8F, 0x32, 0xB7, 0xA6, 0xB3, 0x8B, 0x6B],
+				tag: arr![u8; 0x40, 0x89, 0xC1, 0xC3, 0xDE, 0xF5, 0xCA, 0x5B, 0x34, 0x45, 0xFE, 0x6F, 0x96, 0xBB, 0x9F, 0xC6],
+				message: vec![0x38, 0x72, 0x96, 0x47, 0xCF, 0xDE, 0x01, 0xC2, 0xCE, 0x28, 0xB2, 0x6C, 0x57, 0x47, 0x27, 0x37, 0xF5, 0xC3, 0x56, 0x1A, 0x17, 0x61, 0x18, 0x5B, 0xD8, 0x58, 0x9A, 0x43, 0xCE, 0x0B, 0xBA, 0x75, 0x89, 0x1F, 0xF9, 0xEC, 0x60, 0x14, 0x8D, 0x4B]
+			},
+			TestCase {
+				key: arr![u8; 0xD4, 0xA0, 0x9E, 0xE2, 0xDC, 0x5C, 0x93, 0x31, 0xB4, 0x11, 0x0B, 0xA9, 0x3A, 0xC5, 0x4A, 0xFC],
+				tag: arr![u8; 0x94, 0xDC, 0x7A, 0xA6, 0xB9, 0xF9, 0x0C, 0x8C, 0x51, 0xF9, 0x8C, 0x25, 0x60, 0xCF, 0x71, 0x80],
+				message: vec![0x14, 0xDA, 0x3B, 0xDD, 0x19, 0x61, 0x47, 0x74, 0xA2, 0xD5, 0x5D, 0x29, 0x5E, 0x5A, 0x35, 0xAB, 0x44, 0xB3, 0xEF, 0xAE, 0xA5, 0x12, 0x9B, 0xA2, 0x2B, 0x88, 0xBA, 0x3E, 0x29, 0x76, 0x61, 0x45, 0xFD, 0xEC, 0xA3, 0xB0, 0x8E, 0x38, 0xAF, 0x53, 0xD7, 0xC4, 0xC6, 0x0E, 0x3A, 0xD2, 0x08, 0xCE, 0x50, 0x66, 0x44, 0x10, 0x36, 0xE9, 0xF1, 0x91, 0xE0, 0xB7, 0x50, 0x36, 0xA7, 0x7F, 0x65, 0xE2, 0xEA, 0xA4, 0x75, 0x24, 0x43, 0x23, 0x3F, 0xBE, 0x8F, 0x89, 0x43, 0xBF, 0x95, 0x6D, 0xE5, 0x95, 0x66, 0x5C, 0x38, 0xFF, 0xFF, 0x23, 0x82, 0x7E, 0x17, 0xC1, 0x0C, 0xDC, 0x1C, 0x27, 0xA0, 0x28, 0xCA, 0xAE, 0x6C, 0x98, 0x10, 0x62, 0x61, 0x98, 0xFF, 0x77, 0x87, 0x40, 0xF8, 0x8D, 0xDC, 0xF1, 0x02, 0xAE, 0xB8, 0x1D, 0xAE, 0xE2, 0x89, 0xC0, 0x44, 0xC4, 0xA4, 0x57, 0x1C, 0x4B, 0x6F, 0x28, 0x74, 0x00, 0xF4, 0xB8, 0xE0, 0xB8, 0x43, 0xF8, 0x80, 0xC3, 0x2D, 0x81, 0xE9, 0x1B, 0xDE, 0xA0, 0x4C, 0xD7, 0xA3, 0x81, 0x9B, 0x32, 0x27, 0x5F, 0xC3, 0x29, 0x8A, 0xF4, 0xC7, 0xEC, 0x87, 0xEB, 0x00, 0x99, 0x52, 0x7D, 0x04, 0x1C, 0xED, 0x5C, 0xE0, 0xFC, 0xD4, 0xCE, 0x4E, 0x3D, 0x0E, 0x3D, 0xE0, 0x91, 0xF2, 0x14, 0x15, 0xBB, 0x7C, 0xD0, 0x11, 0xFA, 0xC2, 0x88, 0xC4, 0x20, 0x20, 0xA8, 0x79, 0xF2, 0x8C, 0x2A, 0x43, 0x87, 0xDF, 0x9B]
+			}
+		];
+
+		for TestCase { key, tag, message } in test_cases {
+			// Authentication
+			let mut lea128pmac = Lea128Pmac::new(&key);
+			for chunk in message.chunks(7) {
+				lea128pmac.update(chunk);
+			}
+			assert_eq!(lea128pmac.finalize().into_bytes(), tag);
+
+			// Verification
+			let mut lea128pmac = Lea128Pmac::from_cipher(Lea128::new(&key));
+			lea128pmac.update(&message);
+			lea128pmac.verify(&tag)?;
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea192pmac() -> Result<(), MacError> {
+		let test_cases: [TestCase<Lea192>; 3] = [
+			TestCase {
+				key: arr![u8; 0x6C, 0xF6, 0x36, 0xED, 0x8A, 0xC1, 0xBA, 0xB0, 0x33, 0xB6, 0x4F, 0x66, 0xFE, 0xAB, 0xA6, 0x5F, 0x70, 0xE6, 0x84, 0x73, 0x1E, 0x3F, 0x39, 0x10],
+				tag: arr![u8; 0xB8, 0x5C, 0x05, 0xFC, 0xE6, 0xEB, 0xB0, 0xFC, 0xEA, 0x1C, 0x12, 0xDA, 0x44, 0x9F, 0xDA, 0x1B],
+				message: vec![]
+			},
+			TestCase {
+				key: arr![u8; 0x56, 0x05, 0x96, 0x8D, 0x3A, 0x96, 0x38, 0x01, 0x12, 0xB5, 0xA1, 0x0F, 0x3A, 0x11, 0xE7, 0x08, 0xDC, 0x54, 0x12, 0x83, 0x3C, 0x47, 0xAB, 0x7C],
+				tag: arr![u8; 0xEA, 0x53, 0x45, 0x10, 0x90, 0x76, 0x84, 0xDC, 0x6D, 0x07, 0x78, 0xB0, 0xCF, 0x9E, 0xDC, 0x62],
+				message: vec![0x36, 0x8A, 0x21, 0xB9, 0xEF, 0xE1, 0x92, 0x93, 0x79, 0x3E, 0xC8, 0x79, 0xCE, 0x68, 0x30, 0x18, 0x18, 0xA8, 0x6E, 0x5A, 0x6C, 0x69, 0x77, 0xDD, 0xBA, 0x0D, 0xAC, 0xA7, 0xFB, 0xA5, 0x19, 0x0F, 0x67, 0xBA, 0x56, 0xCC, 0xDC, 0x1B, 0x3F, 0x31]
+			},
+			TestCase {
+				key: arr![u8; 0x30, 0x89, 0x72, 0x23, 0x6C, 0x2E, 0x47, 0x76, 0x3F, 0xDF, 0xEC, 0x13, 0x71, 0xCE, 0xDC, 0xDB, 0x8C, 0x19, 0x0C, 0xA6, 0xFF, 0x8A, 0xD6, 0x03],
+				tag: arr![u8; 0xC1, 0x80, 0x9F, 0x36, 0x7C, 0xFC, 0x49, 0x09, 0x47, 0xCD, 0x15, 0x2A, 0xA5, 0xC1, 0xA9, 0x48],
+				message: vec![0xF8, 0x17, 0xED, 0xC0, 0xD9, 0x3C, 0x2A, 0x68, 0x7C, 0x7B, 0x36, 0xDD, 0x66, 0xE7, 0x0F, 0x2A, 0x61, 0x00, 0xFC, 0x63, 0x43, 0xED, 0xC8, 0xC8, 0x74, 0x49, 0x6C, 0xB2, 0xF5, 0xBB, 0xFE, 0xC8, 0x8E, 0xA9, 0xB7, 0x7C, 0x27, 0x30, 0x4B, 0x37, 0xF7, 0x0E, 0x94, 0xBC, 0x8A, 0x0F, 0xBF, 0x50, 0x0E, 0x0C, 0x95, 0x7A, 0x80, 0xEB, 0xDA, 0x87, 0x28, 0x0E, 0xF5, 0x82, 0x14, 0xD9, 0x2F, 0x11, 0x98, 0x11, 0xAC, 0xDC, 0x3C, 0x67, 0x1E, 0xF1, 0xE3, 0x91, 0x3F, 0x94, 0x98, 0x0A, 0x9E, 0x14, 0x6B, 0xA8, 0x95, 0x90, 0x85, 0x50, 0xEF, 0x42, 0x34, 0xAB, 0xB7, 0x50, 0x3D, 0x43, 0x65, 0x21, 0xAB, 0xA5, 0x4C, 0x75, 0x50, 0xED, 0xC0, 0xEF, 0x12, 0x02, 0x75, 0x9F, 0xFF, 0x90, 0xFF, 0x19, 0x12, 0x89, 0x36, 0x81, 0x43, 0x21, 0xEE, 0x59, 0xE1, 0x11, 0xE1, 0x3E, 0x5E, 0x48, 0x28, 0x70, 0xD5, 0x8B, 0xB4, 0x4D, 0x9C, 0xFB, 0xFC, 0xCE, 0xA7, 0x87, 0x02, 0xAA, 0xD1, 0x8D, 0x4C, 0xEE, 0xA9, 0x1A, 0xF0, 0xE0, 0x22, 0x43, 0x1D, 0xE3, 0x1B, 0xBE, 0x8D, 0x27, 0x45, 0x48, 0x9A, 0x35, 0xB7, 0x57, 0x34, 0xAF, 0xA2, 0xDA, 0x43, 0x81, 0x7D, 0x40, 0xE7, 0xE8, 0xD8, 0x0D, 0x17, 0xA2, 0x6C, 0xD4, 0x46, 0x0B, 0x00, 0x55, 0xC5, 0x21, 0xA3, 0xFA, 0x43, 0x29, 0xBD, 0x71, 0x8D, 0xB4, 0x6D, 0x8F, 0x02, 0x1C, 0x13, 0xF1, 0xE2, 0xB0]
+			}
+		];
+
+		for TestCase { key, tag, message } in test_cases {
+			// Authentication
+			let mut lea192pmac = Lea192Pmac::new(&key);
+			for chunk in message.chunks(7) {
+				lea192pmac.update(chunk);
+			}
+			assert_eq!(lea192pmac.finalize().into_bytes(), tag);
+
+			// Verification
+			let mut lea192pmac = Lea192Pmac::from_cipher(Lea192::new(&key));
+			lea192pmac.update(&message);
+			lea192pmac.verify(&tag)?;
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea256pmac() -> Result<(), MacError> {
+		let test_cases: [TestCase<Lea256>; 3] = [
+			TestCase {
+				key: arr![u8; 0xE7, 0x26, 0x8B, 0x09, 0xD5, 0x5E, 0x95, 0x8D, 0x25, 0x6E, 0x20, 0x0A, 0x4E, 0x5D, 0xE6, 0xEE, 0xCB, 0xF8, 0xDC, 0x0A, 0xE6, 0x5B, 0x35, 0xAE, 0x3F, 0xAA, 0x1A, 0x5A, 0xC7, 0x8F, 0xE2, 0xDF],
+				tag: arr![u8; 0x9B, 0x20, 0x64, 0x8B, 0x7D, 0x85, 0x81, 0xDA, 0x46, 0xD1, 0x22, 0x3A, 0x98, 0x9F, 0xBB, 0xF4],
+				message: vec![]
+			},
+			TestCase {
+				key: arr![u8; 0x68, 0xF9, 0x9E, 0xBF, 0x27, 0xEC, 0xEE, 0x3C, 0xDD, 0x29, 0xF9, 0xCC, 0xCF, 0x2D, 0xE1, 0x69, 0x06, 0x2D, 0xBC, 0xEC, 0x55, 0xC8, 0xEE, 0x69, 0xCD, 0xAB, 0xDD, 0xBC, 0xCF, 0x3F, 0x44, 0x28],
+				tag: arr![u8; 0x0A, 0xBC, 0x40, 0x5B, 0x76, 0xB0, 0x9E, 0x3D, 0x14, 0x66, 0x06, 0xB7, 0xED, 0x97, 0x56, 0xEB],
+				message: vec![0xC9, 0xB3, 0x1B, 0x61, 0xDF, 0x09, 0xDB, 0x78, 0x38, 0x33, 0xD1, 0xEB, 0x75, 0x59, 0x4E, 0xD2, 0xCB, 0xDF, 0x3A, 0x39, 0x06, 0xA8, 0x31, 0x66, 0x54, 0x47, 0xDD, 0x11, 0xF7, 0xC5, 0x47, 0x59, 0xA4, 0x82, 0x66, 0xAD, 0xFB, 0xD7, 0x89, 0x54]
+			},
+			TestCase {
+				key: arr![u8; 0xF0, 0x07, 0x1D, 0xE0, 0xF8, 0x42, 0x2D, 0x94, 0xF6, 0xFB, 0x43, 0x09, 0x1B, 0x98, 0x6F, 0x58, 0xBA, 0xC9, 0x50, 0x6F, 0x9B, 0xFB, 0x82, 0x1D, 0x62, 0xE6, 0x93, 0x30, 0x41, 0x0B, 0xB5, 0x6F],
+				tag: arr![u8; 0x8D, 0x0F, 0x64, 0x10, 0x40, 0x8C, 0xCE, 0xAA, 0xCF, 0xF1, 0x82, 0x70, 0xC1, 0x2C, 0x73, 0x14],
+				message: vec![0x00, 0x85, 0xEC, 0xCE, 0x89, 0xAF, 0xB8, 0xF0, 0xBD, 0xBC, 0xAB, 0x32, 0x5D, 0x6E, 0x11, 0xF2, 0xAA, 0xEB, 0x54, 0x9F, 0x50, 0xA9, 0xD9, 0x1F, 0xB8, 0xE6, 0x4C, 0x81, 0x4F, 0xAA, 0x68, 0x53, 0x67, 0xB2, 0x4B, 0x8D, 0x20, 0x31, 0x6B, 0xAA, 0xF0, 0x61, 0xAD, 0xBF, 0xE7, 0x2C, 0x9D, 0x91, 0x4D, 0x67, 0x8C, 0xD5, 0x00, 0x4D, 0x49, 0x35, 0x6E, 0xC9, 0x94, 0x9B, 0xA7, 0x52, 0x77, 0x71, 0x71, 0xAC, 0x36, 0x82, 0x79, 0xCB, 0xE6, 0xF5, 0xCB, 0xBC, 0x2B, 0xA8, 0x15, 0x48, 0x83, 0xA9, 0xA2, 0x9E, 0x55, 0x17, 0xD1, 0xF3, 0xC0, 0x3C, 0xAC, 0x4F, 0x39, 0xCE, 0x32, 0x25, 0x06, 0x0B, 0x3E, 0xFB, 0x79, 0x9C, 0xD9, 0xC4, 0x12, 0x74, 0x6A, 0xE2, 0xA1, 0x93, 0x31, 0xB7, 0xB2, 0x62, 0x7E, 0x66, 0x3E, 0x25, 0xA7, 0xB0, 0x01, 0xE4, 0xC0, 0xDC, 0xC5, 0xE2, 0x1B, 0xC7, 0x6C, 0x38, 0x2D, 0xCD, 0xF5, 0xB2, 0x84, 0x76, 0x0C, 0x8E, 0x3F, 0xEA, 0xD9, 0x1F, 0x74, 0x22, 0xCD, 0x76, 0xAA, 0x87, 0xFC, 0x8F, 0x98, 0x51, 0xF3, 0xC1, 0xE4, 0x71, 0x9C, 0xD0, 0xB8, 0xE4, 0x81, 0x6D, 0xD4, 0xE8, 0x8C, 0x72, 0xE5, 0x28, 0xBE, 0xDC, 0x79, 0x73, 0x42, 0xC0, 0x3F, 0xD7, 0xA3, 0x46, 0xC4, 0xC7, 0x85, 0x7C, 0xA0, 0x3D, 0x46, 0x70, 0x13, 0xB6, 0x49, 0x3C, 0x45, 0x55, 0x51, 0xE4, 0x8A, 0x14, 0x23, 0x26, 0x3B, 0x62, 0xB1, 0x27]
+			}
+		];
+
+		for TestCase { key, tag, message } in test_cases {
+			// Authentication
+			let mut lea256pmac = Lea256Pmac::new(&key);
+			for chunk in message.chunks(7) {
+				lea256pmac.update(chunk);
+			}
+			assert_eq!(lea256pmac.finalize().into_bytes(), tag);
+
+			// Verification
+			let mut lea256pmac = Lea256Pmac::from_cipher(Lea256::new(&key));
+			lea256pmac.update(&message);
+			lea256pmac.verify(&tag)?;
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128pmac_reject() {
+		let mut lea128pmac = Lea128Pmac::new(&Default::default());
+		lea128pmac.update(&[0; 200]);
+		let mut tag = lea128pmac.clone().finalize().into_bytes();
+		tag[15] ^= 1;
+		assert_eq!(lea128pmac.verify(&tag), Err(MacError));
+	}
+}
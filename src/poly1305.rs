@@ -0,0 +1,351 @@
+// Copyright © 2023 남기훈 <gihunnam@proton.me>
+//
+// This file and its content are subject to the terms of the MIT License (the "License").
+// If a copy of the License was not distributed with this file, you can obtain one at https://opensource.org/licenses/MIT.
+
+//! Poly1305-LEA
+//!
+//! Poly1305-AES as specified by Bernstein, with LEA in place of AES.
+//! The tag is Poly1305<sub>r</sub>(m) + E<sub>K</sub>(n) mod 2<sup>128</sup>, where r is a secret 16-byte Poly1305 key clamped as specified,
+//! and n is a 16-byte nonce.
+//! Unlike GHASH, Poly1305 needs only integer multiplication, which makes it fast in software without carry-less multiply.
+//!
+//! A nonce must never be reused with the same key and r.
+//!
+//! * Example
+//! ```
+//! use lea::{poly1305::{Error, Poly1305Lea}, prelude::*, Lea128};
+//!
+//! let key = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+//! let r = arr![u8; 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F];
+//! let nonce = arr![u8; 0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2A, 0x2B, 0x2C, 0x2D, 0x2E, 0x2F];
+//! let tag = arr![u8; 0x3D, 0x4D, 0x57, 0xBC, 0xB4, 0xEB, 0x2F, 0xA2, 0x85, 0x94, 0x9F, 0x60, 0x4E, 0x98, 0xEB, 0x1C];
+//!
+//! let lea128 = Lea128::new(&key);
+//!
+//! // Authentication
+//! let mut poly1305 = Poly1305Lea::new(&lea128, &r, &nonce);
+//! poly1305.update(b"head");
+//! poly1305.update(b"er");
+//! assert_eq!(poly1305.finalize(), tag);
+//!
+//! // Verification
+//! let mut poly1305 = Poly1305Lea::new(&lea128, &r, &nonce);
+//! poly1305.update(b"header");
+//! poly1305.verify(&tag)?;
+//! # Ok::<(), Error>(())
+//! ```
+
+pub use poly1305;
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use cipher::{generic_array::{typenum::U16, GenericArray}, BlockCipher, BlockEncrypt};
+use poly1305::{universal_hash::{NewUniversalHash, UniversalHash}, Key, Poly1305};
+use subtle::ConstantTimeEq;
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+use crate::{Lea128, Lea192, Lea256};
+
+pub type Lea128Poly1305 = Poly1305Lea<Lea128>;
+pub type Lea192Poly1305 = Poly1305Lea<Lea192>;
+pub type Lea256Poly1305 = Poly1305Lea<Lea256>;
+
+type Block = GenericArray<u8, U16>;
+
+/// Error of Poly1305-LEA
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Error {
+	/// Tag does not match.
+	InvalidTag
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::InvalidTag => "invalid tag"
+		})
+	}
+}
+
+/// Poly1305 with the tag masked by E<sub>K</sub>(n)
+pub struct Poly1305Lea<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	poly1305: Poly1305,
+	block: Block,
+	pos: usize,
+	_pd: PhantomData<C>
+}
+
+impl<C> Poly1305Lea<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	/// `r` is clamped before use, so the cleared bits may have any value.
+	pub fn new(cipher: &C, r: &Block, nonce: &Block) -> Self {
+		let mut key = Key::default();
+		key[..16].copy_from_slice(r);
+		key[16..].copy_from_slice(nonce);
+		cipher.encrypt_block(GenericArray::from_mut_slice(&mut key[16..]));
+
+		let poly1305 = Poly1305::new(&key);
+		#[cfg(feature = "zeroize")]
+		key.zeroize();
+
+		Self { poly1305, block: Block::default(), pos: 0, _pd: PhantomData }
+	}
+
+	pub fn update(&mut self, mut data: &[u8]) {
+		while !data.is_empty() {
+			let n = data.len().min(16 - self.pos);
+			self.block[self.pos..(self.pos + n)].copy_from_slice(&data[..n]);
+			self.pos += n;
+			data = &data[n..];
+
+			if self.pos == 16 {
+				self.poly1305.update(&self.block);
+				self.pos = 0;
+			}
+		}
+	}
+
+	pub fn finalize(self) -> Block {
+		self.poly1305.compute_unpadded(&self.block[..self.pos]).into_bytes()
+	}
+
+	/// Fails if the tag does not match. Tags are compared in constant time.
+	pub fn verify(self, tag: &Block) -> Result<(), Error> {
+		if self.finalize().ct_eq(tag).into() {
+			Ok(())
+		} else {
+			Err(Error::InvalidTag)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	extern crate alloc;
+
+	use alloc::{vec::Vec, vec};
+
+	use cipher::{consts::U16, BlockCipher, BlockCipherKey, NewBlockCipher};
+
+	use crate::{poly1305::{Error, Poly1305Lea}, prelude::*, Lea128, Lea192, Lea256, Lea128Poly1305, Lea192Poly1305, Lea256Poly1305};
+
+	struct TestCase<T> where
+	T: BlockCipher + NewBlockCipher {
+		key: BlockCipherKey<T>,
+		r: GenericArray<u8, U16>,
+		nonce: GenericArray<u8, U16>,
+		tag: GenericArray<u8, U16>,
+		message: Vec<u8>
+	}
+
+	#[test]
+	fn lea128poly1305() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea128>; 3] = [
+			TestCase {
+				key: arr![u8; 0xEA, 0x10, 0x7D, 0x90, 0x69, 0x41, 0xB2, 0x8D, 0xE1, 0x0E, 0x42, 0x1E, 0x67, 0xEB, 0x1B, 0x5A],
+				r: arr![u8; 0xA0, 0x3A, 0x80, 0xA6, 0x4A, 0x31, 0xD0, 0x19, 0xA1, 0xAB, 0x74, 0x4E, 0x9E, 0x53, 0x9E, 0x95],
+				nonce: arr![u8; 0xEC, 0x50, 0x6B, 0xFB, 0xDD, 0x20, 0x57, 0x68, 0x04, 0x2D, 0xEF, 0x9B, 0x39, 0x61, 0x7B, 0xEC],
+				tag: arr![u8; 0xA9, 0x88, 0x23, 0x98, 0x48, 0x13, 0x5B, 0x46, 0x04, 0x3F, 0xA1, 0xC5, 0x74, 0x6A, 0x41, 0xFA],
+				message: vec![]
+			},
+			TestCase {
+				key: arr![u8; 0xE8, 0x32, 0xF1, 0xA9, 0xE8, 0x91, 0x7E, 0x23, 0x13, 0x4C, 0xC5, 0x5F, 0xE4, 0x37, 0xD4, 0x61],
+				r: arr![u8; 0x09, 0xD6, 0xF5, 0x7B, 0x71, 0xE1, 0x63, 0x57, 0x51, 0x9C, 0x81, 0x2E, 0x58, 0xAC, 0x53, 0x7F],
+				nonce: arr![u8; 0x1D, 0x7B, 0x5F, 0x1C, 0x78, 0x6D, 0xD6, 0xDC, 0xF3, 0x9F, 0x9E, 0x7F, 0x10, 0xCC, 0x15, 0x50],
+				tag: arr![u8; 0x22, 0x82, 0x16, 0x59, 0x83, 0xC4, 0x93, 0xFE, 0x2D, 0x46, 0x00, 0x2A, 0x28, 0x9B, 0x91, 0xBC],
+				message: vec![0x53, 0x9F, 0x0E, 0x9B, 0xB9, 0xC9, 0xDA, 0xA4, 0x12, 0x83, 0xF3, 0x82, 0x0B, 0x6D, 0x15, 0xBB, 0x90]
+			},
+			TestCase {
+				key: arr![u8; 0x39, 0x12, 0x0D, 0x9C, 0x09, 0x85, 0x3E, 0x54, 0x5F, 0x0C, 0x09, 0xB6, 0x66, 0xAC, 0xD3, 0xF7],
+				r: arr![u8; 0x11, 0x59, 0x96, 0x0E, 0xF7, 0x30, 0x7F, 0xE4, 0xF5, 0x87, 0xF4, 0x76, 0xE7, 0xC7, 0xA8, 0xE6],
+				nonce: arr![u8; 0xF4, 0x0C, 0x66, 0xDD, 0xB9, 0x49, 0xC9, 0xDE, 0x1A, 0x6A, 0x04, 0x02, 0x51, 0xA7, 0x3D, 0x68],
+				tag: arr![u8; 0xE3, 0x7F, 0x5F, 0xF3, 0x30, 0x98, 0xE5, 0x05, 0x0E, 0xA4, 0x31, 0xC6, 0x1A, 0xEE, 0xC6, 0x77],
+				message: vec![0xAE, 0xDF, 0xB7, 0x06, 0x6B, 0x3B, 0x44, 0x54, 0xC8, 0x3C, 0x22, 0x71, 0xDA, 0xF9, 0x2F, 0x04, 0x21, 0x96, 0x9A, 0x06, 0x70, 0xF7, 0xBF, 0x43, 0xDC, 0x74, 0x25, 0xC5, 0x50, 0x28, 0x11, 0xE1, 0x7A, 0xED, 0xC8, 0x3B, 0x98, 0xFC, 0xF0, 0x78, 0xF1, 0x4B, 0x99, 0xFE, 0xE8, 0xB3, 0xD6, 0x74, 0x19, 0x3F, 0x24, 0x06, 0x3B, 0x2B, 0x67, 0xF8, 0x9A, 0x7C, 0xCE, 0x5A, 0xF5, 0x66, 0xF7, 0xD6]
+			}
+		];
+
+		for TestCase { key, r, nonce, tag, message } in test_cases {
+			let lea128 = Lea128::new(&key);
+
+			// Authentication
+			let mut lea128poly1305 = Lea128Poly1305::new(&lea128, &r, &nonce);
+			for chunk in message.chunks(7) {
+				lea128poly1305.update(chunk);
+			}
+			assert_eq!(lea128poly1305.finalize(), tag);
+
+			// Verification
+			let mut lea128poly1305 = Lea128Poly1305::new(&lea128, &r, &nonce);
+			lea128poly1305.update(&message);
+			lea128poly1305.verify(&tag)?;
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea192poly1305() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea192>; 3] = [
+			TestCase {
+				key: arr![u8; 0x99, 0x25, 0x06, 0x57, 0x3A, 0xD9, 0xBD, 0xF0, 0xD2, 0x66, 0xA8, 0xA6, 0xC5, 0x43, 0x36, 0xDF, 0x2B, 0x88, 0x0F, 0x1C, 0xBF, 0x6F, 0x40, 0xA6],
+				r: arr![u8; 0xDB, 0x3F, 0xF3, 0xA6, 0xE8, 0xF7, 0xB8, 0x6B, 0xF3, 0x7F, 0xAD, 0x67, 0xAF, 0xED, 0x5A, 0x14],
+				nonce: arr![u8; 0x84, 0xE0, 0x5B, 0x1F, 0x49, 0xC8, 0x98, 0xCA, 0x4B, 0x57, 0x9E, 0x0E, 0x67, 0x19, 0x50, 0x5C],
+				tag: arr![u8; 0xA9, 0x09, 0xE4, 0xAF, 0x30, 0xD4, 0x13, 0xCD, 0x82, 0xC7, 0xC7, 0xFF, 0xB2, 0x43, 0xFE, 0x08],
+				message: vec![]
+			},
+			TestCase {
+				key: arr![u8; 0x5F, 0xD8, 0x52, 0x8B, 0x25, 0xC9, 0xA4, 0x25, 0x2E, 0x06, 0xB8, 0xAE, 0xE1, 0x55, 0x89, 0x30, 0x33, 0xB9, 0x7C, 0xEA, 0x1F, 0xCA, 0x0E, 0x45],
+				r: arr![u8; 0x72, 0x7F, 0x7C, 0xEC, 0x73, 0x60, 0x1B, 0xE1, 0xFE, 0xE4, 0xBD, 0x09, 0x55, 0xE1, 0x4D, 0x80],
+				nonce: arr![u8; 0x5E, 0x99, 0x3E, 0xFE, 0x65, 0xBB, 0xE5, 0xEC, 0x7D, 0x6F, 0x1D, 0x65, 0x88, 0x9F, 0xFD, 0x29],
+				tag: arr![u8; 0xAC, 0x83, 0x61, 0x57, 0xB1, 0xF3, 0xDA, 0x03, 0x5F, 0x8D, 0xFA, 0xE3, 0x79, 0xDF, 0xB3, 0x22],
+				message: vec![0x2C, 0x74, 0x03, 0x1A, 0xDE, 0x87, 0xE2, 0x70, 0x2F, 0xFE, 0x19, 0xEF, 0xDD, 0x6E, 0x91, 0xD6, 0x9F]
+			},
+			TestCase {
+				key: arr![u8; 0x29, 0xFB, 0x38, 0x61, 0xF4, 0x30, 0x96, 0xE8, 0xDA, 0xA0, 0x32, 0x40, 0x0D, 0x08, 0xCB, 0x24, 0x34, 0xEA, 0xCD, 0x0D, 0xDC, 0xE8, 0xDC, 0xB3],
+				r: arr![u8; 0xD1, 0x54, 0x00, 0xA0, 0xEF, 0x8B, 0x49, 0x32, 0x00, 0xEA, 0x44, 0x99, 0x41, 0xF7, 0x57, 0xB8],
+				nonce: arr![u8; 0xD0, 0xB6, 0xD5, 0x80, 0xD5, 0x56, 0xF0, 0xE1, 0x9F, 0x75, 0x6A, 0x32, 0xA4, 0xDA, 0x7B, 0x52],
+				tag: arr![u8; 0x19, 0x22, 0xD3, 0x03, 0x25, 0x29, 0xF5, 0xDD, 0xE5, 0x92, 0x85, 0x56, 0xC4, 0xE5, 0xD5, 0x69],
+				message: vec![0xD0, 0x76, 0xF8, 0xC5, 0xC8, 0x58, 0x7F, 0x49, 0xA2, 0x61, 0xA3, 0x41, 0x49, 0xF4, 0x81, 0xEB, 0x45, 0x6B, 0x8E, 0xBF, 0x36, 0x63, 0xB8, 0x06, 0xD1, 0xFD, 0x10, 0x92, 0x09, 0x15, 0xC2, 0xD2, 0xB9, 0x71, 0x43, 0xC7, 0x52, 0x40, 0xAE, 0xCC, 0xAF, 0xE9, 0x0C, 0x52, 0xD0, 0x03, 0x3C, 0x57, 0x20, 0xE6, 0x25, 0x39, 0x1F, 0x5A, 0x19, 0x33, 0x15, 0xD4, 0xEE, 0x8D, 0xAA, 0xB6, 0x38, 0x1E]
+			}
+		];
+
+		for TestCase { key, r, nonce, tag, message } in test_cases {
+			let lea192 = Lea192::new(&key);
+
+			// Authentication
+			let mut lea192poly1305 = Lea192Poly1305::new(&lea192, &r, &nonce);
+			for chunk in message.chunks(7) {
+				lea192poly1305.update(chunk);
+			}
+			assert_eq!(lea192poly1305.finalize(), tag);
+
+			// Verification
+			let mut lea192poly1305 = Lea192Poly1305::new(&lea192, &r, &nonce);
+			lea192poly1305.update(&message);
+			lea192poly1305.verify(&tag)?;
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea256poly1305() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea256>; 3] = [
+			TestCase {
+				key: arr![u8; 0xC0, 0x88, 0x1F, 0x3D, 0x44, 0x02, 0xAB, 0x50, 0x2E, 0xC2, 0xC1, 0x6F, 0x38, 0xC6, 0x2D, 0x4D, 0xB6, 0x7C, 0xBB, 0x9F, 0xE7, 0xE7, 0x85, 0x89, 0x38, 0x45, 0x05, 0xCC, 0x2A, 0xCF, 0xB6, 0x5B],
+				r: arr![u8; 0x1A, 0xE0, 0xE6, 0x23, 0x9F, 0x8C, 0xA8, 0xFC, 0x0B, 0x32, 0x31, 0x65, 0xF1, 0x8A, 0x1F, 0x2A],
+				nonce: arr![u8; 0x81, 0x31, 0x91, 0x32, 0xDA, 0x50, 0x63, 0x1C, 0xBB, 0x3D, 0x2B, 0x1F, 0x5F, 0xB1, 0xA7, 0xB8],
+				tag: arr![u8; 0x60, 0xBC, 0x8F, 0xA5, 0x1A, 0x54, 0x0A, 0x28, 0x9A, 0x23, 0x81, 0x8C, 0x02, 0xD9, 0x7E, 0x5D],
+				message: vec![]
+			},
+			TestCase {
+				key: arr![u8; 0x3E, 0x6B, 0xC2, 0xBB, 0xD5, 0x49, 0x3E, 0xA7, 0xFF, 0x17, 0xF4, 0x41, 0x7A, 0xC6, 0x16, 0x27, 0xFF, 0x07, 0x29, 0x08, 0xD6, 0x43, 0xD1, 0x51, 0x46, 0x2F, 0xEE, 0xD1, 0x17, 0x10, 0xD0, 0xC9],
+				r: arr![u8; 0x9B, 0x24, 0xCC, 0x10, 0x4C, 0x3E, 0xDB, 0x2B, 0x1B, 0x85, 0x2A, 0xF8, 0xA6, 0xE1, 0xCC, 0xB8],
+				nonce: arr![u8; 0x18, 0x04, 0x95, 0x55, 0xB9, 0xCF, 0x50, 0x7C, 0xAA, 0xB7, 0x16, 0x2C, 0x75, 0xA4, 0x0C, 0x8F],
+				tag: arr![u8; 0x1D, 0x15, 0x81, 0x84, 0x7C, 0xAB, 0xCD, 0x5B, 0x4B, 0x94, 0x83, 0x01, 0x77, 0xDA, 0x36, 0x99],
+				message: vec![0x4E, 0x87, 0xC8, 0x1B, 0x3D, 0xF2, 0x62, 0x4F, 0x17, 0x64, 0xAA, 0x79, 0xC5, 0x0A, 0xB2, 0x58, 0xC8]
+			},
+			TestCase {
+				key: arr![u8; 0x8A, 0xA2, 0x75, 0x57, 0x99, 0xFA, 0x07, 0x8F, 0xAC, 0x4A, 0x6A, 0x8C, 0xF7, 0xD1, 0x92, 0xCB, 0x26, 0xD3, 0xEF, 0xE1, 0xB2, 0x58, 0x3F, 0xB7, 0x99, 0xB6, 0x78, 0x79, 0x3C, 0xF9, 0x42, 0x02],
+				r: arr![u8; 0x55, 0xD6, 0xE6, 0x35, 0x54, 0x39, 0x21, 0xC7, 0x8D, 0xBA, 0x72, 0x77, 0x9F, 0x9C, 0x12, 0x6C],
+				nonce: arr![u8; 0xEF, 0xED, 0x0C, 0xD2, 0x8E, 0x1E, 0xB5, 0x6E, 0xD3, 0x21, 0xEA, 0xE7, 0x26, 0x5F, 0xC3, 0xC3],
+				tag: arr![u8; 0x3C, 0x2F, 0x0B, 0x66, 0xAF, 0xF6, 0xE0, 0x56, 0xAD, 0xAB, 0xA8, 0xAC, 0x62, 0x43, 0xF6, 0x21],
+				message: vec![0x38, 0x8A, 0xFD, 0xAD, 0xDC, 0x6B, 0xDB, 0x2E, 0xDB, 0x82, 0x14, 0x2D, 0xA4, 0x3B, 0x0E, 0xAC, 0x2C, 0xA8, 0xBD, 0x4F, 0xA2, 0xBD, 0x83, 0x76, 0xE2, 0x48, 0x04, 0x08, 0x49, 0x35, 0xE2, 0xED, 0x99, 0x1D, 0xC8, 0x59, 0x0D, 0x20, 0x37, 0x7C, 0x1E, 0x0B, 0x3B, 0x0E, 0xC7, 0xE0, 0x74, 0x6F, 0x67, 0x3E, 0xBD, 0xDD, 0x6A, 0x9E, 0x14, 0x95, 0x8B, 0x07, 0x87, 0xCE, 0x29, 0x87, 0x19, 0xFD]
+			}
+		];
+
+		for TestCase { key, r, nonce, tag, message } in test_cases {
+			let lea256 = Lea256::new(&key);
+
+			// Authentication
+			let mut lea256poly1305 = Lea256Poly1305::new(&lea256, &r, &nonce);
+			for chunk in message.chunks(7) {
+				lea256poly1305.update(chunk);
+			}
+			assert_eq!(lea256poly1305.finalize(), tag);
+
+			// Verification
+			let mut lea256poly1305 = Lea256Poly1305::new(&lea256, &r, &nonce);
+			lea256poly1305.update(&message);
+			lea256poly1305.verify(&tag)?;
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128poly1305_nonce() {
+		let lea128 = Lea128::new(&Default::default());
+		let r = arr![u8; 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F];
+		let message = [0x55; 40];
+
+		let tag = |nonce: &GenericArray<u8, U16>| {
+			let mut poly1305 = Poly1305Lea::new(&lea128, &r, nonce);
+			poly1305.update(&message);
+
+			u128::from_le_bytes(poly1305.finalize().into())
+		};
+
+		// Tags of distinct nonces differ by the difference of the encrypted nonces.
+		let nonce_1 = GenericArray::default();
+		let nonce_2 = arr![u8; 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+		let (mut s_1, mut s_2) = (nonce_1, nonce_2);
+		lea128.encrypt_block(&mut s_1);
+		lea128.encrypt_block(&mut s_2);
+
+		let s_1 = u128::from_le_bytes(s_1.into());
+		let s_2 = u128::from_le_bytes(s_2.into());
+		assert_ne!(tag(&nonce_1), tag(&nonce_2));
+		assert_eq!(tag(&nonce_1).wrapping_sub(s_1), tag(&nonce_2).wrapping_sub(s_2));
+
+		// Clamped bits of r are ignored.
+		let mut unclamped_r = r;
+		unclamped_r[3] |= 0xF0;
+		unclamped_r[4] |= 0x03;
+		let mut poly1305 = Poly1305Lea::new(&lea128, &unclamped_r, &nonce_1);
+		poly1305.update(&message);
+		assert_eq!(u128::from_le_bytes(poly1305.finalize().into()), tag(&nonce_1));
+	}
+
+	#[test]
+	fn lea128poly1305_rejection() -> Result<(), Error> {
+		let lea128 = Lea128::new(&Default::default());
+		let r = arr![u8; 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F];
+		let nonce = GenericArray::default();
+		let message = [0; 40];
+
+		let mut poly1305 = Lea128Poly1305::new(&lea128, &r, &nonce);
+		poly1305.update(&message);
+		let tag = poly1305.finalize();
+
+		// Tampered message
+		let mut poly1305 = Lea128Poly1305::new(&lea128, &r, &nonce);
+		poly1305.update(&message[..39]);
+		poly1305.update(&[1]);
+		assert_eq!(poly1305.verify(&tag), Err(Error::InvalidTag));
+
+		// Zero-padded message
+		let mut poly1305 = Lea128Poly1305::new(&lea128, &r, &nonce);
+		poly1305.update(&message);
+		poly1305.update(&[0]);
+		assert_eq!(poly1305.verify(&tag), Err(Error::InvalidTag));
+
+		// Tampered tag
+		let mut tampered_tag = tag;
+		tampered_tag[0] ^= 1;
+		let mut poly1305 = Lea128Poly1305::new(&lea128, &r, &nonce);
+		poly1305.update(&message);
+		assert_eq!(poly1305.verify(&tampered_tag), Err(Error::InvalidTag));
+
+		// Another nonce
+		let mut poly1305 = Lea128Poly1305::new(&lea128, &r, &arr![u8; 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+		poly1305.update(&message);
+		assert_eq!(poly1305.verify(&tag), Err(Error::InvalidTag));
+
+		Ok(())
+	}
+}
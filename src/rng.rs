@@ -0,0 +1,244 @@
+// Copyright © 2023 남기훈 <gihunnam@proton.me>
+//
+// This file and its content are subject to the terms of the MIT License (the "License").
+// If a copy of the License was not distributed with this file, you can obtain one at https://opensource.org/licenses/MIT.
+
+//! LEA-CTR random number generator
+//!
+//! A deterministic [`SeedableRng`] whose output is the `Lea128Ctr` keystream,
+//! with the seed as the key and the nonce made of a 64-bit stream number followed by a zero counter.
+//! Keystream is generated 8 blocks at a time, so that blocks are encrypted in parallel.
+//!
+//! Each stream has 2<sup>66</sup> 32-bit words, and [`CtrRng::set_stream`] and [`CtrRng::set_word_pos`] jump anywhere in constant time,
+//! e.g. to give each thread its own stream of the same seed.
+//!
+//! The generator never reseeds; use `CtrDrbg` where a DRBG is required.
+//!
+//! * Example
+//! ```
+//! use lea::{prelude::*, Lea128CtrRng};
+//!
+//! let seed = arr![u8; 0x7A, 0xD3, 0x6A, 0x75, 0xD5, 0x5F, 0x30, 0x22, 0x09, 0x4E, 0x06, 0xF7, 0xC8, 0x97, 0xD8, 0xBB];
+//! let keystream = [0x23, 0x09, 0xCA, 0x80, 0x8E, 0xDA, 0x46, 0xCB, 0x5E, 0x93, 0xDE, 0x64, 0x28, 0x09, 0x5A, 0x9E];
+//!
+//! let mut lea128ctrrng = Lea128CtrRng::from_seed(seed);
+//!
+//! // Nonce 0C 5F 04 E8 B5 12 19 5E 74 B3 DE 57 E9 70 97 9E
+//! lea128ctrrng.set_stream(0x0C5F04E8B512195E);
+//! lea128ctrrng.set_word_pos(0x74B3DE57E970979E * 4);
+//!
+//! let mut buffer = [0; 16];
+//! lea128ctrrng.fill_bytes(&mut buffer);
+//! assert_eq!(buffer, keystream);
+//! ```
+
+pub use rand_core;
+
+use cipher::{generic_array::{typenum::U16, GenericArray}, BlockCipher, BlockCipherKey, BlockEncrypt, NewBlockCipher};
+use rand_core::{block::{BlockRng, BlockRngCore}, RngCore, SeedableRng};
+
+use crate::{Lea128, Lea192, Lea256};
+
+pub type Lea128CtrRng = CtrRng<Lea128>;
+pub type Lea192CtrRng = CtrRng<Lea192>;
+pub type Lea256CtrRng = CtrRng<Lea256>;
+
+type Block = GenericArray<u8, U16>;
+
+/// Number of blocks generated at a time
+const BATCH_LEN: usize = 8;
+/// Number of 32-bit words in a stream
+const STREAM_LEN: u128 = 1 << 66;
+
+/// CTR random number generator
+pub struct CtrRng<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	rng: BlockRng<CtrRngCore<C>>
+}
+
+impl<C> From<C> for CtrRng<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	fn from(cipher: C) -> Self {
+		Self { rng: BlockRng::new(CtrRngCore { cipher, stream: 0, counter: 0 }) }
+	}
+}
+
+impl<C> CtrRng<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	/// Stream number, i.e. the first 64 bits of the counter block
+	pub fn stream(&self) -> u64 {
+		self.rng.core.stream
+	}
+
+	/// Switches to another stream, keeping the word position.
+	pub fn set_stream(&mut self, stream: u64) {
+		let word_pos = self.word_pos();
+		self.rng.core.stream = stream;
+		self.set_word_pos(word_pos);
+	}
+
+	/// Position in the stream, in 32-bit words
+	pub fn word_pos(&self) -> u128 {
+		let remaining = (BATCH_LEN * 4 - self.rng.index()) as u128;
+
+		(self.rng.core.counter as u128 * 4 + STREAM_LEN - remaining) % STREAM_LEN
+	}
+
+	/// Jumps to `word_pos` 32-bit words into the stream, wrapping around at 2<sup>66</sup>.
+	pub fn set_word_pos(&mut self, word_pos: u128) {
+		let word_pos = word_pos % STREAM_LEN;
+		self.rng.core.counter = (word_pos / 4) as u64;
+
+		if word_pos % 4 == 0 {
+			self.rng.reset();
+		} else {
+			self.rng.generate_and_set((word_pos % 4) as usize);
+		}
+	}
+}
+
+impl<C> RngCore for CtrRng<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	fn next_u32(&mut self) -> u32 {
+		self.rng.next_u32()
+	}
+
+	fn next_u64(&mut self) -> u64 {
+		self.rng.next_u64()
+	}
+
+	fn fill_bytes(&mut self, dest: &mut [u8]) {
+		self.rng.fill_bytes(dest)
+	}
+
+	fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+		self.rng.try_fill_bytes(dest)
+	}
+}
+
+impl<C> SeedableRng for CtrRng<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher {
+	type Seed = BlockCipherKey<C>;
+
+	fn from_seed(seed: Self::Seed) -> Self {
+		Self::from(C::new(&seed))
+	}
+}
+
+/// Block generator of [`CtrRng`]
+pub struct CtrRngCore<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	cipher: C,
+	stream: u64,
+	/// Counter of the next block
+	counter: u64
+}
+
+impl<C> BlockRngCore for CtrRngCore<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	type Item = u32;
+	type Results = [u32; BATCH_LEN * 4];
+
+	fn generate(&mut self, results: &mut Self::Results) {
+		let mut blocks = [Block::default(); BATCH_LEN];
+		for block in blocks.iter_mut() {
+			block[..8].copy_from_slice(&self.stream.to_be_bytes());
+			block[8..].copy_from_slice(&self.counter.to_be_bytes());
+			self.counter = self.counter.wrapping_add(1);
+		}
+
+		self.cipher.encrypt_blocks(&mut blocks);
+
+		for (results, block) in results.chunks_exact_mut(4).zip(blocks.iter()) {
+			for (result, word) in results.iter_mut().zip(block.chunks_exact(4)) {
+				*result = u32::from_le_bytes(word.try_into().unwrap());
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	extern crate alloc;
+
+	use alloc::vec::Vec;
+
+	use crate::{prelude::*, rng::STREAM_LEN, Lea128, Lea128CtrRng};
+
+	/// Keystream of `blocks` counter blocks from `counter`
+	fn keystream(stream: u64, counter: u64, blocks: usize) -> Vec<u8> {
+		let lea128 = Lea128::new(&Default::default());
+
+		(0..blocks as u64).flat_map(|i| {
+			let mut block = GenericArray::default();
+			block[..8].copy_from_slice(&stream.to_be_bytes());
+			block[8..].copy_from_slice(&counter.wrapping_add(i).to_be_bytes());
+			lea128.encrypt_block(&mut block);
+
+			block
+		}).collect()
+	}
+
+	#[test]
+	fn lea128ctrrng() {
+		let mut lea128ctrrng = Lea128CtrRng::from_seed(Default::default());
+		assert_eq!((lea128ctrrng.stream(), lea128ctrrng.word_pos()), (0, 0));
+
+		let mut buffer = [0; 300];
+		lea128ctrrng.fill_bytes(&mut buffer);
+		assert_eq!(buffer[..], keystream(0, 0, 19)[..300]);
+		assert_eq!(lea128ctrrng.word_pos(), 75);
+
+		// Words are little-endian.
+		let keystream = keystream(0, 0, 20);
+		let word = |i: usize| u32::from_le_bytes(keystream[(4 * i)..(4 * i + 4)].try_into().unwrap());
+		assert_eq!(lea128ctrrng.next_u32(), word(75));
+		assert_eq!(lea128ctrrng.next_u64(), word(76) as u64 | (word(77) as u64) << 32);
+		assert_eq!(lea128ctrrng.word_pos(), 78);
+	}
+
+	#[test]
+	fn lea128ctrrng_seek() {
+		let keystream = keystream(7, 0, 40);
+		let word = |i: usize| u32::from_le_bytes(keystream[(4 * i)..(4 * i + 4)].try_into().unwrap());
+
+		let mut lea128ctrrng = Lea128CtrRng::from_seed(Default::default());
+		lea128ctrrng.set_stream(7);
+
+		for word_pos in [0, 1, 3, 4, 31, 32, 33, 100, 155, 3, 0] {
+			lea128ctrrng.set_word_pos(word_pos);
+			assert_eq!(lea128ctrrng.word_pos(), word_pos);
+			assert_eq!(lea128ctrrng.next_u32(), word(word_pos as usize));
+			assert_eq!(lea128ctrrng.word_pos(), word_pos + 1);
+		}
+
+		// Switching streams keeps the position.
+		lea128ctrrng.set_word_pos(33);
+		lea128ctrrng.set_stream(8);
+		assert_eq!((lea128ctrrng.stream(), lea128ctrrng.word_pos()), (8, 33));
+		assert_ne!(lea128ctrrng.next_u32(), word(33));
+		lea128ctrrng.set_stream(7);
+		assert_eq!(lea128ctrrng.next_u32(), word(34));
+
+		// The counter wraps around.
+		lea128ctrrng.set_word_pos(STREAM_LEN - 4);
+		let mut buffer = [0; 32];
+		lea128ctrrng.fill_bytes(&mut buffer);
+		assert_eq!(buffer[..16], self::keystream(7, u64::MAX, 1)[..]);
+		assert_eq!(buffer[16..], keystream[..16]);
+		assert_eq!(lea128ctrrng.word_pos(), 4);
+		lea128ctrrng.set_word_pos(STREAM_LEN + 5);
+		assert_eq!(lea128ctrrng.word_pos(), 5);
+	}
+
+	#[test]
+	fn lea128ctrrng_seed_from_u64() {
+		let mut lea128ctrrng_1 = Lea128CtrRng::seed_from_u64(42);
+		let mut lea128ctrrng_2 = Lea128CtrRng::seed_from_u64(42);
+		let mut lea128ctrrng_3 = Lea128CtrRng::seed_from_u64(43);
+
+		let (x, y, z) = (lea128ctrrng_1.next_u64(), lea128ctrrng_2.next_u64(), lea128ctrrng_3.next_u64());
+		assert_eq!(x, y);
+		assert_ne!(x, z);
+	}
+}
@@ -0,0 +1,587 @@
+// Copyright © 2023 남기훈 <gihunnam@proton.me>
+//
+// This file and its content are subject to the terms of the MIT License (the "License").
+// If a copy of the License was not distributed with this file, you can obtain one at https://opensource.org/licenses/MIT.
+
+//! Seekable segmented LEA-GCM files
+//!
+//! A file is a header followed by segments of a fixed plaintext size, each sealed on its own with LEA-GCM,
+//! so that any range can be read by decrypting and authenticating only the segments it touches.
+//!
+//! * Header (44 bytes)
+//!
+//! | Offset | Size | Field                                                        |
+//! | ------ | ---- | ------------------------------------------------------------ |
+//! | 0      | 4    | Magic, `LEAS`                                                |
+//! | 4      | 1    | Version, 1                                                   |
+//! | 5      | 1    | Cipher suite: 1 for LEA-128-GCM, 2 for LEA-192-GCM, 3 for LEA-256-GCM |
+//! | 6      | 2    | Reserved, 0                                                  |
+//! | 8      | 4    | Segment size in bytes of plaintext, big-endian               |
+//! | 12     | 16   | Key ID, which is not interpreted                             |
+//! | 28     | 16   | Salt, which must be unique for each file under the same key  |
+//!
+//! * Segments
+//!
+//! The file key is derived from the master key with the LEA-CMAC KDF in counter mode ([`crate::kdf`]),
+//! with the label `LEA segmented file` and the whole header as the context,
+//! so any change to the header makes every segment fail to authenticate.
+//!
+//! Segment i is the LEA-GCM ciphertext of the i-th piece of the plaintext followed by its 16-byte tag,
+//! with the nonce 0x000000 \|\| \[i\]<sub>64</sub> \|\| last, where last is 1 only for the last segment, and no associated data.
+//! Every segment but the last holds exactly the segment size of plaintext,
+//! and the last one holds the rest, which may be empty, so a file has at least one segment.
+//! Reordered or replaced segments fail to authenticate, as does the new last segment of a file truncated at a segment boundary.
+//!
+//! [`SegmentedReader`] only authenticates the segments it reads,
+//! so a truncation is detected only when reading the end of the file.
+//!
+//! * Example
+//! ```
+//! use std::io::{Cursor, Read, Seek, SeekFrom, Write};
+//!
+//! use lea::segmented::{CipherSuite, Header, SegmentedReader, SegmentedWriter};
+//!
+//! let key = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+//! let header = Header {
+//!     cipher_suite: CipherSuite::Lea128Gcm,
+//!     segment_size: 4096,
+//!     key_id: [0; 16],
+//!     salt: [0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2A, 0x2B, 0x2C, 0x2D, 0x2E, 0x2F]
+//! };
+//! let ptxt: Vec<u8> = (0..10000).map(|i| i as u8).collect();
+//!
+//! // Writing
+//! let mut writer = SegmentedWriter::new(Vec::new(), &header, &key)?;
+//! writer.write_all(&ptxt)?;
+//! let file = writer.finish()?;
+//!
+//! // Reading a range
+//! let mut reader = SegmentedReader::new(Cursor::new(file), &key)?;
+//! assert_eq!(reader.len(), 10000);
+//! let mut buffer = [0; 100];
+//! reader.seek(SeekFrom::Start(5000))?;
+//! reader.read_exact(&mut buffer)?;
+//! assert_eq!(buffer, ptxt[5000..5100]);
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::vec::Vec;
+use std::{vec, fmt};
+
+use aead::{generic_array::GenericArray, AeadInPlace, NewAead};
+
+use crate::kdf::{self, Lea128Kdf, Lea192Kdf, Lea256Kdf, Mode};
+use crate::{Lea128Gcm, Lea192Gcm, Lea256Gcm};
+
+/// Size of the header in bytes
+pub const HEADER_SIZE: usize = 44;
+
+/// Size of the tag at the end of each segment in bytes
+pub const TAG_SIZE: usize = 16;
+
+/// Largest segment size in bytes, which bounds the memory used for a segment
+pub const MAX_SEGMENT_SIZE: u32 = 1 << 24;
+
+const MAGIC: [u8; 4] = *b"LEAS";
+const VERSION: u8 = 1;
+const LABEL: &[u8] = b"LEA segmented file";
+
+/// Block cipher and AEAD of the segments
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CipherSuite {
+	Lea128Gcm,
+	Lea192Gcm,
+	Lea256Gcm
+}
+
+impl CipherSuite {
+	/// Size of the master key in bytes
+	pub fn key_size(self) -> usize {
+		match self {
+			Self::Lea128Gcm => 16,
+			Self::Lea192Gcm => 24,
+			Self::Lea256Gcm => 32
+		}
+	}
+
+	fn id(self) -> u8 {
+		match self {
+			Self::Lea128Gcm => 1,
+			Self::Lea192Gcm => 2,
+			Self::Lea256Gcm => 3
+		}
+	}
+
+	fn from_id(id: u8) -> Option<Self> {
+		match id {
+			1 => Some(Self::Lea128Gcm),
+			2 => Some(Self::Lea192Gcm),
+			3 => Some(Self::Lea256Gcm),
+			_ => None
+		}
+	}
+}
+
+impl fmt::Display for CipherSuite {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::Lea128Gcm => "LEA-128-GCM",
+			Self::Lea192Gcm => "LEA-192-GCM",
+			Self::Lea256Gcm => "LEA-256-GCM"
+		})
+	}
+}
+
+/// File header
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Header {
+	pub cipher_suite: CipherSuite,
+	/// Segment size in bytes of plaintext, from 1 to [`MAX_SEGMENT_SIZE`]
+	pub segment_size: u32,
+	pub key_id: [u8; 16],
+	pub salt: [u8; 16]
+}
+
+impl Header {
+	/// Reads a header, for example to look up the key by its ID before opening the file.
+	pub fn read<R>(reader: &mut R) -> io::Result<Self> where
+	R: Read {
+		let mut bytes = [0; HEADER_SIZE];
+		reader.read_exact(&mut bytes)?;
+
+		Self::from_bytes(&bytes)
+	}
+
+	pub fn from_bytes(bytes: &[u8; HEADER_SIZE]) -> io::Result<Self> {
+		if bytes[..4] != MAGIC {
+			return Err(invalid_data("not a segmented file"));
+		}
+		if bytes[4] != VERSION || bytes[6..8] != [0, 0] {
+			return Err(invalid_data("unsupported version"));
+		}
+		let cipher_suite = CipherSuite::from_id(bytes[5]).ok_or_else(|| invalid_data("unsupported cipher suite"))?;
+		let segment_size = u32::from_be_bytes(bytes[8..12].try_into().unwrap());
+		if !(1..=MAX_SEGMENT_SIZE).contains(&segment_size) {
+			return Err(invalid_data("invalid segment size"));
+		}
+
+		Ok(Self {
+			cipher_suite,
+			segment_size,
+			key_id: bytes[12..28].try_into().unwrap(),
+			salt: bytes[28..44].try_into().unwrap()
+		})
+	}
+
+	pub fn to_bytes(&self) -> [u8; HEADER_SIZE] {
+		let mut bytes = [0; HEADER_SIZE];
+		bytes[..4].copy_from_slice(&MAGIC);
+		bytes[4] = VERSION;
+		bytes[5] = self.cipher_suite.id();
+		bytes[8..12].copy_from_slice(&self.segment_size.to_be_bytes());
+		bytes[12..28].copy_from_slice(&self.key_id);
+		bytes[28..44].copy_from_slice(&self.salt);
+
+		bytes
+	}
+}
+
+/// LEA-GCM under the file key
+enum SegmentCipher {
+	Lea128(Lea128Gcm),
+	Lea192(Lea192Gcm),
+	Lea256(Lea256Gcm)
+}
+
+impl SegmentCipher {
+	fn new(header: &Header, key: &[u8]) -> io::Result<Self> {
+		if header.segment_size == 0 || header.segment_size > MAX_SEGMENT_SIZE {
+			return Err(io::Error::new(io::ErrorKind::InvalidInput, "invalid segment size"));
+		}
+		if key.len() != header.cipher_suite.key_size() {
+			return Err(io::Error::new(io::ErrorKind::InvalidInput, "invalid key length"));
+		}
+
+		let context = header.to_bytes();
+		Ok(match header.cipher_suite {
+			CipherSuite::Lea128Gcm => {
+				let file_key = Lea128Kdf::new(GenericArray::from_slice(key)).derive_array::<16>(Mode::Counter, LABEL, &context).map_err(derivation_failed)?;
+				Self::Lea128(Lea128Gcm::new(GenericArray::from_slice(&*file_key)))
+			},
+			CipherSuite::Lea192Gcm => {
+				let file_key = Lea192Kdf::new(GenericArray::from_slice(key)).derive_array::<24>(Mode::Counter, LABEL, &context).map_err(derivation_failed)?;
+				Self::Lea192(Lea192Gcm::new(GenericArray::from_slice(&*file_key)))
+			},
+			CipherSuite::Lea256Gcm => {
+				let file_key = Lea256Kdf::new(GenericArray::from_slice(key)).derive_array::<32>(Mode::Counter, LABEL, &context).map_err(derivation_failed)?;
+				Self::Lea256(Lea256Gcm::new(GenericArray::from_slice(&*file_key)))
+			}
+		})
+	}
+
+	/// Encrypts the segment in `buffer` in place and appends its tag.
+	fn seal(&self, index: u64, last: bool, buffer: &mut Vec<u8>) -> io::Result<()> {
+		let nonce = nonce(index, last);
+		match self {
+			Self::Lea128(aead) => aead.encrypt_in_place(&nonce, &[], buffer),
+			Self::Lea192(aead) => aead.encrypt_in_place(&nonce, &[], buffer),
+			Self::Lea256(aead) => aead.encrypt_in_place(&nonce, &[], buffer)
+		}.map_err(|_| io::Error::new(io::ErrorKind::Other, "segment too long"))
+	}
+
+	/// Decrypts the segment in `buffer` in place and removes its tag.
+	fn open(&self, index: u64, last: bool, buffer: &mut Vec<u8>) -> io::Result<()> {
+		let nonce = nonce(index, last);
+		match self {
+			Self::Lea128(aead) => aead.decrypt_in_place(&nonce, &[], buffer),
+			Self::Lea192(aead) => aead.decrypt_in_place(&nonce, &[], buffer),
+			Self::Lea256(aead) => aead.decrypt_in_place(&nonce, &[], buffer)
+		}.map_err(|_| invalid_data("segment authentication failed"))
+	}
+}
+
+/// Writer of a segmented file
+///
+/// The file is complete only after [`SegmentedWriter::finish`], which seals the last segment.
+pub struct SegmentedWriter<W> where
+W: Write {
+	inner: W,
+	cipher: SegmentCipher,
+	segment_size: usize,
+	buffer: Vec<u8>,
+	index: u64
+}
+
+impl<W> SegmentedWriter<W> where
+W: Write {
+	/// Writes `header` to `inner`.
+	/// `key` must be as long as the key of the cipher suite.
+	pub fn new(mut inner: W, header: &Header, key: &[u8]) -> io::Result<Self> {
+		let cipher = SegmentCipher::new(header, key)?;
+		inner.write_all(&header.to_bytes())?;
+
+		let segment_size = header.segment_size as usize;
+		Ok(Self { inner, cipher, segment_size, buffer: Vec::with_capacity(segment_size + TAG_SIZE), index: 0 })
+	}
+
+	/// Seals the last segment and returns the inner writer.
+	pub fn finish(mut self) -> io::Result<W> {
+		self.write_segment(true)?;
+		self.inner.flush()?;
+
+		Ok(self.inner)
+	}
+
+	fn write_segment(&mut self, last: bool) -> io::Result<()> {
+		self.cipher.seal(self.index, last, &mut self.buffer)?;
+		self.inner.write_all(&self.buffer)?;
+		self.buffer.clear();
+		self.index = self.index.checked_add(1).ok_or_else(|| io::Error::new(io::ErrorKind::Other, "too many segments"))?;
+
+		Ok(())
+	}
+}
+
+impl<W> Write for SegmentedWriter<W> where
+W: Write {
+	fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+		if data.is_empty() {
+			return Ok(0);
+		}
+
+		// A full segment is sealed only once more data follows, since it may be the last one.
+		if self.buffer.len() == self.segment_size {
+			self.write_segment(false)?;
+		}
+
+		let n = data.len().min(self.segment_size - self.buffer.len());
+		self.buffer.extend_from_slice(&data[..n]);
+
+		Ok(n)
+	}
+
+	/// Flushes the inner writer. Data of the current segment stays buffered until the segment is sealed.
+	fn flush(&mut self) -> io::Result<()> {
+		self.inner.flush()
+	}
+}
+
+/// Reader of a segmented file
+///
+/// Positions and lengths are those of the plaintext.
+pub struct SegmentedReader<R> where
+R: Read + Seek {
+	inner: R,
+	header: Header,
+	cipher: SegmentCipher,
+	segment_size: u64,
+	segment_count: u64,
+	len: u64,
+	pos: u64,
+	buffer: Vec<u8>,
+	buffered_index: Option<u64>
+}
+
+impl<R> SegmentedReader<R> where
+R: Read + Seek {
+	/// Reads the header from the start of `inner`.
+	/// `key` must be as long as the key of the cipher suite.
+	pub fn new(mut inner: R, key: &[u8]) -> io::Result<Self> {
+		inner.seek(SeekFrom::Start(0))?;
+		let header = Header::read(&mut inner)?;
+		let cipher = SegmentCipher::new(&header, key)?;
+
+		let body_len = inner.seek(SeekFrom::End(0))? - HEADER_SIZE as u64;
+		let segment_size = u64::from(header.segment_size);
+		let sealed_segment_size = segment_size + TAG_SIZE as u64;
+		let segment_count = body_len.div_ceil(sealed_segment_size);
+		let last_len = body_len - segment_count.saturating_sub(1) * sealed_segment_size;
+		if segment_count == 0 || last_len < TAG_SIZE as u64 {
+			return Err(invalid_data("truncated segment"));
+		}
+		let len = (segment_count - 1) * segment_size + last_len - TAG_SIZE as u64;
+
+		Ok(Self {
+			inner,
+			header,
+			cipher,
+			segment_size,
+			segment_count,
+			len,
+			pos: 0,
+			buffer: vec![0; sealed_segment_size as usize],
+			buffered_index: None
+		})
+	}
+
+	pub fn header(&self) -> &Header {
+		&self.header
+	}
+
+	/// Length of the plaintext in bytes
+	pub fn len(&self) -> u64 {
+		self.len
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	pub fn into_inner(self) -> R {
+		self.inner
+	}
+
+	/// Reads, authenticates, and decrypts segment `index` unless it is already buffered.
+	fn load_segment(&mut self, index: u64) -> io::Result<()> {
+		if self.buffered_index == Some(index) {
+			return Ok(());
+		}
+		self.buffered_index = None;
+
+		let last = index + 1 == self.segment_count;
+		let sealed_len = if last {
+			self.len - index * self.segment_size + TAG_SIZE as u64
+		} else {
+			self.segment_size + TAG_SIZE as u64
+		};
+
+		self.inner.seek(SeekFrom::Start(HEADER_SIZE as u64 + index * (self.segment_size + TAG_SIZE as u64)))?;
+		self.buffer.resize(sealed_len as usize, 0);
+		self.inner.read_exact(&mut self.buffer)?;
+		self.cipher.open(index, last, &mut self.buffer)?;
+		self.buffered_index = Some(index);
+
+		Ok(())
+	}
+}
+
+impl<R> Read for SegmentedReader<R> where
+R: Read + Seek {
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		if buf.is_empty() || self.pos >= self.len {
+			return Ok(0);
+		}
+
+		let index = self.pos / self.segment_size;
+		self.load_segment(index)?;
+
+		let offset = (self.pos - index * self.segment_size) as usize;
+		let n = buf.len().min(self.buffer.len() - offset);
+		buf[..n].copy_from_slice(&self.buffer[offset..(offset + n)]);
+		self.pos += n as u64;
+
+		Ok(n)
+	}
+}
+
+impl<R> Seek for SegmentedReader<R> where
+R: Read + Seek {
+	/// Moves within the plaintext. Seeking past the end is allowed, and reading there returns no data.
+	fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+		let pos = match pos {
+			SeekFrom::Start(pos) => Some(pos),
+			SeekFrom::End(offset) => self.len.checked_add_signed(offset),
+			SeekFrom::Current(offset) => self.pos.checked_add_signed(offset)
+		}.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid seek to a negative or overflowing position"))?;
+		self.pos = pos;
+
+		Ok(pos)
+	}
+}
+
+fn nonce(index: u64, last: bool) -> aead::Nonce<Lea128Gcm> {
+	let mut nonce = aead::Nonce::<Lea128Gcm>::default();
+	nonce[3..11].copy_from_slice(&index.to_be_bytes());
+	nonce[11] = last.into();
+
+	nonce
+}
+
+fn invalid_data(message: &'static str) -> io::Error {
+	io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn derivation_failed(_: kdf::Error) -> io::Error {
+	io::Error::new(io::ErrorKind::Other, "key derivation failed")
+}
+
+#[cfg(test)]
+mod tests {
+	use std::io::{Cursor, Read, Seek, SeekFrom, Write};
+	use std::{vec::Vec, vec};
+
+	use crate::segmented::{CipherSuite, Header, SegmentedReader, SegmentedWriter, HEADER_SIZE, TAG_SIZE};
+
+	const KEY: [u8; 16] = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+
+	fn header(cipher_suite: CipherSuite, segment_size: u32) -> Header {
+		Header {
+			cipher_suite,
+			segment_size,
+			key_id: [0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F],
+			salt: [0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2A, 0x2B, 0x2C, 0x2D, 0x2E, 0x2F]
+		}
+	}
+
+	fn write(header: &Header, key: &[u8], ptxt: &[u8]) -> std::io::Result<Vec<u8>> {
+		let mut writer = SegmentedWriter::new(Vec::new(), header, key)?;
+		for chunk in ptxt.chunks(7) {
+			writer.write_all(chunk)?;
+		}
+
+		writer.finish()
+	}
+
+	#[test]
+	fn lea128gcm_segmented() -> std::io::Result<()> {
+		let ptxt = b"seekable random-access encrypted file format";
+		let file = [
+			0x4C, 0x45, 0x41, 0x53, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x10, 0x11, 0x12, 0x13,
+			0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F, 0x20, 0x21, 0x22, 0x23,
+			0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2A, 0x2B, 0x2C, 0x2D, 0x2E, 0x2F, 0x34, 0x3A, 0x60, 0xE0,
+			0xCF, 0xED, 0x37, 0x21, 0xC6, 0xC6, 0xC5, 0x17, 0x67, 0x9A, 0x50, 0x68, 0xDC, 0xAC, 0xBF, 0x70,
+			0x07, 0x2F, 0x11, 0xEE, 0x40, 0xEA, 0xF7, 0x43, 0x64, 0x1D, 0x4B, 0xF3, 0x38, 0x6E, 0xB1, 0xF5,
+			0xDF, 0x6E, 0x22, 0x3C, 0x2D, 0x2C, 0xC0, 0x93, 0xE1, 0x05, 0x88, 0x29, 0xE7, 0x2F, 0x2D, 0x9F,
+			0xCF, 0x42, 0x32, 0x2C, 0x06, 0x75, 0x1E, 0xA8, 0x58, 0x05, 0x2B, 0x92, 0xE4, 0x2A, 0xA0, 0xF0,
+			0xD4, 0x32, 0x6C, 0x99, 0x47, 0x31, 0xEF, 0x19, 0x80, 0x0C, 0xE3, 0xB7, 0xAD, 0x40, 0xCB, 0x5E,
+			0x89, 0xC7, 0x07, 0xFB, 0xBD, 0x2F, 0x86, 0x74
+		];
+
+		// Writing
+		assert_eq!(write(&header(CipherSuite::Lea128Gcm, 16), &KEY, ptxt)?, file);
+
+		// Reading
+		let mut reader = SegmentedReader::new(Cursor::new(file), &KEY)?;
+		assert_eq!(*reader.header(), header(CipherSuite::Lea128Gcm, 16));
+		assert_eq!(reader.len(), ptxt.len() as u64);
+		let mut buffer = Vec::new();
+		reader.read_to_end(&mut buffer)?;
+		assert_eq!(buffer, ptxt);
+
+		Ok(())
+	}
+
+	#[test]
+	fn segmented_random_access() -> std::io::Result<()> {
+		let keys: [&[u8]; 3] = [&[0x42; 16], &[0x42; 24], &[0x42; 32]];
+		let cipher_suites = [CipherSuite::Lea128Gcm, CipherSuite::Lea192Gcm, CipherSuite::Lea256Gcm];
+
+		for (key, cipher_suite) in keys.into_iter().zip(cipher_suites) {
+			for len in [0_usize, 1, 31, 32, 33, 64, 100] {
+				let ptxt: Vec<u8> = (0..len).map(|i| i as u8).collect();
+				let file = write(&header(cipher_suite, 32), key, &ptxt)?;
+				let segment_count = len.max(1).div_ceil(32);
+				assert_eq!(file.len(), HEADER_SIZE + len + segment_count * TAG_SIZE);
+
+				let mut reader = SegmentedReader::new(Cursor::new(file), key)?;
+				assert_eq!(reader.len(), len as u64);
+				for start in [0, 5, 31, 32, 40, 99] {
+					for end in [start, start + 1, start + 30, start + 70] {
+						let end = end.min(len);
+						let start = start.min(end);
+						let mut buffer = vec![0; end - start];
+						reader.seek(SeekFrom::Start(start as u64))?;
+						reader.read_exact(&mut buffer)?;
+						assert_eq!(buffer, ptxt[start..end]);
+					}
+				}
+
+				assert_eq!(reader.seek(SeekFrom::End(-1)).ok(), len.checked_sub(1).map(|pos| pos as u64));
+				assert_eq!(reader.seek(SeekFrom::End(10))?, len as u64 + 10);
+				assert_eq!(reader.read(&mut [0; 1])?, 0);
+			}
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn segmented_reject() -> std::io::Result<()> {
+		let ptxt: Vec<u8> = (0..100).collect();
+		let file = write(&header(CipherSuite::Lea128Gcm, 32), &KEY, &ptxt)?;
+		let sealed_segment_size = 32 + TAG_SIZE;
+		let segment = |i: usize| HEADER_SIZE + i * sealed_segment_size;
+
+		// Tampering with a segment fails only when that segment is read.
+		let mut tampered = file.clone();
+		tampered[segment(1) + 3] ^= 1;
+		let mut reader = SegmentedReader::new(Cursor::new(tampered), &KEY)?;
+		reader.read_exact(&mut [0; 32])?;
+		assert_eq!(reader.read(&mut [0; 1]).unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+		reader.seek(SeekFrom::Start(64))?;
+		reader.read_exact(&mut [0; 36])?;
+
+		// Reordering
+		let mut reordered = file.clone();
+		reordered[segment(0)..segment(2)].rotate_left(sealed_segment_size);
+		let mut reader = SegmentedReader::new(Cursor::new(reordered), &KEY)?;
+		assert!(reader.read(&mut [0; 1]).is_err());
+
+		// Truncation at a segment boundary
+		let mut reader = SegmentedReader::new(Cursor::new(&file[..segment(2)]), &KEY)?;
+		assert_eq!(reader.len(), 64);
+		reader.read_exact(&mut [0; 32])?;
+		assert!(reader.read(&mut [0; 32]).is_err());
+
+		// Truncation inside a tag
+		assert!(SegmentedReader::new(Cursor::new(&file[..(segment(3) + TAG_SIZE - 1)]), &KEY).is_err());
+		assert!(SegmentedReader::new(Cursor::new(&file[..HEADER_SIZE]), &KEY).is_err());
+
+		// Header
+		let mut tampered = file.clone();
+		tampered[12] ^= 1;
+		let mut reader = SegmentedReader::new(Cursor::new(tampered), &KEY)?;
+		assert!(reader.read(&mut [0; 1]).is_err());
+		let mut tampered = file.clone();
+		tampered[5] = 4;
+		assert!(SegmentedReader::new(Cursor::new(tampered), &KEY).is_err());
+
+		// Key
+		let mut reader = SegmentedReader::new(Cursor::new(&file), &[0; 16])?;
+		assert!(reader.read(&mut [0; 1]).is_err());
+		assert!(SegmentedReader::new(Cursor::new(&file), &[0; 32]).is_err());
+
+		Ok(())
+	}
+}
@@ -0,0 +1,524 @@
+// Copyright © 2023 남기훈 <gihunnam@proton.me>
+//
+// This file and its content are subject to the terms of the MIT License (the "License").
+// If a copy of the License was not distributed with this file, you can obtain one at https://opensource.org/licenses/MIT.
+
+//! LEA-SIV
+//!
+//! SIV as specified in RFC 5297, built from S2V over CMAC and CTR with two independent keys.
+//! The key is the CMAC key followed by the CTR key, so `Lea128Siv` takes a 256-bit key.
+//!
+//! Encryption is deterministic: the synthetic IV is both the initial counter and the tag.
+//! [`Siv`] takes any number (up to 126) of associated data components and needs no nonce,
+//! while [`SivAead`] implements [`AeadInPlace`] and passes the nonce as the last associated data component.
+//! `encrypt_in_place` and `decrypt_in_place` put the tag in front of the ciphertext, as in RFC 5297.
+//!
+//! * Example
+//! ```
+//! use lea::{siv::aead, prelude::*, Lea128Siv};
+//!
+//! let key = arr![u8;
+//!     0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F,
+//!     0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F
+//! ];
+//! let tag = arr![u8; 0xAD, 0x0A, 0xA6, 0xB1, 0xDF, 0x1C, 0x8B, 0xF5, 0x75, 0xFD, 0xFD, 0xB1, 0x35, 0x9B, 0xF7, 0x89];
+//! let associated_data: [&[u8]; 2] = [b"users", b"email"];
+//! let ptxt = *b"alice@example.com";
+//! let ctxt = [0x30, 0xF4, 0xAB, 0x09, 0xA8, 0x83, 0xE2, 0x28, 0x84, 0x21, 0x6F, 0xCE, 0xF8, 0xC5, 0x3C, 0xFC, 0x25];
+//!
+//! let lea128siv = Lea128Siv::new(&key);
+//!
+//! // Encryption
+//! let mut buffer = ptxt;
+//! let calculated_tag: Result<_, aead::Error> = lea128siv.encrypt_in_place_detached(associated_data, &mut buffer);
+//! assert_eq!(buffer, ctxt);
+//! assert_eq!(calculated_tag.unwrap(), tag);
+//!
+//! // Decryption
+//! let mut buffer = ctxt;
+//! let _: Result<(), aead::Error> = lea128siv.decrypt_in_place_detached(associated_data, &mut buffer, &tag);
+//! assert_eq!(buffer, ptxt);
+//! ```
+
+pub use aead;
+
+use core::marker::PhantomData;
+use core::ops::Add;
+
+use aead::consts::{U0, U16};
+use aead::generic_array::{typenum::{Sum, Unsigned}, ArrayLength, GenericArray};
+use aead::{AeadCore, AeadInPlace, Buffer, Error, Key, NewAead, Nonce, Tag};
+use cipher::{BlockCipher, BlockEncrypt, FromBlockCipher, NewBlockCipher, StreamCipher};
+use cmac::{Cmac, FromBlockCipher as _, Mac};
+use ctr::Ctr128BE;
+use subtle::ConstantTimeEq;
+
+use crate::{Lea128, Lea192, Lea256};
+
+pub type Lea128Siv = Siv<Lea128>;
+pub type Lea192Siv = Siv<Lea192>;
+pub type Lea256Siv = Siv<Lea256>;
+
+pub type Lea128SivAead<NonceSize = U16> = SivAead<Lea128, NonceSize>;
+pub type Lea192SivAead<NonceSize = U16> = SivAead<Lea192, NonceSize>;
+pub type Lea256SivAead<NonceSize = U16> = SivAead<Lea256, NonceSize>;
+
+/// SIV key, i.e. the CMAC key followed by the CTR key
+pub type SivKey<C> = GenericArray<u8, Sum<<C as NewBlockCipher>::KeySize, <C as NewBlockCipher>::KeySize>>;
+
+type Block = GenericArray<u8, U16>;
+
+/// Maximum number of associated data components, including the nonce if any
+pub const MAX_ASSOCIATED_DATA: usize = 126;
+
+/// SIV with vector associated data
+///
+/// On decryption failure, the buffer is zeroed.
+pub struct Siv<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	mac_cipher: C,
+	ctr_cipher: C
+}
+
+impl<C> Siv<C> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	pub fn new(key: &SivKey<C>) -> Self where
+	C: NewBlockCipher,
+	C::KeySize: Add,
+	Sum<C::KeySize, C::KeySize>: ArrayLength<u8> {
+		let (mac_key, ctr_key) = key.split_at(C::KeySize::USIZE);
+
+		Self::from_ciphers(C::new(GenericArray::from_slice(mac_key)), C::new(GenericArray::from_slice(ctr_key)))
+	}
+
+	/// Creates SIV from a CMAC cipher and a CTR cipher, which must use independent keys.
+	pub fn from_ciphers(mac_cipher: C, ctr_cipher: C) -> Self {
+		Self { mac_cipher, ctr_cipher }
+	}
+
+	/// Encrypts `buffer` in place and inserts the tag in front of it.
+	pub fn encrypt_in_place<I, T>(&self, associated_data: I, buffer: &mut dyn Buffer) -> Result<(), Error> where
+	I: IntoIterator<Item = T>,
+	T: AsRef<[u8]> {
+		let len = buffer.len();
+		buffer.extend_from_slice(&[0; 16])?;
+		buffer.as_mut().copy_within(..len, 16);
+
+		let (tag, buffer) = buffer.as_mut().split_at_mut(16);
+		tag.copy_from_slice(&self.encrypt_in_place_detached(associated_data, buffer)?);
+
+		Ok(())
+	}
+
+	pub fn encrypt_in_place_detached<I, T>(&self, associated_data: I, buffer: &mut [u8]) -> Result<Block, Error> where
+	I: IntoIterator<Item = T>,
+	T: AsRef<[u8]> {
+		let v = self.s2v(associated_data, buffer)?;
+		self.ctr(&v).apply_keystream(buffer);
+
+		Ok(v)
+	}
+
+	/// Decrypts `buffer` in place, which starts with the tag, and removes the tag.
+	pub fn decrypt_in_place<I, T>(&self, associated_data: I, buffer: &mut dyn Buffer) -> Result<(), Error> where
+	I: IntoIterator<Item = T>,
+	T: AsRef<[u8]> {
+		let len = buffer.len();
+		if len < 16 {
+			return Err(Error);
+		}
+
+		let (tag, ctxt) = buffer.as_mut().split_at_mut(16);
+		let result = self.decrypt_in_place_detached(associated_data, ctxt, Block::from_slice(tag));
+		buffer.as_mut().copy_within(16.., 0);
+		buffer.truncate(len - 16);
+
+		result
+	}
+
+	pub fn decrypt_in_place_detached<I, T>(&self, associated_data: I, buffer: &mut [u8], tag: &Block) -> Result<(), Error> where
+	I: IntoIterator<Item = T>,
+	T: AsRef<[u8]> {
+		self.ctr(tag).apply_keystream(buffer);
+
+		match self.s2v(associated_data, buffer) {
+			Ok(v) if v.ct_eq(tag).into() => Ok(()),
+			_ => {
+				buffer.fill(0);
+
+				Err(Error)
+			}
+		}
+	}
+
+	/// S2V over the associated data components and the plaintext
+	fn s2v<I, T>(&self, associated_data: I, ptxt: &[u8]) -> Result<Block, Error> where
+	I: IntoIterator<Item = T>,
+	T: AsRef<[u8]> {
+		let mac = Cmac::from_cipher(&self.mac_cipher);
+		let mut d = cmac(&mac, &[0; 16]);
+
+		for (i, component) in associated_data.into_iter().enumerate() {
+			if i == MAX_ASSOCIATED_DATA {
+				return Err(Error);
+			}
+
+			d = dbl(&d);
+			d.iter_mut().zip(cmac(&mac, component.as_ref())).for_each(|(d, c)| *d ^= c);
+		}
+
+		let mut mac = mac;
+		if ptxt.len() >= 16 {
+			let (head, tail) = ptxt.split_at(ptxt.len() - 16);
+			d.iter_mut().zip(tail).for_each(|(d, p)| *d ^= p);
+			mac.update(head);
+		} else {
+			d = dbl(&d);
+			d.iter_mut().zip(ptxt).for_each(|(d, p)| *d ^= p);
+			d[ptxt.len()] ^= 0x80;
+		}
+		mac.update(&d);
+
+		Ok(mac.finalize().into_bytes())
+	}
+
+	/// CTR with the 31st and 63rd bits of the synthetic IV cleared
+	fn ctr(&self, v: &Block) -> Ctr128BE<&C> {
+		let mut q = *v;
+		q[8] &= 0x7F;
+		q[12] &= 0x7F;
+
+		Ctr128BE::from_block_cipher(&self.ctr_cipher, &q)
+	}
+}
+
+/// SIV as an AEAD, with the nonce as the last associated data component
+///
+/// * `N`: Nonce size
+///
+/// On decryption failure, the buffer is zeroed.
+pub struct SivAead<C, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+N: ArrayLength<u8> {
+	siv: Siv<C>,
+	_pd: PhantomData<N>
+}
+
+impl<C, N> From<Siv<C>> for SivAead<C, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+N: ArrayLength<u8> {
+	fn from(siv: Siv<C>) -> Self {
+		Self { siv, _pd: PhantomData }
+	}
+}
+
+impl<C, N> NewAead for SivAead<C, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+C::KeySize: Add,
+Sum<C::KeySize, C::KeySize>: ArrayLength<u8>,
+N: ArrayLength<u8> {
+	type KeySize = Sum<C::KeySize, C::KeySize>;
+
+	fn new(key: &Key<Self>) -> Self {
+		Self::from(Siv::new(key))
+	}
+}
+
+impl<C, N> AeadCore for SivAead<C, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+N: ArrayLength<u8> {
+	type NonceSize = N;
+	type TagSize = U16;
+	type CiphertextOverhead = U0;
+}
+
+impl<C, N> AeadInPlace for SivAead<C, N> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+N: ArrayLength<u8> {
+	fn encrypt_in_place(&self, nonce: &Nonce<Self>, associated_data: &[u8], buffer: &mut dyn Buffer) -> Result<(), Error> {
+		self.siv.encrypt_in_place([associated_data, nonce.as_slice()], buffer)
+	}
+
+	fn encrypt_in_place_detached(&self, nonce: &Nonce<Self>, associated_data: &[u8], buffer: &mut [u8]) -> Result<Tag<Self>, Error> {
+		self.siv.encrypt_in_place_detached([associated_data, nonce.as_slice()], buffer)
+	}
+
+	fn decrypt_in_place(&self, nonce: &Nonce<Self>, associated_data: &[u8], buffer: &mut dyn Buffer) -> Result<(), Error> {
+		self.siv.decrypt_in_place([associated_data, nonce.as_slice()], buffer)
+	}
+
+	fn decrypt_in_place_detached(&self, nonce: &Nonce<Self>, associated_data: &[u8], buffer: &mut [u8], tag: &Tag<Self>) -> Result<(), Error> {
+		self.siv.decrypt_in_place_detached([associated_data, nonce.as_slice()], buffer, tag)
+	}
+}
+
+fn cmac<C>(mac: &Cmac<&C>, data: &[u8]) -> Block where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	let mut mac = mac.clone();
+	mac.update(data);
+
+	mac.finalize().into_bytes()
+}
+
+/// Doubling in GF(2<sup>128</sup>)
+fn dbl(block: &Block) -> Block {
+	let x = u128::from_be_bytes((*block).into());
+
+	((x << 1) ^ ((x >> 127) * 0x87)).to_be_bytes().into()
+}
+
+#[cfg(test)]
+mod tests {
+	extern crate alloc;
+
+	use alloc::{vec::Vec, vec};
+
+	use cipher::{BlockCipher, NewBlockCipher};
+
+	use crate::{prelude::*, siv::{aead::{generic_array::ArrayLength, Aead, Error, Payload}, SivKey, MAX_ASSOCIATED_DATA}, Lea128, Lea192, Lea256, Lea128Siv, Lea128SivAead, Lea192Siv, Lea256Siv};
+
+	struct TestCase<T> where
+	T: BlockCipher + NewBlockCipher,
+	T::KeySize: core::ops::Add,
+	<T::KeySize as core::ops::Add>::Output: ArrayLength<u8> {
+		key: SivKey<T>,
+		associated_data: Vec<Vec<u8>>,
+		tag: GenericArray<u8, U16>,
+		ptxt: Vec<u8>,
+		ctxt: Vec<u8>
+	}
+
+	#[test]
+	fn lea128siv() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea128>; 5] = [
+			TestCase {
+				key: arr![u8; 0x09, 0xED, 0x57, 0xA1, 0x49, 0x36, 0x73, 0xEA, 0xAC, 0xDF, 0x51, 0xF8, 0x81, 0x31, 0x37, 0x4D, 0xF2, 0x0A, 0x30, 0x1C, 0x05, 0x9C, 0xB3, 0xEB, 0xB4, 0x53, 0xD1, 0xE1, 0xDC, 0x0F, 0x41, 0xE4],
+				associated_data: vec![],
+				tag: arr![u8; 0xAA, 0xC0, 0x91, 0xE0, 0x39, 0x25, 0x26, 0xAF, 0xB2, 0x76, 0x26, 0x09, 0xE9, 0x05, 0x59, 0x56],
+				ptxt: vec![],
+				ctxt: vec![]
+			},
+			TestCase {
+				key: arr![u8; 0x7C, 0x17, 0xB6, 0x2D, 0x79, 0x19, 0x49, 0x06, 0x15, 0x57, 0x7B, 0xBC, 0x74, 0x67, 0xE9, 0xF5, 0xEB, 0xA6, 0xC0, 0x30, 0xA7, 0x60, 0x6B, 0x57, 0xC2, 0x9F, 0x33, 0xB1, 0x6F, 0x5F, 0x9C, 0x4A],
+				associated_data: vec![vec![0x09, 0xA3, 0xEE, 0x0C, 0x6B, 0xA8, 0x75, 0x3F]],
+				tag: arr![u8; 0x71, 0x06, 0xC7, 0xE7, 0x5F, 0x0D, 0x0F, 0x46, 0x79, 0xBF, 0x5C, 0x54, 0x09, 0x60, 0x8E, 0x24],
+				ptxt: vec![],
+				ctxt: vec![]
+			},
+			TestCase {
+				key: arr![u8; 0xA4, 0xC0, 0x7A, 0xC2, 0x41, 0xA3, 0xAD, 0x49, 0x44, 0x5C, 0xF7, 0xB1, 0xAE, 0xD6, 0xF4, 0xDB, 0x8F, 0xA1, 0x65, 0xEA, 0xDC, 0xE3, 0xD0, 0xBD, 0xA1, 0x63, 0x56, 0xB8, 0xDB, 0xB3, 0x25, 0xE8],
+				associated_data: vec![vec![0x1E, 0x91, 0xA5, 0x09, 0x7B, 0x24, 0x14, 0xC8, 0x58, 0xD6, 0x68, 0xC7, 0xAD, 0x1B, 0xFA, 0xB3, 0x1A, 0xC7, 0x76, 0xAE, 0xA1, 0xDC, 0x43, 0x89]],
+				tag: arr![u8; 0x2F, 0x31, 0x55, 0x05, 0x5F, 0x52, 0x83, 0x4C, 0x54, 0x09, 0xB0, 0xC6, 0xD6, 0xAF, 0x36, 0xBA],
+				ptxt: vec![0x01, 0x35, 0x07, 0x0E, 0x5A, 0x3E, 0xB4, 0x89, 0x37, 0xFA, 0x97, 0x73, 0x8B],
+				ctxt: vec![0x88, 0x9A, 0xC5, 0x4B, 0xD5, 0x19, 0x5C, 0xE3, 0x2C, 0x3F, 0xDA, 0x91, 0x00]
+			},
+			TestCase {
+				key: arr![u8; 0x00, 0xA7, 0x1A, 0xF8, 0xB0, 0xCC, 0xD6, 0x2C, 0x44, 0xD5, 0x6C, 0xCF, 0x44, 0x83, 0x41, 0x5C, 0x0F, 0xDF, 0x90, 0x7A, 0x5F, 0xAD, 0xF0, 0x67, 0xA1, 0xF5, 0x54, 0xE0, 0xB6, 0x6C, 0xB3, 0x53],
+				associated_data: vec![vec![0xC1, 0xB0, 0x21, 0x91, 0x76, 0x87, 0x41, 0x8F, 0x04, 0xE4, 0x7E, 0x07, 0xDF, 0xD3, 0x67, 0xB1], vec![]],
+				tag: arr![u8; 0x2C, 0xC1, 0x6A, 0x66, 0x3F, 0x1F, 0xD6, 0xD2, 0x41, 0x5F, 0xB5, 0xD8, 0xDC, 0x67, 0x94, 0x53],
+				ptxt: vec![0x52, 0x14, 0xD7, 0xE0, 0xC4, 0xDD, 0x85, 0x3D, 0xAE, 0xE7, 0x82, 0xE6, 0x82, 0xC9, 0x4C, 0x4C],
+				ctxt: vec![0x34, 0xB2, 0xED, 0xCF, 0x37, 0xEF, 0x72, 0xAC, 0x69, 0xB4, 0x69, 0x71, 0xDC, 0x86, 0x74, 0xD9]
+			},
+			TestCase {
+				key: arr![u8; 0x4F, 0xA3, 0x94, 0xDA, 0x3A, 0xA2, 0x3D, 0x45, 0x0E, 0xC9, 0xD0, 0x1B, 0x25, 0xA4, 0x90, 0xDE, 0x4C, 0xB4, 0xC5, 0x0B, 0x2B, 0x81, 0x6C, 0x1E, 0x3F, 0x8F, 0xAF, 0x46, 0x7E, 0xB8, 0xC2, 0x50],
+				associated_data: vec![vec![0x78, 0x28, 0x62, 0x85, 0xF1, 0x33, 0xC2, 0xCF, 0xB6, 0xB5, 0x63, 0xCF, 0x7E, 0x5C, 0x7E, 0x74, 0x94, 0x3A, 0xDE, 0xE3, 0x4F, 0x1B, 0x0C, 0x41, 0x10, 0xAA, 0x76, 0xC1, 0x36, 0xEF, 0x95, 0x15, 0x0B, 0x80, 0xB9, 0x8D, 0x2B, 0x99, 0x74, 0x12], vec![0x43, 0x95, 0xC4, 0xB8, 0x5A, 0x97, 0xCA, 0x61, 0x16, 0x7A], vec![0xB7, 0x9B, 0x93, 0xD8, 0x78, 0x18, 0xCD, 0x51, 0x2A, 0x86, 0x94, 0x42, 0x98, 0xCA, 0x47, 0x92]],
+				tag: arr![u8; 0x73, 0xBE, 0xA5, 0x20, 0xF4, 0xFD, 0x4A, 0x02, 0x16, 0xDA, 0xAA, 0xCF, 0x77, 0x98, 0x16, 0x02],
+				ptxt: vec![0xAF, 0x58, 0xDE, 0x80, 0xBB, 0x2D, 0x56, 0x9D, 0xA9, 0x9B, 0xC3, 0xEB, 0x51, 0xF7, 0x90, 0xAD, 0x70, 0x9A, 0xF0, 0x26, 0x16, 0xEB, 0x7D, 0xC8, 0x3C, 0xC6, 0xAD, 0x30, 0x66, 0x3E, 0xA4, 0x09, 0x08, 0x30, 0xCD, 0x80, 0x68, 0x17, 0x19, 0xD2, 0xF1, 0x82, 0x33, 0x41, 0x4E, 0xE8, 0xFE],
+				ctxt: vec![0xBC, 0x50, 0xCA, 0xEA, 0xFA, 0xAA, 0x58, 0x72, 0x26, 0xFA, 0x94, 0x3D, 0x1E, 0x50, 0xE9, 0x9A, 0x9E, 0x04, 0x87, 0xCA, 0x4E, 0xD3, 0x20, 0x24, 0xAE, 0x2A, 0x2E, 0xD1, 0x38, 0xF6, 0x91, 0xF6, 0x36, 0x74, 0xB1, 0x5F, 0xF5, 0x65, 0xD5, 0xD1, 0x56, 0xEF, 0xD3, 0x18, 0x4B, 0xCA, 0x37]
+			}
+		];
+
+		for TestCase { key, associated_data, tag, ptxt, ctxt } in test_cases {
+			let lea128siv = Lea128Siv::new(&key);
+
+			// Encryption
+			let mut buffer = ptxt.clone();
+			let calculated_tag = lea128siv.encrypt_in_place_detached(&associated_data, &mut buffer)?;
+			assert_eq!(buffer, ctxt);
+			assert_eq!(calculated_tag, tag);
+
+			// Decryption
+			let mut buffer = ctxt.clone();
+			lea128siv.decrypt_in_place_detached(&associated_data, &mut buffer, &tag)?;
+			assert_eq!(buffer, ptxt);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea192siv() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea192>; 5] = [
+			TestCase {
+				key: arr![u8; 0x1A, 0x8B, 0x16, 0xAC, 0x54, 0xDD, 0x3F, 0x1F, 0x29, 0x28, 0x50, 0x99, 0x1C, 0x11, 0x9E, 0x8F, 0x7D, 0xB4, 0x1C, 0xB9, 0x95, 0x4C, 0x2B, 0xBC, 0xAB, 0x1D, 0xAD, 0x94, 0x19, 0x16, 0xE6, 0x9C, 0x87, 0x36, 0x5E, 0xFA, 0x77, 0xC3, 0x60, 0x3E, 0x0F, 0x3D, 0x96, 0x01, 0x3E, 0x74, 0x37, 0x68],
+				associated_data: vec![],
+				tag: arr![u8; 0xA6, 0xAB, 0xF6, 0xFA, 0x26, 0x90, 0x82, 0xB2, 0xB2, 0xEA, 0x29, 0xE5, 0x0A, 0x79, 0x02, 0xA8],
+				ptxt: vec![],
+				ctxt: vec![]
+			},
+			TestCase {
+				key: arr![u8; 0xFE, 0xAA, 0xC3, 0xB9, 0x19, 0xEF, 0xE3, 0x56, 0x11, 0x38, 0x81, 0x0D, 0xE9, 0x60, 0x57, 0xBC, 0xB9, 0x74, 0xD9, 0x2B, 0xBB, 0xA6, 0x3B, 0x6E, 0xD4, 0x52, 0xFA, 0x6E, 0x31, 0x08, 0xCC, 0xBA, 0x3F, 0x75, 0xFC, 0xDE, 0x1C, 0xA0, 0xA0, 0xA0, 0x91, 0xC9, 0x72, 0x7F, 0x60, 0x6C, 0xCC, 0x85],
+				associated_data: vec![vec![0xA4, 0x7C, 0x68, 0x08, 0x32, 0x70, 0x99, 0x42]],
+				tag: arr![u8; 0xAB, 0x8F, 0xD6, 0xCC, 0x8A, 0xBE, 0x0D, 0xA2, 0x6F, 0x52, 0x2C, 0xD2, 0x22, 0xAE, 0xFC, 0xBD],
+				ptxt: vec![],
+				ctxt: vec![]
+			},
+			TestCase {
+				key: arr![u8; 0x51, 0x9C, 0x7D, 0xA9, 0x49, 0x02, 0xDA, 0xED, 0x1F, 0xA0, 0x21, 0x52, 0x64, 0x5E, 0x87, 0xBA, 0x10, 0xBF, 0x1C, 0x61, 0x38, 0xF4, 0x5D, 0x26, 0xD7, 0xEE, 0xF5, 0x7E, 0x38, 0xC5, 0x72, 0x6A, 0x17, 0x8F, 0xC6, 0x90, 0x87, 0xDC, 0xFD, 0x69, 0xDC, 0x02, 0xA7, 0xAF, 0xE6, 0x9B, 0xE3, 0x02],
+				associated_data: vec![vec![0x89, 0x92, 0x82, 0xC0, 0xDC, 0xA1, 0xF1, 0x41, 0x70, 0x3F, 0x85, 0xE8, 0x14, 0x69, 0x28, 0x6B, 0x72, 0x83, 0xA4, 0x2A, 0x3E, 0xD5, 0xA7, 0xA6]],
+				tag: arr![u8; 0xF2, 0x91, 0xF8, 0xA7, 0x90, 0x3B, 0xF3, 0xC5, 0xA0, 0x55, 0x53, 0xB4, 0x90, 0x81, 0xA7, 0xB7],
+				ptxt: vec![0xB7, 0x63, 0xCC, 0xB3, 0xC1, 0x69, 0xC1, 0x36, 0xF8, 0x4B, 0x39, 0xE2, 0x74],
+				ctxt: vec![0x51, 0x7F, 0x21, 0x9F, 0xCC, 0x7E, 0x94, 0xEA, 0x64, 0xD5, 0xB4, 0x43, 0x24]
+			},
+			TestCase {
+				key: arr![u8; 0xFB, 0xF8, 0xC7, 0xF2, 0x95, 0x0A, 0xE9, 0x39, 0xBF, 0xBD, 0x4B, 0x84, 0x1B, 0x37, 0x48, 0xDE, 0x2C, 0xD7, 0x94, 0x14, 0xCC, 0x5A, 0xF0, 0x55, 0x79, 0x97, 0xF9, 0xEC, 0xC8, 0x8D, 0x63, 0xC6, 0x9C, 0xA9, 0x58, 0xB5, 0x68, 0xA1, 0xCE, 0x0D, 0x96, 0xF9, 0xF8, 0x04, 0x9A, 0x16, 0x7F, 0x28],
+				associated_data: vec![vec![0x54, 0x00, 0xBD, 0x85, 0x36, 0x66, 0x0D, 0xB2, 0xAB, 0xCF, 0x90, 0xC4, 0x30, 0xCA, 0x22, 0x4A], vec![]],
+				tag: arr![u8; 0x65, 0xAC, 0x81, 0xC1, 0x0C, 0xDB, 0x77, 0x57, 0x05, 0x0B, 0x8C, 0x0E, 0xBD, 0xBD, 0x4D, 0x0E],
+				ptxt: vec![0x35, 0xD3, 0x2C, 0x72, 0xA9, 0x0B, 0x91, 0xBB, 0xA9, 0x5D, 0xC1, 0x6C, 0x35, 0xED, 0xF7, 0xF0],
+				ctxt: vec![0x7E, 0x7C, 0xC6, 0x0B, 0x0E, 0xBD, 0x75, 0x1B, 0xD8, 0x27, 0xC8, 0x93, 0x15, 0x6C, 0xFD, 0x7B]
+			},
+			TestCase {
+				key: arr![u8; 0x5A, 0x94, 0x43, 0x54, 0x75, 0x4C, 0x4D, 0x5A, 0xC3, 0x01, 0x41, 0x83, 0x7A, 0x0C, 0x52, 0x42, 0x5B, 0x7B, 0xC7, 0x36, 0x1D, 0xD8, 0x1A, 0x8C, 0x27, 0x1D, 0x1B, 0xC4, 0x3A, 0x2F, 0x70, 0x8C, 0x86, 0xD6, 0xF5, 0x7B, 0xF1, 0x77, 0x29, 0xF9, 0xD0, 0xE6, 0x89, 0x23, 0x35, 0xDC, 0x73, 0x85],
+				associated_data: vec![vec![0x7F, 0x8D, 0xAE, 0xCC, 0xB3, 0x6B, 0x32, 0x95, 0xFA, 0x5F, 0x7B, 0x1A, 0xD6, 0x90, 0xB4, 0xDC, 0xB8, 0x8E, 0x4B, 0xA7, 0x26, 0xB6, 0x3A, 0x75, 0x2C, 0x94, 0x70, 0x40, 0x8C, 0xF2, 0x4A, 0x11, 0x7E, 0x91, 0x8F, 0x79, 0x1D, 0x59, 0x1B, 0x08], vec![0x6F, 0x54, 0x8C, 0x9C, 0x01, 0x0E, 0xAC, 0x54, 0xFB, 0xAB], vec![0x63, 0x21, 0x03, 0xA3, 0x79, 0xCE, 0x9D, 0xA5, 0x89, 0xA4, 0xD9, 0xFF, 0x83, 0x3C, 0x44, 0x5E]],
+				tag: arr![u8; 0xCF, 0xF9, 0xD4, 0x95, 0xF1, 0x42, 0x42, 0x85, 0x46, 0xEF, 0x21, 0x75, 0x48, 0x02, 0xB8, 0xC6],
+				ptxt: vec![0x9D, 0x69, 0x9A, 0x2C, 0x06, 0xF2, 0x72, 0x8F, 0x2D, 0x03, 0x2E, 0x41, 0x33, 0x80, 0xE5, 0x31, 0x29, 0x7C, 0x08, 0x1E, 0x6D, 0x3A, 0x30, 0x59, 0x9A, 0x2E, 0x9F, 0x8A, 0x21, 0x24, 0x98, 0x11, 0x0F, 0xB7, 0xE9, 0xC8, 0xFD, 0x32, 0x5A, 0x56, 0xAE, 0x15, 0x28, 0x89, 0x2B, 0xD2, 0x6A],
+				ctxt: vec![0x34, 0x2B, 0xC9, 0x03, 0x5D, 0x8C, 0x99, 0x1F, 0x0B, 0x85, 0xBA, 0x86, 0xE5, 0x9E, 0xDB, 0x4C, 0x64, 0x9C, 0x6B, 0x65, 0x15, 0x45, 0xE0, 0xC7, 0x6B, 0x58, 0x86, 0xD9, 0x99, 0x38, 0xD1, 0xBC, 0x94, 0xE3, 0x9D, 0x65, 0xA6, 0x97, 0x25, 0x94, 0xE2, 0x33, 0xDA, 0x7A, 0x42, 0xBC, 0x2A]
+			}
+		];
+
+		for TestCase { key, associated_data, tag, ptxt, ctxt } in test_cases {
+			let lea192siv = Lea192Siv::new(&key);
+
+			// Encryption
+			let mut buffer = ptxt.clone();
+			let calculated_tag = lea192siv.encrypt_in_place_detached(&associated_data, &mut buffer)?;
+			assert_eq!(buffer, ctxt);
+			assert_eq!(calculated_tag, tag);
+
+			// Decryption
+			let mut buffer = ctxt.clone();
+			lea192siv.decrypt_in_place_detached(&associated_data, &mut buffer, &tag)?;
+			assert_eq!(buffer, ptxt);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea256siv() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea256>; 5] = [
+			TestCase {
+				key: arr![u8; 0xFF, 0xB3, 0x7D, 0xAA, 0x14, 0x18, 0x48, 0xD9, 0xF2, 0xDE, 0x28, 0x6B, 0x67, 0xED, 0x64, 0x32, 0xA1, 0x4A, 0x7B, 0x1C, 0xF1, 0x40, 0x89, 0x12, 0x90, 0x0E, 0xA8, 0x6F, 0x85, 0x6E, 0x0F, 0xF8, 0x8B, 0x17, 0x8B, 0x36, 0xA1, 0x65, 0x2A, 0xF3, 0xE0, 0xC8, 0x8E, 0xE0, 0x86, 0x72, 0xD4, 0x64, 0x77, 0xE3, 0xB4, 0x79, 0x8D, 0x60, 0x6F, 0x8F, 0x2B, 0x27, 0xDF, 0x35, 0x42, 0x87, 0x5E, 0xF9],
+				associated_data: vec![],
+				tag: arr![u8; 0x2B, 0xB8, 0xD6, 0x1E, 0xBC, 0xBF, 0xFA, 0x72, 0x54, 0xFE, 0x85, 0xB4, 0xC8, 0xA5, 0x3E, 0xFB],
+				ptxt: vec![],
+				ctxt: vec![]
+			},
+			TestCase {
+				key: arr![u8; 0x1D, 0x94, 0xCB, 0xEB, 0x59, 0x14, 0x90, 0xBA, 0xD0, 0x72, 0x3D, 0xAC, 0xF7, 0x16, 0x67, 0xCF, 0x3F, 0x30, 0x0C, 0x0A, 0xB4, 0x00, 0xAF, 0x15, 0xAB, 0xEA, 0x4A, 0x46, 0xD2, 0x64, 0x0F, 0x9F, 0x9D, 0x9B, 0xDA, 0x11, 0xF6, 0x0A, 0x3C, 0xD2, 0xE5, 0xBE, 0xDB, 0xB7, 0x0B, 0x53, 0xE0, 0xC8, 0x93, 0x08, 0x9C, 0x7D, 0x78, 0xA6, 0x2A, 0x88, 0x3B, 0x99, 0x7B, 0xC3, 0x33, 0x14, 0x2C, 0xBE],
+				associated_data: vec![vec![0xF6, 0x3E, 0x44, 0x0F, 0x7A, 0x46, 0xEF, 0x78]],
+				tag: arr![u8; 0x65, 0x8B, 0x82, 0x3D, 0x06, 0x6D, 0x4A, 0xE6, 0x3D, 0xFF, 0x46, 0x57, 0xBA, 0x1C, 0x99, 0x2F],
+				ptxt: vec![],
+				ctxt: vec![]
+			},
+			TestCase {
+				key: arr![u8; 0x65, 0xBB, 0xFC, 0x58, 0x77, 0xC1, 0x97, 0x04, 0xDE, 0x03, 0x66, 0x71, 0x57, 0x6E, 0x87, 0xA4, 0x86, 0x24, 0xFB, 0x67, 0x71, 0x75, 0xF8, 0xEE, 0x11, 0x57, 0x5F, 0x64, 0xE3, 0x91, 0x6D, 0x81, 0x70, 0x75, 0x97, 0x21, 0x8B, 0xE3, 0x0E, 0x5F, 0x6C, 0x59, 0x78, 0x0A, 0x20, 0x0F, 0xFA, 0xE5, 0x96, 0x15, 0xC0, 0xA4, 0x0D, 0xD4, 0x2A, 0xB9, 0x1C, 0x0A, 0x96, 0x42, 0xB0, 0x7F, 0x02, 0xB3],
+				associated_data: vec![vec![0xCB, 0x2E, 0x41, 0x35, 0xC6, 0xEC, 0xD1, 0x15, 0x28, 0x62, 0x93, 0x45, 0x81, 0x05, 0x59, 0x02, 0x53, 0xD6, 0x84, 0xF4, 0xA3, 0x60, 0x82, 0x90]],
+				tag: arr![u8; 0x52, 0x4A, 0xC6, 0xED, 0x76, 0x31, 0x2C, 0x14, 0x54, 0x08, 0x8A, 0xE3, 0x9B, 0x11, 0xA7, 0x5F],
+				ptxt: vec![0x86, 0x3D, 0xFC, 0x6E, 0x5C, 0x1C, 0x8E, 0xA3, 0xBF, 0x5F, 0xEE, 0x5F, 0xC2],
+				ctxt: vec![0xA0, 0xF5, 0xC7, 0x14, 0xB6, 0x8E, 0x93, 0xF1, 0xC1, 0xD6, 0x6E, 0x3C, 0xCD]
+			},
+			TestCase {
+				key: arr![u8; 0xE7, 0x9F, 0x63, 0xA9, 0x99, 0xD8, 0x71, 0xB6, 0xEE, 0xBC, 0x79, 0xFD, 0xEB, 0x78, 0x81, 0x74, 0xCF, 0xEF, 0xEE, 0x9C, 0xB4, 0x00, 0xF0, 0xA3, 0xF9, 0x62, 0xCA, 0xFE, 0xDB, 0x0F, 0xAB, 0x65, 0xA8, 0x39, 0x75, 0xE3, 0x0E, 0x73, 0x50, 0x1E, 0xDA, 0xF0, 0x0F, 0xC6, 0x9D, 0x7A, 0x3C, 0xFC, 0x91, 0x77, 0xEC, 0x1E, 0x33, 0x57, 0xF8, 0x4A, 0x52, 0x49, 0x48, 0xDE, 0x11, 0xD3, 0x98, 0xB9],
+				associated_data: vec![vec![0xDC, 0xB8, 0x49, 0x1F, 0x82, 0xC0, 0xAE, 0xD4, 0xD5, 0x62, 0x44, 0x39, 0x49, 0x04, 0x24, 0x7E], vec![]],
+				tag: arr![u8; 0x40, 0xE8, 0xFC, 0x5B, 0x6E, 0x64, 0xBF, 0x54, 0xC3, 0x7C, 0x29, 0xBB, 0x8E, 0x87, 0xD2, 0x0E],
+				ptxt: vec![0x60, 0xC5, 0xD9, 0xA3, 0x2A, 0x1C, 0x97, 0x7B, 0x10, 0x0B, 0x85, 0xE6, 0x3A, 0xA2, 0x21, 0xE4],
+				ctxt: vec![0xF4, 0x54, 0xDB, 0xC7, 0x41, 0x00, 0xF2, 0x24, 0xFE, 0xF4, 0x14, 0x96, 0xB4, 0x9E, 0x61, 0xAE]
+			},
+			TestCase {
+				key: arr![u8; 0xB9, 0x3D, 0x8E, 0x77, 0x0B, 0x41, 0x64, 0x40, 0xB2, 0x2A, 0x3C, 0x91, 0xA7, 0xF1, 0xD1, 0xFB, 0x7E, 0xBC, 0x1B, 0xA6, 0x03, 0x7E, 0xE1, 0xF6, 0x95, 0xED, 0xF6, 0xBE, 0x1C, 0xDF, 0xA9, 0x8F, 0x44, 0x5C, 0xEF, 0x3A, 0xEA, 0xDC, 0xED, 0xEB, 0xAC, 0xEF, 0x35, 0x51, 0xFC, 0x09, 0x23, 0x5A, 0xE5, 0x92, 0x80, 0x40, 0xB0, 0x19, 0xE0, 0xC4, 0x1C, 0xE1, 0x46, 0x1A, 0xA7, 0x57, 0xAB, 0xF2],
+				associated_data: vec![vec![0x92, 0xDC, 0x39, 0x1A, 0xEE, 0xF4, 0xA6, 0xFF, 0x0E, 0x4A, 0xDF, 0x50, 0xA9, 0xBE, 0x69, 0x67, 0xCD, 0xA1, 0x2C, 0xB7, 0xB6, 0x60, 0xFF, 0x21, 0x76, 0xAF, 0xE4, 0x1C, 0x32, 0x3E, 0x0C, 0xC8, 0x38, 0xF9, 0xED, 0xF1, 0xC1, 0xF8, 0x54, 0x11], vec![0x59, 0x04, 0xFD, 0x42, 0xAD, 0x1E, 0x33, 0xB7, 0xBF, 0x71], vec![0x16, 0x33, 0x2E, 0xE5, 0x16, 0xAE, 0x47, 0xA0, 0xDD, 0xE1, 0xE8, 0x8A, 0xE9, 0x1E, 0x52, 0x2A]],
+				tag: arr![u8; 0xB8, 0xF4, 0xC2, 0x42, 0x9F, 0x8A, 0x4F, 0x45, 0x44, 0x7D, 0xCF, 0x50, 0xF0, 0xB6, 0x12, 0xDD],
+				ptxt: vec![0xB1, 0x79, 0xF3, 0xF8, 0xC6, 0x66, 0x07, 0x20, 0x37, 0x6F, 0x91, 0xA4, 0x9C, 0xB3, 0x4C, 0xF3, 0x92, 0x37, 0x2B, 0xDB, 0xF0, 0xF8, 0x7A, 0xE5, 0xCE, 0x2A, 0x0E, 0x03, 0x9E, 0x91, 0xDA, 0x28, 0xB5, 0xCB, 0xA8, 0x5F, 0x01, 0x83, 0x5C, 0x71, 0xB4, 0x1A, 0x46, 0xB9, 0xC0, 0xAC, 0xC0],
+				ctxt: vec![0xEA, 0x7F, 0x75, 0x03, 0x30, 0x7A, 0x69, 0xDB, 0xA4, 0xB3, 0x80, 0xA2, 0x85, 0xD9, 0xFD, 0x5B, 0x37, 0x3E, 0x68, 0x0D, 0xAF, 0x5A, 0x77, 0x33, 0xEB, 0x67, 0x85, 0x79, 0x8D, 0xCE, 0x68, 0xE9, 0x49, 0xEE, 0x5E, 0x1D, 0x9F, 0x23, 0xEC, 0xC7, 0x70, 0x71, 0x8C, 0xF8, 0x33, 0x22, 0x20]
+			}
+		];
+
+		for TestCase { key, associated_data, tag, ptxt, ctxt } in test_cases {
+			let lea256siv = Lea256Siv::new(&key);
+
+			// Encryption
+			let mut buffer = ptxt.clone();
+			let calculated_tag = lea256siv.encrypt_in_place_detached(&associated_data, &mut buffer)?;
+			assert_eq!(buffer, ctxt);
+			assert_eq!(calculated_tag, tag);
+
+			// Decryption
+			let mut buffer = ctxt.clone();
+			lea256siv.decrypt_in_place_detached(&associated_data, &mut buffer, &tag)?;
+			assert_eq!(buffer, ptxt);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128siv_aead() -> Result<(), Error> {
+		let key = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F];
+		let nonce = arr![u8; 0xFF, 0x90, 0x10, 0x05, 0xB0, 0xC0, 0x64, 0x81, 0x34, 0x6E, 0x88, 0xD4, 0xBA, 0x92, 0xE6, 0x5D];
+		let associated_data = [0x7F, 0x0C, 0x1F, 0x9A, 0x1A, 0xBC, 0x13, 0xB2, 0x6C, 0xE6, 0x31];
+		let ptxt = [0xB3, 0x1D, 0x33, 0x46, 0xB9, 0x89, 0x43, 0x19, 0xDD, 0x00, 0x30, 0x5D, 0x37, 0x10, 0x74, 0x89, 0x26, 0xE5, 0x89, 0x85, 0x74, 0xA0, 0xCF, 0xF2, 0x41, 0x2D, 0x4F, 0x12, 0xB3, 0x87];
+		let ctxt = [0x8A, 0x15, 0x14, 0xBD, 0x20, 0x16, 0xB9, 0xCC, 0x56, 0x54, 0xA6, 0xB0, 0xE4, 0x01, 0xF8, 0x3C, 0xA8, 0x7C, 0xFB, 0x94, 0xAC, 0x99, 0x60, 0xD3, 0xCF, 0x6F, 0x82, 0xA9, 0xD1, 0x87, 0x70, 0x5B, 0x5B, 0xC3, 0xEB, 0x17, 0x0B, 0x49, 0x9F, 0x77, 0x13, 0x2F, 0x99, 0x7B, 0xBC, 0x0A];
+
+		let lea128siv = Lea128SivAead::<U16>::new(&key);
+
+		// The tag comes first.
+		let ciphertext = lea128siv.encrypt(&nonce, Payload { msg: &ptxt, aad: &associated_data })?;
+		assert_eq!(ciphertext, ctxt);
+		assert_eq!(lea128siv.decrypt(&nonce, Payload { msg: &ciphertext, aad: &associated_data })?, ptxt);
+
+		// The nonce is just the last associated data component.
+		let mut buffer = ptxt;
+		let tag = Lea128Siv::new(&key).encrypt_in_place_detached([&associated_data[..], &nonce[..]], &mut buffer)?;
+		assert_eq!([&tag[..], &buffer].concat(), ctxt);
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128siv_rejection() -> Result<(), Error> {
+		let lea128siv = Lea128Siv::new(&Default::default());
+		let associated_data = [&[1, 2, 3][..], &[4, 5]];
+		let ptxt: Vec<u8> = (0..40).collect();
+
+		let mut ctxt = ptxt.clone();
+		let tag = lea128siv.encrypt_in_place_detached(associated_data, &mut ctxt)?;
+
+		// Tampered ciphertext; the buffer is zeroed.
+		for i in [0, 23, 24, 39] {
+			let mut buffer = ctxt.clone();
+			buffer[i] ^= 1;
+			assert!(lea128siv.decrypt_in_place_detached(associated_data, &mut buffer, &tag).is_err());
+			assert_eq!(buffer, [0; 40]);
+		}
+
+		// Tampered tag or associated data
+		let mut tampered_tag = tag;
+		tampered_tag[15] ^= 1;
+		assert!(lea128siv.decrypt_in_place_detached(associated_data, &mut ctxt.clone(), &tampered_tag).is_err());
+		assert!(lea128siv.decrypt_in_place_detached([&[1, 2, 3][..]], &mut ctxt.clone(), &tag).is_err());
+		assert!(lea128siv.decrypt_in_place_detached([&[4, 5][..], &[1, 2, 3]], &mut ctxt.clone(), &tag).is_err());
+		assert!(lea128siv.decrypt_in_place_detached([&[1, 2, 3, 4, 5][..]], &mut ctxt.clone(), &tag).is_err());
+
+		// Too many associated data components
+		let too_many = vec![&[][..]; MAX_ASSOCIATED_DATA + 1];
+		assert!(lea128siv.encrypt_in_place_detached(&too_many[..MAX_ASSOCIATED_DATA], &mut ctxt.clone()).is_ok());
+		assert!(lea128siv.encrypt_in_place_detached(&too_many, &mut ctxt.clone()).is_err());
+
+		// Truncated ciphertext
+		let lea128siv = Lea128SivAead::<U16>::new(&Default::default());
+		assert!(lea128siv.decrypt(&Default::default(), &[0; 15][..]).is_err());
+
+		Ok(())
+	}
+}
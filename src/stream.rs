@@ -0,0 +1,188 @@
+// Copyright © 2023 남기훈 <gihunnam@proton.me>
+//
+// This file and its content are subject to the terms of the MIT License (the "License").
+// If a copy of the License was not distributed with this file, you can obtain one at https://opensource.org/licenses/MIT.
+
+//! STREAM over LEA-CCM and LEA-GCM
+//!
+//! STREAM as specified by Hoang, Reyhanitabar, Rogaway, and Vizár in "Online Authenticated-Encryption and its Nonce-Reuse Misuse-Resistance",
+//! which splits a message into segments encrypted one at a time.
+//! The nonce of each segment is the nonce prefix, followed by the 32-bit big-endian segment counter and a byte that is 1 only for the last segment.
+//! Reordered, dropped, or appended segments fail to decrypt, and so does a message truncated at a segment boundary,
+//! because its new last segment was not encrypted as the last one.
+//!
+//! The nonce prefix is 5 bytes shorter than the nonce of the AEAD, so 7 bytes for LEA-GCM and 8 bytes for LEA-CCM with a 13-byte nonce,
+//! and must never be reused with the same key.
+//!
+//! * Example
+//! ```
+//! use lea::{stream::aead, prelude::*, Lea128GcmEncryptorBE32, Lea128GcmDecryptorBE32};
+//!
+//! let key = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+//! let nonce_prefix = arr![u8; 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16];
+//! let ctxt0 = [
+//!     0xF0, 0x93, 0x67, 0x37, 0xF7, 0x5B, 0x1F, 0xFE, 0x6B, 0xE6, 0x7A, 0x3E, 0xB7, 0x16, 0x42, 0x24,
+//!     0x3F, 0x34, 0xE9, 0x98, 0x10, 0x20, 0x7F, 0xA6, 0x07, 0x9F, 0xCB, 0x87, 0xAA
+//! ];
+//! let ctxt1 = [
+//!     0xEB, 0xE6, 0x4B, 0xA8, 0x68, 0x1F, 0x46, 0xEB, 0x87, 0x75, 0x2F, 0xB8, 0xDA, 0x75, 0x3B, 0xBA,
+//!     0x3A, 0x2B, 0x1B, 0x3A, 0xAE, 0xAE, 0x3D, 0xC9, 0xDD, 0x5D, 0xBF, 0x47
+//! ];
+//!
+//! // Encryption
+//! let mut encryptor = Lea128GcmEncryptorBE32::new(&key, &nonce_prefix);
+//! let mut segment0 = b"first segment".to_vec();
+//! encryptor.encrypt_next_in_place(b"", &mut segment0)?;
+//! assert_eq!(segment0, ctxt0);
+//! let mut segment1 = b"last segment".to_vec();
+//! encryptor.encrypt_last_in_place(b"", &mut segment1)?;
+//! assert_eq!(segment1, ctxt1);
+//!
+//! // Decryption
+//! let mut decryptor = Lea128GcmDecryptorBE32::new(&key, &nonce_prefix);
+//! decryptor.decrypt_next_in_place(b"", &mut segment0)?;
+//! assert_eq!(segment0, b"first segment");
+//! decryptor.decrypt_last_in_place(b"", &mut segment1)?;
+//! assert_eq!(segment1, b"last segment");
+//!
+//! // Truncation
+//! let mut decryptor = Lea128GcmDecryptorBE32::new(&key, &nonce_prefix);
+//! let mut segment0 = ctxt0.to_vec();
+//! assert!(decryptor.decrypt_last_in_place(b"", &mut segment0).is_err());
+//! # Ok::<(), aead::Error>(())
+//! ```
+
+pub use aead;
+pub use aead::stream::{Decryptor, DecryptorBE32, DecryptorLE31, Encryptor, EncryptorBE32, EncryptorLE31, NewStream, StreamBE32, StreamLE31, StreamPrimitive};
+
+use aead::consts::{U13, U16};
+
+use crate::{Lea128Ccm, Lea192Ccm, Lea256Ccm, Lea128Gcm, Lea192Gcm, Lea256Gcm};
+
+pub type Lea128CcmEncryptorBE32<NonceSize = U13, TagSize = U16> = EncryptorBE32<Lea128Ccm<NonceSize, TagSize>>;
+pub type Lea192CcmEncryptorBE32<NonceSize = U13, TagSize = U16> = EncryptorBE32<Lea192Ccm<NonceSize, TagSize>>;
+pub type Lea256CcmEncryptorBE32<NonceSize = U13, TagSize = U16> = EncryptorBE32<Lea256Ccm<NonceSize, TagSize>>;
+
+pub type Lea128CcmDecryptorBE32<NonceSize = U13, TagSize = U16> = DecryptorBE32<Lea128Ccm<NonceSize, TagSize>>;
+pub type Lea192CcmDecryptorBE32<NonceSize = U13, TagSize = U16> = DecryptorBE32<Lea192Ccm<NonceSize, TagSize>>;
+pub type Lea256CcmDecryptorBE32<NonceSize = U13, TagSize = U16> = DecryptorBE32<Lea256Ccm<NonceSize, TagSize>>;
+
+pub type Lea128GcmEncryptorBE32 = EncryptorBE32<Lea128Gcm>;
+pub type Lea192GcmEncryptorBE32 = EncryptorBE32<Lea192Gcm>;
+pub type Lea256GcmEncryptorBE32 = EncryptorBE32<Lea256Gcm>;
+
+pub type Lea128GcmDecryptorBE32 = DecryptorBE32<Lea128Gcm>;
+pub type Lea192GcmDecryptorBE32 = DecryptorBE32<Lea192Gcm>;
+pub type Lea256GcmDecryptorBE32 = DecryptorBE32<Lea256Gcm>;
+
+#[cfg(test)]
+mod tests {
+	extern crate alloc;
+
+	use alloc::{vec::Vec, vec};
+
+	use cipher::{BlockCipher, BlockCipherKey, NewBlockCipher};
+
+	use crate::{stream::aead::{consts::U7, Error}, prelude::*, Lea128, Lea128CcmDecryptorBE32, Lea128CcmEncryptorBE32, Lea128GcmDecryptorBE32, Lea128GcmEncryptorBE32};
+
+	struct TestCase<T> where
+	T: BlockCipher + NewBlockCipher {
+		key: BlockCipherKey<T>,
+		nonce_prefix: GenericArray<u8, U7>,
+		ptxt: [Vec<u8>; 3],
+		ctxt: [Vec<u8>; 3]
+	}
+
+	#[test]
+	fn lea128gcm_stream() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea128>; 1] = [
+			TestCase {
+				key: arr![u8; 0x45, 0x6A, 0x7C, 0x41, 0x14, 0x4D, 0x56, 0x05, 0x12, 0x7B, 0x02, 0x1D, 0x48, 0xCE, 0x1E, 0xF4],
+				nonce_prefix: arr![u8; 0x4F, 0xDA, 0xBE, 0xA6, 0x09, 0xE6, 0x9F],
+				ptxt: [vec![0x11, 0x22, 0x45, 0xCB, 0xE5, 0xBC, 0x27, 0x36, 0xFE, 0xD9, 0xC2, 0xB7, 0x6D, 0x13, 0x9C, 0x68], vec![0x24, 0xB3, 0x0E, 0xEC, 0x08, 0x2C, 0x52, 0x40, 0x5B, 0xC6, 0xB9, 0xF6, 0x51, 0x18, 0x69, 0x16, 0x05, 0xC0, 0x54, 0x51, 0xC8, 0x63, 0xB2], vec![0xE0, 0xEF, 0xBE, 0x87, 0xA9]],
+				ctxt: [
+					vec![0x57, 0x6F, 0x98, 0x41, 0x39, 0xB0, 0xA8, 0xCB, 0x61, 0xAF, 0x9B, 0xD8, 0x89, 0x19, 0x93, 0x6A, 0x1B, 0x86, 0x59, 0xB6, 0x17, 0x29, 0x60, 0x01, 0x57, 0xB1, 0xAD, 0xEA, 0x62, 0xEA, 0xDB, 0x6C],
+					vec![0xC3, 0x9D, 0x66, 0x55, 0x66, 0xF3, 0x51, 0x1E, 0xB1, 0x55, 0x4A, 0x7A, 0x62, 0x8F, 0x51, 0x35, 0xF4, 0xF9, 0x5B, 0x3D, 0x21, 0xE7, 0x51, 0xF4, 0xB6, 0x0F, 0x43, 0x0A, 0x2E, 0x1D, 0x69, 0xEF, 0xE7, 0xFD, 0xE6, 0xE9, 0x1B, 0x7C, 0xDF],
+					vec![0x71, 0x82, 0xC8, 0x89, 0x35, 0xFC, 0xAE, 0x17, 0x45, 0x3B, 0x44, 0x4F, 0xEC, 0x0A, 0x0E, 0x68, 0x32, 0x37, 0xD3, 0xCC, 0x22]
+				]
+			}
+		];
+
+		for TestCase { key, nonce_prefix, ptxt, ctxt } in test_cases {
+			// Encryption
+			let mut encryptor = Lea128GcmEncryptorBE32::new(&key, &nonce_prefix);
+			assert_eq!(encryptor.encrypt_next(ptxt[0].as_slice())?, ctxt[0]);
+			assert_eq!(encryptor.encrypt_next(ptxt[1].as_slice())?, ctxt[1]);
+			assert_eq!(encryptor.encrypt_last(ptxt[2].as_slice())?, ctxt[2]);
+
+			// Decryption
+			let mut decryptor = Lea128GcmDecryptorBE32::new(&key, &nonce_prefix);
+			assert_eq!(decryptor.decrypt_next(ctxt[0].as_slice())?, ptxt[0]);
+			assert_eq!(decryptor.decrypt_next(ctxt[1].as_slice())?, ptxt[1]);
+			assert_eq!(decryptor.decrypt_last(ctxt[2].as_slice())?, ptxt[2]);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128gcm_stream_reject() -> Result<(), Error> {
+		let key = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+		let nonce_prefix = arr![u8; 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16];
+
+		let mut encryptor = Lea128GcmEncryptorBE32::new(&key, &nonce_prefix);
+		let ctxt = [
+			encryptor.encrypt_next(&b"segment 0"[..])?,
+			encryptor.encrypt_next(&b"segment 1"[..])?,
+			encryptor.encrypt_last(&b"segment 2"[..])?
+		];
+
+		// Reordering
+		let mut decryptor = Lea128GcmDecryptorBE32::new(&key, &nonce_prefix);
+		assert_eq!(decryptor.decrypt_next(ctxt[1].as_slice()), Err(Error));
+
+		// Truncation
+		let mut decryptor = Lea128GcmDecryptorBE32::new(&key, &nonce_prefix);
+		decryptor.decrypt_next(ctxt[0].as_slice())?;
+		assert_eq!(decryptor.decrypt_last(ctxt[1].as_slice()), Err(Error));
+
+		// Last segment out of place
+		let mut decryptor = Lea128GcmDecryptorBE32::new(&key, &nonce_prefix);
+		decryptor.decrypt_next(ctxt[0].as_slice())?;
+		decryptor.decrypt_next(ctxt[1].as_slice())?;
+		assert_eq!(decryptor.decrypt_next(ctxt[2].as_slice()), Err(Error));
+
+		// Another nonce prefix
+		let mut decryptor = Lea128GcmDecryptorBE32::new(&key, &Default::default());
+		assert_eq!(decryptor.decrypt_next(ctxt[0].as_slice()), Err(Error));
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128ccm_stream() -> Result<(), Error> {
+		let key = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+		let nonce_prefix = arr![u8; 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17];
+		let ptxt: Vec<u8> = (0..100).collect();
+
+		let mut encryptor = Lea128CcmEncryptorBE32::<U13, U8>::new(&key, &nonce_prefix);
+		let mut ctxt = Vec::new();
+		for chunk in ptxt[..96].chunks(32) {
+			ctxt.push(encryptor.encrypt_next(chunk)?);
+		}
+		ctxt.push(encryptor.encrypt_last(&ptxt[96..])?);
+
+		let mut decryptor = Lea128CcmDecryptorBE32::<U13, U8>::new(&key, &nonce_prefix);
+		let mut decrypted = Vec::new();
+		for segment in &ctxt[..3] {
+			decrypted.extend(decryptor.decrypt_next(segment.as_slice())?);
+		}
+		decrypted.extend(decryptor.decrypt_last(ctxt[3].as_slice())?);
+		assert_eq!(decrypted, ptxt);
+
+		let decryptor = Lea128CcmDecryptorBE32::<U13, U8>::new(&key, &nonce_prefix);
+		assert_eq!(decryptor.decrypt_last(ctxt[0].as_slice()), Err(Error));
+
+		Ok(())
+	}
+}
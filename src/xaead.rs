@@ -0,0 +1,350 @@
+// Copyright © 2023 남기훈 <gihunnam@proton.me>
+//
+// This file and its content are subject to the terms of the MIT License (the "License").
+// If a copy of the License was not distributed with this file, you can obtain one at https://opensource.org/licenses/MIT.
+
+//! Extended-nonce LEA-CCM and LEA-GCM
+//!
+//! XAES-256-GCM as specified by C2SP, generalized to LEA and to any AEAD.
+//! The first 12 bytes of the nonce derive a per-message subkey, and the rest of the nonce is passed to the AEAD under that subkey.
+//! Nonces are long enough to be picked at random for an effectively unlimited number of messages.
+//!
+//! With L = E<sub>K</sub>(0<sup>128</sup>) and K<sub>1</sub> = dbl(L) as in CMAC,
+//! the subkey is E<sub>K</sub>(M<sub>1</sub> ⊕ K<sub>1</sub>) \|\| E<sub>K</sub>(M<sub>2</sub> ⊕ K<sub>1</sub>) truncated to the key size of the AEAD,
+//! where M<sub>i</sub> = \[i\]<sub>16</sub> \|\| 0x58 \|\| 0x00 \|\| N\[..12\].
+//!
+//! | Type         | Nonce size                | Inner AEAD                           |
+//! | ------------ | ------------------------- | ------------------------------------ |
+//! | `Lea128XGcm` | 24 bytes                  | `Lea128Gcm` with a 12-byte nonce     |
+//! | `Lea128XCcm` | 12 + `NonceSize` bytes    | `Lea128Ccm<NonceSize, TagSize>`      |
+//!
+//! * Example
+//! ```
+//! use lea::{xaead::aead, prelude::*, Lea128XGcm};
+//!
+//! let key = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+//! let nonce = arr![u8;
+//!     0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B,
+//!     0x1C, 0x1D, 0x1E, 0x1F, 0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27
+//! ];
+//! let associated_data = b"header";
+//! let ptxt = b"plaintext";
+//! let ctxt = [
+//!     0x26, 0xC4, 0xF9, 0xEE, 0xE3, 0xA7, 0xF9, 0x27, 0x04, 0x25, 0xAF, 0x10, 0x15, 0x19, 0x52, 0x97,
+//!     0x36, 0xB1, 0x6A, 0x60, 0xB6, 0x8F, 0xD4, 0x6B, 0xE4
+//! ];
+//!
+//! let lea128xgcm = Lea128XGcm::new(&key);
+//!
+//! // Encryption
+//! let mut buffer = ptxt.to_vec();
+//! lea128xgcm.encrypt_in_place(&nonce, associated_data, &mut buffer)?;
+//! assert_eq!(buffer, ctxt);
+//!
+//! // Decryption
+//! lea128xgcm.decrypt_in_place(&nonce, associated_data, &mut buffer)?;
+//! assert_eq!(buffer, ptxt);
+//! # Ok::<(), aead::Error>(())
+//! ```
+
+pub use aead;
+
+use core::marker::PhantomData;
+use core::ops::Add;
+
+use aead::consts::{U12, U13, U16, U32};
+use aead::generic_array::{typenum::{IsLessOrEqual, Sum, True, Unsigned}, ArrayLength, GenericArray};
+use aead::{AeadCore, AeadInPlace, Buffer, Error, Key, NewAead, Nonce, Tag};
+use cipher::{BlockCipher, BlockEncrypt, NewBlockCipher};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+use crate::{Lea128, Lea192, Lea256, Lea128Ccm, Lea192Ccm, Lea256Ccm, Lea128Gcm, Lea192Gcm, Lea256Gcm};
+
+pub type Lea128XCcm<NonceSize = U13, TagSize = U16> = XAead<Lea128, Lea128Ccm<NonceSize, TagSize>>;
+pub type Lea192XCcm<NonceSize = U13, TagSize = U16> = XAead<Lea192, Lea192Ccm<NonceSize, TagSize>>;
+pub type Lea256XCcm<NonceSize = U13, TagSize = U16> = XAead<Lea256, Lea256Ccm<NonceSize, TagSize>>;
+
+pub type Lea128XGcm = XAead<Lea128, Lea128Gcm>;
+pub type Lea192XGcm = XAead<Lea192, Lea192Gcm>;
+pub type Lea256XGcm = XAead<Lea256, Lea256Gcm>;
+
+type Block = GenericArray<u8, U16>;
+
+/// Number of nonce bytes used for the subkey derivation
+pub const DERIVATION_NONCE_SIZE: usize = 12;
+
+/// AEAD with an extended nonce
+///
+/// * `C`: Block cipher for the subkey derivation
+/// * `A`: AEAD under the subkey, whose key must be at most 32 bytes long
+pub struct XAead<C, A> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	cipher: C,
+	k1: Block,
+	_pd: PhantomData<A>
+}
+
+impl<C, A> XAead<C, A> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+A: NewAead,
+A::KeySize: IsLessOrEqual<U32, Output = True> {
+	/// AEAD under the subkey derived from the first 12 bytes of `nonce`
+	fn aead(&self, nonce: &[u8]) -> A {
+		let mut subkey = GenericArray::<u8, U32>::default();
+		for (i, block) in subkey.chunks_exact_mut(16).enumerate() {
+			block[1] = i as u8 + 1;
+			block[2] = b'X';
+			block[4..].copy_from_slice(&nonce[..DERIVATION_NONCE_SIZE]);
+			block.iter_mut().zip(&self.k1).for_each(|(x, y)| *x ^= y);
+			self.cipher.encrypt_block(Block::from_mut_slice(block));
+		}
+
+		let aead = A::new(Key::<A>::from_slice(&subkey[..A::KeySize::USIZE]));
+		#[cfg(feature = "zeroize")]
+		subkey.zeroize();
+
+		aead
+	}
+}
+
+impl<C, A> From<C> for XAead<C, A> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt {
+	fn from(cipher: C) -> Self {
+		let mut l = Block::default();
+		cipher.encrypt_block(&mut l);
+		let k1 = dbl(&l);
+
+		Self { cipher, k1, _pd: PhantomData }
+	}
+}
+
+impl<C, A> NewAead for XAead<C, A> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher {
+	type KeySize = C::KeySize;
+
+	fn new(key: &Key<Self>) -> Self {
+		Self::from(C::new(key))
+	}
+}
+
+impl<C, A> AeadCore for XAead<C, A> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+A: AeadCore,
+U12: Add<A::NonceSize>,
+Sum<U12, A::NonceSize>: ArrayLength<u8> {
+	type NonceSize = Sum<U12, A::NonceSize>;
+	type TagSize = A::TagSize;
+	type CiphertextOverhead = A::CiphertextOverhead;
+}
+
+impl<C, A> AeadInPlace for XAead<C, A> where
+C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+A: AeadInPlace + NewAead,
+A::KeySize: IsLessOrEqual<U32, Output = True>,
+U12: Add<A::NonceSize>,
+Sum<U12, A::NonceSize>: ArrayLength<u8> {
+	fn encrypt_in_place(&self, nonce: &Nonce<Self>, associated_data: &[u8], buffer: &mut dyn Buffer) -> Result<(), Error> {
+		self.aead(nonce).encrypt_in_place(inner_nonce::<A>(nonce), associated_data, buffer)
+	}
+
+	fn encrypt_in_place_detached(&self, nonce: &Nonce<Self>, associated_data: &[u8], buffer: &mut [u8]) -> Result<Tag<Self>, Error> {
+		self.aead(nonce).encrypt_in_place_detached(inner_nonce::<A>(nonce), associated_data, buffer)
+	}
+
+	fn decrypt_in_place(&self, nonce: &Nonce<Self>, associated_data: &[u8], buffer: &mut dyn Buffer) -> Result<(), Error> {
+		self.aead(nonce).decrypt_in_place(inner_nonce::<A>(nonce), associated_data, buffer)
+	}
+
+	fn decrypt_in_place_detached(&self, nonce: &Nonce<Self>, associated_data: &[u8], buffer: &mut [u8], tag: &Tag<Self>) -> Result<(), Error> {
+		self.aead(nonce).decrypt_in_place_detached(inner_nonce::<A>(nonce), associated_data, buffer, tag)
+	}
+}
+
+fn inner_nonce<A>(nonce: &[u8]) -> &Nonce<A> where
+A: AeadCore {
+	Nonce::<A>::from_slice(&nonce[DERIVATION_NONCE_SIZE..])
+}
+
+fn dbl(block: &Block) -> Block {
+	let x = u128::from_be_bytes((*block).into());
+
+	((x << 1) ^ ((x >> 127) * 0x87)).to_be_bytes().into()
+}
+
+#[cfg(test)]
+mod tests {
+	extern crate alloc;
+
+	use alloc::{vec::Vec, vec};
+
+	use cipher::{BlockCipher, BlockCipherKey, NewBlockCipher};
+
+	use crate::{xaead::aead::{consts::U24, Error}, prelude::*, Lea128, Lea192, Lea256, Lea128Ccm, Lea128XCcm, Lea128XGcm, Lea192XGcm, Lea256XGcm};
+
+	struct TestCase<T> where
+	T: BlockCipher + NewBlockCipher {
+		key: BlockCipherKey<T>,
+		nonce: GenericArray<u8, U24>,
+		associated_data: Vec<u8>,
+		ptxt: Vec<u8>,
+		ctxt: Vec<u8>
+	}
+
+	#[test]
+	fn lea128xgcm() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea128>; 3] = [
+			TestCase {
+				key: arr![u8; 0x68, 0x85, 0x8A, 0xB3, 0xDC, 0x1D, 0x2D, 0x61, 0x39, 0x4A, 0x07, 0x39, 0x1D, 0x91, 0x02, 0x19],
+				nonce: arr![u8; 0x28, 0xC6, 0x83, 0xB0, 0xFD, 0x4D, 0xB1, 0xA2, 0x60, 0x97, 0x67, 0xC4, 0xB0, 0xAB, 0x53, 0x5B, 0x12, 0x56, 0xD7, 0x48, 0x9E, 0x28, 0x1C, 0x82],
+				associated_data: vec![],
+				ptxt: vec![],
+				ctxt: vec![0xF8, 0xE0, 0xFC, 0x33, 0xE0, 0x11, 0x19, 0xFD, 0x39, 0x8D, 0xD7, 0xAE, 0xC3, 0x4B, 0x80, 0xD5]
+			},
+			TestCase {
+				key: arr![u8; 0x11, 0x96, 0x18, 0x45, 0xF5, 0x50, 0x1B, 0xF1, 0xA0, 0xD2, 0xF8, 0x4A, 0x08, 0x58, 0x62, 0x18],
+				nonce: arr![u8; 0x7A, 0x79, 0xEA, 0x30, 0x30, 0x21, 0x42, 0xBF, 0x3F, 0xC6, 0x0C, 0x0B, 0xAC, 0xF5, 0xD1, 0xAE, 0x6F, 0xB5, 0x09, 0xBD, 0xB6, 0xB1, 0xBC, 0xD8],
+				associated_data: vec![0xA7, 0xCF, 0x54, 0x5E, 0xD4, 0x78, 0xA1],
+				ptxt: vec![0x74, 0x97, 0xCD, 0x8A, 0x26, 0x3B, 0x7B, 0x74, 0xFE, 0xCB, 0xBC, 0xAF, 0xFE, 0x58, 0x9D, 0xD1],
+				ctxt: vec![0xE7, 0x09, 0xEC, 0x54, 0x0C, 0x0E, 0x2C, 0xCA, 0x2F, 0x57, 0xE3, 0x41, 0xF9, 0x9A, 0xA2, 0x1B, 0x0F, 0x8E, 0xFE, 0xFD, 0x27, 0x4C, 0x97, 0xFB, 0x6F, 0x6B, 0x64, 0x80, 0x10, 0x93, 0x02, 0xB7]
+			},
+			TestCase {
+				key: arr![u8; 0xF9, 0xD6, 0xBC, 0x0B, 0x47, 0x28, 0x02, 0x39, 0xE2, 0x03, 0x94, 0xC0, 0x9A, 0xA5, 0x8E, 0x8B],
+				nonce: arr![u8; 0x48, 0xFE, 0xF1, 0x5B, 0x90, 0x32, 0x67, 0x1C, 0x5C, 0x57, 0x1C, 0xD6, 0x39, 0x9B, 0xB7, 0x0F, 0xFF, 0x6E, 0xC2, 0x0C, 0xAD, 0x9F, 0x79, 0x1F],
+				associated_data: vec![0x24, 0x2D, 0xEE, 0xA4, 0xBC, 0xD5, 0x01, 0x9E, 0xA6, 0x4B, 0xEB, 0xA5, 0x10, 0x45, 0x13, 0x01, 0x6F, 0x46, 0x2D],
+				ptxt: vec![0xB0, 0x86, 0xA0, 0xAD, 0x31, 0xDB, 0xD7, 0xFA, 0xD4, 0x0F, 0x47, 0x7B, 0xF5, 0xFA, 0x43, 0xF9, 0x10, 0xFF, 0x83, 0xC0, 0xDD, 0xC9, 0x99, 0x54, 0x8B, 0xCC, 0x7C, 0x10, 0x48, 0x7D, 0x4C, 0x66, 0x67, 0x4D, 0x8C, 0xE1, 0xF1],
+				ctxt: vec![0xFD, 0x8A, 0xC2, 0xDC, 0xE8, 0x77, 0xBE, 0xF8, 0xA2, 0x07, 0x30, 0xCF, 0x40, 0x00, 0xB3, 0x6F, 0x55, 0xA9, 0x9A, 0x7C, 0x98, 0x3A, 0x46, 0x5E, 0xB5, 0x2C, 0x03, 0x1B, 0xC8, 0x30, 0x79, 0xAF, 0x54, 0x43, 0x19, 0xB7, 0xE4, 0x6E, 0xD4, 0xBE, 0x84, 0xD9, 0x02, 0xEC, 0x2B, 0x66, 0x44, 0x06, 0x68, 0xBE, 0x9F, 0xAD, 0x17]
+			}
+		];
+
+		for TestCase { key, nonce, associated_data, ptxt, ctxt } in test_cases {
+			let lea128xgcm = Lea128XGcm::new(&key);
+
+			// Encryption
+			let mut buffer = ptxt.clone();
+			lea128xgcm.encrypt_in_place(&nonce, &associated_data, &mut buffer)?;
+			assert_eq!(buffer, ctxt);
+
+			// Decryption
+			lea128xgcm.decrypt_in_place(&nonce, &associated_data, &mut buffer)?;
+			assert_eq!(buffer, ptxt);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea192xgcm() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea192>; 3] = [
+			TestCase {
+				key: arr![u8; 0x73, 0x12, 0x51, 0x40, 0x21, 0xF1, 0x21, 0xA8, 0x21, 0x13, 0xEC, 0x71, 0x65, 0xA6, 0x58, 0xE9, 0x71, 0x15, 0x46, 0xE6, 0x93, 0xC5, 0x22, 0x47],
+				nonce: arr![u8; 0xBE, 0x88, 0x80, 0xD3, 0x5C, 0x3E, 0x08, 0xB3, 0x3B, 0x8F, 0x7C, 0x12, 0x81, 0x45, 0xDD, 0x69, 0xB1, 0x23, 0xFD, 0xF1, 0x73, 0x40, 0x21, 0x3B],
+				associated_data: vec![],
+				ptxt: vec![],
+				ctxt: vec![0xA9, 0xED, 0xE1, 0x14, 0x3F, 0x3A, 0x32, 0x4A, 0xD5, 0x69, 0x6E, 0x89, 0x35, 0xA8, 0xDA, 0x63]
+			},
+			TestCase {
+				key: arr![u8; 0xEC, 0x98, 0x5D, 0x87, 0x1C, 0x51, 0xF7, 0x53, 0xE9, 0x7F, 0x25, 0xE8, 0x01, 0x14, 0xEA, 0xE9, 0x67, 0xF0, 0xA2, 0xAE, 0xB0, 0xCD, 0x67, 0x61],
+				nonce: arr![u8; 0x93, 0xF6, 0xA1, 0xBE, 0xF3, 0xAF, 0x81, 0x72, 0x38, 0x38, 0x28, 0xB1, 0x25, 0xBC, 0xFF, 0x01, 0x55, 0x4F, 0x03, 0x89, 0xB2, 0x36, 0x9B, 0x21],
+				associated_data: vec![0x2A, 0x30, 0x8B, 0x04, 0x13, 0x1E, 0x18],
+				ptxt: vec![0x64, 0x88, 0x80, 0x0D, 0xA3, 0xE1, 0x4E, 0x67, 0x20, 0x47, 0xEB, 0xD0, 0xA1, 0x29, 0xAD, 0x9E],
+				ctxt: vec![0xF7, 0x26, 0xE0, 0x84, 0x22, 0x9D, 0x4A, 0xE0, 0xBA, 0xDA, 0x9D, 0x17, 0x8B, 0x6C, 0x59, 0x5F, 0x23, 0x0B, 0x3E, 0x01, 0xF7, 0xAE, 0xA2, 0xB9, 0x15, 0x3C, 0xB2, 0x77, 0x8F, 0x38, 0xD0, 0x7E]
+			},
+			TestCase {
+				key: arr![u8; 0xB0, 0x11, 0x52, 0x8A, 0x97, 0xEF, 0x51, 0xFA, 0xA7, 0x9F, 0xD1, 0x49, 0x04, 0x08, 0xCB, 0x34, 0x1D, 0x31, 0x04, 0x2A, 0x13, 0x2C, 0x8F, 0x1D],
+				nonce: arr![u8; 0xDB, 0xE2, 0xEC, 0xC9, 0x28, 0xE5, 0x6D, 0xB2, 0xF1, 0x45, 0xE3, 0x60, 0xD3, 0x5F, 0x3F, 0x95, 0x46, 0xF6, 0x26, 0x4C, 0x43, 0xC5, 0x67, 0xC4],
+				associated_data: vec![0xCC, 0x29, 0xA8, 0xF2, 0x2C, 0x7A, 0x2D, 0x48, 0x73, 0xE9, 0xE3, 0x7C, 0x78, 0x48, 0xC9, 0x4C, 0xB0, 0x93, 0xE0],
+				ptxt: vec![0x71, 0x7F, 0xFE, 0x60, 0x7B, 0x75, 0x1C, 0xCD, 0xB0, 0x47, 0x95, 0x31, 0x40, 0xD6, 0x39, 0x7A, 0xE4, 0xA4, 0xC6, 0x2C, 0x58, 0x0A, 0x5D, 0x39, 0x6C, 0x6D, 0x4E, 0x08, 0xFF, 0x45, 0x89, 0xF1, 0xB3, 0x6F, 0x18, 0x01, 0x3A],
+				ctxt: vec![0xD2, 0x63, 0xF5, 0xCF, 0xA1, 0x82, 0xCC, 0x49, 0xC4, 0x95, 0xB0, 0xE7, 0x4B, 0xF0, 0x6F, 0xBD, 0xE6, 0x72, 0x66, 0xE6, 0x19, 0xDE, 0x2E, 0xF6, 0xBB, 0xEF, 0x52, 0xBA, 0xD1, 0x0F, 0x71, 0x0A, 0xFB, 0x0E, 0x61, 0x9E, 0x0A, 0x77, 0xE7, 0x77, 0x9F, 0x54, 0x21, 0xE9, 0x46, 0x1E, 0x44, 0xA6, 0xB2, 0xA7, 0xD0, 0x2B, 0x3C]
+			}
+		];
+
+		for TestCase { key, nonce, associated_data, ptxt, ctxt } in test_cases {
+			let lea192xgcm = Lea192XGcm::new(&key);
+
+			// Encryption
+			let mut buffer = ptxt.clone();
+			lea192xgcm.encrypt_in_place(&nonce, &associated_data, &mut buffer)?;
+			assert_eq!(buffer, ctxt);
+
+			// Decryption
+			lea192xgcm.decrypt_in_place(&nonce, &associated_data, &mut buffer)?;
+			assert_eq!(buffer, ptxt);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea256xgcm() -> Result<(), Error> {
+		let test_cases: [TestCase<Lea256>; 3] = [
+			TestCase {
+				key: arr![u8; 0xCD, 0x89, 0x4D, 0x04, 0x2C, 0xC6, 0xA3, 0x35, 0xAA, 0xAD, 0x53, 0xA8, 0xB5, 0xA2, 0xE0, 0xEC, 0x56, 0x71, 0x7F, 0xF2, 0x01, 0x92, 0x97, 0x0C, 0x4A, 0x2B, 0xA7, 0x38, 0xC2, 0x90, 0x57, 0xD5],
+				nonce: arr![u8; 0xAA, 0x02, 0xF1, 0x59, 0x2B, 0x0F, 0xCC, 0xAB, 0x7A, 0x0C, 0xF1, 0xB1, 0x12, 0x4F, 0x62, 0x1E, 0x29, 0x70, 0x42, 0x0F, 0xE3, 0x3B, 0x87, 0x06],
+				associated_data: vec![],
+				ptxt: vec![],
+				ctxt: vec![0x4F, 0xEF, 0xB2, 0xFE, 0x8B, 0x22, 0xBB, 0xB7, 0x58, 0x19, 0x89, 0x50, 0x11, 0x4D, 0x97, 0x06]
+			},
+			TestCase {
+				key: arr![u8; 0x5B, 0x13, 0xF6, 0xCC, 0x0D, 0x0D, 0x04, 0x8D, 0xC4, 0xB6, 0x8E, 0x56, 0xD5, 0xA7, 0x7F, 0x1C, 0xF1, 0x8A, 0x54, 0x72, 0x3A, 0xDD, 0xE2, 0xB4, 0x88, 0x7C, 0x07, 0x0B, 0x02, 0x64, 0x04, 0xD4],
+				nonce: arr![u8; 0xBA, 0x8E, 0xA3, 0x17, 0x1C, 0xE3, 0xCB, 0x9A, 0xC6, 0x38, 0xA1, 0x9B, 0x93, 0x8D, 0x6F, 0x8E, 0xB1, 0x0D, 0x63, 0x7B, 0xED, 0xD3, 0x97, 0x85],
+				associated_data: vec![0xAC, 0xFD, 0xEC, 0x36, 0xFB, 0x13, 0x88],
+				ptxt: vec![0x61, 0x1C, 0xA5, 0x86, 0xC6, 0xEB, 0x22, 0x35, 0x18, 0x41, 0xE2, 0x46, 0x47, 0x3B, 0xCE, 0xCF],
+				ctxt: vec![0xC8, 0xAC, 0xAC, 0x71, 0xE2, 0xB0, 0x18, 0xA9, 0x45, 0x16, 0x3C, 0xBB, 0x48, 0x7F, 0x0E, 0xD2, 0x2A, 0xC6, 0x4F, 0x97, 0x42, 0xC6, 0xC2, 0x86, 0x37, 0x11, 0x81, 0xCB, 0xBF, 0xFA, 0x00, 0xF2]
+			},
+			TestCase {
+				key: arr![u8; 0xED, 0x89, 0x57, 0x15, 0x9E, 0x82, 0x61, 0xB2, 0x8C, 0x06, 0x55, 0x32, 0x6B, 0x03, 0xB3, 0xB2, 0x72, 0x66, 0x23, 0x03, 0x65, 0x3F, 0x32, 0x31, 0xC2, 0x91, 0x51, 0x6D, 0x55, 0x6B, 0xD9, 0x54],
+				nonce: arr![u8; 0xE0, 0x0A, 0x64, 0x53, 0x77, 0xA2, 0x8D, 0x89, 0x42, 0x96, 0x5D, 0x28, 0xD7, 0x0A, 0x7B, 0xC3, 0x7D, 0xB6, 0x99, 0xA8, 0x1B, 0xF6, 0x47, 0x4E],
+				associated_data: vec![0x3D, 0xBB, 0xC4, 0x49, 0x91, 0x99, 0x87, 0xE8, 0x36, 0x74, 0x03, 0x9D, 0x15, 0xFC, 0x30, 0x7D, 0x4F, 0x3D, 0x87],
+				ptxt: vec![0xF4, 0xE0, 0xAC, 0x3A, 0x53, 0x28, 0x46, 0x01, 0x05, 0x89, 0x16, 0x6A, 0xFA, 0x78, 0x8F, 0xED, 0xC7, 0x9C, 0x3D, 0x9B, 0xD7, 0x3B, 0xB4, 0x88, 0xDF, 0x33, 0x7C, 0xFB, 0xEA, 0x4D, 0x91, 0x53, 0x24, 0xFD, 0xE8, 0x65, 0x4D],
+				ctxt: vec![0x04, 0x63, 0xB8, 0xC8, 0x1A, 0x64, 0xF4, 0xAC, 0x7F, 0x84, 0x4C, 0xD7, 0x09, 0x52, 0xDA, 0x3F, 0x41, 0x4D, 0x1C, 0x38, 0x19, 0x8F, 0x12, 0xD8, 0xA3, 0x52, 0x13, 0x02, 0x19, 0x3D, 0xF8, 0x23, 0x4F, 0xDC, 0xB4, 0x50, 0x9A, 0x01, 0x2D, 0x15, 0x3A, 0x46, 0xCF, 0xBC, 0xCF, 0xFA, 0x37, 0xB8, 0x9C, 0x05, 0x69, 0x03, 0x45]
+			}
+		];
+
+		for TestCase { key, nonce, associated_data, ptxt, ctxt } in test_cases {
+			let lea256xgcm = Lea256XGcm::new(&key);
+
+			// Encryption
+			let mut buffer = ptxt.clone();
+			lea256xgcm.encrypt_in_place(&nonce, &associated_data, &mut buffer)?;
+			assert_eq!(buffer, ctxt);
+
+			// Decryption
+			lea256xgcm.decrypt_in_place(&nonce, &associated_data, &mut buffer)?;
+			assert_eq!(buffer, ptxt);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn lea128xccm() -> Result<(), Error> {
+		let key = arr![u8; 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+		let nonce = arr![u8;
+			0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2A, 0x2B,
+			0x2C, 0x2D, 0x2E, 0x2F, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38
+		];
+		let subkey = arr![u8; 0xD5, 0x91, 0x89, 0xCB, 0x4B, 0xDC, 0x83, 0x11, 0xE9, 0x6F, 0x13, 0x7B, 0x96, 0x64, 0x34, 0x57];
+		let ptxt = *b"randomly chosen nonce";
+
+		// Encryption
+		let mut buffer = ptxt.to_vec();
+		Lea128XCcm::<U13>::new(&key).encrypt_in_place(&nonce, b"header", &mut buffer)?;
+
+		let mut expected = ptxt.to_vec();
+		Lea128Ccm::<U13>::new(&subkey).encrypt_in_place(GenericArray::from_slice(&nonce[12..]), b"header", &mut expected)?;
+		assert_eq!(buffer, expected);
+
+		// Decryption
+		Lea128XCcm::<U13>::new(&key).decrypt_in_place(&nonce, b"header", &mut buffer)?;
+		assert_eq!(buffer, ptxt);
+
+		// Each nonce uses its own subkey
+		let mut other_nonce = nonce;
+		other_nonce[0] ^= 1;
+		let mut buffer = expected.clone();
+		assert_eq!(Lea128XCcm::<U13>::new(&key).decrypt_in_place(&other_nonce, b"header", &mut buffer), Err(Error));
+
+		Ok(())
+	}
+}